@@ -0,0 +1,184 @@
+/*
+ * The from-scratch collections against std's on the same keys, a
+ * million random u64s:
+ *
+ *   MyHashMap  vs HashMap      insert, lookup half of them hits, remove
+ *   BinaryHeap vs BinaryHeap   push, pop everything
+ *   Bst        vs BTreeSet     insert, lookup, remove
+ *
+ *   cargo bench --bench collections
+ *
+ * A heap has no lookup, only peek at the top, which is one load and
+ * not worth timing. Every workload checks that both sides gave the
+ * same answers, the times only mean something if they did the same
+ * work.
+ * */
+use rust_exercises::core_utils::heap::BinaryHeap;
+use rust_exercises::extras::bst::Bst;
+use rust_exercises::extras::hash_map::MyHashMap;
+use rust_exercises::extras::rng::XorShift64;
+use std::collections::{BTreeSet, BinaryHeap as StdBinaryHeap, HashMap};
+use std::time::{Duration, Instant};
+
+const ITEMS: usize = 1_000_000;
+
+fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn row(workload: &str, ours: Duration, std: Duration) {
+    println!(
+        "  {:<8} ours {:>9.2?}  std {:>9.2?}  x{:.2}",
+        workload,
+        ours,
+        std,
+        ours.as_secs_f64() / std.as_secs_f64()
+    );
+}
+
+// The keys inserted, and as many lookups of which every other one misses
+fn keys() -> (Vec<u64>, Vec<u64>) {
+    let keys: Vec<u64> = XorShift64::new(42).take(ITEMS).collect();
+    let mut others = XorShift64::new(7);
+    let lookups = keys
+        .iter()
+        .enumerate()
+        .map(|(i, &key)| {
+            if i % 2 == 0 {
+                key
+            } else {
+                others.next().unwrap()
+            }
+        })
+        .collect();
+    (keys, lookups)
+}
+
+fn hash_maps(keys: &[u64], lookups: &[u64]) {
+    let (mut ours, ours_insert) = time(|| {
+        let mut map = MyHashMap::new();
+        for &key in keys {
+            map.insert(key, key);
+        }
+        map
+    });
+    let (mut std, std_insert) = time(|| {
+        let mut map = HashMap::new();
+        for &key in keys {
+            map.insert(key, key);
+        }
+        map
+    });
+    assert_eq!(ours.len(), std.len());
+
+    let (ours_hits, ours_lookup) =
+        time(|| lookups.iter().filter(|key| ours.contains_key(key)).count());
+    let (std_hits, std_lookup) =
+        time(|| lookups.iter().filter(|key| std.contains_key(key)).count());
+    assert_eq!(ours_hits, std_hits);
+
+    let (_, ours_remove) = time(|| {
+        for key in keys {
+            ours.remove(key);
+        }
+    });
+    let (_, std_remove) = time(|| {
+        for key in keys {
+            std.remove(key);
+        }
+    });
+    assert!(ours.is_empty() && std.is_empty());
+
+    println!("MyHashMap vs HashMap");
+    row("insert", ours_insert, std_insert);
+    row("lookup", ours_lookup, std_lookup);
+    row("remove", ours_remove, std_remove);
+}
+
+fn heaps(keys: &[u64]) {
+    let (ours, ours_push) = time(|| {
+        let mut heap = BinaryHeap::new();
+        for &key in keys {
+            heap.push(key);
+        }
+        heap
+    });
+    let (std, std_push) = time(|| {
+        let mut heap = StdBinaryHeap::new();
+        for &key in keys {
+            heap.push(key);
+        }
+        heap
+    });
+
+    let (ours_popped, ours_pop) = time(move || {
+        let mut heap = ours;
+        let mut popped = Vec::with_capacity(ITEMS);
+        while let Some(key) = heap.pop() {
+            popped.push(key);
+        }
+        popped
+    });
+    let (std_popped, std_pop) = time(move || {
+        let mut heap = std;
+        let mut popped = Vec::with_capacity(ITEMS);
+        while let Some(key) = heap.pop() {
+            popped.push(key);
+        }
+        popped
+    });
+    assert_eq!(ours_popped, std_popped);
+
+    println!("BinaryHeap vs std's BinaryHeap");
+    row("push", ours_push, std_push);
+    row("pop", ours_pop, std_pop);
+}
+
+fn trees(keys: &[u64], lookups: &[u64]) {
+    let (mut ours, ours_insert) = time(|| {
+        let mut tree = Bst::new();
+        for &key in keys {
+            tree.insert(key);
+        }
+        tree
+    });
+    let (mut std, std_insert) = time(|| {
+        let mut tree = BTreeSet::new();
+        for &key in keys {
+            tree.insert(key);
+        }
+        tree
+    });
+    assert_eq!(ours.len(), std.len());
+
+    let (ours_hits, ours_lookup) = time(|| lookups.iter().filter(|key| ours.contains(key)).count());
+    let (std_hits, std_lookup) = time(|| lookups.iter().filter(|key| std.contains(key)).count());
+    assert_eq!(ours_hits, std_hits);
+
+    let (_, ours_remove) = time(|| {
+        for key in keys {
+            ours.remove(key);
+        }
+    });
+    let (_, std_remove) = time(|| {
+        for key in keys {
+            std.remove(key);
+        }
+    });
+    assert!(ours.is_empty() && std.is_empty());
+
+    println!("Bst vs BTreeSet");
+    row("insert", ours_insert, std_insert);
+    row("lookup", ours_lookup, std_lookup);
+    row("remove", ours_remove, std_remove);
+}
+
+fn main() {
+    let (keys, lookups) = keys();
+    println!("{} random u64 keys", ITEMS);
+    hash_maps(&keys, &lookups);
+    heaps(&keys);
+    trees(&keys, &lookups);
+}
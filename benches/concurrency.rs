@@ -0,0 +1,100 @@
+/*
+ * The same mixed CPU-bound workload on every way of running tasks in
+ * parallel the chapters cover: the chapter 20 pool with both strategies,
+ * rayon, and one thread::spawn per task.
+ *
+ *   cargo bench --bench concurrency
+ *   cargo bench --features parallel --bench concurrency   (adds rayon)
+ * */
+use rust_exercises::extras::thread_pool::{Strategy, ThreadPool};
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const THREADS: usize = 4;
+const TASKS: usize = 1_000;
+
+/*
+ * Mostly short tasks, a few ten times as long and a few a hundred times,
+ * so a scheduler that hands out work badly leaves threads idle
+ * */
+fn task_size(i: usize) -> u64 {
+    match i % 20 {
+        0 => 100,
+        1..=4 => 10,
+        _ => 1,
+    }
+}
+
+fn work(i: usize) -> u64 {
+    (0..task_size(i) * 2_000).fold(i as u64, |acc, x| black_box(acc.rotate_left(5) ^ x))
+}
+
+fn expected() -> u64 {
+    (0..TASKS).map(work).fold(0, u64::wrapping_add)
+}
+
+fn pool(strategy: Strategy) -> u64 {
+    let pool = ThreadPool::with_strategy(THREADS, strategy);
+    let (tx, rx) = mpsc::channel();
+    for i in 0..TASKS {
+        let tx = tx.clone();
+        pool.execute(move || tx.send(work(i)).unwrap());
+    }
+    drop(tx);
+    rx.iter().fold(0, u64::wrapping_add)
+}
+
+fn spawn_per_task() -> u64 {
+    let handles: Vec<_> = (0..TASKS).map(|i| thread::spawn(move || work(i))).collect();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .fold(0, u64::wrapping_add)
+}
+
+#[cfg(feature = "parallel")]
+fn rayon() -> u64 {
+    use rayon::prelude::*;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(THREADS)
+        .build()
+        .unwrap();
+    pool.install(|| {
+        (0..TASKS)
+            .into_par_iter()
+            .map(work)
+            .reduce(|| 0, u64::wrapping_add)
+    })
+}
+
+fn report(name: &str, baseline: Duration, run: impl FnOnce() -> u64, expected: u64) {
+    let start = Instant::now();
+    let result = run();
+    let elapsed = start.elapsed();
+    assert_eq!(result, expected, "{} got a different result", name);
+
+    println!(
+        "{:<16} {:>10.2?}  x{:.1}",
+        name,
+        elapsed,
+        baseline.as_secs_f64() / elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    println!("{} threads, {} tasks", THREADS, TASKS);
+
+    let start = Instant::now();
+    let expected = expected();
+    let sequential = start.elapsed();
+    println!("{:<16} {:>10.2?}", "sequential", sequential);
+
+    report("shared queue", sequential, || pool(Strategy::SharedQueue), expected);
+    report("work stealing", sequential, || pool(Strategy::WorkStealing), expected);
+    report("spawn per task", sequential, spawn_per_task, expected);
+    #[cfg(feature = "parallel")]
+    report("rayon", sequential, rayon, expected);
+}
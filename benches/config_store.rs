@@ -0,0 +1,76 @@
+/*
+ * Read-heavy workload on the RwLock and Mutex config stores:
+ * 8 reader threads and 1 writer updating every millisecond
+ *
+ *   cargo bench --bench config_store
+ * */
+use rust_exercises::extras::config_store::{ConfigStore, MutexConfigStore};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const READERS: usize = 8;
+const READS_PER_THREAD: usize = 200_000;
+
+/*
+ * get and set are passed as closures so both stores share the workload
+ * */
+fn run<S, G, W>(store: Arc<S>, get: G, set: W) -> Duration
+where
+    S: Send + Sync + 'static,
+    G: Fn(&S, &str) -> Option<String> + Send + Sync + Copy + 'static,
+    W: Fn(&S, &str, &str) + Send + 'static,
+{
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let writer = {
+        let store = store.clone();
+        let stop = stop.clone();
+        thread::spawn(move || {
+            let mut i = 0;
+            while !stop.load(Ordering::Relaxed) {
+                set(&store, "version", &i.to_string());
+                i += 1;
+                thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    let start = Instant::now();
+    let readers: Vec<_> = (0..READERS)
+        .map(|_| {
+            let store = store.clone();
+            thread::spawn(move || {
+                for _ in 0..READS_PER_THREAD {
+                    get(&store, "version");
+                }
+            })
+        })
+        .collect();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    let elapsed = start.elapsed();
+
+    stop.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+    elapsed
+}
+
+fn main() {
+    let rwlock = run(
+        Arc::new(ConfigStore::new()),
+        |store: &ConfigStore, key| store.get(key),
+        |store: &ConfigStore, key, value| store.set(key, value),
+    );
+    let mutex = run(
+        Arc::new(MutexConfigStore::new()),
+        |store: &MutexConfigStore, key| store.get(key),
+        |store: &MutexConfigStore, key, value| store.set(key, value),
+    );
+
+    println!("{} readers x {} reads", READERS, READS_PER_THREAD);
+    println!("RwLock  {:?}", rwlock);
+    println!("Mutex   {:?}", mutex);
+}
@@ -0,0 +1,43 @@
+/*
+ * Parses the same generated log with borrowed and owned fields
+ *
+ *   cargo bench --bench logparse
+ * */
+use rust_exercises::extras::logparse::{self, LogLine, OwnedLogLine};
+use std::time::Instant;
+
+const LINES: usize = 500_000;
+
+fn main() {
+    let mut log = String::new();
+    for i in 0..LINES {
+        log.push_str(&format!(
+            "10.0.{}.{} - user{} [10/Oct/2000:13:55:{:02} -0700] \"GET /page/{} HTTP/1.1\" {} {}\n",
+            i % 256,
+            i % 100,
+            i % 50,
+            i % 60,
+            i,
+            if i % 10 == 0 { 404 } else { 200 },
+            i * 3
+        ));
+    }
+
+    let start = Instant::now();
+    let borrowed: Vec<LogLine> = logparse::parse_all(&log)
+        .map(|(_, line)| line.unwrap())
+        .collect();
+    let borrowed_time = start.elapsed();
+
+    let start = Instant::now();
+    let owned: Vec<OwnedLogLine> = log
+        .lines()
+        .map(|line| OwnedLogLine::parse(line).unwrap())
+        .collect();
+    let owned_time = start.elapsed();
+
+    assert_eq!(borrowed.len(), owned.len());
+    println!("{} lines", LINES);
+    println!("borrowed  {:?}", borrowed_time);
+    println!("owned     {:?}", owned_time);
+}
@@ -0,0 +1,49 @@
+/*
+ * The three multiplications of extras::matrix on 512x512 matrices, with
+ * criterion, which is what this bench needs the dev-dependency for:
+ *
+ *   cargo bench --bench matrix
+ *   cargo bench --bench matrix -- parallel     only the matching ones
+ *
+ * A few samples are enough at this size, the naive one takes over half
+ * a second per run, and they get the time to collect them.
+ * */
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_exercises::extras::matrix::Matrix;
+use std::hint::black_box;
+use std::thread;
+use std::time::Duration;
+
+const SIZE: usize = 512;
+
+fn multiplications(c: &mut Criterion) {
+    let a = Matrix::from_fn(SIZE, SIZE, |i, j| ((i * 7 + j * 3) % 17) as f64 - 8.0);
+    let b = Matrix::from_fn(SIZE, SIZE, |i, j| ((i * 5 + j * 11) % 13) as f64 * 0.5);
+    let threads = thread::available_parallelism().map_or(4, |n| n.get());
+
+    // Timing a wrong answer would be pointless
+    let naive = a.mul(&b).unwrap();
+    assert!(naive == a.mul_blocked(&b).unwrap());
+    assert!(naive == a.mul_parallel(&b, threads).unwrap());
+
+    let mut group = c.benchmark_group(format!("{}x{}", SIZE, SIZE));
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+    group.bench_function("mul", |bench| {
+        bench.iter(|| black_box(&a).mul(black_box(&b)).unwrap())
+    });
+    group.bench_function("mul_blocked", |bench| {
+        bench.iter(|| black_box(&a).mul_blocked(black_box(&b)).unwrap())
+    });
+    group.bench_with_input(
+        BenchmarkId::new("mul_parallel", threads),
+        &threads,
+        |bench, &threads| {
+            bench.iter(|| black_box(&a).mul_parallel(black_box(&b), threads).unwrap())
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(benches, multiplications);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+/*
+ * Sequential against pooled word counting, over a generated tree of
+ * files in the system temp directory
+ *
+ *   cargo bench --bench par_wc
+ * */
+use rust_exercises::extras::par_wc;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+const DIRS: usize = 8;
+const FILES_PER_DIR: usize = 25;
+const WORDS_PER_FILE: usize = 20_000;
+
+fn build_fixture() -> PathBuf {
+    let root = env::temp_dir().join(format!("par-wc-bench-{}", std::process::id()));
+    let words = ["lorem", "ipsum", "dolor", "sit", "amet", "rust", "thread", "pool"];
+
+    for d in 0..DIRS {
+        let dir = root.join(format!("dir{}", d));
+        fs::create_dir_all(&dir).unwrap();
+        for f in 0..FILES_PER_DIR {
+            let text: Vec<&str> = (0..WORDS_PER_FILE)
+                .map(|i| words[(i * 7 + f + d) % words.len()])
+                .collect();
+            fs::write(dir.join(format!("{}.txt", f)), text.join(" ")).unwrap();
+        }
+    }
+    root
+}
+
+fn main() {
+    let root = build_fixture();
+    let files = par_wc::collect_files(&root).unwrap();
+    println!("{} files, {} words each", files.len(), WORDS_PER_FILE);
+
+    let start = Instant::now();
+    let sequential = par_wc::count_sequential(&files).unwrap();
+    println!("sequential  {:?}", start.elapsed());
+
+    for &threads in &[2, 4, 8] {
+        let start = Instant::now();
+        let parallel = par_wc::count_parallel(&files, threads).unwrap();
+        println!("{} threads   {:?}", threads, start.elapsed());
+        assert_eq!(parallel, sequential);
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
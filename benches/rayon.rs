@@ -0,0 +1,47 @@
+/*
+ * Sequential vs rayon on the rayon_basics tasks. That both give the
+ * same results is checked in tests/rayon_basics.rs, this only times them.
+ *
+ *   cargo bench --features parallel --bench rayon
+ * */
+use rust_exercises::chapters::rayon_basics::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+fn time<T, F: FnMut() -> T>(mut f: F) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+fn report(name: &str, sequential: Duration, parallel: Duration) {
+    println!(
+        "{:<16} sequential {:>10.2?}  parallel {:>10.2?}  x{:.1}",
+        name,
+        sequential,
+        parallel,
+        sequential.as_secs_f64() / parallel.as_secs_f64()
+    );
+}
+
+fn main() {
+    // Too little work per item for threads to pay off, unless very large
+    let numbers: Vec<u64> = (0..20_000_000).collect();
+    let sequential = time(|| sum_of_squares(black_box(&numbers)));
+    let parallel = time(|| sum_of_squares_par(black_box(&numbers)));
+    report("sum of squares", sequential, parallel);
+
+    let text = "the quick brown fox jumps over the lazy dog The End";
+    let lines: Vec<&str> = (0..500_000).map(|_| text).collect();
+    let sequential = time(|| word_count(black_box(&lines)));
+    let parallel = time(|| word_count_par(black_box(&lines)));
+    report("word count", sequential, parallel);
+
+    // A 4K RGB image
+    let pixels: Vec<u8> = (0..3840 * 2160 * 3).map(|i| (i % 251) as u8).collect();
+    let mut a = pixels.clone();
+    let mut b = pixels;
+    let sequential = time(|| grayscale(black_box(&mut a)));
+    let parallel = time(|| grayscale_par(black_box(&mut b)));
+    report("grayscale", sequential, parallel);
+}
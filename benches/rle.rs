@@ -0,0 +1,38 @@
+/*
+ * Compresses and decompresses a few MB of repetitive data
+ *
+ *   cargo bench --bench rle
+ * */
+use rust_exercises::extras::rle;
+use std::time::Instant;
+
+const SIZE: usize = 8 * 1024 * 1024;
+
+fn main() {
+    // Runs of 1 to 300 bytes, like a simple bitmap would have
+    let mut input = Vec::with_capacity(SIZE);
+    let mut run = 1;
+    while input.len() < SIZE {
+        let byte = (run % 7) as u8;
+        input.extend(std::iter::repeat_n(byte, run));
+        run = run % 300 + 1;
+    }
+
+    let start = Instant::now();
+    let compressed = rle::compress_bytes(&input);
+    let compress_time = start.elapsed();
+
+    let start = Instant::now();
+    let decompressed = rle::decompress_bytes(&compressed).unwrap();
+    let decompress_time = start.elapsed();
+
+    assert!(decompressed == input);
+    println!(
+        "{} bytes -> {} bytes ({:.1}%)",
+        input.len(),
+        compressed.len(),
+        100.0 * compressed.len() as f64 / input.len() as f64
+    );
+    println!("compress    {:?}", compress_time);
+    println!("decompress  {:?}", decompress_time);
+}
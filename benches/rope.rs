@@ -0,0 +1,54 @@
+/*
+ * A document of many short lines built three ways: appended to a Rope,
+ * push_str onto one String, and format! of the whole text so far plus
+ * the next line, which copies everything on every line
+ *
+ *   cargo bench --bench rope
+ * */
+use rust_exercises::extras::rope::Rope;
+use std::time::{Duration, Instant};
+
+const LINES: usize = 10_000;
+
+fn line(i: usize) -> String {
+    format!(
+        "line {:>6}: the quick brown fox jumps over the lazy dog\n",
+        i
+    )
+}
+
+fn time<F: FnMut() -> String>(mut f: F) -> (String, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn main() {
+    let (rope, rope_time) = time(|| {
+        let mut rope = Rope::new();
+        for i in 0..LINES {
+            rope.append(line(i));
+        }
+        rope.into_string()
+    });
+    let (pushed, push_time) = time(|| {
+        let mut text = String::new();
+        for i in 0..LINES {
+            text.push_str(&line(i));
+        }
+        text
+    });
+    let (formatted, format_time) = time(|| {
+        let mut text = String::new();
+        for i in 0..LINES {
+            text = format!("{}{}", text, line(i));
+        }
+        text
+    });
+    assert!(rope == pushed && rope == formatted);
+
+    println!("{} lines, {} KB", LINES, rope.len() / 1024);
+    println!("rope, flattened once   {:>10.2?}", rope_time);
+    println!("String::push_str       {:>10.2?}", push_time);
+    println!("format! in a loop      {:>10.2?}", format_time);
+}
@@ -0,0 +1,36 @@
+/*
+ * Both thread pool strategies on a skewed workload, where one job in
+ * eight takes fifty times longer than the others
+ *
+ *   cargo bench --bench thread_pool
+ * */
+use rust_exercises::extras::thread_pool::{Strategy, ThreadPool};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const THREADS: usize = 4;
+const JOBS: usize = 2_000;
+
+fn busy_work(rounds: u64) -> u64 {
+    (0..rounds * 1_000).fold(0, |acc, x| black_box(acc ^ x.wrapping_mul(31)))
+}
+
+fn run(strategy: Strategy) -> Duration {
+    let start = Instant::now();
+    let pool = ThreadPool::with_strategy(THREADS, strategy);
+    for i in 0..JOBS {
+        let rounds = if i % 8 == 0 { 50 } else { 1 };
+        pool.execute(move || {
+            black_box(busy_work(rounds));
+        });
+    }
+    // Dropping the pool waits for every job
+    drop(pool);
+    start.elapsed()
+}
+
+fn main() {
+    println!("{} threads, {} jobs", THREADS, JOBS);
+    println!("shared queue   {:?}", run(Strategy::SharedQueue));
+    println!("work stealing  {:?}", run(Strategy::WorkStealing));
+}
@@ -0,0 +1,34 @@
+/*
+ * The 100 largest of 20 million generated numbers, streamed through the
+ * bounded heap against collected and sorted
+ *
+ *   cargo bench --bench top_k
+ * */
+use rust_exercises::extras::rng::XorShift64;
+use rust_exercises::extras::top_k::{top_k, top_k_streaming};
+use std::time::Instant;
+
+const ITEMS: usize = 20_000_000;
+const K: usize = 100;
+
+fn main() {
+    let start = Instant::now();
+    let streamed = top_k_streaming(XorShift64::new(42).take(ITEMS), K);
+    let streaming_time = start.elapsed();
+
+    let start = Instant::now();
+    let all: Vec<u64> = XorShift64::new(42).take(ITEMS).collect();
+    let sorted = top_k(&all, K);
+    let sorting_time = start.elapsed();
+
+    assert_eq!(streamed, sorted);
+    println!(
+        "top {} of {}: streaming {:.2?} holding {} items, sorting {:.2?} holding {} MB and a copy",
+        K,
+        ITEMS,
+        streaming_time,
+        K,
+        sorting_time,
+        ITEMS * std::mem::size_of::<u64>() / 1_000_000
+    );
+}
@@ -0,0 +1,11 @@
+// Compiles the C half of the FFI chapter into a static library
+fn main() {
+    println!("cargo:rerun-if-changed=c/ffi_helpers.c");
+    // Nothing to link against when the chapter is left out
+    if std::env::var_os("CARGO_FEATURE_CH19").is_none() {
+        return;
+    }
+    cc::Build::new()
+        .file("c/ffi_helpers.c")
+        .compile("ffi_helpers");
+}
@@ -0,0 +1,34 @@
+/*
+ * Compiled by build.rs and linked into the library, called from
+ * src/chapters/ffi.rs
+ */
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+
+int64_t ffi_sum(const int32_t *values, size_t len)
+{
+    int64_t total = 0;
+    for (size_t i = 0; i < len; i++) {
+        total += values[i];
+    }
+    return total;
+}
+
+/*
+ * Writes "Hello, <name>!" into buf, truncated to fit and always NUL
+ * terminated. Returns the length the whole greeting needs, like snprintf.
+ */
+size_t ffi_greet(const char *name, char *buf, size_t cap)
+{
+    int needed = snprintf(buf, cap, "Hello, %s!", name);
+    return needed < 0 ? 0 : (size_t)needed;
+}
+
+/* Calls back into Rust for every value */
+void ffi_map(int32_t *values, size_t len, int32_t (*f)(int32_t))
+{
+    for (size_t i = 0; i < len; i++) {
+        values[i] = f(values[i]);
+    }
+}
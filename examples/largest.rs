@@ -0,0 +1,21 @@
+/*
+ * The chapter 10 largest functions on a few kinds of lists
+ *
+ *   cargo run --example largest
+ * */
+use rust_exercises::chapters::largest::{largest_generic, largest_generic_copy};
+
+fn main() {
+    let numbers = vec![34, 50, 25, 100, 65];
+    println!("largest number {:?}", largest_generic_copy(&numbers));
+
+    let chars = vec!['y', 'm', 'a', 'q'];
+    println!("largest char {:?}", largest_generic_copy(&chars));
+
+    // Strings aren't Copy, the reference version works for them
+    let words: Vec<String> = "the quick brown fox".split(' ').map(String::from).collect();
+    println!("largest word {:?}", largest_generic(&words));
+
+    let empty: Vec<f64> = Vec::new();
+    println!("largest of nothing {:?}", largest_generic_copy(&empty));
+}
@@ -0,0 +1,28 @@
+/*
+ * Chapter 12's minigrep
+ *
+ *   cargo run --example minigrep -- the README.md
+ *   IGNORE_CASE=1 cargo run --example minigrep -- RUST README.md
+ * */
+use rust_exercises::chapters::minigrep::{self, Config};
+use std::env;
+use std::process;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {}", err);
+        process::exit(1);
+    });
+
+    match minigrep::run(&config) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Err(e) => {
+            eprintln!("Application error: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+/*
+ * Decoding arbitrary text must not panic, and encoding the input as
+ * bytes must always decode back to it
+ * */
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_exercises::extras::base64;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = base64::decode(text);
+    }
+    assert_eq!(base64::decode(&base64::encode(data)).unwrap(), data);
+});
@@ -0,0 +1,12 @@
+/*
+ * Errors are fine, panics and stack overflows are not
+ * */
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_exercises::extras::calc;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = calc::calc(text);
+    }
+});
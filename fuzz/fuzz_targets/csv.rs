@@ -0,0 +1,27 @@
+/*
+ * Raw bytes on purpose, invalid UTF-8 has to be an error and not a panic.
+ * Records that parse are written out and read back.
+ * */
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_exercises::extras::csv;
+
+fuzz_target!(|data: &[u8]| {
+    let mut records = Vec::new();
+    for record in csv::Reader::new(data) {
+        match record {
+            Ok(record) => records.push(record),
+            Err(_) => return,
+        }
+    }
+
+    let mut writer = csv::Writer::new(Vec::new());
+    for record in records.iter() {
+        writer.write_record(record).unwrap();
+    }
+    let written = writer.into_inner();
+    let read_back: Vec<Vec<String>> = csv::Reader::new(&written[..])
+        .map(|record| record.unwrap())
+        .collect();
+    assert_eq!(read_back, records);
+});
@@ -0,0 +1,18 @@
+/*
+ * Anything that parses has to come back the same after being written out,
+ * compact or pretty
+ * */
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use rust_exercises::extras::json;
+
+fuzz_target!(|data: &[u8]| {
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    if let Ok(value) = json::parse(text) {
+        assert_eq!(json::parse(&value.to_string()), Ok(value.clone()));
+        assert_eq!(json::parse(&value.to_pretty()), Ok(value));
+    }
+});
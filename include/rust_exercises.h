@@ -0,0 +1,30 @@
+/*
+ * C declarations of the functions in src/ffi_exports.rs, link with
+ * -lrust_exercises after cargo build
+ *
+ * Maintained by hand, not generated: change it along with
+ * ffi_exports.rs. tests/ffi_dlopen.rs checks every export is declared.
+ */
+#ifndef RUST_EXERCISES_H
+#define RUST_EXERCISES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Writes the largest of the len values to *out, false if there are none */
+bool largest_i32_c(const int32_t *ptr, size_t len, int32_t *out);
+bool largest_f64_c(const double *ptr, size_t len, double *out);
+
+/* Largest byte of a NUL terminated string, 0 if it's empty or NULL */
+uint8_t largest_byte_c(const char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,41 @@
+/*
+ * Chat server, see extras::chat for the protocol. Ctrl-C disconnects
+ * everyone with a notice and exits.
+ *
+ *   cargo run --features async-rt --bin chat-server -- 127.0.0.1:8000
+ * */
+use rust_exercises::extras::chat::ChatRoom;
+use rust_exercises::extras::shutdown::{self, ShutdownFlag};
+use std::env;
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() {
+    let addr = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:8000".to_string());
+
+    let listener = match TcpListener::bind(&addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("chat-server: can't listen on {}: {}", addr, e);
+            std::process::exit(1);
+        }
+    };
+    // The port the OS picked when it was given as 0
+    match listener.local_addr() {
+        Ok(local) => println!("listening on {}", local),
+        Err(_) => println!("listening on {}", addr),
+    }
+
+    let shutdown_flag = ShutdownFlag::new();
+    if let Err(e) = shutdown::install_ctrl_c(&shutdown_flag) {
+        eprintln!("chat-server: {}, Ctrl-C will kill it on the spot", e);
+    }
+
+    if let Err(e) = ChatRoom::new().serve_until(listener, shutdown_flag).await {
+        eprintln!("chat-server: {}", e);
+        std::process::exit(1);
+    }
+    println!("shut down");
+}
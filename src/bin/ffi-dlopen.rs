@@ -0,0 +1,102 @@
+/*
+ * Loads the crate's own cdylib at runtime with dlopen, the way a C
+ * program or another language's FFI would, and calls ffi_exports
+ * through the symbols it finds.
+ *
+ *   cargo build && cargo run --bin ffi-dlopen -- [path/to/librust_exercises.so]
+ *
+ * Defaults to the library next to this binary in target/<profile>.
+ * dlopen is POSIX, on other platforms this only says so and exits.
+ * */
+use std::env;
+use std::ffi::{CStr, CString};
+#[cfg(unix)]
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::PathBuf;
+use std::process;
+
+#[cfg(unix)]
+const RTLD_NOW: c_int = 2;
+
+#[cfg(unix)]
+extern "C" {
+    fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlerror() -> *const c_char;
+    fn dlclose(handle: *mut c_void) -> c_int;
+}
+
+#[cfg(unix)]
+type LargestI32 = unsafe extern "C" fn(*const i32, usize, *mut i32) -> bool;
+
+#[cfg(unix)]
+fn last_error() -> String {
+    // SAFETY: dlerror returns null or a NUL terminated message
+    unsafe {
+        let message = dlerror();
+        if message.is_null() {
+            String::from("unknown error")
+        } else {
+            CStr::from_ptr(message).to_string_lossy().into_owned()
+        }
+    }
+}
+
+fn default_library() -> PathBuf {
+    let exe = env::current_exe().expect("no path to the current executable");
+    exe.with_file_name(format!(
+        "{}rust_exercises{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ))
+}
+
+#[cfg(unix)]
+fn run(library: &CStr) -> Result<(), String> {
+    // SAFETY: both strings are NUL terminated, the handle is checked
+    // before use and the symbol is cast to the type it's defined with
+    unsafe {
+        let handle = dlopen(library.as_ptr(), RTLD_NOW);
+        if handle.is_null() {
+            return Err(last_error());
+        }
+
+        let name = CString::new("largest_i32_c").unwrap();
+        let symbol = dlsym(handle, name.as_ptr());
+        if symbol.is_null() {
+            let error = last_error();
+            dlclose(handle);
+            return Err(error);
+        }
+        let largest_i32_c: LargestI32 = std::mem::transmute(symbol);
+
+        let numbers = [34, 50, 25, 100, 65];
+        let mut largest = 0;
+        if largest_i32_c(numbers.as_ptr(), numbers.len(), &mut largest) {
+            println!("largest_i32_c({:?}) = {}", numbers, largest);
+        }
+        let found = largest_i32_c(numbers.as_ptr(), 0, &mut largest);
+        println!("largest_i32_c([]) found a value: {}", found);
+
+        dlclose(handle);
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn run(_library: &CStr) -> Result<(), String> {
+    Err(String::from("dlopen is only available on Unix"))
+}
+
+fn main() {
+    let library = env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(default_library);
+    let path = CString::new(library.to_string_lossy().into_owned()).unwrap();
+
+    if let Err(e) = run(&path) {
+        eprintln!("ffi-dlopen: {}: {}", library.display(), e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,24 @@
+/*
+ * Filters stdin to stdout line by line, see extras::filter for the flags
+ *
+ *   cat /etc/passwd | cargo run --bin filter -- -d : -f 1,7 -v nologin -u
+ * */
+use rust_exercises::extras::filter::Chain;
+use std::env;
+use std::io;
+use std::process;
+
+fn main() {
+    let chain = Chain::from_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("filter: {}", e);
+        eprintln!("usage: filter [-d c] [-f list] [-m text] [-v text] [-u] [-l] ...");
+        process::exit(2);
+    });
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    if let Err(e) = chain.run(stdin.lock(), stdout.lock()) {
+        eprintln!("filter: {}", e);
+        process::exit(1);
+    }
+}
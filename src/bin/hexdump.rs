@@ -0,0 +1,35 @@
+/*
+ * xxd-like hexdump of a file, or of stdin when no file is given
+ *
+ *   cargo run --bin hexdump -- src/lib.rs
+ * */
+use rust_exercises::extras::hexdump;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process;
+
+fn main() {
+    let stdout = io::stdout();
+    let output = stdout.lock();
+
+    let result = match env::args().nth(1) {
+        Some(path) => match File::open(&path) {
+            Ok(file) => hexdump::dump(BufReader::new(file), output),
+            Err(e) => {
+                eprintln!("hexdump: {}: {}", path, e);
+                process::exit(1);
+            }
+        },
+        None => {
+            let stdin = io::stdin();
+            let input = stdin.lock();
+            hexdump::dump(input, output)
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("hexdump: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * Prints the most frequent words of every file under a directory,
+ * counted across a thread pool.
+ *
+ *   cargo run --release --bin par-wc -- src --threads 4 --top 20
+ * */
+use rust_exercises::extras::par_wc;
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "usage: par-wc DIR [--threads N] [--top N]";
+
+struct Args {
+    root: PathBuf,
+    threads: usize,
+    top: usize,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut root = None;
+    let mut threads = 4;
+    let mut top = 10;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threads" | "--top" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("{} needs a value", arg))?;
+                let value: usize = value
+                    .parse()
+                    .map_err(|_| format!("{}: not a number: {}", arg, value))?;
+                if arg == "--threads" {
+                    threads = value.max(1);
+                } else {
+                    top = value;
+                }
+            }
+            _ if root.is_none() => root = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    let root = root.ok_or_else(|| USAGE.to_string())?;
+    Ok(Args { root, threads, top })
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("par-wc: {}", e);
+        process::exit(2);
+    });
+
+    let result = par_wc::collect_files(&args.root)
+        .and_then(|files| par_wc::count_parallel(&files, args.threads));
+    let frequencies = result.unwrap_or_else(|e| {
+        eprintln!("par-wc: {}: {}", args.root.display(), e);
+        process::exit(1);
+    });
+
+    for (word, count) in par_wc::top(&frequencies, args.top) {
+        println!("{:>8} {}", count, word);
+    }
+}
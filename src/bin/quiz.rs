@@ -0,0 +1,59 @@
+/*
+ * Guess what small programs print, see extras::quiz
+ *
+ *   cargo run --bin quiz                  every snippet
+ *   cargo run --bin quiz -- drop_order    only the ones named
+ *   cargo run --bin quiz -- --check       run them all against their answers
+ * */
+use rust_exercises::extras::quiz::{self, snippets};
+use std::env;
+use std::io;
+use std::process;
+
+fn check() -> bool {
+    let runner = snippets::Runner::new().unwrap_or_else(|e| {
+        eprintln!("quiz: {}", e);
+        process::exit(1);
+    });
+    let mut ok = true;
+    for snippet in snippets::SNIPPETS {
+        match runner.verify(snippet) {
+            Ok(_) => println!("ok    {}", snippet.name),
+            Err(e) => {
+                println!("FAIL  {}", e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--check") {
+        process::exit(if check() { 0 } else { 1 });
+    }
+
+    let selected: Vec<&snippets::Snippet> = if args.is_empty() {
+        snippets::SNIPPETS.iter().collect()
+    } else {
+        args.iter()
+            .map(|name| {
+                snippets::find(name).unwrap_or_else(|| {
+                    eprintln!("quiz: no snippet named {}", name);
+                    process::exit(2);
+                })
+            })
+            .collect()
+    };
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    match quiz::run(&selected, &mut stdin.lock(), &mut stdout.lock()) {
+        Ok(score) => println!("{} right out of {}", score.right, score.asked),
+        Err(e) => {
+            eprintln!("quiz: {}", e);
+            process::exit(1);
+        }
+    }
+}
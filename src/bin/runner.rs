@@ -0,0 +1,446 @@
+/*
+ * Entry point for working through the exercises
+ *
+ *   cargo run --bin runner -- play snippet.rs           build and run a snippet
+ *   echo 'fn main() {}' | cargo run --bin runner -- play -
+ *   cargo run --bin runner -- update --dry-run          what upstream has added
+ *   cargo run --bin runner -- list --difficulty intro   the chapters, by difficulty
+ *   cargo run --bin runner -- verify --max-difficulty core
+ *   cargo run --bin runner -- verify chapter-12          recorded for stats
+ *   cargo run --bin runner -- verify --unsafe            the unsafe code under Miri
+ *   cargo run --bin runner -- stats chapter-12
+ *   cargo run --bin runner -- plugins run rot13 hello    input from stdin if none
+ *
+ * -v, -vv or -vvv before the command logs at info, debug or trace to
+ * stderr, --log takes a RUST_LOG-style spec like runner=debug. Without
+ * them RUST_LOG is read, and only errors are logged when it isn't set.
+ * */
+use rust_exercises::chapters::{Chapter, Difficulty, CATALOG};
+use rust_exercises::extras::datetime;
+use rust_exercises::extras::logger::{self, Filter, Logger, ParseLevelError};
+use rust_exercises::extras::playground::{self, Playground};
+use rust_exercises::extras::plugins::{PluginError, Registry};
+use rust_exercises::extras::attempts::{self, Attempt, Summary};
+use rust_exercises::extras::term::style;
+use rust_exercises::extras::update::{self, Action, LocalDir, Remote, Source};
+use rust_exercises::{log_debug, log_info};
+use std::env;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::process::{self, Command, Stdio};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "usage: runner [-v | -vv | -vvv | --log <spec>] <command>
+       runner play <file.rs | ->
+       runner update [--dry-run] [--from <url | dir>]
+       runner list [--difficulty <intro | core | challenge>]
+       runner verify [--max-difficulty <intro | core | challenge> | chapter-<n> | --unsafe]
+       runner stats chapter-<n> [stats.jsonl...]
+       runner plugins run <name> [input...]";
+
+fn fail(message: &str) -> ! {
+    eprintln!("runner: {}", message);
+    process::exit(1);
+}
+
+fn usage() -> i32 {
+    eprintln!("{}", USAGE);
+    2
+}
+
+fn run_play(args: &[String]) -> i32 {
+    let path = match args {
+        [path] => path,
+        _ => return usage(),
+    };
+
+    let source = playground::read_source(path)
+        .unwrap_or_else(|e| fail(&format!("can't read {}: {}", path, e)));
+    let playground =
+        Playground::new().unwrap_or_else(|e| fail(&format!("can't set up a project: {}", e)));
+    match playground.run(&source) {
+        // No code means a signal killed it, report it like a failure
+        Ok(status) => status.code().unwrap_or(1),
+        Err(e) => fail(&format!("can't run cargo: {}", e)),
+    }
+}
+
+fn run_update(args: &[String]) -> i32 {
+    let mut dry_run = false;
+    let mut from = update::DEFAULT_UPSTREAM.to_string();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--from" => match args.next() {
+                Some(source) => from = source.clone(),
+                None => return usage(),
+            },
+            _ => return usage(),
+        }
+    }
+
+    let root = Path::new(".");
+    if !root.join(update::CATALOG).exists() {
+        fail("run it from the root of the exercises, next to catalog.txt");
+    }
+    let source: Box<dyn Source> = if from.starts_with("https://") || from.starts_with("http://") {
+        Box::new(Remote::new(&from))
+    } else {
+        Box::new(LocalDir::new(&from))
+    };
+
+    let changes = update::plan(source.as_ref(), root).unwrap_or_else(|e| fail(&e.to_string()));
+    for change in changes
+        .iter()
+        .filter(|change| change.action != Action::Unchanged)
+    {
+        let action = match change.action {
+            Action::New => style(change.action).green(),
+            Action::Updated => style(change.action).cyan(),
+            _ => style(change.action).yellow(),
+        };
+        println!("{:<15} {}", action, change.path);
+    }
+    let installing = changes
+        .iter()
+        .filter(|change| matches!(change.action, Action::New | Action::Updated))
+        .count();
+
+    if dry_run {
+        println!("{} files would be installed", installing);
+    } else {
+        update::apply(root, &changes).unwrap_or_else(|e| fail(&e.to_string()));
+        println!("{} files installed", installing);
+    }
+    0
+}
+
+// The value after a flag like --difficulty, None if the flag isn't there
+fn difficulty_arg(args: &[String], flag: &str) -> Result<Option<Difficulty>, i32> {
+    match args {
+        [] => Ok(None),
+        [given, value] if given == flag => value.parse().map(Some).map_err(|e: String| {
+            eprintln!("runner: {}", e);
+            2
+        }),
+        _ => Err(usage()),
+    }
+}
+
+fn run_list(args: &[String]) -> i32 {
+    let difficulty = match difficulty_arg(args, "--difficulty") {
+        Ok(difficulty) => difficulty,
+        Err(code) => return code,
+    };
+
+    let chapters = CATALOG
+        .iter()
+        .filter(|chapter| difficulty.is_none_or(|wanted| chapter.difficulty == wanted));
+    for chapter in chapters {
+        println!(
+            "{:<16} {:<10} {}",
+            chapter.module, chapter.difficulty, chapter.title
+        );
+    }
+    0
+}
+
+// "chapter-12" is the exercise behind the ch12 feature
+fn chapter_feature(exercise: &str) -> Option<String> {
+    let number = exercise.strip_prefix("chapter-")?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("ch{}", number))
+}
+
+/*
+ * Runs the test suite with only the selected chapters compiled in, so a
+ * chapter left out of the session can't fail it. cargo's stderr is
+ * passed through as it comes and kept, for stats to find the first
+ * error in.
+ * */
+fn cargo_test(chapters: &[&Chapter]) -> (i32, String) {
+    let mut features = vec!["alloc"];
+    for chapter in chapters.iter() {
+        features.extend(chapter.features);
+    }
+    features.sort_unstable();
+    features.dedup();
+
+    let modules: Vec<&str> = chapters.iter().map(|chapter| chapter.module).collect();
+    println!("verifying {}", modules.join(", "));
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    log_info!(
+        "{} test --no-default-features --features {}",
+        cargo,
+        features.join(",")
+    );
+    let mut child = Command::new(cargo)
+        .args(["test", "--no-default-features", "--features"])
+        .arg(features.join(","))
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| fail(&format!("can't run cargo: {}", e)));
+
+    let mut stderr = String::new();
+    if let Some(pipe) = child.stderr.take() {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            eprintln!("{}", line);
+            stderr.push_str(&line);
+            stderr.push('\n');
+        }
+    }
+    let status = child
+        .wait()
+        .unwrap_or_else(|e| fail(&format!("can't run cargo: {}", e)));
+    (status.code().unwrap_or(1), stderr)
+}
+
+/*
+ * The verdict after cargo's output, and for a compiler error the command
+ * that explains it in depth
+ * */
+fn report(code: i32, error: Option<&str>) {
+    if code == 0 {
+        println!("{} every test passed", style("pass").green().bold());
+        return;
+    }
+    println!(
+        "{} cargo test exited with {}",
+        style("fail").red().bold(),
+        code
+    );
+    if let Some(error) = error {
+        println!("  first error: {}", error);
+        let explain = error
+            .strip_prefix("error[")
+            .and_then(|rest| rest.split_once(']'))
+            .map(|(code, _)| code);
+        if let Some(code) = explain {
+            println!(
+                "{} see rustc --explain {}",
+                style("hint").yellow().bold(),
+                code
+            );
+        }
+    }
+}
+
+/*
+ * Either every chapter up to a difficulty, or the modules of one book
+ * chapter. Only the second is an attempt at an exercise and gets
+ * recorded for runner stats.
+ * */
+fn run_verify(args: &[String]) -> i32 {
+    if let [flag] = args {
+        if flag == "--unsafe" {
+            return run_miri();
+        }
+    }
+    let (max, exercise) = match args {
+        [exercise] if !exercise.starts_with("--") => (Difficulty::Challenge, Some(exercise)),
+        _ => match difficulty_arg(args, "--max-difficulty") {
+            Ok(max) => (max.unwrap_or(Difficulty::Challenge), None),
+            Err(code) => return code,
+        },
+    };
+    if !Path::new("Cargo.toml").exists() {
+        fail("run it from the root of the exercises, next to Cargo.toml");
+    }
+
+    let chapters: Vec<&Chapter> = match exercise {
+        Some(exercise) => {
+            let feature = chapter_feature(exercise).unwrap_or_else(|| {
+                fail(&format!(
+                    "{} isn't a chapter, expected chapter-<number>",
+                    exercise
+                ))
+            });
+            CATALOG
+                .iter()
+                .filter(|chapter| chapter.features.contains(&feature.as_str()))
+                .collect()
+        }
+        None => CATALOG
+            .iter()
+            .filter(|chapter| chapter.difficulty <= max)
+            .collect(),
+    };
+    if chapters.is_empty() {
+        fail("no chapter compiled into the runner matches");
+    }
+
+    let started = SystemTime::now();
+    let clock = Instant::now();
+    let (code, stderr) = cargo_test(&chapters);
+    let error = attempts::first_error(&stderr);
+    log_debug!(
+        "cargo exited with {} after {:?}, first error {:?}",
+        code,
+        clock.elapsed(),
+        error
+    );
+    report(code, error.as_deref());
+
+    if let Some(exercise) = exercise {
+        let attempt = Attempt {
+            exercise: exercise.clone(),
+            started: started
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            duration: clock.elapsed(),
+            passed: code == 0,
+            error,
+        };
+        match attempts::record(attempts::LOG, &attempt) {
+            Ok(()) => log_info!("attempt at {} recorded in {}", exercise, attempts::LOG),
+            Err(e) => eprintln!("runner: can't record the attempt: {}", e),
+        }
+    }
+    code
+}
+
+/*
+ * tests/unsafe_code.rs under Miri, which reports undefined behaviour
+ * that plain cargo test lets through. Miri only comes with nightly, and
+ * needs the rustup proxy to pick it, so this is always cargo and never
+ * $CARGO.
+ * */
+fn run_miri() -> i32 {
+    if !Path::new("Cargo.toml").exists() {
+        fail("run it from the root of the exercises, next to Cargo.toml");
+    }
+    println!("verifying the unsafe code under Miri");
+    log_info!("cargo +nightly miri test --test unsafe_code");
+    let status = Command::new("cargo")
+        .args(["+nightly", "miri", "test", "--test", "unsafe_code"])
+        .status()
+        .unwrap_or_else(|e| fail(&format!("can't run cargo: {}", e)));
+    let code = status.code().unwrap_or(1);
+    log_debug!("miri exited with {}", code);
+    if code != 0 {
+        println!(
+            "{} if Miri is missing: rustup +nightly component add miri",
+            style("hint").yellow().bold()
+        );
+    }
+    report(code, None);
+    code
+}
+
+/*
+ * Summarizes the attempts at one exercise from this checkout's log, or
+ * from the logs given, one per learner
+ * */
+fn run_stats(args: &[String]) -> i32 {
+    let (exercise, logs) = match args.split_first() {
+        Some((exercise, logs)) if !exercise.starts_with("--") => (exercise, logs),
+        _ => return usage(),
+    };
+    let default_log = [String::from(attempts::LOG)];
+    let logs = if logs.is_empty() {
+        &default_log[..]
+    } else {
+        logs
+    };
+
+    let mut summary = Summary::new(exercise);
+    for log in logs {
+        match attempts::load(log) {
+            Ok(attempts) => summary.add(&attempts),
+            Err(e) => fail(&format!("can't read {}: {}", log, e)),
+        }
+    }
+    if summary.attempts == 0 {
+        println!("no attempts at {} recorded", exercise);
+        return 0;
+    }
+
+    println!(
+        "{}: {} attempts, {} passed",
+        exercise, summary.attempts, summary.passed
+    );
+    match summary.median_time_to_green() {
+        Some(median) => println!(
+            "median time to green: {}",
+            datetime::format_duration(median)
+        ),
+        None => println!("median time to green: never passed"),
+    }
+    if let Some((error, count)) = summary.most_common_error() {
+        println!("most common error: {} ({}x)", error, count);
+    }
+    0
+}
+
+fn run_plugins(args: &[String]) -> i32 {
+    let (name, words) = match args {
+        [command, name, words @ ..] if command == "run" => (name, words),
+        _ => return usage(),
+    };
+    let input = if words.is_empty() {
+        io::read_to_string(io::stdin())
+            .unwrap_or_else(|e| fail(&format!("can't read stdin: {}", e)))
+    } else {
+        words.join(" ")
+    };
+
+    let registry = Registry::builtin();
+    match registry.run(name, &input) {
+        Ok(output) => {
+            println!("{}", output);
+            0
+        }
+        Err(e @ PluginError::Unknown(_)) => {
+            fail(&format!("{}, there is {}", e, registry.names().join(", ")))
+        }
+        Err(e) => fail(&e.to_string()),
+    }
+}
+
+/*
+ * Takes the logging options off the front of args and installs the
+ * logger they ask for, the last one given wins
+ * */
+fn init_logging(args: &mut Vec<String>) {
+    let mut spec = None;
+    loop {
+        match args.first().map(String::as_str) {
+            Some("-v") => spec = Some(String::from("info")),
+            Some("-vv") => spec = Some(String::from("debug")),
+            Some("-vvv") => spec = Some(String::from("trace")),
+            Some("--log") if args.len() > 1 => spec = Some(args.remove(1)),
+            Some("--log") => fail("--log needs a spec, like runner=debug"),
+            _ => break,
+        }
+        args.remove(0);
+    }
+
+    let filter = match spec {
+        Some(spec) => spec
+            .parse()
+            .unwrap_or_else(|e: ParseLevelError| fail(&e.to_string())),
+        None => Filter::from_env(),
+    };
+    // Nothing else in the runner installs one, this can't be refused
+    let _ = logger::set_logger(Box::new(Logger::stderr(filter)));
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    init_logging(&mut args);
+    log_debug!("runner {:?}", args);
+    let code = match args.split_first() {
+        Some((command, rest)) if command == "play" => run_play(rest),
+        Some((command, rest)) if command == "update" => run_update(rest),
+        Some((command, rest)) if command == "list" => run_list(rest),
+        Some((command, rest)) if command == "verify" => run_verify(rest),
+        Some((command, rest)) if command == "stats" => run_stats(rest),
+        Some((command, rest)) if command == "plugins" => run_plugins(rest),
+        _ => usage(),
+    };
+    process::exit(code);
+}
@@ -0,0 +1,51 @@
+/*
+ * wc-like counter printing lines, words and bytes of every file given,
+ * with a total row when there is more than one. Reads stdin without files.
+ *
+ *   cargo run --bin wc -- src/lib.rs src/extras/wc.rs
+ * */
+use rust_exercises::extras::wc::{self, Counts};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::process;
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+
+    if paths.is_empty() {
+        let stdin = io::stdin();
+        match wc::count(stdin.lock()) {
+            Ok(counts) => println!("{}", counts),
+            Err(e) => {
+                eprintln!("wc: {}", e);
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let mut total = Counts::default();
+    let mut failed = false;
+
+    // Keep going after an error like wc does, but exit with 1 at the end
+    for path in paths.iter() {
+        match File::open(path).and_then(|file| wc::count(BufReader::new(file))) {
+            Ok(counts) => {
+                println!("{} {}", counts, path);
+                total += counts;
+            }
+            Err(e) => {
+                eprintln!("wc: {}: {}", path, e);
+                failed = true;
+            }
+        }
+    }
+
+    if paths.len() > 1 {
+        println!("{} total", total);
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,344 @@
+/*
+ * What async runtimes do under the hood, without any crate: futures
+ * written by hand, a Waker to be polled again, and an executor.
+ * Follows https://rust-lang.github.io/async-book/02_execution/01_chapter.html
+ *
+ * A future does nothing until polled. poll() either returns Ready with
+ * the output, or Pending after making sure cx.waker() gets called once
+ * it's worth polling again. The executor's job is to poll futures whose
+ * waker was called, and to sleep the rest of the time.
+ * */
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+struct DelayState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+/*
+ * Completes once duration has passed. The first poll starts a thread that
+ * sleeps and then calls the waker, the future itself never blocks.
+ * */
+pub struct Delay {
+    duration: Duration,
+    state: Option<Arc<Mutex<DelayState>>>,
+}
+
+impl Delay {
+    pub fn new(duration: Duration) -> Delay {
+        Delay {
+            duration,
+            state: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let duration = self.duration;
+        let state = self.state.get_or_insert_with(|| {
+            let state = Arc::new(Mutex::new(DelayState {
+                done: false,
+                waker: None,
+            }));
+            let timer_state = state.clone();
+            thread::spawn(move || {
+                thread::sleep(duration);
+                let mut state = timer_state.lock().unwrap();
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+            state
+        });
+
+        let mut state = state.lock().unwrap();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            // The future may have moved to another task since the last
+            // poll, so the waker is replaced every time
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/*
+ * Pending on the first poll and Ready on the second, giving the executor
+ * a chance to run other tasks in between
+ * */
+pub struct YieldNow {
+    yielded: bool,
+}
+
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+enum MaybeDone<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(Option<F::Output>),
+}
+
+impl<F: Future> MaybeDone<F> {
+    // true once the output is available
+    fn poll(&mut self, cx: &mut Context) -> bool {
+        let output = match *self {
+            MaybeDone::Pending(ref mut future) => match future.as_mut().poll(cx) {
+                Poll::Ready(output) => output,
+                Poll::Pending => return false,
+            },
+            MaybeDone::Done(_) => return true,
+        };
+        *self = MaybeDone::Done(Some(output));
+        true
+    }
+
+    fn take(&mut self) -> F::Output {
+        match *self {
+            MaybeDone::Done(ref mut output) => output.take().expect("polled after completion"),
+            MaybeDone::Pending(_) => unreachable!("taken before completion"),
+        }
+    }
+}
+
+/*
+ * Runs two futures concurrently and completes with both outputs once
+ * both are done. The futures are boxed so they can be polled through
+ * Pin<Box<F>> without any unsafe pin projection.
+ * */
+pub struct Join<A: Future, B: Future> {
+    a: MaybeDone<A>,
+    b: MaybeDone<B>,
+}
+
+pub fn join<A: Future, B: Future>(a: A, b: B) -> Join<A, B> {
+    Join {
+        a: MaybeDone::Pending(Box::pin(a)),
+        b: MaybeDone::Pending(Box::pin(b)),
+    }
+}
+
+// Only Pin<Box<_>> and Options inside, so Join can be moved freely
+impl<A: Future, B: Future> Unpin for Join<A, B> {}
+
+impl<A: Future, B: Future> Future for Join<A, B> {
+    type Output = (A::Output, B::Output);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<(A::Output, B::Output)> {
+        // Both are polled every time, one being pending doesn't stop the other
+        let a_done = self.a.poll(cx);
+        let b_done = self.b.poll(cx);
+
+        if a_done && b_done {
+            Poll::Ready((self.a.take(), self.b.take()))
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/*
+ * Waking unparks the thread sleeping in block_on
+ * */
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/*
+ * Polls future on the current thread until it's done, parking the
+ * thread while it's pending
+ * */
+/// ```
+/// use rust_exercises::chapters::async_executor::{block_on, join, yield_now};
+///
+/// let (a, b) = block_on(join(async { 1 }, async {
+///     yield_now().await;
+///     2
+/// }));
+/// assert_eq!(a + b, 3);
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            // A wake that happened before park makes it return right away
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/*
+ * A spawned future along with the way back to the queue. Waking the task
+ * sends it to the queue again, that's how the executor learns what to poll.
+ * */
+struct Task {
+    // None once the future completed
+    future: Mutex<Option<BoxFuture>>,
+    queue: Sender<Arc<Task>>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        // Fails only when the executor is gone, then nobody cares
+        let _ = self.queue.send(self.clone());
+    }
+}
+
+#[derive(Clone)]
+pub struct Spawner {
+    queue: Sender<Arc<Task>>,
+}
+
+impl Spawner {
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(&self, future: F) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queue: self.queue.clone(),
+        });
+        let _ = self.queue.send(task);
+    }
+}
+
+/*
+ * Single-threaded executor: every task is polled on the thread calling
+ * run(), one at a time, in the order they were woken.
+ * */
+/// ```
+/// use rust_exercises::chapters::async_executor::{Delay, Executor};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+///
+/// let executor = Executor::new();
+/// let spawner = executor.spawner();
+/// let finished = Arc::new(Mutex::new(Vec::new()));
+/// for ms in [30, 10] {
+///     let finished = finished.clone();
+///     spawner.spawn(async move {
+///         Delay::new(Duration::from_millis(ms)).await;
+///         finished.lock().unwrap().push(ms);
+///     });
+/// }
+/// // A Spawner kept outside the tasks would keep run() from returning
+/// drop(spawner);
+/// executor.run();
+/// assert_eq!(*finished.lock().unwrap(), [10, 30]);
+/// ```
+pub struct Executor {
+    queue: Receiver<Arc<Task>>,
+    spawner: Spawner,
+}
+
+impl Default for Executor {
+    fn default() -> Executor {
+        Executor::new()
+    }
+}
+
+impl Executor {
+    pub fn new() -> Executor {
+        let (sender, receiver) = mpsc::channel();
+        Executor {
+            queue: receiver,
+            spawner: Spawner { queue: sender },
+        }
+    }
+
+    pub fn spawner(&self) -> Spawner {
+        self.spawner.clone()
+    }
+
+    /*
+     * Runs until every task completed. Each pending task is kept alive by
+     * its waker, which holds a sender to the queue, so the queue closes
+     * exactly when no task can be woken anymore. Keeping a Spawner around
+     * outside the tasks keeps the queue open and run() never returns.
+     * */
+    pub fn run(self) {
+        let Executor { queue, spawner } = self;
+        drop(spawner);
+
+        while let Ok(task) = queue.recv() {
+            let mut slot = task.future.lock().unwrap();
+            if let Some(mut future) = slot.take() {
+                let waker = Waker::from(task.clone());
+                let mut cx = Context::from_waker(&waker);
+                if future.as_mut().poll(&mut cx).is_pending() {
+                    *slot = Some(future);
+                }
+            }
+        }
+    }
+}
+
+/*
+ * Two delays joined take as long as the longest one, not the sum.
+ * Returns how long it took.
+ * */
+pub fn demo_join() -> Duration {
+    let start = Instant::now();
+    block_on(join(
+        Delay::new(Duration::from_millis(50)),
+        Delay::new(Duration::from_millis(50)),
+    ));
+    start.elapsed()
+}
+
+/*
+ * Three tasks sleeping for different times on the executor, they finish
+ * shortest first even though they were spawned longest first.
+ * Returns the order they finished in.
+ * */
+pub fn demo_executor() -> Vec<u64> {
+    let executor = Executor::new();
+    let spawner = executor.spawner();
+    let finished = Arc::new(Mutex::new(Vec::new()));
+
+    for id in 0..3 {
+        let finished = finished.clone();
+        spawner.spawn(async move {
+            Delay::new(Duration::from_millis(30 - id * 10)).await;
+            finished.lock().unwrap().push(id);
+        });
+    }
+
+    // Otherwise run() would wait forever for tasks from this spawner
+    drop(spawner);
+    executor.run();
+
+    let finished = finished.lock().unwrap();
+    finished.clone()
+}
@@ -0,0 +1,219 @@
+/*
+ * Deadlocks with two mutexes, from chapter 16's warning,
+ * https://doc.rust-lang.org/book/ch16-03-shared-state.html#similarities-between-refcelltrc-and-mutexarct
+ *
+ * Two threads transfer money between the same two accounts in opposite
+ * directions. Each locks its source account and then its destination,
+ * so if both get their first lock before either gets its second one,
+ * each waits forever on the mutex the other one holds.
+ *
+ * The fixes below either always lock the accounts in the same order, or
+ * never block on the second lock and start over when it is taken.
+ * */
+use std::fmt;
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Mutex, MutexGuard, TryLockError};
+use std::thread;
+use std::time::Duration;
+
+pub struct Account {
+    id: u32,
+    balance: Mutex<i64>,
+}
+
+impl Account {
+    pub fn new(id: u32, balance: i64) -> Account {
+        Account {
+            id,
+            balance: Mutex::new(balance),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn balance(&self) -> i64 {
+        *self.balance.lock().unwrap()
+    }
+}
+
+// Returned by the watchdog when the work didn't finish in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hung(pub Duration);
+
+impl fmt::Display for Hung {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "still not done after {:?}, probably deadlocked", self.0)
+    }
+}
+
+impl std::error::Error for Hung {}
+
+/*
+ * Runs `work` on its own thread and waits at most `timeout` for it.
+ *
+ * A deadlocked thread can't be stopped from the outside, so when this
+ * returns Err(Hung) the worker and whatever it holds are leaked until
+ * the process exits.
+ * */
+pub fn with_watchdog<T, F>(timeout: Duration, work: F) -> Result<T, Hung>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        // The watchdog may have given up already, nobody to tell then
+        let _ = tx.send(work());
+    });
+
+    rx.recv_timeout(timeout).map_err(|_| Hung(timeout))
+}
+
+// Locks source then destination, deadlock-prone
+pub fn transfer_naive(from: &Account, to: &Account, amount: i64) {
+    let mut source = from.balance.lock().unwrap();
+    let mut destination = to.balance.lock().unwrap();
+    *source -= amount;
+    *destination += amount;
+}
+
+/*
+ * Builds the deadlock on purpose: the barrier makes both threads hold
+ * their first lock before any of them asks for the second one, so the
+ * hang doesn't depend on scheduling luck.
+ * */
+/// ```
+/// use rust_exercises::chapters::deadlock::{provoke_deadlock, Hung};
+/// use std::time::Duration;
+///
+/// let timeout = Duration::from_millis(200);
+/// assert_eq!(provoke_deadlock(timeout), Err(Hung(timeout)));
+/// ```
+pub fn provoke_deadlock(timeout: Duration) -> Result<(), Hung> {
+    with_watchdog(timeout, || {
+        let a = Arc::new(Account::new(1, 100));
+        let b = Arc::new(Account::new(2, 100));
+        let barrier = Arc::new(Barrier::new(2));
+
+        let handles: Vec<_> = vec![(a.clone(), b.clone()), (b, a)]
+            .into_iter()
+            .map(|(from, to)| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let mut source = from.balance.lock().unwrap();
+                    barrier.wait();
+                    let mut destination = to.balance.lock().unwrap();
+                    *source -= 10;
+                    *destination += 10;
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    })
+}
+
+/*
+ * Fix 1: every thread locks the account with the lowest id first.
+ * Whoever gets it can always go on and get the other one, since nobody
+ * holds the second lock while waiting on the first.
+ * */
+/// ```
+/// use rust_exercises::chapters::deadlock::{run_opposite_transfers, transfer_ordered};
+/// use std::time::Duration;
+///
+/// let balances = run_opposite_transfers(transfer_ordered, 1_000, Duration::from_secs(10));
+/// // 1_000 rounds of 1 one way and 2 the other
+/// assert_eq!(balances, Ok((2_000, 0)));
+/// ```
+pub fn transfer_ordered(from: &Account, to: &Account, amount: i64) {
+    assert_ne!(from.id, to.id, "transfer to the same account");
+
+    let (mut source, mut destination);
+    if from.id < to.id {
+        source = from.balance.lock().unwrap();
+        destination = to.balance.lock().unwrap();
+    } else {
+        destination = to.balance.lock().unwrap();
+        source = from.balance.lock().unwrap();
+    }
+    *source -= amount;
+    *destination += amount;
+}
+
+/*
+ * Fix 2: only block on the first lock, try_lock the second one and if
+ * it is taken, let go of the first and retry after a growing pause so
+ * the two threads stop bumping into each other.
+ *
+ * Returns how many attempts it took.
+ * */
+pub fn transfer_backoff(from: &Account, to: &Account, amount: i64) -> u32 {
+    // try_lock on the account already locked would fail forever
+    assert_ne!(from.id, to.id, "transfer to the same account");
+
+    let mut pause = Duration::from_micros(1);
+    let mut attempts = 1;
+
+    loop {
+        let mut source = from.balance.lock().unwrap();
+        if let Ok(mut destination) = try_lock_balance(to) {
+            *source -= amount;
+            *destination += amount;
+            return attempts;
+        }
+        drop(source);
+
+        thread::sleep(pause);
+        pause = (pause * 2).min(Duration::from_millis(1));
+        attempts += 1;
+    }
+}
+
+// try_lock without the poisoned case, a panicking transfer is a bug here
+fn try_lock_balance(account: &Account) -> Result<MutexGuard<'_, i64>, ()> {
+    match account.balance.try_lock() {
+        Ok(guard) => Ok(guard),
+        Err(TryLockError::WouldBlock) => Err(()),
+        Err(TryLockError::Poisoned(e)) => panic!("{}", e),
+    }
+}
+
+/*
+ * Same scenario as provoke_deadlock, two threads transferring in
+ * opposite directions `rounds` times, with the given transfer function.
+ * Returns the final balances of both accounts.
+ * */
+pub fn run_opposite_transfers(
+    transfer: fn(&Account, &Account, i64),
+    rounds: usize,
+    timeout: Duration,
+) -> Result<(i64, i64), Hung> {
+    with_watchdog(timeout, move || {
+        let a = Arc::new(Account::new(1, 1_000));
+        let b = Arc::new(Account::new(2, 1_000));
+        let barrier = Arc::new(Barrier::new(2));
+
+        let handles: Vec<_> = vec![(a.clone(), b.clone(), 1), (b.clone(), a.clone(), 2)]
+            .into_iter()
+            .map(|(from, to, amount)| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    for _ in 0..rounds {
+                        transfer(&from, &to, amount);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        (a.balance(), b.balance())
+    })
+}
@@ -0,0 +1,98 @@
+/*
+ * Calling C from Rust, chapter 19's extern "C" example taken further,
+ * https://doc.rust-lang.org/book/ch19-01-unsafe-rust.html#using-extern-functions-to-call-external-code
+ *
+ * abs and strlen come from the C standard library, the ffi_* functions
+ * from c/ffi_helpers.c which build.rs compiles and links in.
+ *
+ * The compiler can't check anything on the other side of an extern
+ * block, so every call is unsafe. Each one is wrapped in a safe function
+ * that upholds what the C side expects: valid pointers, correct lengths,
+ * NUL terminated strings and no integer overflow.
+ * */
+use std::ffi::{CStr, CString, NulError};
+use std::os::raw::{c_char, c_int};
+
+extern "C" {
+    fn abs(input: c_int) -> c_int;
+    fn strlen(s: *const c_char) -> usize;
+
+    fn ffi_sum(values: *const i32, len: usize) -> i64;
+    fn ffi_greet(name: *const c_char, buf: *mut c_char, cap: usize) -> usize;
+    fn ffi_map(values: *mut i32, len: usize, f: extern "C" fn(i32) -> i32);
+}
+
+/*
+ * abs(INT_MIN) is undefined behavior in C since the result doesn't fit,
+ * so that case never reaches C
+ * */
+pub fn c_abs(n: i32) -> Option<i32> {
+    if n == i32::MIN {
+        return None;
+    }
+    // SAFETY: abs has no preconditions for any other int
+    Some(unsafe { abs(n) })
+}
+
+pub fn c_strlen(s: &CStr) -> usize {
+    // SAFETY: a CStr always points to a NUL terminated string
+    unsafe { strlen(s.as_ptr()) }
+}
+
+pub fn c_sum(values: &[i32]) -> i64 {
+    // SAFETY: the pointer is valid for len reads, C only reads them
+    unsafe { ffi_sum(values.as_ptr(), values.len()) }
+}
+
+/*
+ * Fails if name contains a NUL byte, C would see a shorter string.
+ * Called twice when the first buffer is too small: the C function
+ * returns the length it needed, snprintf style.
+ * */
+/// ```
+/// use rust_exercises::chapters::ffi::c_greet;
+///
+/// assert_eq!(c_greet("Ferris").unwrap(), "Hello, Ferris!");
+/// // Longer than the first buffer, so C is called a second time
+/// assert_eq!(c_greet(&"a".repeat(40)).unwrap().len(), 48);
+/// assert!(c_greet("nul\0byte").is_err());
+/// ```
+pub fn c_greet(name: &str) -> Result<String, NulError> {
+    let name = CString::new(name)?;
+    let mut buf = vec![0u8; 16];
+
+    loop {
+        // SAFETY: name is NUL terminated and buf is valid for buf.len() writes
+        let needed =
+            unsafe { ffi_greet(name.as_ptr(), buf.as_mut_ptr() as *mut c_char, buf.len()) };
+        if needed < buf.len() {
+            let greeting =
+                CStr::from_bytes_until_nul(&buf).expect("C always terminates the string");
+            return Ok(greeting.to_string_lossy().into_owned());
+        }
+        buf.resize(needed + 1, 0);
+    }
+}
+
+/*
+ * C can only call plain extern "C" functions, not closures, so the
+ * callback is a function pointer. A panic must not unwind into C, which
+ * an extern "C" fn guarantees by aborting instead.
+ * */
+/// ```
+/// use rust_exercises::chapters::ffi::{c_abs, c_map, c_sum, double};
+///
+/// let mut values = [1, -2, 3];
+/// c_map(&mut values, double);
+/// assert_eq!(values, [2, -4, 6]);
+/// assert_eq!(c_sum(&values), 4);
+/// assert_eq!((c_abs(-4), c_abs(i32::MIN)), (Some(4), None));
+/// ```
+pub fn c_map(values: &mut [i32], f: extern "C" fn(i32) -> i32) {
+    // SAFETY: the pointer is valid for len reads and writes
+    unsafe { ffi_map(values.as_mut_ptr(), values.len(), f) }
+}
+
+pub extern "C" fn double(n: i32) -> i32 {
+    n.wrapping_mul(2)
+}
@@ -0,0 +1,122 @@
+/*
+ * Reading and writing files, past chapter 12's fs::read_to_string,
+ * https://doc.rust-lang.org/std/fs/index.html
+ *
+ * Every function takes the paths to work on. TempDir, from
+ * extras::temp_dir, gives a scratch directory that is removed again when
+ * it's dropped.
+ * */
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+
+pub use crate::extras::temp_dir::TempDir;
+
+/*
+ * Unbuffered, every writeln! would be its own write system call.
+ * BufWriter collects them and writes in large blocks, flush() makes sure
+ * the last block is written and reports its error, which drop can't.
+ * */
+pub fn write_lines<P: AsRef<Path>>(path: P, lines: &[&str]) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for line in lines {
+        writeln!(writer, "{}", line)?;
+    }
+    writer.flush()
+}
+
+// Whole file in memory at once, simplest when it's known to be small
+pub fn read_all<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let mut contents = String::new();
+    File::open(path)?.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+// One line at a time, memory use doesn't grow with the file
+pub fn count_matching_lines<P: AsRef<Path>>(path: P, needle: &str) -> io::Result<usize> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut count = 0;
+    for line in reader.lines() {
+        if line?.contains(needle) {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub fn read_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    BufReader::new(File::open(path)?).lines().collect()
+}
+
+// Creates the file the first time, adds to the end after that
+pub fn append_line<P: AsRef<Path>>(path: P, line: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/*
+ * Streams src into dst uppercased, line by line, and returns how many
+ * lines were copied. Lines are read into one reused String.
+ * */
+/// ```
+/// use rust_exercises::chapters::file_io::{copy_uppercase, read_lines, write_lines, TempDir};
+///
+/// let dir = TempDir::new("file_io_doc").unwrap();
+/// write_lines(dir.join("in.txt"), &["hello", "world"]).unwrap();
+/// assert_eq!(copy_uppercase(dir.join("in.txt"), dir.join("out.txt")).unwrap(), 2);
+/// assert_eq!(read_lines(dir.join("out.txt")).unwrap(), ["HELLO", "WORLD"]);
+/// ```
+pub fn copy_uppercase<P: AsRef<Path>, Q: AsRef<Path>>(src: P, dst: Q) -> io::Result<usize> {
+    let mut reader = BufReader::new(File::open(src)?);
+    let mut writer = BufWriter::new(File::create(dst)?);
+    let mut line = String::new();
+    let mut lines = 0;
+
+    while reader.read_line(&mut line)? > 0 {
+        writer.write_all(line.to_uppercase().as_bytes())?;
+        line.clear();
+        lines += 1;
+    }
+
+    writer.flush()?;
+    Ok(lines)
+}
+
+/*
+ * Matching on the ErrorKind: a missing file isn't an error here, it
+ * means use the default, anything else still is
+ * */
+/// ```
+/// use rust_exercises::chapters::file_io::{describe_error, read_all, read_or_default, TempDir};
+///
+/// let dir = TempDir::new("file_io_doc").unwrap();
+/// assert_eq!(read_or_default(dir.join("missing.toml"), "x = 1").unwrap(), "x = 1");
+/// let error = read_all(dir.join("missing.toml")).unwrap_err();
+/// assert_eq!(describe_error(&error), "no such file");
+/// ```
+pub fn read_or_default<P: AsRef<Path>>(path: P, default: &str) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(default.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn describe_error(error: &io::Error) -> &'static str {
+    match error.kind() {
+        ErrorKind::NotFound => "no such file",
+        ErrorKind::PermissionDenied => "permission denied",
+        ErrorKind::AlreadyExists => "already exists",
+        ErrorKind::InvalidData => "not valid UTF-8",
+        _ => "other I/O error",
+    }
+}
+
+/*
+ * create_new fails with AlreadyExists instead of truncating, so two
+ * callers can't both think they created the file
+ * */
+pub fn create_exclusive<P: AsRef<Path>>(path: P, contents: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    file.write_all(contents.as_bytes())
+}
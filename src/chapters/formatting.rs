@@ -0,0 +1,147 @@
+/*
+ * Chapter 5's Rectangle printed with hand-written Display and Debug
+ * instead of #[derive(Debug)],
+ * https://doc.rust-lang.org/book/ch05-02-example-structs.html
+ * https://doc.rust-lang.org/std/fmt/index.html
+ *
+ * Grid goes further with multi-line output, every column right-aligned
+ * to its widest cell with {:>width$}.
+ * */
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    pub fn area(&self) -> u32 {
+        self.width * self.height
+    }
+}
+
+/// Width by height, the way people write it.
+///
+/// ```
+/// use rust_exercises::chapters::formatting::Rectangle;
+///
+/// let rect = Rectangle { width: 30, height: 50 };
+/// assert_eq!(rect.to_string(), "30x50");
+/// // Padding applies to the whole thing
+/// assert_eq!(format!("[{:>7}]", rect), "[  30x50]");
+/// ```
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // pad() honors width and alignment, write! would ignore them
+        f.pad(&format!("{}x{}", self.width, self.height))
+    }
+}
+
+/// Like the derived one, plus the area, which isn't a field.
+/// debug_struct also gives `{:#?}` its one-field-per-line form for free.
+///
+/// ```
+/// use rust_exercises::chapters::formatting::Rectangle;
+///
+/// let rect = Rectangle { width: 3, height: 4 };
+/// assert_eq!(
+///     format!("{:?}", rect),
+///     "Rectangle { width: 3, height: 4, area: 12 }"
+/// );
+/// assert_eq!(
+///     format!("{:#?}", rect),
+///     "Rectangle {\n    width: 3,\n    height: 4,\n    area: 12,\n}"
+/// );
+/// ```
+impl fmt::Debug for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Rectangle")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("area", &self.area())
+            .finish()
+    }
+}
+
+/*
+ * Rows of cells, rows may have different lengths
+ * */
+#[derive(Clone, PartialEq)]
+pub struct Grid<T> {
+    rows: Vec<Vec<T>>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: Vec<Vec<T>>) -> Grid<T> {
+        Grid { rows }
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        &self.rows
+    }
+
+    // The length of the longest row
+    pub fn columns(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+/// One row per line, cells separated by a space and right-aligned to the
+/// widest cell of their column.
+///
+/// ```
+/// use rust_exercises::chapters::formatting::Grid;
+///
+/// let grid = Grid::new(vec![vec![1, 200, 3], vec![-40, 5, 6]]);
+/// assert_eq!(grid.to_string(), "  1 200 3\n-40   5 6\n");
+///
+/// let short_row = Grid::new(vec![vec!["a", "bb"], vec!["ccc"]]);
+/// assert_eq!(short_row.to_string(), "  a bb\nccc\n");
+/// ```
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Every cell is formatted once up front, the widths depend on all of them
+        let cells: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect();
+
+        let mut widths = vec![0; self.columns()];
+        for row in cells.iter() {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(cell.chars().count());
+            }
+        }
+
+        for row in cells.iter() {
+            for (column, cell) in row.iter().enumerate() {
+                if column > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{:>width$}", cell, width = widths[column])?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// The shape only, a big grid would bury the output. `..` says there is
+/// more to it than what's shown.
+///
+/// ```
+/// use rust_exercises::chapters::formatting::Grid;
+///
+/// let grid = Grid::new(vec![vec![1, 2, 3], vec![4]]);
+/// assert_eq!(format!("{:?}", grid), "Grid { rows: 2, columns: 3, .. }");
+/// ```
+impl<T> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("rows", &self.rows.len())
+            .field("columns", &self.columns())
+            .finish_non_exhaustive()
+    }
+}
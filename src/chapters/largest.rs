@@ -0,0 +1,45 @@
+/*
+ * Chapter 10's largest functions, from the exercises binary, in the
+ * library so they can be called and documented with runnable examples,
+ * https://doc.rust-lang.org/book/ch10-01-syntax.html
+ *
+ * Unlike the book's versions they return None for an empty list rather
+ * than panicking on list[0]. The code itself is in core_utils::largest,
+ * which builds without std.
+ * */
+use crate::core_utils::largest;
+
+/// Largest item of the list, by reference so `T` needs no `Copy`.
+///
+/// ```
+/// use rust_exercises::chapters::largest::largest_generic;
+///
+/// let words = vec![String::from("apple"), String::from("pear")];
+/// assert_eq!(largest_generic(&words), Some(&String::from("pear")));
+/// assert_eq!(largest_generic::<i32>(&[]), None);
+/// ```
+pub fn largest_generic<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    largest::largest(list)
+}
+
+/// Largest item of the list, copied out of it.
+///
+/// ```
+/// use rust_exercises::chapters::largest::largest_generic_copy;
+///
+/// assert_eq!(largest_generic_copy(&[34, 50, 25, 100, 65]), Some(100));
+/// assert_eq!(largest_generic_copy(&['y', 'm', 'a', 'q']), Some('y'));
+/// ```
+///
+/// With floats, NaN is never larger than anything, so it only wins
+/// when it comes first:
+///
+/// ```
+/// use rust_exercises::chapters::largest::largest_generic_copy;
+///
+/// assert_eq!(largest_generic_copy(&[1.0, f64::NAN, 2.0]), Some(2.0));
+/// assert!(largest_generic_copy(&[f64::NAN, 1.0]).unwrap().is_nan());
+/// ```
+pub fn largest_generic_copy<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    largest::largest_copy(list)
+}
@@ -0,0 +1,133 @@
+/*
+ * Chapter 3's loops, as functions returning what the book prints,
+ * https://doc.rust-lang.org/book/ch03-05-control-flow.html#repetition-with-loops
+ *
+ *   countdown       while, the liftoff example
+ *   countdown_rev   the same with for over a reversed range
+ *   sum_inclusive   ..= ranges
+ *   find_in_grid    a labeled break out of two loops
+ *   retry           loop { break value } with a limit on the attempts
+ * */
+
+/// The numbers a `while` loop counts down through, from `from` to 1.
+///
+/// ```
+/// use rust_exercises::chapters::loops::countdown;
+///
+/// assert_eq!(countdown(3), vec![3, 2, 1]);
+/// assert!(countdown(0).is_empty());
+/// ```
+pub fn countdown(from: u32) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    let mut number = from;
+    while number != 0 {
+        numbers.push(number);
+        number -= 1;
+    }
+    numbers
+}
+
+/// Same as [`countdown`] with `for` over `(1..=from).rev()`, no counter
+/// to get wrong.
+///
+/// ```
+/// use rust_exercises::chapters::loops::{countdown, countdown_rev};
+///
+/// assert_eq!(countdown_rev(3), vec![3, 2, 1]);
+/// assert_eq!(countdown_rev(10), countdown(10));
+/// ```
+pub fn countdown_rev(from: u32) -> Vec<u32> {
+    let mut numbers = Vec::new();
+    for number in (1..=from).rev() {
+        numbers.push(number);
+    }
+    numbers
+}
+
+/// Sum of every number from `start` to `end`, both included. `start..end`
+/// would leave `end` out.
+///
+/// ```
+/// use rust_exercises::chapters::loops::sum_inclusive;
+///
+/// assert_eq!(sum_inclusive(1, 4), 10);
+/// assert_eq!(sum_inclusive(5, 5), 5);
+/// // An empty range when start is past end
+/// assert_eq!(sum_inclusive(4, 1), 0);
+/// ```
+pub fn sum_inclusive(start: i64, end: i64) -> i64 {
+    let mut sum = 0;
+    for n in start..=end {
+        sum += n;
+    }
+    sum
+}
+
+/// Row and column of the first cell equal to `target`, rows first.
+///
+/// A plain `break` would only leave the inner loop and the search
+/// would go on with the next row, `break 'rows` leaves both.
+///
+/// ```
+/// use rust_exercises::chapters::loops::find_in_grid;
+///
+/// let grid = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 5, 9]];
+/// assert_eq!(find_in_grid(&grid, &5), Some((1, 1)));
+/// assert_eq!(find_in_grid(&grid, &8), None);
+/// ```
+pub fn find_in_grid<T: PartialEq>(grid: &[Vec<T>], target: &T) -> Option<(usize, usize)> {
+    let mut found = None;
+    'rows: for (row, cells) in grid.iter().enumerate() {
+        for (column, cell) in cells.iter().enumerate() {
+            if cell == target {
+                found = Some((row, column));
+                break 'rows;
+            }
+        }
+    }
+    found
+}
+
+/// Calls `attempt` with 1, 2, 3... until it succeeds or `max_attempts`
+/// calls have failed, and gives back the success or the last error.
+/// It always makes at least one call, even with `max_attempts` 0.
+///
+/// The `loop` is an expression: `break value` ends it and `value` is
+/// what it evaluates to.
+///
+/// ```
+/// use rust_exercises::chapters::loops::retry;
+///
+/// // Succeeds on the third try
+/// let result: Result<u32, String> = retry(5, |n| {
+///     if n == 3 {
+///         Ok(n * 10)
+///     } else {
+///         Err(format!("try {} failed", n))
+///     }
+/// });
+/// assert_eq!(result, Ok(30));
+///
+/// // Gives up after two
+/// let mut calls = 0;
+/// let result: Result<(), &str> = retry(2, |_| {
+///     calls += 1;
+///     Err("still down")
+/// });
+/// assert_eq!(result, Err("still down"));
+/// assert_eq!(calls, 2);
+/// ```
+pub fn retry<T, E, F>(max_attempts: u32, mut attempt: F) -> Result<T, E>
+where
+    F: FnMut(u32) -> Result<T, E>,
+{
+    let mut n = 0;
+    loop {
+        n += 1;
+        match attempt(n) {
+            Ok(value) => break Ok(value),
+            Err(e) if n >= max_attempts => break Err(e),
+            Err(_) => continue,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * The search functions of chapter 12's minigrep,
+ * https://doc.rust-lang.org/book/ch12-04-testing-the-librarys-functionality.html
+ *
+ * examples/minigrep.rs is the command line around them, and
+ * minigrep_async searches several files at once with the same matching.
+ * extras::pipeline::grep is the same search put together from stages.
+ * */
+use std::env;
+use std::error::Error;
+use std::fs;
+
+/// Lines of `contents` containing `query`, case-sensitive.
+///
+/// ```
+/// use rust_exercises::chapters::minigrep::search;
+///
+/// let contents = "Rust:\nsafe, fast, productive.\nPick three.\nDuct tape.";
+/// assert_eq!(search("duct", contents), vec!["safe, fast, productive."]);
+/// ```
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents.lines().filter(|line| line.contains(query)).collect()
+}
+
+/// Same as [`search`], ignoring case.
+///
+/// ```
+/// use rust_exercises::chapters::minigrep::search_case_insensitive;
+///
+/// let contents = "Rust:\nsafe, fast, productive.\nPick three.\nTrust me.";
+/// assert_eq!(search_case_insensitive("rUsT", contents), vec!["Rust:", "Trust me."]);
+/// ```
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub query: String,
+    pub file_path: String,
+    pub ignore_case: bool,
+}
+
+impl Config {
+    /// Builds the config from the program arguments, the first one being
+    /// the program name. Setting `IGNORE_CASE` makes the search
+    /// case-insensitive.
+    ///
+    /// ```
+    /// use rust_exercises::chapters::minigrep::Config;
+    ///
+    /// let args = ["minigrep", "to", "poem.txt"].iter().map(|s| s.to_string());
+    /// let config = Config::build(args).unwrap();
+    /// assert_eq!(config.query, "to");
+    /// assert_eq!(config.file_path, "poem.txt");
+    ///
+    /// assert!(Config::build(vec![String::from("minigrep")].into_iter()).is_err());
+    /// ```
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+        args.next();
+
+        let query = args.next().ok_or("Didn't get a query string")?;
+        let file_path = args.next().ok_or("Didn't get a file path")?;
+        let ignore_case = env::var("IGNORE_CASE").is_ok();
+
+        Ok(Config {
+            query,
+            file_path,
+            ignore_case,
+        })
+    }
+}
+
+/// Reads the file and returns the matching lines.
+pub fn run(config: &Config) -> Result<Vec<String>, Box<dyn Error>> {
+    let contents = fs::read_to_string(&config.file_path)?;
+
+    let results = if config.ignore_case {
+        search_case_insensitive(&config.query, &contents)
+    } else {
+        search(&config.query, &contents)
+    };
+    Ok(results.into_iter().map(String::from).collect())
+}
@@ -0,0 +1,156 @@
+/*
+ * Chapter 12's minigrep over several files at once, each one read and
+ * searched by its own tokio task with a buffered async reader,
+ * https://doc.rust-lang.org/book/ch12-00-an-io-project.html
+ *
+ * Matches are streamed over a channel as soon as a task finds them,
+ * tagged with the file they come from, so lines of different files
+ * interleave while the lines of one file stay in order.
+ *
+ * Only built with the async-rt feature.
+ * */
+use crate::chapters::minigrep;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub query: String,
+    pub paths: Vec<PathBuf>,
+    pub case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub path: PathBuf,
+    pub line_number: usize,
+    pub line: String,
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line_number, self.line)
+    }
+}
+
+// A file that couldn't be read, the other files are still searched
+#[derive(Debug)]
+pub struct FileError {
+    pub path: PathBuf,
+    pub error: io::Error,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.error)
+    }
+}
+
+impl std::error::Error for FileError {}
+
+// minigrep's search and search_case_insensitive, one line at a time
+pub fn line_matches(query: &str, line: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        !minigrep::search_case_insensitive(query, line).is_empty()
+    } else {
+        !minigrep::search(query, line).is_empty()
+    }
+}
+
+async fn search_file(
+    query: &str,
+    path: PathBuf,
+    case_insensitive: bool,
+    matches: &mpsc::Sender<Result<Match, FileError>>,
+) -> Result<(), FileError> {
+    let file = File::open(&path).await.map_err(|error| FileError {
+        path: path.clone(),
+        error,
+    })?;
+    let mut lines = BufReader::new(file).lines();
+    let mut line_number = 0;
+
+    loop {
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => return Ok(()),
+            Err(error) => return Err(FileError { path, error }),
+        };
+        line_number += 1;
+
+        if line_matches(query, &line, case_insensitive) {
+            let found = Match {
+                path: path.clone(),
+                line_number,
+                line,
+            };
+            // The receiver went away, no point reading further
+            if matches.send(Ok(found)).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/*
+ * Must be called from inside a tokio runtime. The channel closes once
+ * every file has been searched.
+ * */
+pub fn search(config: Config) -> mpsc::Receiver<Result<Match, FileError>> {
+    let (tx, rx) = mpsc::channel(64);
+
+    for path in config.paths {
+        let tx = tx.clone();
+        let query = config.query.clone();
+        let case_insensitive = config.case_insensitive;
+        tokio::spawn(async move {
+            if let Err(e) = search_file(&query, path, case_insensitive, &tx).await {
+                let _ = tx.send(Err(e)).await;
+            }
+        });
+    }
+
+    rx
+}
+
+// Waits for the whole search, matches sorted by file then line
+/// ```
+/// use rust_exercises::chapters::minigrep_async::{search_all, Config};
+/// use rust_exercises::extras::temp_dir::TempDir;
+/// use std::fs;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let dir = TempDir::new("minigrep_async_doc").unwrap();
+///     fs::write(dir.join("poem.txt"), "I'm nobody!\nWho are you?\nAre you nobody, too?\n").unwrap();
+///
+///     let config = Config {
+///         query: String::from("nobody"),
+///         paths: vec![dir.join("poem.txt"), dir.join("missing.txt")],
+///         case_insensitive: false,
+///     };
+///     let (matches, errors) = search_all(config).await;
+///     let lines: Vec<usize> = matches.iter().map(|m| m.line_number).collect();
+///     assert_eq!(lines, [1, 3]);
+///     assert_eq!(errors.len(), 1);
+/// }
+/// ```
+pub async fn search_all(config: Config) -> (Vec<Match>, Vec<FileError>) {
+    let mut rx = search(config);
+    let mut matches = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some(result) = rx.recv().await {
+        match result {
+            Ok(found) => matches.push(found),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    matches.sort_by(|a, b| (&a.path, a.line_number).cmp(&(&b.path, b.line_number)));
+    (matches, errors)
+}
@@ -0,0 +1,194 @@
+/*
+ * Every chapter module is behind the feature of its book chapter, all
+ * of them on by default through all-chapters. The ones needing a
+ * dependency also need that dependency's feature, like tokio_basics
+ * with async and async-rt.
+ * */
+#[cfg(feature = "async")]
+pub mod async_executor;
+#[cfg(feature = "ch16")]
+pub mod deadlock;
+#[cfg(feature = "ch19")]
+pub mod ffi;
+#[cfg(feature = "ch12")]
+pub mod file_io;
+#[cfg(feature = "ch5")]
+pub mod formatting;
+#[cfg(feature = "ch10")]
+pub mod largest;
+#[cfg(feature = "ch3")]
+pub mod loops;
+#[cfg(feature = "ch12")]
+pub mod minigrep;
+#[cfg(all(feature = "ch12", feature = "async-rt"))]
+pub mod minigrep_async;
+#[cfg(feature = "ch10")]
+pub mod numeric;
+#[cfg(all(feature = "ch16", feature = "parallel"))]
+pub mod rayon_basics;
+#[cfg(feature = "ch16")]
+pub mod scoped_threads;
+#[cfg(feature = "serde")]
+pub mod serde_basics;
+#[cfg(all(feature = "async", feature = "async-rt"))]
+pub mod tokio_basics;
+
+use std::fmt;
+use std::str::FromStr;
+
+/*
+ * Ordered, so a session can be limited to everything up to Core
+ * */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    // New to the idea, the book chapter is enough
+    Intro,
+    Core,
+    // Goes well past the book
+    Challenge,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Intro => "intro",
+            Difficulty::Core => "core",
+            Difficulty::Challenge => "challenge",
+        };
+        f.pad(name)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        match s.to_lowercase().as_str() {
+            "intro" => Ok(Difficulty::Intro),
+            "core" => Ok(Difficulty::Core),
+            "challenge" => Ok(Difficulty::Challenge),
+            _ => Err(format!(
+                "unknown difficulty {}, expected intro, core or challenge",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chapter {
+    // The features that have to be on for it to be compiled
+    pub features: &'static [&'static str],
+    pub module: &'static str,
+    pub title: &'static str,
+    pub difficulty: Difficulty,
+}
+
+/*
+ * The chapter modules compiled into this build, in module order, so
+ * whatever lists the chapters shows only the ones that are there
+ * */
+pub const CATALOG: &[Chapter] = &[
+    #[cfg(feature = "async")]
+    Chapter {
+        features: &["async"],
+        module: "async_executor",
+        title: "A futures executor from scratch",
+        difficulty: Difficulty::Challenge,
+    },
+    #[cfg(feature = "ch16")]
+    Chapter {
+        features: &["ch16"],
+        module: "scoped_threads",
+        title: "Threads borrowing from their parent",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(feature = "ch16")]
+    Chapter {
+        features: &["ch16"],
+        module: "deadlock",
+        title: "Deadlocks and how to avoid them",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(feature = "ch19")]
+    Chapter {
+        features: &["ch19"],
+        module: "ffi",
+        title: "Calling C from Rust",
+        difficulty: Difficulty::Challenge,
+    },
+    #[cfg(feature = "ch12")]
+    Chapter {
+        features: &["ch12"],
+        module: "file_io",
+        title: "Reading and writing files",
+        difficulty: Difficulty::Intro,
+    },
+    #[cfg(feature = "ch3")]
+    Chapter {
+        features: &["ch3"],
+        module: "loops",
+        title: "Loops, labeled breaks and ranges",
+        difficulty: Difficulty::Intro,
+    },
+    #[cfg(feature = "ch5")]
+    Chapter {
+        features: &["ch5"],
+        module: "formatting",
+        title: "Display and Debug by hand",
+        difficulty: Difficulty::Intro,
+    },
+    #[cfg(feature = "ch10")]
+    Chapter {
+        features: &["ch10"],
+        module: "largest",
+        title: "Generic largest",
+        difficulty: Difficulty::Intro,
+    },
+    #[cfg(feature = "ch10")]
+    Chapter {
+        features: &["ch10"],
+        module: "numeric",
+        title: "Generic sums with a Numeric trait",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(feature = "ch12")]
+    Chapter {
+        features: &["ch12"],
+        module: "minigrep",
+        title: "minigrep's search functions",
+        difficulty: Difficulty::Intro,
+    },
+    #[cfg(all(feature = "async", feature = "async-rt"))]
+    Chapter {
+        features: &["async", "async-rt"],
+        module: "tokio_basics",
+        title: "The same with tokio",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(all(feature = "ch16", feature = "parallel"))]
+    Chapter {
+        features: &["ch16", "parallel"],
+        module: "rayon_basics",
+        title: "Data parallelism with rayon",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(all(feature = "ch12", feature = "async-rt"))]
+    Chapter {
+        features: &["ch12", "async-rt"],
+        module: "minigrep_async",
+        title: "minigrep over many files with tokio",
+        difficulty: Difficulty::Core,
+    },
+    #[cfg(feature = "serde")]
+    Chapter {
+        features: &["serde"],
+        module: "serde_basics",
+        title: "Serialization with serde",
+        difficulty: Difficulty::Intro,
+    },
+];
+
+pub fn is_compiled(module: &str) -> bool {
+    CATALOG.iter().any(|chapter| chapter.module == module)
+}
@@ -0,0 +1,148 @@
+/*
+ * Chapter 10's generics taken from comparing to arithmetic,
+ * https://doc.rust-lang.org/book/ch10-02-traits.html#trait-bound-syntax
+ *
+ * largest only needs PartialOrd, but a generic sum can't be written with
+ * it: PartialOrd compares two values, it can't add them, and it has no
+ * zero to start from. There's no trait in std for "any number" either,
+ * so this one is local, with just what the functions below use, and
+ * implemented for every primitive number type by a macro.
+ * */
+
+pub trait Numeric: Copy + PartialOrd {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    // Division by a count, for averages. Integer types round toward zero.
+    fn div_usize(self, n: usize) -> Self;
+}
+
+macro_rules! impl_numeric {
+    ($zero:expr, $one:expr, $($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn zero() -> $t {
+                    $zero
+                }
+
+                fn one() -> $t {
+                    $one
+                }
+
+                fn add(self, other: $t) -> $t {
+                    self + other
+                }
+
+                fn mul(self, other: $t) -> $t {
+                    self * other
+                }
+
+                // A count too big for the type would wrap, it's clamped instead
+                fn div_usize(self, n: usize) -> $t {
+                    let n = if (n as u128) > (<$t>::MAX as u128) { <$t>::MAX } else { n as $t };
+                    self / n
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(0, 1, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Numeric for f32 {
+    fn zero() -> f32 {
+        0.0
+    }
+
+    fn one() -> f32 {
+        1.0
+    }
+
+    fn add(self, other: f32) -> f32 {
+        self + other
+    }
+
+    fn mul(self, other: f32) -> f32 {
+        self * other
+    }
+
+    fn div_usize(self, n: usize) -> f32 {
+        self / n as f32
+    }
+}
+
+impl Numeric for f64 {
+    fn zero() -> f64 {
+        0.0
+    }
+
+    fn one() -> f64 {
+        1.0
+    }
+
+    fn add(self, other: f64) -> f64 {
+        self + other
+    }
+
+    fn mul(self, other: f64) -> f64 {
+        self * other
+    }
+
+    fn div_usize(self, n: usize) -> f64 {
+        self / n as f64
+    }
+}
+
+/// Sum of the list, zero when it's empty.
+///
+/// ```
+/// use rust_exercises::chapters::numeric::sum_generic;
+///
+/// assert_eq!(sum_generic(&[1, 2, 3, 4]), 10);
+/// assert_eq!(sum_generic(&[0.5, 0.25]), 0.75);
+/// assert_eq!(sum_generic::<u8>(&[]), 0);
+/// ```
+pub fn sum_generic<T: Numeric>(list: &[T]) -> T {
+    let mut sum = T::zero();
+    for &item in list {
+        sum = sum.add(item);
+    }
+    sum
+}
+
+/// Product of the list, one when it's empty.
+///
+/// ```
+/// use rust_exercises::chapters::numeric::product_generic;
+///
+/// assert_eq!(product_generic(&[2, 3, 4]), 24);
+/// assert_eq!(product_generic(&[1.5, -2.0]), -3.0);
+/// assert_eq!(product_generic::<i64>(&[]), 1);
+/// ```
+pub fn product_generic<T: Numeric>(list: &[T]) -> T {
+    let mut product = T::one();
+    for &item in list {
+        product = product.mul(item);
+    }
+    product
+}
+
+/// Mean of the list, None when it's empty. The sum is taken in `T`, so
+/// it overflows the way `sum_generic` would.
+///
+/// ```
+/// use rust_exercises::chapters::numeric::average;
+///
+/// assert_eq!(average(&[1.0, 2.0, 4.5]), Some(2.5));
+/// // Integers round toward zero
+/// assert_eq!(average(&[1, 2]), Some(1));
+/// assert_eq!(average(&[-3, -4]), Some(-3));
+/// assert_eq!(average::<f64>(&[]), None);
+/// ```
+pub fn average<T: Numeric>(list: &[T]) -> Option<T> {
+    if list.is_empty() {
+        return None;
+    }
+    Some(sum_generic(list).div_usize(list.len()))
+}
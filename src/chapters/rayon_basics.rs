@@ -0,0 +1,91 @@
+/*
+ * Data parallelism with rayon, https://docs.rs/rayon
+ *
+ * Each task is written twice, sequentially and with par_iter. rayon
+ * splits the work between a pool of threads, so the parallel version is
+ * usually the sequential one with iter() replaced by par_iter(). Both must
+ * give the same result, only faster when there is enough work per item
+ * to pay for splitting it up (see benches/rayon.rs).
+ *
+ * Only built with the parallel feature:
+ *   cargo bench --features parallel --bench rayon
+ * */
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+pub fn sum_of_squares(numbers: &[u64]) -> u64 {
+    numbers.iter().map(|&n| n * n).sum()
+}
+
+pub fn sum_of_squares_par(numbers: &[u64]) -> u64 {
+    numbers.par_iter().map(|&n| n * n).sum()
+}
+
+fn count_words_into(mut counts: HashMap<String, usize>, line: &str) -> HashMap<String, usize> {
+    for word in line.split_whitespace() {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn word_count(lines: &[&str]) -> HashMap<String, usize> {
+    lines
+        .iter()
+        .fold(HashMap::new(), |counts, line| count_words_into(counts, line))
+}
+
+/*
+ * fold gives every thread its own HashMap to fill without locking,
+ * reduce then merges those maps two by two.
+ * */
+/// ```
+/// use rust_exercises::chapters::rayon_basics::{word_count, word_count_par};
+///
+/// let lines = ["the cat", "The hat", "a bat"];
+/// let counts = word_count_par(&lines);
+/// assert_eq!(counts["the"], 2);
+/// assert_eq!(counts, word_count(&lines));
+/// ```
+pub fn word_count_par(lines: &[&str]) -> HashMap<String, usize> {
+    lines
+        .par_iter()
+        .fold(HashMap::new, |counts, line| count_words_into(counts, line))
+        .reduce(HashMap::new, |mut merged, counts| {
+            for (word, count) in counts {
+                *merged.entry(word).or_insert(0) += count;
+            }
+            merged
+        })
+}
+
+// Luma from RGB with the ITU-R BT.601 weights
+fn gray(pixel: &[u8]) -> u8 {
+    let luma = 0.299 * pixel[0] as f32 + 0.587 * pixel[1] as f32 + 0.114 * pixel[2] as f32;
+    luma.round() as u8
+}
+
+fn gray_pixel(pixel: &mut [u8]) {
+    let luma = gray(pixel);
+    pixel[0] = luma;
+    pixel[1] = luma;
+    pixel[2] = luma;
+}
+
+/*
+ * Turns an RGB buffer (3 bytes per pixel) into shades of gray in place
+ * */
+pub fn grayscale(pixels: &mut [u8]) {
+    pixels.chunks_mut(3).for_each(gray_pixel);
+}
+
+// par_chunks_mut hands disjoint &mut pieces to threads, no locking needed
+/// ```
+/// use rust_exercises::chapters::rayon_basics::grayscale_par;
+///
+/// let mut pixels = [255, 255, 255, 255, 0, 0];
+/// grayscale_par(&mut pixels);
+/// assert_eq!(pixels, [255, 255, 255, 76, 76, 76]);
+/// ```
+pub fn grayscale_par(pixels: &mut [u8]) {
+    pixels.par_chunks_mut(3).for_each(gray_pixel);
+}
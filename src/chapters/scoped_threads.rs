@@ -0,0 +1,96 @@
+/*
+ * Borrowing data from threads with std::thread::scope,
+ * https://doc.rust-lang.org/std/thread/fn.scope.html
+ *
+ * thread::spawn needs 'static closures since the thread could outlive
+ * the caller, which is why chapter 16 moves data behind an Arc first.
+ * Threads spawned in a scope are all joined before scope() returns, so
+ * they can borrow local data, even mutably as long as the borrows don't
+ * overlap.
+ * */
+use std::sync::Arc;
+use std::thread;
+
+// Chunk length so that the data is split between at most `threads` pieces
+fn chunk_len(len: usize, threads: usize) -> usize {
+    assert!(threads > 0, "need at least one thread");
+    len.div_ceil(threads).max(1)
+}
+
+/*
+ * Every thread doubles its own chunk in place and returns the chunk's
+ * new sum. chunks_mut gives non-overlapping &mut slices, so the borrow
+ * checker accepts handing one to each thread.
+ * */
+/// ```
+/// use rust_exercises::chapters::scoped_threads::double_and_sum_scoped;
+///
+/// let mut data = [1, 2, 3, 4, 5];
+/// assert_eq!(double_and_sum_scoped(&mut data, 2), 30);
+/// assert_eq!(data, [2, 4, 6, 8, 10]);
+/// ```
+pub fn double_and_sum_scoped(data: &mut [i64], threads: usize) -> i64 {
+    let chunk_len = chunk_len(data.len(), threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = data
+            .chunks_mut(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    for value in chunk.iter_mut() {
+                        *value *= 2;
+                    }
+                    chunk.iter().sum::<i64>()
+                })
+            })
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    })
+}
+
+/*
+ * The chapter 16 way: the data has to be owned by an Arc to be shared,
+ * each thread gets a clone of the Arc and works out its own range.
+ * Mutating it would additionally need a Mutex, so this one only sums.
+ * */
+pub fn sum_arc(data: Vec<i64>, threads: usize) -> i64 {
+    let chunk_len = chunk_len(data.len(), threads);
+    let data = Arc::new(data);
+
+    let handles: Vec<_> = (0..data.len())
+        .step_by(chunk_len)
+        .map(|start| {
+            let data = Arc::clone(&data);
+            thread::spawn(move || {
+                let end = (start + chunk_len).min(data.len());
+                data[start..end].iter().sum::<i64>()
+            })
+        })
+        .collect();
+
+    handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+}
+
+/*
+ * The scoped version on shared data, no Arc and no cloning
+ * */
+/// ```
+/// use rust_exercises::chapters::scoped_threads::{sum_arc, sum_scoped};
+///
+/// let data: Vec<i64> = (1..=100).collect();
+/// assert_eq!(sum_scoped(&data, 4), 5050);
+/// assert_eq!(sum_arc(data, 3), 5050);
+/// ```
+pub fn sum_scoped(data: &[i64], threads: usize) -> i64 {
+    let chunk_len = chunk_len(data.len(), threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = data
+            .chunks(chunk_len)
+            .map(|chunk| scope.spawn(move || chunk.iter().sum::<i64>()))
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    })
+}
@@ -0,0 +1,194 @@
+/*
+ * Serialization with serde, https://serde.rs
+ *
+ * #[derive(Serialize, Deserialize)] generates the code that the
+ * hand-written extras::json parser leaves to its caller: walking a
+ * Value tree, checking every field's type and building the struct.
+ * The same derives work for any format with a serde implementation,
+ * here JSON and TOML.
+ *
+ * Only built with the serde feature:
+ *   cargo test --features serde
+ * */
+use crate::extras::json::{self, Value};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Position {
+    #[default]
+    Forward,
+    Defense,
+    Goalie,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Player {
+    pub name: String,
+    pub number: u8,
+    // Missing from the input means Position::default()
+    #[serde(default)]
+    pub position: Position,
+    #[serde(default, rename = "isCaptain")]
+    pub captain: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Team {
+    pub name: String,
+    // The field is called city in the serialized form
+    #[serde(rename = "city")]
+    pub home_city: String,
+    pub players: Vec<Player>,
+    // Derived data, never written and None after reading
+    #[serde(skip)]
+    pub rating: Option<f64>,
+}
+
+pub fn sample_team() -> Team {
+    Team {
+        name: String::from("Canadiens"),
+        home_city: String::from("Montreal"),
+        players: vec![
+            Player {
+                name: String::from("Nick Suzuki"),
+                number: 14,
+                position: Position::Forward,
+                captain: true,
+            },
+            Player {
+                name: String::from("Sam Montembeault"),
+                number: 35,
+                position: Position::Goalie,
+                captain: false,
+            },
+        ],
+        rating: Some(87.5),
+    }
+}
+
+/// ```
+/// use rust_exercises::chapters::serde_basics::{from_json, sample_team, to_json};
+///
+/// let team = sample_team();
+/// let json = to_json(&team).unwrap();
+/// assert!(json.contains("\"city\": \"Montreal\""));
+/// assert!(!json.contains("rating"));
+///
+/// let back = from_json(&json).unwrap();
+/// assert_eq!(back.players, team.players);
+/// assert_eq!(back.rating, None);
+/// ```
+pub fn to_json(team: &Team) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(team)
+}
+
+pub fn from_json(input: &str) -> serde_json::Result<Team> {
+    serde_json::from_str(input)
+}
+
+pub fn to_toml(team: &Team) -> Result<String, toml::ser::Error> {
+    toml::to_string(team)
+}
+
+pub fn from_toml(input: &str) -> Result<Team, toml::de::Error> {
+    toml::from_str(input)
+}
+
+/*
+ * The same job done with the hand-written parser, what the derive saves.
+ * Every missing field, wrong type and default has to be spelled out, and
+ * the errors say much less than serde_json's.
+ * */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManualError {
+    Parse(json::ParseError),
+    Missing(&'static str),
+    WrongType(&'static str),
+    UnknownPosition(String),
+}
+
+impl fmt::Display for ManualError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManualError::Parse(e) => write!(f, "{}", e),
+            ManualError::Missing(field) => write!(f, "missing field `{}`", field),
+            ManualError::WrongType(field) => write!(f, "wrong type for field `{}`", field),
+            ManualError::UnknownPosition(p) => write!(f, "unknown position {:?}", p),
+        }
+    }
+}
+
+impl std::error::Error for ManualError {}
+
+fn field<'a>(value: &'a Value, name: &'static str) -> Result<Option<&'a Value>, ManualError> {
+    match value {
+        Value::Object(fields) => Ok(fields.get(name)),
+        _ => Err(ManualError::WrongType(name)),
+    }
+}
+
+fn string_field(value: &Value, name: &'static str) -> Result<String, ManualError> {
+    match field(value, name)? {
+        Some(Value::String(s)) => Ok(s.clone()),
+        Some(_) => Err(ManualError::WrongType(name)),
+        None => Err(ManualError::Missing(name)),
+    }
+}
+
+fn player_from_value(value: &Value) -> Result<Player, ManualError> {
+    let number = match field(value, "number")? {
+        Some(&Value::Number(n)) if n.fract() == 0.0 && (0.0..=255.0).contains(&n) => n as u8,
+        Some(_) => return Err(ManualError::WrongType("number")),
+        None => return Err(ManualError::Missing("number")),
+    };
+    let position = match field(value, "position")? {
+        Some(Value::String(p)) => match p.as_str() {
+            "forward" => Position::Forward,
+            "defense" => Position::Defense,
+            "goalie" => Position::Goalie,
+            _ => return Err(ManualError::UnknownPosition(p.clone())),
+        },
+        Some(_) => return Err(ManualError::WrongType("position")),
+        None => Position::default(),
+    };
+    let captain = match field(value, "isCaptain")? {
+        Some(&Value::Bool(b)) => b,
+        Some(_) => return Err(ManualError::WrongType("isCaptain")),
+        None => false,
+    };
+
+    Ok(Player {
+        name: string_field(value, "name")?,
+        number,
+        position,
+        captain,
+    })
+}
+
+/// ```
+/// use rust_exercises::chapters::serde_basics::{from_json, from_json_by_hand, sample_team, to_json};
+///
+/// let json = to_json(&sample_team()).unwrap();
+/// assert_eq!(from_json_by_hand(&json).unwrap(), from_json(&json).unwrap());
+/// ```
+pub fn from_json_by_hand(input: &str) -> Result<Team, ManualError> {
+    let value = json::parse(input).map_err(ManualError::Parse)?;
+
+    let players = match field(&value, "players")? {
+        Some(Value::Array(players)) => players
+            .iter()
+            .map(player_from_value)
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(_) => return Err(ManualError::WrongType("players")),
+        None => return Err(ManualError::Missing("players")),
+    };
+
+    Ok(Team {
+        name: string_field(&value, "name")?,
+        home_city: string_field(&value, "city")?,
+        players,
+        rating: None,
+    })
+}
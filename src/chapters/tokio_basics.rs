@@ -0,0 +1,111 @@
+/*
+ * The same ideas as async_executor, with a real runtime doing the work,
+ * https://tokio.rs/tokio/tutorial
+ *
+ * Only built with the async-rt feature:
+ *   cargo run --features async-rt ...
+ * */
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{self, error::Elapsed};
+
+/*
+ * Spawns one task per number, each squaring it after a short sleep.
+ * JoinHandles are awaited in spawn order so the results come back in
+ * that order, whatever order the tasks finished in.
+ * */
+pub async fn spawn_squares(numbers: Vec<u64>) -> Vec<u64> {
+    let handles: Vec<_> = numbers
+        .into_iter()
+        .map(|n| {
+            tokio::spawn(async move {
+                time::sleep(Duration::from_millis(10 * (n % 3))).await;
+                n * n
+            })
+        })
+        .collect();
+
+    let mut squares = Vec::new();
+    for handle in handles {
+        // Err only if the task panicked
+        squares.push(handle.await.expect("task panicked"));
+    }
+    squares
+}
+
+/*
+ * select! waits on several futures and runs the branch of whichever
+ * finishes first, the other one is dropped (cancelled).
+ * */
+/// ```
+/// use rust_exercises::chapters::tokio_basics::{race, with_timeout};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     assert_eq!(race(10, 500).await, "first");
+///     assert_eq!(with_timeout(10, 500).await, Ok(10));
+///     assert!(with_timeout(500, 10).await.is_err());
+/// }
+/// ```
+pub async fn race(first_ms: u64, second_ms: u64) -> &'static str {
+    tokio::select! {
+        _ = time::sleep(Duration::from_millis(first_ms)) => "first",
+        _ = time::sleep(Duration::from_millis(second_ms)) => "second",
+    }
+}
+
+/*
+ * Gives up on work that takes longer than limit_ms
+ * */
+pub async fn with_timeout(work_ms: u64, limit_ms: u64) -> Result<u64, Elapsed> {
+    let work = async {
+        time::sleep(Duration::from_millis(work_ms)).await;
+        work_ms
+    };
+    time::timeout(Duration::from_millis(limit_ms), work).await
+}
+
+/*
+ * A producer task sends numbers over a bounded async channel, send()
+ * waits whenever the buffer of 4 is full. The channel closes when the
+ * producer finishes and drops its sender, ending the receiving loop.
+ * */
+/// ```
+/// use rust_exercises::chapters::tokio_basics::{channel_sum, spawn_squares};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     assert_eq!(spawn_squares(vec![1, 2, 3]).await, [1, 4, 9]);
+///     assert_eq!(channel_sum(10).await, 55);
+/// }
+/// ```
+pub async fn channel_sum(count: u32) -> u32 {
+    let (sender, mut receiver) = mpsc::channel(4);
+
+    tokio::spawn(async move {
+        for n in 1..=count {
+            if sender.send(n).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut sum = 0;
+    while let Some(n) = receiver.recv().await {
+        sum += n;
+    }
+    sum
+}
+
+/*
+ * #[tokio::main] isn't just for main: it turns any async fn into a
+ * regular one that starts a runtime and blocks on the body.
+ * */
+#[tokio::main]
+pub async fn demo() {
+    println!("squares {:?}", spawn_squares(vec![1, 2, 3, 4]).await);
+    println!("race won by {}", race(10, 50).await);
+    println!("fast work {:?}", with_timeout(10, 100).await);
+    println!("slow work {:?}", with_timeout(100, 10).await);
+    println!("sum over channel {}", channel_sum(100).await);
+}
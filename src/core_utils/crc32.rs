@@ -0,0 +1,112 @@
+/*
+ * CRC-32 as used by zip, gzip and PNG (reflected polynomial 0xEDB88320),
+ * checksum(b"123456789") == 0xCBF43926
+ *
+ * The bitwise algorithm handles one bit per step, the table holds the
+ * result of 8 such steps for every possible byte so update() only does
+ * one lookup per byte. The table is computed by a const fn at compile time.
+ * */
+use core::hash::Hasher;
+
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+const fn make_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    // No for loops in const fn, while does the job
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+static TABLE: [u32; 256] = make_table();
+
+/*
+ * Incremental version, feeding the data in several update() calls gives
+ * the same result as a single one.
+ * */
+/// ```
+/// use rust_exercises::core_utils::crc32::{checksum, Crc32};
+///
+/// let mut crc = Crc32::new();
+/// crc.update(b"12345");
+/// crc.update(b"6789");
+/// assert_eq!(crc.finalize(), 0xCBF4_3926);
+/// assert_eq!(checksum(b"123456789"), crc.finalize());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Crc32 {
+    // Kept inverted, the way the algorithm works on it
+    state: u32,
+}
+
+impl Default for Crc32 {
+    fn default() -> Crc32 {
+        Crc32::new()
+    }
+}
+
+impl Crc32 {
+    pub fn new() -> Crc32 {
+        Crc32 { state: !0 }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = ((self.state ^ byte as u32) & 0xff) as usize;
+            self.state = (self.state >> 8) ^ TABLE[index];
+        }
+    }
+
+    // Doesn't consume the hasher, more data can still be added after
+    pub fn finalize(&self) -> u32 {
+        !self.state
+    }
+}
+
+// So it can be used anywhere a Hasher is expected
+impl Hasher for Crc32 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.finalize() as u64
+    }
+}
+
+pub fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finalize()
+}
+
+/*
+ * Bit by bit reference implementation, the table version must agree
+ * */
+pub fn checksum_bitwise(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,87 @@
+/*
+ * A max heap in a Vec, the largest item always at index 0, without
+ * std::collections::BinaryHeap. The children of i are at 2i + 1 and
+ * 2i + 2, the same layout heap_sort sorts in, so popping uses its
+ * sift_down. The whole module needs alloc.
+ *
+ *   push  O(log n), added at the end and swapped up past smaller parents
+ *   pop   O(log n), the last item takes the root's place and sinks
+ *   peek  O(1)
+ * */
+use super::sort::sift_down;
+use alloc::vec::Vec;
+
+/// ```
+/// use rust_exercises::core_utils::heap::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::new();
+/// for n in [3, 1, 4, 1, 5] {
+///     heap.push(n);
+/// }
+/// assert_eq!(heap.peek(), Some(&5));
+/// heap.retain(|&n| n != 4);
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(3));
+/// assert_eq!(heap.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T> {
+    items: Vec<T>,
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> BinaryHeap<T> {
+        BinaryHeap::new()
+    }
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> BinaryHeap<T> {
+        BinaryHeap { items: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        let mut child = self.items.len() - 1;
+        while child > 0 {
+            let parent = (child - 1) / 2;
+            if self.items[parent] >= self.items[child] {
+                break;
+            }
+            self.items.swap(parent, child);
+            child = parent;
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.items.len().checked_sub(1)?;
+        self.items.swap(0, last);
+        let largest = self.items.pop();
+        sift_down(&mut self.items, 0, last);
+        largest
+    }
+
+    /*
+     * Keeps the items keep() says yes to and rebuilds the heap from
+     * them, O(n) whatever was removed
+     * */
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F) {
+        self.items.retain(keep);
+        let len = self.items.len();
+        for root in (0..len / 2).rev() {
+            sift_down(&mut self.items, root, len);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+/*
+ * Chapter 10's largest, which never needed more than a slice.
+ * chapters::largest has the documented versions.
+ * */
+
+// By reference so T needs no Copy, None for an empty list
+/// ```
+/// use rust_exercises::core_utils::largest::largest;
+///
+/// assert_eq!(largest(&[34, 50, 25, 100, 65]), Some(&100));
+/// assert_eq!(largest::<char>(&[]), None);
+/// ```
+pub fn largest<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    let mut iter = list.iter();
+    let mut largest = iter.next()?;
+
+    for item in iter {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    Some(largest)
+}
+
+pub fn largest_copy<T: PartialOrd + Copy>(list: &[T]) -> Option<T> {
+    largest(list).copied()
+}
@@ -0,0 +1,19 @@
+/*
+ * The pure algorithms of the crate, written against core alone so they
+ * build in a #![no_std] crate: no heap, no io, no threads, no OS. That
+ * is most of what std adds on top of core.
+ *
+ * The few functions handing back a Vec need the alloc crate and are
+ * behind the alloc feature, on by default. tests/no_std_build.rs
+ * compiles this directory as a #![no_std] crate with and without it.
+ *
+ * Paths between these files stay relative (super::, never crate::) so
+ * they also work as the root of that crate.
+ * */
+pub mod crc32;
+#[cfg(feature = "alloc")]
+pub mod heap;
+pub mod largest;
+pub mod rle;
+pub mod search;
+pub mod sort;
@@ -0,0 +1,190 @@
+/*
+ * The run-length format of extras::rle, encoded and decoded between
+ * slices instead of streams. The caller provides the output buffer, so
+ * this works without an allocator:
+ *
+ *   n in 0..=127    the next n + 1 bytes are copied as is (a literal run)
+ *   n in 128..=255  the next byte is repeated n - 126 times (2 to 129)
+ *
+ * Both produce exactly the same bytes as the streaming version.
+ * */
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub const MAX_LITERAL: usize = 128;
+pub const MIN_REPEAT: usize = 2;
+pub const MAX_REPEAT: usize = 129;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RleError {
+    // The output buffer filled up before the end of the input
+    OutputTooSmall,
+    // The input stops in the middle of a block
+    Truncated,
+}
+
+impl fmt::Display for RleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RleError::OutputTooSmall => write!(f, "output buffer too small"),
+            RleError::Truncated => write!(f, "input cut in the middle of a block"),
+        }
+    }
+}
+
+/*
+ * An output buffer of this size is always big enough for encode. Every
+ * literal run costs one control byte and takes at least 3 bytes of input
+ * counting the repeat after it, so the worst case is a lone byte between
+ * every two repeats: "abbcdd" becomes 8 bytes.
+ * */
+pub const fn max_encoded_len(input_len: usize) -> usize {
+    input_len + input_len.div_ceil(3)
+}
+
+struct Output<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Output<'_> {
+    fn put(&mut self, bytes: &[u8]) -> Result<(), RleError> {
+        let end = self.len + bytes.len();
+        if end > self.buffer.len() {
+            return Err(RleError::OutputTooSmall);
+        }
+        self.buffer[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    // Literals are a range of the input, between two repeats
+    fn put_literals(&mut self, literals: &[u8]) -> Result<(), RleError> {
+        if literals.is_empty() {
+            return Ok(());
+        }
+        self.put(&[(literals.len() - 1) as u8])?;
+        self.put(literals)
+    }
+}
+
+/*
+ * Writes the encoded input to the start of output and returns how many
+ * bytes that took
+ * */
+/// ```
+/// use rust_exercises::core_utils::rle::{decode, encode, max_encoded_len, RleError};
+///
+/// let input = b"aaaaaabc";
+/// let mut packed = [0; max_encoded_len(8)];
+/// let len = encode(input, &mut packed).unwrap();
+/// assert_eq!(packed[..len], [132, b'a', 1, b'b', b'c']);
+///
+/// let mut unpacked = [0; 8];
+/// assert_eq!(decode(&packed[..len], &mut unpacked), Ok(8));
+/// assert_eq!(&unpacked, input);
+/// assert_eq!(decode(&packed[..len], &mut [0; 4]), Err(RleError::OutputTooSmall));
+/// ```
+pub fn encode(input: &[u8], output: &mut [u8]) -> Result<usize, RleError> {
+    let mut output = Output {
+        buffer: output,
+        len: 0,
+    };
+    let mut literals_start = 0;
+    let mut i = 0;
+
+    while i < input.len() {
+        let byte = input[i];
+        let mut count = 1;
+        while count < MAX_REPEAT && input.get(i + count) == Some(&byte) {
+            count += 1;
+        }
+
+        // A single byte is cheaper as part of the literals around it
+        if count >= MIN_REPEAT {
+            output.put_literals(&input[literals_start..i])?;
+            output.put(&[(count + 126) as u8, byte])?;
+            literals_start = i + count;
+        } else if i + 1 - literals_start == MAX_LITERAL {
+            output.put_literals(&input[literals_start..=i])?;
+            literals_start = i + 1;
+        }
+        i += count;
+    }
+
+    output.put_literals(&input[literals_start..])?;
+    Ok(output.len)
+}
+
+enum Block<'a> {
+    Literals(&'a [u8]),
+    Repeat(u8, usize),
+}
+
+// Calls block with every block of the input in order
+fn blocks<F>(input: &[u8], mut block: F) -> Result<(), RleError>
+where
+    F: FnMut(Block) -> Result<(), RleError>,
+{
+    let mut i = 0;
+    while i < input.len() {
+        let n = input[i] as usize;
+        i += 1;
+        if n < MAX_LITERAL {
+            let literals = input.get(i..i + n + 1).ok_or(RleError::Truncated)?;
+            block(Block::Literals(literals))?;
+            i += n + 1;
+        } else {
+            let byte = *input.get(i).ok_or(RleError::Truncated)?;
+            block(Block::Repeat(byte, n - 126))?;
+            i += 1;
+        }
+    }
+    Ok(())
+}
+
+// How big decode's output has to be for this input
+pub fn decoded_len(input: &[u8]) -> Result<usize, RleError> {
+    let mut len = 0;
+    blocks(input, |block| {
+        len += match block {
+            Block::Literals(literals) => literals.len(),
+            Block::Repeat(_, count) => count,
+        };
+        Ok(())
+    })?;
+    Ok(len)
+}
+
+/*
+ * Writes the decoded input to the start of output and returns how many
+ * bytes that took
+ * */
+pub fn decode(input: &[u8], output: &mut [u8]) -> Result<usize, RleError> {
+    let mut output = Output {
+        buffer: output,
+        len: 0,
+    };
+    blocks(input, |block| match block {
+        Block::Literals(literals) => output.put(literals),
+        Block::Repeat(byte, count) => output.put(&[byte; MAX_REPEAT][..count]),
+    })?;
+    Ok(output.len)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec(input: &[u8]) -> Vec<u8> {
+    let mut output = alloc::vec![0; max_encoded_len(input.len())];
+    let len = encode(input, &mut output).expect("max_encoded_len is always enough");
+    output.truncate(len);
+    output
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec(input: &[u8]) -> Result<Vec<u8>, RleError> {
+    let mut output = alloc::vec![0; decoded_len(input)?];
+    decode(input, &mut output)?;
+    Ok(output)
+}
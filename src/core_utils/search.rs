@@ -0,0 +1,58 @@
+/*
+ * Binary search over sorted slices. Everything is built on
+ * partition_point, the others are just different predicates.
+ * */
+use core::cmp::Ordering;
+
+/*
+ * The slice must be partitioned: pred is true for a first part of it
+ * and false for the rest. Returns where the false part starts.
+ * */
+pub fn partition_point<T, P: FnMut(&T) -> bool>(items: &[T], mut pred: P) -> usize {
+    // The answer is always in low..=high
+    let mut low = 0;
+    let mut high = items.len();
+
+    while low < high {
+        // Can't overflow, unlike (low + high) / 2
+        let middle = low + (high - low) / 2;
+        if pred(&items[middle]) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+// First position whose item is not less than target
+pub fn lower_bound<T: Ord>(items: &[T], target: &T) -> usize {
+    partition_point(items, |item| item < target)
+}
+
+// First position whose item is greater than target
+pub fn upper_bound<T: Ord>(items: &[T], target: &T) -> usize {
+    partition_point(items, |item| item <= target)
+}
+
+/*
+ * Same contract as slice::binary_search: Ok with the position of an
+ * item equal to target, or Err with where it would have to be inserted
+ * to keep the slice sorted. With duplicates this one always finds the
+ * first of them.
+ * */
+/// ```
+/// use rust_exercises::core_utils::search::{binary_search, lower_bound, upper_bound};
+///
+/// let items = [1, 3, 3, 3, 7];
+/// assert_eq!((lower_bound(&items, &3), upper_bound(&items, &3)), (1, 4));
+/// assert_eq!(binary_search(&items, &7), Ok(4));
+/// assert_eq!(binary_search(&items, &5), Err(4));
+/// ```
+pub fn binary_search<T: Ord>(items: &[T], target: &T) -> Result<usize, usize> {
+    let index = lower_bound(items, target);
+    match items.get(index).map(|item| item.cmp(target)) {
+        Some(Ordering::Equal) => Ok(index),
+        _ => Err(index),
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Sorting in place with nothing but swaps, so no allocation, plus a
+ * merge sort that needs a buffer and therefore alloc.
+ *
+ *   insertion_sort  O(n²), fast on small or nearly sorted slices, stable
+ *   heap_sort       O(n log n) always, not stable
+ *   merge_sort      O(n log n) always, stable, n extra items of memory
+ * */
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub fn is_sorted<T: PartialOrd>(items: &[T]) -> bool {
+    items.windows(2).all(|pair| pair[0] <= pair[1])
+}
+
+/*
+ * Grows a sorted prefix one item at a time, swapping the new item back
+ * until the one before it isn't bigger
+ * */
+pub fn insertion_sort<T: Ord>(items: &mut [T]) {
+    for i in 1..items.len() {
+        let mut j = i;
+        while j > 0 && items[j - 1] > items[j] {
+            items.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/*
+ * Moves items[root] down the heap items[..end] until both its children
+ * are smaller. The children of i are at 2i + 1 and 2i + 2. heap.rs
+ * pops with it too.
+ * */
+pub(super) fn sift_down<T: Ord>(items: &mut [T], mut root: usize, end: usize) {
+    loop {
+        let mut largest = root;
+        for child in [2 * root + 1, 2 * root + 2] {
+            if child < end && items[child] > items[largest] {
+                largest = child;
+            }
+        }
+        if largest == root {
+            return;
+        }
+        items.swap(root, largest);
+        root = largest;
+    }
+}
+
+/*
+ * Turns the slice into a max heap, then repeatedly swaps the largest
+ * item to the end and restores the heap on what's left
+ * */
+/// ```
+/// use rust_exercises::core_utils::sort::{heap_sort, is_sorted};
+///
+/// let mut items = [5, 2, 9, 1, 5, 6];
+/// heap_sort(&mut items);
+/// assert_eq!(items, [1, 2, 5, 5, 6, 9]);
+/// assert!(is_sorted(&items));
+/// ```
+pub fn heap_sort<T: Ord>(items: &mut [T]) {
+    let len = items.len();
+    for root in (0..len / 2).rev() {
+        sift_down(items, root, len);
+    }
+    for end in (1..len).rev() {
+        items.swap(0, end);
+        sift_down(items, 0, end);
+    }
+}
+
+/*
+ * Top down merge sort. Each level merges the two sorted halves through
+ * a copy of them, which is the memory heap_sort does without.
+ * */
+#[cfg(feature = "alloc")]
+pub fn merge_sort<T: Ord + Clone>(items: &mut [T]) {
+    if items.len() <= 1 {
+        return;
+    }
+    let middle = items.len() / 2;
+    merge_sort(&mut items[..middle]);
+    merge_sort(&mut items[middle..]);
+
+    let merged: Vec<T> = {
+        let (left, right) = items.split_at(middle);
+        let mut merged = Vec::with_capacity(items.len());
+        let (mut i, mut j) = (0, 0);
+        while i < left.len() && j < right.len() {
+            // <= takes from the left first on ties, which keeps it stable
+            if left[i] <= right[j] {
+                merged.push(left[i].clone());
+                i += 1;
+            } else {
+                merged.push(right[j].clone());
+                j += 1;
+            }
+        }
+        merged.extend_from_slice(&left[i..]);
+        merged.extend_from_slice(&right[j..]);
+        merged
+    };
+    items.clone_from_slice(&merged);
+}
@@ -0,0 +1,313 @@
+/*
+ * Actor model: every actor runs on its own thread and owns its state,
+ * the only way to reach it is to send a message to its mailbox, an mpsc
+ * channel as in https://doc.rust-lang.org/book/ch16-02-message-passing.html
+ *
+ * Messages are handled one at a time in the order they arrive, so an
+ * actor never needs a Mutex around its own fields.
+ * */
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+pub trait Actor: Send + 'static {
+    type Msg: Send + 'static;
+
+    fn handle(&mut self, msg: Self::Msg);
+
+    // Called once on the actor's thread after its last message
+    fn stopped(&mut self) {}
+}
+
+enum Envelope<M> {
+    Msg(M),
+    Stop,
+}
+
+// Typed handle to an actor, only accepts that actor's messages
+pub struct Addr<A: Actor> {
+    mailbox: Sender<Envelope<A::Msg>>,
+}
+
+// Manual impl, #[derive(Clone)] would require A: Clone
+impl<A: Actor> Clone for Addr<A> {
+    fn clone(&self) -> Addr<A> {
+        Addr {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+}
+
+// The actor has stopped, the message comes back undelivered
+#[derive(PartialEq, Eq)]
+pub struct Stopped<M>(pub M);
+
+// Without the M: Debug bound a derive would add, messages rarely need it
+impl<M> fmt::Debug for Stopped<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Stopped(..)")
+    }
+}
+
+impl<M> fmt::Display for Stopped<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the actor has stopped")
+    }
+}
+
+impl<M> std::error::Error for Stopped<M> {}
+
+impl<A: Actor> Addr<A> {
+    pub fn send(&self, msg: A::Msg) -> Result<(), Stopped<A::Msg>> {
+        self.mailbox.send(Envelope::Msg(msg)).map_err(|e| match e.0 {
+            Envelope::Msg(msg) => Stopped(msg),
+            Envelope::Stop => unreachable!(),
+        })
+    }
+}
+
+pub struct ActorHandle<A: Actor> {
+    addr: Addr<A>,
+    thread: JoinHandle<A>,
+}
+
+impl<A: Actor> ActorHandle<A> {
+    pub fn addr(&self) -> Addr<A> {
+        self.addr.clone()
+    }
+
+    /*
+     * Messages sent before the stop are still handled, then the actor is
+     * given back so its final state can be looked at. Err if handling a
+     * message panicked.
+     * */
+    pub fn stop(self) -> thread::Result<A> {
+        // Already gone if it panicked, join() reports that
+        let _ = self.addr.mailbox.send(Envelope::Stop);
+        self.thread.join()
+    }
+}
+
+fn run<A: Actor>(mut actor: A, mailbox: Receiver<Envelope<A::Msg>>) -> A {
+    for envelope in mailbox {
+        match envelope {
+            Envelope::Msg(msg) => actor.handle(msg),
+            Envelope::Stop => break,
+        }
+    }
+    actor.stopped();
+    actor
+}
+
+/// Starts the actor on its own thread. Its state comes back from stop().
+///
+/// ```
+/// use rust_exercises::extras::actor::{self, Actor};
+///
+/// struct Counter(u32);
+///
+/// impl Actor for Counter {
+///     type Msg = u32;
+///
+///     fn handle(&mut self, msg: u32) {
+///         self.0 += msg;
+///     }
+/// }
+///
+/// let counter = actor::spawn(Counter(0));
+/// let addr = counter.addr();
+/// addr.send(2).unwrap();
+/// addr.send(3).unwrap();
+/// assert_eq!(counter.stop().unwrap().0, 5);
+/// // Nobody is receiving anymore, the message comes back
+/// assert_eq!(addr.send(1).unwrap_err().0, 1);
+/// ```
+pub fn spawn<A: Actor>(actor: A) -> ActorHandle<A> {
+    let (tx, rx) = mpsc::channel();
+    let thread = thread::spawn(move || run(actor, rx));
+
+    ActorHandle {
+        addr: Addr { mailbox: tx },
+        thread,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShutdownReport {
+    pub stopped: usize,
+    pub panicked: usize,
+}
+
+/*
+ * Keeps the handles of actors of any type and stops them all together,
+ * in the order they were spawned. Dropping it shuts down too, without
+ * the report.
+ * */
+/// ```
+/// use rust_exercises::extras::actor::{Ponger, Supervisor};
+///
+/// let mut supervisor = Supervisor::new();
+/// supervisor.spawn(Ponger { pings: 0 });
+/// supervisor.spawn(Ponger { pings: 0 });
+/// let report = supervisor.shutdown();
+/// assert_eq!((report.stopped, report.panicked), (2, 0));
+/// ```
+#[derive(Default)]
+pub struct Supervisor {
+    stoppers: Vec<Box<dyn FnOnce() -> bool + Send>>,
+}
+
+impl Supervisor {
+    pub fn new() -> Supervisor {
+        Supervisor {
+            stoppers: Vec::new(),
+        }
+    }
+
+    pub fn spawn<A: Actor>(&mut self, actor: A) -> Addr<A> {
+        let handle = spawn(actor);
+        let addr = handle.addr();
+        self.stoppers.push(Box::new(move || handle.stop().is_ok()));
+        addr
+    }
+
+    pub fn len(&self) -> usize {
+        self.stoppers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stoppers.is_empty()
+    }
+
+    pub fn shutdown(mut self) -> ShutdownReport {
+        self.stop_all()
+    }
+
+    fn stop_all(&mut self) -> ShutdownReport {
+        let mut report = ShutdownReport::default();
+        for stop in self.stoppers.drain(..) {
+            if stop() {
+                report.stopped += 1;
+            } else {
+                report.panicked += 1;
+            }
+        }
+        report
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        self.stop_all();
+    }
+}
+
+/*
+ * Ping pong between two actors: the Pinger sends a Ping carrying its own
+ * address, the Ponger answers with a Pong, until `rounds` pongs came back.
+ * */
+pub struct Ponger {
+    pub pings: u32,
+}
+
+pub struct Ping {
+    pub count: u32,
+    pub reply_to: Addr<Pinger>,
+}
+
+impl Actor for Ponger {
+    type Msg = Ping;
+
+    fn handle(&mut self, ping: Ping) {
+        self.pings += 1;
+        // The pinger stops first once it's done
+        let _ = ping.reply_to.send(PingerMsg::Pong { count: ping.count });
+    }
+}
+
+pub enum PingerMsg {
+    Start {
+        me: Addr<Pinger>,
+        peer: Addr<Ponger>,
+    },
+    Pong {
+        count: u32,
+    },
+}
+
+pub struct Pinger {
+    rounds: u32,
+    me: Option<Addr<Pinger>>,
+    peer: Option<Addr<Ponger>>,
+    done: Sender<u32>,
+}
+
+impl Pinger {
+    pub fn new(rounds: u32, done: Sender<u32>) -> Pinger {
+        Pinger {
+            rounds,
+            me: None,
+            peer: None,
+            done,
+        }
+    }
+
+    fn ping(&self, count: u32) {
+        if let (Some(me), Some(peer)) = (&self.me, &self.peer) {
+            let ping = Ping {
+                count,
+                reply_to: me.clone(),
+            };
+            peer.send(ping).expect("the ponger stopped");
+        }
+    }
+}
+
+impl Actor for Pinger {
+    type Msg = PingerMsg;
+
+    fn handle(&mut self, msg: PingerMsg) {
+        match msg {
+            PingerMsg::Start { me, peer } => {
+                self.me = Some(me);
+                self.peer = Some(peer);
+                if self.rounds == 0 {
+                    let _ = self.done.send(0);
+                } else {
+                    self.ping(1);
+                }
+            }
+            PingerMsg::Pong { count } if count >= self.rounds => {
+                let _ = self.done.send(count);
+            }
+            PingerMsg::Pong { count } => self.ping(count + 1),
+        }
+    }
+
+    // Its own address would otherwise keep the mailbox open
+    fn stopped(&mut self) {
+        self.me = None;
+    }
+}
+
+// Returns the number of pongs the pinger got back
+/// ```
+/// assert_eq!(rust_exercises::extras::actor::ping_pong(10), 10);
+/// ```
+pub fn ping_pong(rounds: u32) -> u32 {
+    let (done_tx, done_rx) = mpsc::channel();
+    let mut supervisor = Supervisor::new();
+
+    let ponger = supervisor.spawn(Ponger { pings: 0 });
+    let pinger = supervisor.spawn(Pinger::new(rounds, done_tx));
+    pinger
+        .send(PingerMsg::Start {
+            me: pinger.clone(),
+            peer: ponger,
+        })
+        .unwrap();
+
+    let pongs = done_rx.recv().unwrap();
+    supervisor.shutdown();
+    pongs
+}
@@ -0,0 +1,118 @@
+/*
+ * Three interview classics, each written twice: the obvious brute
+ * force, easy to trust, and the fast one, easy to get wrong. The brute
+ * force versions are there to test the fast ones against.
+ *
+ *   two_sum           O(n) with a HashMap     two_sum_brute           O(n^2)
+ *   max_subarray_sum  O(n) with Kadane's      max_subarray_sum_brute  O(n^2)
+ *   binary_gap        O(gaps) on the bits     binary_gap_brute        on a string
+ * */
+use std::collections::HashMap;
+
+/*
+ * Indices i < j with nums[i] + nums[j] == target. With several answers
+ * it's the one with the smallest j, then the smallest i, both versions
+ * agree on which.
+ * */
+/// ```
+/// use rust_exercises::extras::algos::two_sum;
+///
+/// assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+/// assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+/// assert_eq!(two_sum(&[1, 2], 7), None);
+/// ```
+pub fn two_sum(nums: &[i64], target: i64) -> Option<(usize, usize)> {
+    // First index each value was seen at
+    let mut seen: HashMap<i64, usize> = HashMap::new();
+    for (j, &num) in nums.iter().enumerate() {
+        if let Some(&i) = target.checked_sub(num).and_then(|want| seen.get(&want)) {
+            return Some((i, j));
+        }
+        seen.entry(num).or_insert(j);
+    }
+    None
+}
+
+pub fn two_sum_brute(nums: &[i64], target: i64) -> Option<(usize, usize)> {
+    for j in 0..nums.len() {
+        for i in 0..j {
+            if nums[i].checked_add(nums[j]) == Some(target) {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+/*
+ * Largest sum of a non-empty run of consecutive numbers, None for an
+ * empty slice. Kadane's: the best run ending here either extends the
+ * best run ending just before, or starts over, whichever is bigger.
+ * */
+/// ```
+/// use rust_exercises::extras::algos::max_subarray_sum;
+///
+/// assert_eq!(max_subarray_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), Some(6));
+/// assert_eq!(max_subarray_sum(&[-3, -1, -2]), Some(-1));
+/// assert_eq!(max_subarray_sum(&[]), None);
+/// ```
+pub fn max_subarray_sum(nums: &[i64]) -> Option<i64> {
+    let (&first, rest) = nums.split_first()?;
+    let mut ending_here = first;
+    let mut best = first;
+    for &num in rest {
+        ending_here = num.max(ending_here + num);
+        best = best.max(ending_here);
+    }
+    Some(best)
+}
+
+pub fn max_subarray_sum_brute(nums: &[i64]) -> Option<i64> {
+    let mut best = None;
+    for start in 0..nums.len() {
+        let mut sum = 0;
+        for &num in &nums[start..] {
+            sum += num;
+            best = Some(best.map_or(sum, |best: i64| best.max(sum)));
+        }
+    }
+    best
+}
+
+/*
+ * Longest run of zeros with a one on both sides in n's binary form,
+ * 0b1000_1001 has a gap of 3. Trailing zeros have no one after them and
+ * don't count.
+ * */
+/// ```
+/// use rust_exercises::extras::algos::binary_gap;
+///
+/// assert_eq!(binary_gap(0b1000_1001), 3);
+/// assert_eq!(binary_gap(0b1010_0000), 1);
+/// assert_eq!(binary_gap(0), 0);
+/// ```
+pub fn binary_gap(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    // Drop the trailing zeros and the one above them
+    let mut bits = n >> n.trailing_zeros() >> 1;
+    let mut longest = 0;
+    while bits != 0 {
+        let gap = bits.trailing_zeros();
+        longest = longest.max(gap);
+        bits = bits >> gap >> 1;
+    }
+    longest
+}
+
+pub fn binary_gap_brute(n: u32) -> u32 {
+    let binary = format!("{:b}", n);
+    // Splitting on '1' gives the zero runs, the last one isn't closed
+    let runs: Vec<&str> = binary.split('1').collect();
+    runs[..runs.len() - 1]
+        .iter()
+        .map(|run| run.len() as u32)
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,293 @@
+/*
+ * Command-line parser built from scratch, understands
+ *   --verbose -v           flags, which are on or off
+ *   --level=3 --level 3    options taking a value
+ *   input.txt              positional arguments, in declaration order
+ *   --                     everything after it is positional
+ *   --help -h              returns the generated help text as an error
+ * */
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    UnknownArgument(String),
+    MissingValue(String),
+    // The flag was given a value, as in --verbose=yes
+    UnexpectedValue(String),
+    MissingPositional(String),
+    TooManyPositionals(String),
+    InvalidValue { name: String, value: String },
+    // Not a failure as such, holds the text to print before exiting
+    HelpRequested(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::UnknownArgument(ref arg) => write!(f, "unknown argument '{}'", arg),
+            ParseError::MissingValue(ref name) => write!(f, "--{} needs a value", name),
+            ParseError::UnexpectedValue(ref name) => write!(f, "--{} doesn't take a value", name),
+            ParseError::MissingPositional(ref name) => write!(f, "missing <{}>", name),
+            ParseError::TooManyPositionals(ref arg) => {
+                write!(f, "unexpected extra argument '{}'", arg)
+            }
+            ParseError::InvalidValue {
+                ref name,
+                ref value,
+            } => write!(f, "invalid value '{}' for {}", value, name),
+            ParseError::HelpRequested(ref help) => write!(f, "{}", help),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+struct Flag {
+    name: String,
+    short: Option<char>,
+    help: String,
+}
+
+struct Opt {
+    name: String,
+    default: Option<String>,
+    help: String,
+}
+
+struct Positional {
+    name: String,
+    help: String,
+}
+
+/// ```
+/// use rust_exercises::extras::argparse::{ArgSpec, ParseError};
+///
+/// let spec = ArgSpec::new("grep")
+///     .flag("ignore-case", Some('i'), "match without case")
+///     .option("max", None, "stop after this many matches")
+///     .positional("pattern", "what to look for");
+///
+/// let matches = spec.parse(vec!["-i", "--max=2", "rust"]).unwrap();
+/// assert!(matches.flag("ignore-case"));
+/// assert_eq!(matches.value_as::<u32>("max"), Ok(Some(2)));
+/// assert_eq!(matches.value("pattern"), Some("rust"));
+///
+/// assert_eq!(
+///     spec.parse(vec!["--color"]).unwrap_err(),
+///     ParseError::UnknownArgument(String::from("--color"))
+/// );
+/// ```
+pub struct ArgSpec {
+    program: String,
+    about: String,
+    flags: Vec<Flag>,
+    options: Vec<Opt>,
+    positionals: Vec<Positional>,
+}
+
+impl ArgSpec {
+    pub fn new(program: &str) -> ArgSpec {
+        ArgSpec {
+            program: program.to_string(),
+            about: String::new(),
+            flags: Vec::new(),
+            options: Vec::new(),
+            positionals: Vec::new(),
+        }
+    }
+
+    pub fn about(mut self, about: &str) -> ArgSpec {
+        self.about = about.to_string();
+        self
+    }
+
+    pub fn flag(mut self, name: &str, short: Option<char>, help: &str) -> ArgSpec {
+        self.flags.push(Flag {
+            name: name.to_string(),
+            short,
+            help: help.to_string(),
+        });
+        self
+    }
+
+    pub fn option(mut self, name: &str, default: Option<&str>, help: &str) -> ArgSpec {
+        self.options.push(Opt {
+            name: name.to_string(),
+            default: default.map(|value| value.to_string()),
+            help: help.to_string(),
+        });
+        self
+    }
+
+    // Positionals are all required
+    pub fn positional(mut self, name: &str, help: &str) -> ArgSpec {
+        self.positionals.push(Positional {
+            name: name.to_string(),
+            help: help.to_string(),
+        });
+        self
+    }
+
+    pub fn help(&self) -> String {
+        let mut usage = format!("Usage: {} [OPTIONS]", self.program);
+        for positional in self.positionals.iter() {
+            usage.push_str(&format!(" <{}>", positional.name));
+        }
+
+        // Left column of every line, padded to the widest one
+        let mut rows: Vec<(String, String)> = Vec::new();
+        for positional in self.positionals.iter() {
+            rows.push((format!("<{}>", positional.name), positional.help.clone()));
+        }
+        for flag in self.flags.iter() {
+            let left = match flag.short {
+                Some(short) => format!("-{}, --{}", short, flag.name),
+                None => format!("    --{}", flag.name),
+            };
+            rows.push((left, flag.help.clone()));
+        }
+        for option in self.options.iter() {
+            let help = match option.default {
+                Some(ref default) => format!("{} [default: {}]", option.help, default),
+                None => option.help.clone(),
+            };
+            rows.push((format!("    --{} <value>", option.name), help));
+        }
+        rows.push(("-h, --help".to_string(), "Print this help".to_string()));
+
+        let width = rows.iter().map(|(left, _)| left.len()).max().unwrap_or(0);
+        let mut help = String::new();
+        if !self.about.is_empty() {
+            help.push_str(&self.about);
+            help.push_str("\n\n");
+        }
+        help.push_str(&usage);
+        help.push_str("\n\n");
+        for (left, right) in rows {
+            help.push_str(&format!("  {:<width$}  {}\n", left, right, width = width));
+        }
+        help
+    }
+
+    fn find_flag(&self, name: &str) -> Option<&Flag> {
+        self.flags.iter().find(|flag| flag.name == name)
+    }
+
+    fn find_option(&self, name: &str) -> Option<&Opt> {
+        self.options.iter().find(|option| option.name == name)
+    }
+
+    /*
+     * args doesn't include the program name, pass
+     * std::env::args().skip(1) for the real command line.
+     * */
+    pub fn parse<I, S>(&self, args: I) -> Result<Matches, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut matches = Matches {
+            flags: HashSet::new(),
+            values: HashMap::new(),
+        };
+        for option in self.options.iter() {
+            if let Some(ref default) = option.default {
+                matches.values.insert(option.name.clone(), default.clone());
+            }
+        }
+
+        let mut positionals = Vec::new();
+        let mut only_positionals = false;
+        let mut args = args.into_iter().map(Into::<String>::into);
+
+        while let Some(arg) = args.next() {
+            if only_positionals || arg == "-" || !arg.starts_with('-') {
+                positionals.push(arg);
+            } else if arg == "--" {
+                only_positionals = true;
+            } else if arg == "--help" || arg == "-h" {
+                return Err(ParseError::HelpRequested(self.help()));
+            } else if let Some(long) = arg.strip_prefix("--") {
+                let (name, inline_value) = match long.find('=') {
+                    Some(eq) => (&long[..eq], Some(long[eq + 1..].to_string())),
+                    None => (long, None),
+                };
+
+                if self.find_flag(name).is_some() {
+                    if inline_value.is_some() {
+                        return Err(ParseError::UnexpectedValue(name.to_string()));
+                    }
+                    matches.flags.insert(name.to_string());
+                } else if self.find_option(name).is_some() {
+                    let value = match inline_value {
+                        Some(value) => value,
+                        None => args
+                            .next()
+                            .ok_or_else(|| ParseError::MissingValue(name.to_string()))?,
+                    };
+                    matches.values.insert(name.to_string(), value);
+                } else {
+                    return Err(ParseError::UnknownArgument(arg.clone()));
+                }
+            } else {
+                // Short flags can be grouped, -vq is -v -q
+                for short in arg[1..].chars() {
+                    match self.flags.iter().find(|flag| flag.short == Some(short)) {
+                        Some(flag) => {
+                            matches.flags.insert(flag.name.clone());
+                        }
+                        None => return Err(ParseError::UnknownArgument(format!("-{}", short))),
+                    }
+                }
+            }
+        }
+
+        if positionals.len() > self.positionals.len() {
+            return Err(ParseError::TooManyPositionals(
+                positionals[self.positionals.len()].clone(),
+            ));
+        }
+        if let Some(missing) = self.positionals.get(positionals.len()) {
+            return Err(ParseError::MissingPositional(missing.name.clone()));
+        }
+        for (spec, value) in self.positionals.iter().zip(positionals) {
+            matches.values.insert(spec.name.clone(), value);
+        }
+
+        Ok(matches)
+    }
+}
+
+/*
+ * Options and positionals share one namespace of values,
+ * flags are only present or not.
+ * */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matches {
+    flags: HashSet<String>,
+    values: HashMap<String, String>,
+}
+
+impl Matches {
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|value| value.as_str())
+    }
+
+    // Ok(None) when the value wasn't given and has no default
+    pub fn value_as<T: FromStr>(&self, name: &str) -> Result<Option<T>, ParseError> {
+        match self.values.get(name) {
+            None => Ok(None),
+            Some(value) => value.parse().map(Some).map_err(|_| ParseError::InvalidValue {
+                name: name.to_string(),
+                value: value.clone(),
+            }),
+        }
+    }
+}
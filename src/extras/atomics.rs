@@ -0,0 +1,242 @@
+/*
+ * Small synchronization primitives built on atomics, to see which memory
+ * ordering each one needs and why. https://marabos.nl/atomics/ covers all
+ * of this in depth.
+ *
+ *   Relaxed  the operation itself is atomic, nothing more. Enough for a
+ *            counter where only the final value matters.
+ *   Acquire  on a load: what the Releasing thread wrote before its store
+ *   Release  on a store: becomes visible once the store is seen. The pair
+ *            is what hands data over from a thread to another.
+ *   SeqCst   Acquire/Release plus one order of all SeqCst operations that
+ *            every thread agrees on. Rarely needed, see seq_cst_flags.
+ * */
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+/*
+ * Hands out unique increasing numbers, from any number of threads.
+ * Relaxed is enough: fetch_add is atomic under every ordering, so no two
+ * callers get the same number, and no other memory depends on it.
+ * */
+#[derive(Debug, Default)]
+pub struct SequenceCounter {
+    next: AtomicUsize,
+}
+
+impl SequenceCounter {
+    pub fn new() -> SequenceCounter {
+        SequenceCounter {
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn next(&self) -> usize {
+        self.next.fetch_add(1, Ordering::Relaxed)
+    }
+
+    // Only a snapshot, other threads may have moved on already
+    pub fn current(&self) -> usize {
+        self.next.load(Ordering::Relaxed)
+    }
+}
+
+/*
+ * A lock that busy-waits instead of putting the thread to sleep, fine for
+ * very short critical sections. Taking the lock is an Acquire and letting
+ * go is a Release, so whatever a thread wrote while holding it is seen by
+ * the next thread to take it.
+ * */
+pub struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// Safe because the lock makes sure only one thread at a time gets at value
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    pub fn new(value: T) -> SpinLock<T> {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        // compare_exchange_weak may fail spuriously, that just means one more spin
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+        SpinLockGuard {
+            lock: self,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+/// Unlocks when dropped, like a MutexGuard. Also like it, sharing the
+/// guard between threads hands out `&T` to all of them, so that is only
+/// allowed when `T` is Sync:
+///
+/// ```
+/// use rust_exercises::extras::atomics::SpinLock;
+/// use std::thread;
+///
+/// let lock = SpinLock::new(5);
+/// let guard = lock.lock();
+/// thread::scope(|s| {
+///     s.spawn(|| assert_eq!(*guard, 5));
+/// });
+/// ```
+///
+/// A `Cell` may be sent to another thread but not shared, reading it
+/// from a second thread through the guard doesn't compile:
+///
+/// ```compile_fail,E0277
+/// use rust_exercises::extras::atomics::SpinLock;
+/// use std::cell::Cell;
+/// use std::thread;
+///
+/// let lock = SpinLock::new(Cell::new(5));
+/// let guard = lock.lock();
+/// thread::scope(|s| {
+///     s.spawn(|| guard.set(6));
+/// });
+/// ```
+pub struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+    /*
+     * Raw pointers are neither Send nor Sync, so this takes away the
+     * automatic Sync the &SpinLock<T> would give whenever T is only Send.
+     * The guard isn't Send either, as with MutexGuard.
+     * */
+    marker: PhantomData<*const ()>,
+}
+
+// Sharing the guard shares the &T it derefs to, nothing more
+unsafe impl<T: Sync> Sync for SpinLockGuard<'_, T> {}
+
+impl<T> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // The guard existing means this thread holds the lock
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const DONE: u8 = 2;
+
+/*
+ * A value initialized the first time it's asked for, even when many
+ * threads ask at once, like std::sync::OnceLock. The thread that wins the
+ * race runs init, the others spin until it stores DONE with Release, and
+ * their Acquire load of DONE guarantees they see the written value.
+ * */
+pub struct OnceInit<T> {
+    state: AtomicU8,
+    value: UnsafeCell<Option<T>>,
+}
+
+unsafe impl<T: Send + Sync> Sync for OnceInit<T> {}
+
+impl<T> Default for OnceInit<T> {
+    fn default() -> OnceInit<T> {
+        OnceInit::new()
+    }
+}
+
+impl<T> OnceInit<T> {
+    pub fn new() -> OnceInit<T> {
+        OnceInit {
+            state: AtomicU8::new(UNINIT),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /*
+     * If init panics the state stays RUNNING and other callers spin
+     * forever, std's version handles this by poisoning.
+     * */
+    pub fn get_or_init<F: FnOnce() -> T>(&self, init: F) -> &T {
+        match self
+            .state
+            .compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire)
+        {
+            Ok(_) => {
+                // Only this thread can be here, and nobody reads before DONE
+                unsafe { *self.value.get() = Some(init()) };
+                self.state.store(DONE, Ordering::Release);
+            }
+            Err(_) => {
+                while self.state.load(Ordering::Acquire) != DONE {
+                    std::hint::spin_loop();
+                }
+            }
+        }
+
+        // DONE was seen with Acquire, the value is written and never changes again
+        unsafe { (*self.value.get()).as_ref().unwrap() }
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == DONE {
+            unsafe { (*self.value.get()).as_ref() }
+        } else {
+            None
+        }
+    }
+}
+
+/*
+ * Two threads each raise their own flag, then look at the other's.
+ * With SeqCst all four operations happen in one global order, so at
+ * least one thread sees the other's flag: the result is never (false, false).
+ * With Acquire/Release that isn't guaranteed, each thread may still see
+ * the other's flag down because the stores and loads are to different
+ * variables, and on x86 this actually happens through store buffers.
+ * */
+pub fn seq_cst_flags() -> (bool, bool) {
+    let a = Arc::new(AtomicBool::new(false));
+    let b = Arc::new(AtomicBool::new(false));
+
+    let (a1, b1) = (a.clone(), b.clone());
+    let first = thread::spawn(move || {
+        a1.store(true, Ordering::SeqCst);
+        b1.load(Ordering::SeqCst)
+    });
+
+    let second = thread::spawn(move || {
+        b.store(true, Ordering::SeqCst);
+        a.load(Ordering::SeqCst)
+    });
+
+    (first.join().unwrap(), second.join().unwrap())
+}
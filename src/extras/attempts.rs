@@ -0,0 +1,232 @@
+/*
+ * What happened each time a learner ran `runner verify chapter-N`, so a
+ * mentor can see where a chapter trips people up.
+ *
+ * Every attempt is one JSON object appended to .runner/stats.jsonl:
+ *
+ *   {"duration_ms":5120,"error":"error[E0308]: mismatched types",
+ *    "exercise":"chapter-12","passed":false,"started":1760000000}
+ *
+ * The file is only ever appended to, a mentor collects them from
+ * several learners and summarizes them all at once.
+ * */
+use crate::extras::json::{self, Value};
+use crate::extras::stats;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+pub const LOG: &str = ".runner/stats.jsonl";
+
+/// ```
+/// use rust_exercises::extras::attempts::Attempt;
+/// use std::time::Duration;
+///
+/// let attempt = Attempt {
+///     exercise: String::from("chapter-12"),
+///     started: 1_760_000_000,
+///     duration: Duration::from_millis(5120),
+///     passed: false,
+///     error: Some(String::from("error[E0308]: mismatched types")),
+/// };
+/// assert_eq!(Attempt::from_json(&attempt.to_json()), Some(attempt));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub exercise: String,
+    // Seconds since the Unix epoch
+    pub started: u64,
+    pub duration: Duration,
+    pub passed: bool,
+    // The first compiler error, None when it built
+    pub error: Option<String>,
+}
+
+impl Attempt {
+    pub fn to_json(&self) -> Value {
+        let mut members = BTreeMap::new();
+        members.insert(
+            String::from("exercise"),
+            Value::String(self.exercise.clone()),
+        );
+        members.insert(String::from("started"), Value::Number(self.started as f64));
+        members.insert(
+            String::from("duration_ms"),
+            Value::Number(self.duration.as_millis() as f64),
+        );
+        members.insert(String::from("passed"), Value::Bool(self.passed));
+        let error = match self.error {
+            Some(ref error) => Value::String(error.clone()),
+            None => Value::Null,
+        };
+        members.insert(String::from("error"), error);
+        Value::Object(members)
+    }
+
+    pub fn from_json(value: &Value) -> Option<Attempt> {
+        let members = match value {
+            Value::Object(members) => members,
+            _ => return None,
+        };
+        let number = |key: &str| match members.get(key) {
+            Some(&Value::Number(n)) if n >= 0.0 => Some(n as u64),
+            _ => None,
+        };
+
+        Some(Attempt {
+            exercise: match members.get("exercise") {
+                Some(Value::String(exercise)) => exercise.clone(),
+                _ => return None,
+            },
+            started: number("started")?,
+            duration: Duration::from_millis(number("duration_ms")?),
+            passed: match members.get("passed") {
+                Some(&Value::Bool(passed)) => passed,
+                _ => return None,
+            },
+            error: match members.get("error") {
+                Some(Value::String(error)) => Some(error.clone()),
+                _ => None,
+            },
+        })
+    }
+
+    fn finished(&self) -> u64 {
+        self.started + self.duration.as_secs()
+    }
+}
+
+/*
+ * The first error rustc reported in cargo's output. Cargo's own closing
+ * lines like "error: could not compile" say nothing about the mistake,
+ * they're skipped.
+ * */
+/// ```
+/// use rust_exercises::extras::attempts::first_error;
+///
+/// let output = "error[E0425]: cannot find value `x`\nerror: could not compile `demo`";
+/// assert_eq!(
+///     first_error(output).as_deref(),
+///     Some("error[E0425]: cannot find value `x`")
+/// );
+/// assert_eq!(first_error("    Finished dev"), None);
+/// ```
+pub fn first_error(output: &str) -> Option<String> {
+    const CARGO_LINES: [&str; 3] = [
+        "error: could not compile",
+        "error: aborting due to",
+        "error: test failed",
+    ];
+
+    output
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| line.starts_with("error[") || line.starts_with("error:"))
+        .find(|line| !CARGO_LINES.iter().any(|cargo| line.starts_with(cargo)))
+        .map(str::to_string)
+}
+
+pub fn record<P: AsRef<Path>>(path: P, attempt: &Attempt) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", attempt.to_json())
+}
+
+/*
+ * Every attempt in the file, in the order they were made. A line cut
+ * short by a crash or edited by hand is skipped rather than failing the
+ * whole file.
+ * */
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Vec<Attempt>> {
+    let text = fs::read_to_string(path)?;
+    let attempts = text
+        .lines()
+        .filter_map(|line| json::parse(line).ok())
+        .filter_map(|value| Attempt::from_json(&value))
+        .collect();
+    Ok(attempts)
+}
+
+/*
+ * Adds up the attempts at one exercise, one learner's log at a time.
+ *
+ * Time to green runs from the first attempt after the previous success,
+ * or the first attempt at all, to the end of the attempt that passed. An
+ * unbroken run of failures at the end of a log hasn't gone green yet and
+ * doesn't count.
+ * */
+/// ```
+/// use rust_exercises::extras::attempts::{Attempt, Summary};
+/// use std::time::Duration;
+///
+/// let attempt = |started, passed| Attempt {
+///     exercise: String::from("chapter-3"),
+///     started,
+///     duration: Duration::from_secs(10),
+///     passed,
+///     error: if passed { None } else { Some(String::from("error[E0384]")) },
+/// };
+/// let mut summary = Summary::new("chapter-3");
+/// summary.add(&[attempt(100, false), attempt(200, false), attempt(300, true)]);
+///
+/// assert_eq!((summary.attempts, summary.passed), (3, 1));
+/// // From the first failure to the end of the passing attempt
+/// assert_eq!(summary.median_time_to_green(), Some(Duration::from_secs(210)));
+/// assert_eq!(summary.most_common_error(), Some(("error[E0384]", 2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    pub exercise: String,
+    pub attempts: usize,
+    pub passed: usize,
+    to_green: Vec<Duration>,
+    errors: BTreeMap<String, usize>,
+}
+
+impl Summary {
+    pub fn new(exercise: &str) -> Summary {
+        Summary {
+            exercise: exercise.to_string(),
+            ..Summary::default()
+        }
+    }
+
+    pub fn add(&mut self, log: &[Attempt]) {
+        let mut streak_start = None;
+
+        for attempt in log {
+            if attempt.exercise != self.exercise {
+                continue;
+            }
+            self.attempts += 1;
+            let start = *streak_start.get_or_insert(attempt.started);
+            if let Some(ref error) = attempt.error {
+                *self.errors.entry(error.clone()).or_insert(0) += 1;
+            }
+            if attempt.passed {
+                self.passed += 1;
+                let took = attempt.finished().saturating_sub(start);
+                self.to_green.push(Duration::from_secs(took));
+                streak_start = None;
+            }
+        }
+    }
+
+    pub fn median_time_to_green(&self) -> Option<Duration> {
+        let seconds: Vec<f64> = self.to_green.iter().map(Duration::as_secs_f64).collect();
+        stats::median(&seconds).map(Duration::from_secs_f64)
+    }
+
+    // Ties go to the error that sorts first, so the answer is stable
+    pub fn most_common_error(&self) -> Option<(&str, usize)> {
+        self.errors
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(error, &count)| (error.as_str(), count))
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * Base64 with the standard alphabet and '=' padding,
+ * https://datatracker.ietf.org/doc/html/rfc4648#section-4
+ *
+ * Every 3 input bytes are 24 bits, cut into 4 groups of 6 bits which each
+ * index into the 64 letter alphabet.
+ * */
+use std::error::Error;
+use std::fmt;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+/// ```
+/// use rust_exercises::extras::base64::encode;
+///
+/// assert_eq!(encode(b"Man"), "TWFu");
+/// assert_eq!(encode(b"Ma"), "TWE=");
+/// assert_eq!(encode(b""), "");
+/// ```
+pub fn encode(input: &[u8]) -> String {
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+
+    for chunk in input.chunks(3) {
+        // Missing bytes of the last chunk count as zeros
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).cloned().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).cloned().unwrap_or(0) as u32;
+        let bits = (b0 << 16) | (b1 << 8) | b2;
+
+        output.push(ALPHABET[(bits >> 18) as usize & 0x3f] as char);
+        output.push(ALPHABET[(bits >> 12) as usize & 0x3f] as char);
+
+        // 1 byte needs 2 letters and 2 bytes need 3, the rest is padding
+        if chunk.len() > 1 {
+            output.push(ALPHABET[(bits >> 6) as usize & 0x3f] as char);
+        } else {
+            output.push(PAD as char);
+        }
+        if chunk.len() > 2 {
+            output.push(ALPHABET[bits as usize & 0x3f] as char);
+        } else {
+            output.push(PAD as char);
+        }
+    }
+
+    output
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecodeError {
+    // Padded input always comes in groups of 4
+    InvalidLength(usize),
+    // Byte offset of the offending character
+    InvalidChar { pos: usize, ch: char },
+    // '=' anywhere else than the last one or two positions
+    InvalidPadding(usize),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::InvalidLength(len) => {
+                write!(f, "length {} is not a multiple of 4", len)
+            }
+            DecodeError::InvalidChar { pos, ch } => {
+                write!(f, "invalid character {:?} at {}", ch, pos)
+            }
+            DecodeError::InvalidPadding(pos) => write!(f, "misplaced padding at {}", pos),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn decode_char(byte: u8) -> Option<u32> {
+    let value = match byte {
+        b'A'..=b'Z' => byte - b'A',
+        b'a'..=b'z' => byte - b'a' + 26,
+        b'0'..=b'9' => byte - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+    Some(value as u32)
+}
+
+/// ```
+/// use rust_exercises::extras::base64::{decode, DecodeError};
+///
+/// assert_eq!(decode("TWE="), Ok(b"Ma".to_vec()));
+/// assert_eq!(decode("TWE"), Err(DecodeError::InvalidLength(3)));
+/// assert_eq!(decode("TW!u"), Err(DecodeError::InvalidChar { pos: 2, ch: '!' }));
+/// ```
+pub fn decode(input: &str) -> Result<Vec<u8>, DecodeError> {
+    let invalid = input
+        .char_indices()
+        .find(|&(_, ch)| !ch.is_ascii() || (ch as u8 != PAD && decode_char(ch as u8).is_none()));
+    if let Some((pos, ch)) = invalid {
+        return Err(DecodeError::InvalidChar { pos, ch });
+    }
+
+    let bytes = input.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(DecodeError::InvalidLength(bytes.len()));
+    }
+
+    let mut output = Vec::with_capacity(bytes.len() / 4 * 3);
+
+    for (index, chunk) in bytes.chunks(4).enumerate() {
+        let start = index * 4;
+        let is_last = start + 4 == bytes.len();
+
+        // Padding is only allowed at the end of the last group
+        let padding = chunk.iter().rev().take_while(|&&b| b == PAD).count();
+        if padding > 0 && !is_last {
+            return Err(DecodeError::InvalidPadding(start + 4 - padding));
+        }
+        if padding > 2 {
+            return Err(DecodeError::InvalidPadding(start + 4 - padding));
+        }
+
+        let mut bits = 0;
+        for (i, &byte) in chunk[..4 - padding].iter().enumerate() {
+            // Every other character was checked above, this can only be '='
+            let value = match decode_char(byte) {
+                Some(value) => value,
+                None => return Err(DecodeError::InvalidPadding(start + i)),
+            };
+            bits |= value << (18 - 6 * i);
+        }
+
+        output.push((bits >> 16) as u8);
+        if padding < 2 {
+            output.push((bits >> 8) as u8);
+        }
+        if padding < 1 {
+            output.push(bits as u8);
+        }
+    }
+
+    Ok(output)
+}
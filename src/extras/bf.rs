@@ -0,0 +1,182 @@
+/*
+ * Brainfuck interpreter, https://en.wikipedia.org/wiki/Brainfuck
+ *
+ * The program is parsed into instructions first so unbalanced brackets
+ * are reported before anything runs, and every jump target is known.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Right,
+    Left,
+    Increment,
+    Decrement,
+    Output,
+    Input,
+    // Both hold the index of the matching bracket
+    JumpIfZero(usize),
+    JumpUnlessZero(usize),
+}
+
+#[derive(Debug)]
+pub enum BfError {
+    // Byte offsets in the source program
+    UnmatchedOpen(usize),
+    UnmatchedClose(usize),
+    // Moving left of the first cell
+    TapeUnderflow,
+    Io(io::Error),
+}
+
+impl fmt::Display for BfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BfError::UnmatchedOpen(pos) => write!(f, "unmatched '[' at {}", pos),
+            BfError::UnmatchedClose(pos) => write!(f, "unmatched ']' at {}", pos),
+            BfError::TapeUnderflow => write!(f, "moved left of the first cell"),
+            BfError::Io(ref e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl Error for BfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            BfError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BfError {
+    fn from(e: io::Error) -> BfError {
+        BfError::Io(e)
+    }
+}
+
+/*
+ * Any character that isn't one of the eight commands is a comment
+ * */
+pub fn parse(source: &str) -> Result<Vec<Instruction>, BfError> {
+    let mut program = Vec::new();
+    // Index in program and byte offset in source of every '[' not closed yet
+    let mut open: Vec<(usize, usize)> = Vec::new();
+
+    for (pos, c) in source.char_indices() {
+        let instruction = match c {
+            '>' => Instruction::Right,
+            '<' => Instruction::Left,
+            '+' => Instruction::Increment,
+            '-' => Instruction::Decrement,
+            '.' => Instruction::Output,
+            ',' => Instruction::Input,
+            '[' => {
+                open.push((program.len(), pos));
+                // Patched once the matching ']' is found
+                Instruction::JumpIfZero(0)
+            }
+            ']' => {
+                let (start, _) = open.pop().ok_or(BfError::UnmatchedClose(pos))?;
+                program[start] = Instruction::JumpIfZero(program.len());
+                Instruction::JumpUnlessZero(start)
+            }
+            _ => continue,
+        };
+        program.push(instruction);
+    }
+
+    match open.pop() {
+        Some((_, pos)) => Err(BfError::UnmatchedOpen(pos)),
+        None => Ok(program),
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::bf::{BfError, Interpreter};
+///
+/// // Prints its input back until the input runs out
+/// let echo = Interpreter::new(",[.,]").unwrap();
+/// assert_eq!(echo.run_to_string(b"hi").unwrap(), "hi");
+///
+/// let hello = "++++++++[>++++++++<-]>+.+.";
+/// assert_eq!(Interpreter::new(hello).unwrap().run_to_string(b"").unwrap(), "AB");
+///
+/// assert!(matches!(Interpreter::new("+[").err(), Some(BfError::UnmatchedOpen(1))));
+/// ```
+pub struct Interpreter {
+    program: Vec<Instruction>,
+}
+
+impl Interpreter {
+    pub fn new(source: &str) -> Result<Interpreter, BfError> {
+        Ok(Interpreter {
+            program: parse(source)?,
+        })
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /*
+     * Runs the program on a fresh tape that grows to the right as needed.
+     * Cells are bytes that wrap around, and ',' at the end of input
+     * stores 0 so loops like ",[.,]" terminate.
+     * */
+    pub fn run<R: Read, W: Write>(&self, mut input: R, mut output: W) -> Result<(), BfError> {
+        let mut tape: Vec<u8> = vec![0];
+        let mut cell = 0;
+        let mut pc = 0;
+
+        while pc < self.program.len() {
+            match self.program[pc] {
+                Instruction::Right => {
+                    cell += 1;
+                    if cell == tape.len() {
+                        tape.push(0);
+                    }
+                }
+                Instruction::Left => {
+                    if cell == 0 {
+                        return Err(BfError::TapeUnderflow);
+                    }
+                    cell -= 1;
+                }
+                Instruction::Increment => tape[cell] = tape[cell].wrapping_add(1),
+                Instruction::Decrement => tape[cell] = tape[cell].wrapping_sub(1),
+                Instruction::Output => output.write_all(&tape[cell..cell + 1])?,
+                Instruction::Input => {
+                    let mut byte = [0];
+                    tape[cell] = match input.read(&mut byte)? {
+                        0 => 0,
+                        _ => byte[0],
+                    };
+                }
+                Instruction::JumpIfZero(end) => {
+                    if tape[cell] == 0 {
+                        pc = end;
+                    }
+                }
+                Instruction::JumpUnlessZero(start) => {
+                    if tape[cell] != 0 {
+                        pc = start;
+                    }
+                }
+            }
+            pc += 1;
+        }
+
+        output.flush()?;
+        Ok(())
+    }
+
+    // Convenience for programs that only print text
+    pub fn run_to_string(&self, input: &[u8]) -> Result<String, BfError> {
+        let mut output = Vec::new();
+        self.run(input, &mut output)?;
+        Ok(String::from_utf8_lossy(&output).into_owned())
+    }
+}
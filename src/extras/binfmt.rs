@@ -0,0 +1,262 @@
+/*
+ * A small binary record format, all integers little endian:
+ *
+ *   header   "RXBF"  magic
+ *            u16     version, currently 1
+ *            u16     flags, reserved, always 0
+ *   record   u32     payload length
+ *            [u8]    payload
+ *            u32     CRC-32 of the payload
+ *
+ * Records follow each other until the end of the file. Ending exactly
+ * between two records is fine, anywhere else means the file was cut.
+ * */
+use crate::extras::crc32;
+use std::fmt;
+use std::io::{self, ErrorKind, Read, Write};
+
+pub const MAGIC: [u8; 4] = *b"RXBF";
+pub const VERSION: u16 = 1;
+// A corrupted length shouldn't make the reader allocate gigabytes
+pub const MAX_RECORD_LEN: u32 = 16 * 1024 * 1024;
+pub const HEADER_LEN: u64 = 8;
+
+// Bytes a record takes in the file, framing included
+pub fn record_len(payload_len: usize) -> u64 {
+    4 + payload_len as u64 + 4
+}
+
+#[derive(Debug)]
+pub enum FormatError {
+    Io(io::Error),
+    BadMagic([u8; 4]),
+    UnsupportedVersion(u16),
+    // Record index, counting from 0
+    TooLarge {
+        record: usize,
+        len: u32,
+    },
+    ChecksumMismatch {
+        record: usize,
+        expected: u32,
+        actual: u32,
+    },
+    Truncated {
+        record: usize,
+    },
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::Io(e) => write!(f, "{}", e),
+            FormatError::BadMagic(magic) => write!(f, "not a record file, magic {:02x?}", magic),
+            FormatError::UnsupportedVersion(v) => write!(f, "unsupported version {}", v),
+            FormatError::TooLarge { record, len } => {
+                write!(
+                    f,
+                    "record {} claims {} bytes, more than the limit",
+                    record, len
+                )
+            }
+            FormatError::ChecksumMismatch {
+                record,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "record {} is corrupted, checksum {:08x} instead of {:08x}",
+                record, actual, expected
+            ),
+            FormatError::Truncated { record } => write!(f, "file ends inside record {}", record),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<io::Error> for FormatError {
+    fn from(e: io::Error) -> FormatError {
+        FormatError::Io(e)
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::binfmt::{FormatError, Reader, Writer};
+///
+/// let mut writer = Writer::new(Vec::new()).unwrap();
+/// writer.write_record(b"first").unwrap();
+/// writer.write_record(b"").unwrap();
+/// let mut file = writer.finish().unwrap();
+///
+/// let records: Vec<Vec<u8>> = Reader::new(&file[..]).unwrap().map(Result::unwrap).collect();
+/// assert_eq!(records, [b"first".to_vec(), Vec::new()]);
+///
+/// // A flipped payload byte fails its checksum
+/// file[12] ^= 1;
+/// let mut reader = Reader::new(&file[..]).unwrap();
+/// assert!(matches!(
+///     reader.next(),
+///     Some(Err(FormatError::ChecksumMismatch { record: 0, .. }))
+/// ));
+/// ```
+pub struct Writer<W: Write> {
+    inner: W,
+    records: usize,
+}
+
+impl<W: Write> Writer<W> {
+    // Writes the header right away
+    pub fn new(mut inner: W) -> io::Result<Writer<W>> {
+        inner.write_all(&MAGIC)?;
+        inner.write_all(&VERSION.to_le_bytes())?;
+        inner.write_all(&0u16.to_le_bytes())?;
+        Ok(Writer { inner, records: 0 })
+    }
+
+    /*
+     * Adds records to something that already has a header, like a file
+     * opened in append mode
+     * */
+    pub fn append(inner: W) -> Writer<W> {
+        Writer { inner, records: 0 }
+    }
+
+    pub fn write_record(&mut self, payload: &[u8]) -> io::Result<()> {
+        if payload.len() > MAX_RECORD_LEN as usize {
+            return Err(io::Error::new(ErrorKind::InvalidInput, "record too large"));
+        }
+        let len = payload.len() as u32;
+
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(payload)?;
+        self.inner
+            .write_all(&crc32::checksum(payload).to_le_bytes())?;
+        self.records += 1;
+        Ok(())
+    }
+
+    pub fn records(&self) -> usize {
+        self.records
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    // Flushes and gives the writer back, a BufWriter must be flushed
+    pub fn finish(mut self) -> io::Result<W> {
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+pub struct Reader<R: Read> {
+    inner: R,
+    version: u16,
+    records: usize,
+    // After an error there is no telling where the next record starts
+    failed: bool,
+}
+
+/*
+ * Fills buf completely, Ok(false) if the input was already at its end,
+ * an UnexpectedEof error if it ended part way
+ * */
+fn read_exact_or_eof<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(false),
+            Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(true)
+}
+
+impl<R: Read> Reader<R> {
+    // Reads and checks the header
+    pub fn new(mut inner: R) -> Result<Reader<R>, FormatError> {
+        let mut header = [0; 8];
+        inner.read_exact(&mut header).map_err(|e| match e.kind() {
+            ErrorKind::UnexpectedEof => FormatError::BadMagic([0; 4]),
+            _ => FormatError::Io(e),
+        })?;
+
+        let magic = [header[0], header[1], header[2], header[3]];
+        if magic != MAGIC {
+            return Err(FormatError::BadMagic(magic));
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != VERSION {
+            return Err(FormatError::UnsupportedVersion(version));
+        }
+
+        Ok(Reader {
+            inner,
+            version,
+            records: 0,
+            failed: false,
+        })
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    fn read_record(&mut self) -> Result<Option<Vec<u8>>, FormatError> {
+        let record = self.records;
+        let truncated = |e: io::Error| match e.kind() {
+            ErrorKind::UnexpectedEof => FormatError::Truncated { record },
+            _ => FormatError::Io(e),
+        };
+
+        let mut len = [0; 4];
+        if !read_exact_or_eof(&mut self.inner, &mut len).map_err(truncated)? {
+            return Ok(None);
+        }
+        let len = u32::from_le_bytes(len);
+        if len > MAX_RECORD_LEN {
+            return Err(FormatError::TooLarge { record, len });
+        }
+
+        let mut payload = vec![0; len as usize];
+        self.inner.read_exact(&mut payload).map_err(truncated)?;
+        let mut checksum = [0; 4];
+        self.inner.read_exact(&mut checksum).map_err(truncated)?;
+
+        let expected = u32::from_le_bytes(checksum);
+        let actual = crc32::checksum(&payload);
+        if expected != actual {
+            return Err(FormatError::ChecksumMismatch {
+                record,
+                expected,
+                actual,
+            });
+        }
+
+        self.records += 1;
+        Ok(Some(payload))
+    }
+
+    // None at the end, and forever after the first error
+    pub fn next_record(&mut self) -> Option<Result<Vec<u8>, FormatError>> {
+        if self.failed {
+            return None;
+        }
+        let result = self.read_record();
+        self.failed = result.is_err();
+        result.transpose()
+    }
+}
+
+impl<R: Read> Iterator for Reader<R> {
+    type Item = Result<Vec<u8>, FormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record()
+    }
+}
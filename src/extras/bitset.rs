@@ -0,0 +1,335 @@
+/*
+ * Sets of small numbers as one bit each, 64 to a u64. Bit i lives in
+ * word i / 64 at position i % 64, and the Vec grows when a bit past
+ * the end is set.
+ *
+ * | and & are union and intersection, on references so neither side is
+ * consumed. Iterating goes word by word and jumps straight to the next
+ * set bit with trailing_zeros, a mostly empty set costs one step per
+ * word, not per bit.
+ *
+ * flags! is the other use of bits: a handful of named flags packed in
+ * one integer, in the style of the bitflags crate,
+ * https://docs.rs/bitflags
+ * */
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+const WORD_BITS: usize = 64;
+
+/// ```
+/// use rust_exercises::extras::bitset::BitSet;
+///
+/// let mut odd = BitSet::new();
+/// for bit in [1, 3, 5, 200] {
+///     odd.set(bit);
+/// }
+/// assert!(odd.test(200) && !odd.test(2));
+/// assert!(odd.clear(200));
+///
+/// let small: BitSet = (0..4).collect();
+/// assert_eq!((&odd & &small).iter().collect::<Vec<_>>(), [1, 3]);
+/// assert_eq!((&odd | &small).len(), 5);
+/// ```
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> BitSet {
+        BitSet::default()
+    }
+
+    // Room for bits 0..bits without growing
+    pub fn with_capacity(bits: usize) -> BitSet {
+        BitSet {
+            words: vec![0; bits.div_ceil(WORD_BITS)],
+        }
+    }
+
+    // Whether it was set before
+    pub fn set(&mut self, bit: usize) -> bool {
+        let (word, mask) = (bit / WORD_BITS, 1 << (bit % WORD_BITS));
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        was_set
+    }
+
+    // Whether it was set before
+    pub fn clear(&mut self, bit: usize) -> bool {
+        let (word, mask) = (bit / WORD_BITS, 1 << (bit % WORD_BITS));
+        match self.words.get_mut(word) {
+            Some(word) => {
+                let was_set = *word & mask != 0;
+                *word &= !mask;
+                was_set
+            }
+            None => false,
+        }
+    }
+
+    pub fn test(&self, bit: usize) -> bool {
+        let (word, mask) = (bit / WORD_BITS, 1 << (bit % WORD_BITS));
+        self.words.get(word).is_some_and(|word| word & mask != 0)
+    }
+
+    // How many bits are set
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: &self.words,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+/*
+ * Set bits in increasing order. current is what's left of the word at
+ * index, each bit is cleared from it once handed out.
+ * */
+pub struct Iter<'a> {
+    words: &'a [u64],
+    index: usize,
+    current: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Clears the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * WORD_BITS + bit)
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(bits: I) -> BitSet {
+        let mut set = BitSet::new();
+        for bit in bits {
+            set.set(bit);
+        }
+        set
+    }
+}
+
+/*
+ * Same bits, however many zero words either one has at the end, a set
+ * that grew and was cleared again equals a new one
+ * */
+impl PartialEq for BitSet {
+    fn eq(&self, other: &BitSet) -> bool {
+        let longest = self.words.len().max(other.words.len());
+        (0..longest).all(|i| {
+            self.words.get(i).copied().unwrap_or(0) == other.words.get(i).copied().unwrap_or(0)
+        })
+    }
+}
+
+impl Eq for BitSet {}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl BitOrAssign<&BitSet> for BitSet {
+    fn bitor_assign(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (word, &theirs) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= theirs;
+        }
+    }
+}
+
+impl BitAndAssign<&BitSet> for BitSet {
+    fn bitand_assign(&mut self, other: &BitSet) {
+        // Past the end of other everything is 0 in the intersection
+        self.words.truncate(other.words.len());
+        for (word, &theirs) in self.words.iter_mut().zip(other.words.iter()) {
+            *word &= theirs;
+        }
+    }
+}
+
+impl BitOr for &BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: &BitSet) -> BitSet {
+        let mut union = self.clone();
+        union |= other;
+        union
+    }
+}
+
+impl BitAnd for &BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: &BitSet) -> BitSet {
+        let mut intersection = self.clone();
+        intersection &= other;
+        intersection
+    }
+}
+
+/*
+ * A struct over an integer with one constant per flag. from_bits
+ * refuses bits no flag has, from_bits_truncate drops them.
+ * */
+/// ```
+/// use rust_exercises::flags;
+///
+/// flags! {
+///     pub struct Permissions: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// let rw = Permissions::READ | Permissions::WRITE;
+/// assert!(rw.contains(Permissions::READ));
+/// assert!(!rw.contains(Permissions::EXECUTE));
+/// assert_eq!(format!("{:?}", rw), "Permissions(READ | WRITE)");
+/// assert_eq!(Permissions::from_bits(0b1000), None);
+/// assert_eq!(Permissions::from_bits_truncate(0b1001), Permissions::READ);
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident: $bits:ty {
+            $(const $flag:ident = $value:expr;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name {
+            bits: $bits,
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            $(pub const $flag: $name = $name { bits: $value };)*
+
+            const NAMED: &'static [(&'static str, $bits)] = &[$((stringify!($flag), $value)),*];
+
+            pub const fn empty() -> $name {
+                $name { bits: 0 }
+            }
+
+            pub const fn all() -> $name {
+                $name { bits: 0 $(| $value)* }
+            }
+
+            pub const fn bits(self) -> $bits {
+                self.bits
+            }
+
+            pub fn from_bits(bits: $bits) -> Option<$name> {
+                if bits & !$name::all().bits != 0 {
+                    return None;
+                }
+                Some($name { bits })
+            }
+
+            pub fn from_bits_truncate(bits: $bits) -> $name {
+                $name { bits: bits & $name::all().bits }
+            }
+
+            pub fn is_empty(self) -> bool {
+                self.bits == 0
+            }
+
+            // Every flag of other is set here too
+            pub fn contains(self, other: $name) -> bool {
+                self.bits & other.bits == other.bits
+            }
+
+            pub fn insert(&mut self, other: $name) {
+                self.bits |= other.bits;
+            }
+
+            pub fn remove(&mut self, other: $name) {
+                self.bits &= !other.bits;
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name { bits: self.bits | other.bits }
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = $name;
+
+            fn bitand(self, other: $name) -> $name {
+                $name { bits: self.bits & other.bits }
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: $name) {
+                self.bits |= other.bits;
+            }
+        }
+
+        impl ::std::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, other: $name) {
+                self.bits &= other.bits;
+            }
+        }
+
+        // The names of the flags that are set, "Permissions(READ | WRITE)"
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let names: Vec<&str> = $name::NAMED
+                    .iter()
+                    .filter(|&&(_, value)| value != 0 && self.bits & value == value)
+                    .map(|&(name, _)| name)
+                    .collect();
+                if names.is_empty() {
+                    write!(f, "{}(empty)", stringify!($name))
+                } else {
+                    write!(f, "{}({})", stringify!($name), names.join(" | "))
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,104 @@
+/*
+ * Blocking FIFO queue with a fixed capacity, for producer/consumer setups
+ * where producers should slow down when consumers can't keep up.
+ *
+ * A Condvar lets a thread sleep until another one says something changed.
+ * wait() releases the mutex while sleeping and takes it back on wake up,
+ * and it's always called in a loop since a wake up doesn't guarantee the
+ * condition holds (another thread may have been faster, or it's spurious).
+ * https://doc.rust-lang.org/std/sync/struct.Condvar.html
+ * */
+use std::collections::VecDeque;
+use std::sync::{Condvar, Mutex};
+
+/// ```
+/// use rust_exercises::extras::bounded_queue::BoundedQueue;
+/// use std::thread;
+///
+/// let queue = BoundedQueue::new(2);
+/// assert_eq!(queue.try_push(1), Ok(()));
+/// assert_eq!(queue.try_push(2), Ok(()));
+/// assert_eq!(queue.try_push(3), Err(3));
+///
+/// thread::scope(|s| {
+///     // Blocks until the pops below make room
+///     s.spawn(|| queue.push(3));
+///     assert_eq!(queue.pop(), 1);
+///     assert_eq!(queue.pop(), 2);
+///     assert_eq!(queue.pop(), 3);
+/// });
+/// assert_eq!(queue.try_pop(), None);
+/// ```
+pub struct BoundedQueue<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    // Signaled after a pop, producers wait on it when full
+    not_full: Condvar,
+    // Signaled after a push, consumers wait on it when empty
+    not_empty: Condvar,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn new(capacity: usize) -> BoundedQueue<T> {
+        assert!(capacity > 0, "capacity must be at least 1");
+        BoundedQueue {
+            items: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            not_full: Condvar::new(),
+            not_empty: Condvar::new(),
+        }
+    }
+
+    // Blocks while the queue is full
+    pub fn push(&self, item: T) {
+        let mut items = self.items.lock().unwrap();
+        while items.len() == self.capacity {
+            items = self.not_full.wait(items).unwrap();
+        }
+        items.push_back(item);
+        self.not_empty.notify_one();
+    }
+
+    // Blocks while the queue is empty
+    pub fn pop(&self) -> T {
+        let mut items = self.items.lock().unwrap();
+        loop {
+            if let Some(item) = items.pop_front() {
+                self.not_full.notify_one();
+                return item;
+            }
+            items = self.not_empty.wait(items).unwrap();
+        }
+    }
+
+    // Gives the item back when the queue is full
+    pub fn try_push(&self, item: T) -> Result<(), T> {
+        let mut items = self.items.lock().unwrap();
+        if items.len() == self.capacity {
+            return Err(item);
+        }
+        items.push_back(item);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    pub fn try_pop(&self) -> Option<T> {
+        let item = self.items.lock().unwrap().pop_front();
+        if item.is_some() {
+            self.not_full.notify_one();
+        }
+        item
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.lock().unwrap().is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * A binary search tree set: everything smaller than a node is in its
+ * left subtree, everything bigger in its right one, so insert, contains
+ * and remove walk a single path down from the root.
+ *
+ * Nothing keeps it balanced. Random insertions give a depth around
+ * 2 ln n, sorted ones a linked list where every operation is O(n).
+ * std's BTreeSet stays O(log n) whatever the order, and keeps several
+ * values per node, which is kinder to the cache,
+ * https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+ *
+ * Removing a node with two children swaps in the smallest value of its
+ * right subtree, the next one in order.
+ * */
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// ```
+/// use rust_exercises::extras::bst::Bst;
+///
+/// let mut tree: Bst<i32> = [5, 2, 8, 2].iter().copied().collect();
+/// assert_eq!(tree.len(), 3);
+/// assert!(tree.contains(&8));
+/// assert!(tree.remove(&5));
+/// assert_eq!(tree.iter().copied().collect::<Vec<_>>(), [2, 8]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bst<T> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> Default for Bst<T> {
+    fn default() -> Bst<T> {
+        Bst::new()
+    }
+}
+
+impl<T: Ord> Bst<T> {
+    pub fn new() -> Bst<T> {
+        Bst { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // False if it was already there, the tree is left as it was
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+                Ordering::Equal => return false,
+            };
+        }
+        *link = Some(Box::new(Node {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    // False if it wasn't there
+    pub fn remove(&mut self, value: &T) -> bool {
+        /*
+         * Comparing first and borrowing the child after, a match holding
+         * on to the node would keep link borrowed past the loop
+         * */
+        let mut link = &mut self.root;
+        loop {
+            let ordering = match link {
+                None => return false,
+                Some(node) => value.cmp(&node.value),
+            };
+            if ordering == Ordering::Equal {
+                break;
+            }
+            let node = link.as_mut().expect("matched Some above");
+            link = if ordering == Ordering::Less {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+
+        let mut node = link.take().expect("the loop stopped on a node");
+        *link = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let (next, right) = take_min(right);
+                node.value = next;
+                node.left = Some(left);
+                node.right = right;
+                Some(node)
+            }
+        };
+        self.len -= 1;
+        true
+    }
+
+    // Smallest first
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+// The smallest value of a subtree and what's left of the subtree without it
+fn take_min<T>(mut node: Box<Node<T>>) -> (T, Link<T>) {
+    match node.left.take() {
+        None => (node.value, node.right),
+        Some(left) => {
+            let (min, left) = take_min(left);
+            node.left = left;
+            (min, Some(node))
+        }
+    }
+}
+
+/*
+ * The derived drop would recurse once per level, and a tree built from
+ * sorted values is as deep as it is long. Taking the children off before
+ * each node is dropped keeps it to a loop.
+ * */
+impl<T> Drop for Bst<T> {
+    fn drop(&mut self) {
+        let mut pending: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = pending.pop() {
+            pending.extend(node.left.take());
+            pending.extend(node.right.take());
+        }
+    }
+}
+
+impl<T: Ord> Extend<T> for Bst<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        for value in values {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Bst<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Bst<T> {
+        let mut tree = Bst::new();
+        tree.extend(values);
+        tree
+    }
+}
+
+/*
+ * In order without recursion: the stack holds the nodes whose left side
+ * is done but which haven't been handed out yet
+ * */
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
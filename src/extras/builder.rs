@@ -0,0 +1,290 @@
+/*
+ * Builder pattern: optional settings are chained one call at a time and
+ * build() checks that the result is a valid request.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    timeout: Duration,
+}
+
+impl HttpRequest {
+    pub fn builder() -> HttpRequestBuilder {
+        HttpRequestBuilder::new()
+    }
+
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    // Header names are case insensitive, the first match wins
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    MissingUrl,
+    InvalidUrl(String),
+    BodyNotAllowed(Method),
+    ZeroTimeout,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::MissingUrl => write!(f, "a url is required"),
+            BuildError::InvalidUrl(ref url) => {
+                write!(f, "'{}' is not an http:// or https:// url", url)
+            }
+            BuildError::BodyNotAllowed(method) => write!(f, "{:?} requests have no body", method),
+            BuildError::ZeroTimeout => write!(f, "the timeout must be greater than zero"),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/*
+ * Everything has a default except the url:
+ * GET, no headers, no body and a 30 seconds timeout.
+ * */
+/// ```
+/// use rust_exercises::extras::builder::{BuildError, HttpRequest, Method};
+/// use std::time::Duration;
+///
+/// let request = HttpRequest::builder()
+///     .method(Method::Post)
+///     .url("https://example.com/api")
+///     .header("Content-Type", "application/json")
+///     .body("{}")
+///     .build()
+///     .unwrap();
+/// assert_eq!(request.header("content-type"), Some("application/json"));
+/// assert_eq!(request.timeout(), Duration::from_secs(30));
+///
+/// assert_eq!(HttpRequest::builder().build(), Err(BuildError::MissingUrl));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HttpRequestBuilder {
+    method: Method,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+    timeout: Duration,
+}
+
+impl Default for HttpRequestBuilder {
+    fn default() -> HttpRequestBuilder {
+        HttpRequestBuilder::new()
+    }
+}
+
+impl HttpRequestBuilder {
+    pub fn new() -> HttpRequestBuilder {
+        HttpRequestBuilder {
+            method: Method::Get,
+            url: None,
+            headers: Vec::new(),
+            body: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn method(mut self, method: Method) -> HttpRequestBuilder {
+        self.method = method;
+        self
+    }
+
+    pub fn url(mut self, url: &str) -> HttpRequestBuilder {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    // Can be called several times, headers are kept in order
+    pub fn header(mut self, name: &str, value: &str) -> HttpRequestBuilder {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> HttpRequestBuilder {
+        self.body = Some(body.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> HttpRequestBuilder {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> Result<HttpRequest, BuildError> {
+        let url = self.url.ok_or(BuildError::MissingUrl)?;
+
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(BuildError::InvalidUrl(url));
+        }
+        if self.body.is_some() && (self.method == Method::Get || self.method == Method::Delete) {
+            return Err(BuildError::BodyNotAllowed(self.method));
+        }
+        if self.timeout == Duration::from_secs(0) {
+            return Err(BuildError::ZeroTimeout);
+        }
+
+        Ok(HttpRequest {
+            method: self.method,
+            url,
+            headers: self.headers,
+            body: self.body,
+            timeout: self.timeout,
+        })
+    }
+}
+
+/*
+ * Typestate variant of the builder: whether the url was given is part of
+ * the builder's type, and build() is only implemented once it was, so
+ * forgetting the url is caught by the compiler instead of MissingUrl.
+ * */
+// Zero-sized markers, they never exist at runtime
+pub struct NoUrl;
+pub struct HasUrl;
+
+/// Once the url is set, `build()` is there:
+///
+/// ```
+/// use rust_exercises::extras::builder::{Method, TypedRequestBuilder};
+///
+/// let request = TypedRequestBuilder::new()
+///     .method(Method::Post)
+///     .url("https://example.com")
+///     .build()
+///     .unwrap();
+/// assert_eq!(request.url(), "https://example.com");
+/// ```
+///
+/// Without it there is no `build()` on `TypedRequestBuilder<NoUrl>`:
+///
+/// ```compile_fail,E0599
+/// use rust_exercises::extras::builder::{Method, TypedRequestBuilder};
+///
+/// let request = TypedRequestBuilder::new().method(Method::Post).build();
+/// ```
+///
+/// and `url()` only exists on `NoUrl`, so it can't be given twice:
+///
+/// ```compile_fail,E0599
+/// use rust_exercises::extras::builder::TypedRequestBuilder;
+///
+/// let builder = TypedRequestBuilder::new()
+///     .url("https://example.com")
+///     .url("https://example.org");
+/// ```
+pub struct TypedRequestBuilder<U> {
+    // The url stays None as long as U is NoUrl
+    inner: HttpRequestBuilder,
+    state: PhantomData<U>,
+}
+
+impl Default for TypedRequestBuilder<NoUrl> {
+    fn default() -> TypedRequestBuilder<NoUrl> {
+        TypedRequestBuilder::new()
+    }
+}
+
+impl TypedRequestBuilder<NoUrl> {
+    pub fn new() -> TypedRequestBuilder<NoUrl> {
+        TypedRequestBuilder {
+            inner: HttpRequestBuilder::new(),
+            state: PhantomData,
+        }
+    }
+
+    // Consumes the NoUrl builder and gives back a HasUrl one
+    pub fn url(self, url: &str) -> TypedRequestBuilder<HasUrl> {
+        TypedRequestBuilder {
+            inner: self.inner.url(url),
+            state: PhantomData,
+        }
+    }
+}
+
+/*
+ * The optional settings are available in every state and keep it as is
+ * */
+impl<U> TypedRequestBuilder<U> {
+    pub fn method(self, method: Method) -> TypedRequestBuilder<U> {
+        TypedRequestBuilder {
+            inner: self.inner.method(method),
+            state: PhantomData,
+        }
+    }
+
+    pub fn header(self, name: &str, value: &str) -> TypedRequestBuilder<U> {
+        TypedRequestBuilder {
+            inner: self.inner.header(name, value),
+            state: PhantomData,
+        }
+    }
+
+    pub fn body(self, body: &str) -> TypedRequestBuilder<U> {
+        TypedRequestBuilder {
+            inner: self.inner.body(body),
+            state: PhantomData,
+        }
+    }
+
+    pub fn timeout(self, timeout: Duration) -> TypedRequestBuilder<U> {
+        TypedRequestBuilder {
+            inner: self.inner.timeout(timeout),
+            state: PhantomData,
+        }
+    }
+}
+
+impl TypedRequestBuilder<HasUrl> {
+    /*
+     * The url scheme, body and timeout are still checked at runtime,
+     * but this can never return BuildError::MissingUrl.
+     * */
+    pub fn build(self) -> Result<HttpRequest, BuildError> {
+        self.inner.build()
+    }
+}
@@ -0,0 +1,342 @@
+/*
+ * Arithmetic calculator in three steps: tokenize the input, parse the
+ * tokens into an Expr tree by recursive descent, then evaluate the tree.
+ *
+ * Grammar, lowest precedence first:
+ *   expr   := term (('+' | '-') term)*
+ *   term   := unary (('*' | '/') unary)*
+ *   unary  := '-' unary | atom
+ *   atom   := number | '(' expr ')'
+ *
+ * Nesting deeper than MAX_DEPTH '(' and unary '-' is refused: parsing
+ * recurses once per level, so "((((..." could otherwise run out of stack.
+ * A long "1+1+1+..." isn't nested, it's a chain of binary operators
+ * leaning left, and evaluating and dropping walk down those chains in a
+ * loop. The derived Clone, Debug and PartialEq still recurse along them.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::mem;
+
+pub const MAX_DEPTH: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+}
+
+/*
+ * Moves the children out onto a stack before they're dropped, so that
+ * dropping a long chain doesn't recurse once per operator
+ * */
+impl Drop for Expr {
+    fn drop(&mut self) {
+        let mut stack = Vec::new();
+        take_children(self, &mut stack);
+        while let Some(mut expr) = stack.pop() {
+            take_children(&mut expr, &mut stack);
+        }
+    }
+}
+
+fn take_children(expr: &mut Expr, stack: &mut Vec<Expr>) {
+    let mut take = |child: &mut Box<Expr>| {
+        // Numbers have nothing to take, leave them to the usual drop
+        if let Expr::Number(_) = **child {
+            return;
+        }
+        stack.push(mem::replace(&mut **child, Expr::Number(0.0)));
+    };
+    match expr {
+        Expr::Number(_) => {}
+        Expr::Neg(inner) => take(inner),
+        Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+            take(a);
+            take(b);
+        }
+    }
+}
+
+/*
+ * Every error carries the byte offset in the input where it was found,
+ * except the ones that can only happen at the very end or while evaluating.
+ * */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcError {
+    UnexpectedChar { pos: usize, ch: char },
+    InvalidNumber { pos: usize, text: String },
+    UnexpectedToken { pos: usize, token: Token },
+    UnclosedParen { pos: usize },
+    TooDeep { pos: usize },
+    UnexpectedEnd,
+    DivisionByZero,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CalcError::UnexpectedChar { pos, ch } => {
+                write!(f, "unexpected character '{}' at {}", ch, pos)
+            }
+            CalcError::InvalidNumber { pos, ref text } => {
+                write!(f, "invalid number '{}' at {}", text, pos)
+            }
+            CalcError::UnexpectedToken { pos, token } => {
+                write!(f, "unexpected '{}' at {}", token, pos)
+            }
+            CalcError::UnclosedParen { pos } => write!(f, "unclosed '(' opened at {}", pos),
+            CalcError::TooDeep { pos } => {
+                write!(f, "expression nested deeper than {} at {}", MAX_DEPTH, pos)
+            }
+            CalcError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            CalcError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Error for CalcError {}
+
+/*
+ * Splits the input into tokens, each paired with its byte offset
+ * */
+pub fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, CalcError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(pos, ch)) = chars.peek() {
+        let token = match ch {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let mut end = pos;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_ascii_digit() || c == '.') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let text = &input[pos..end];
+                match text.parse() {
+                    Ok(n) => tokens.push((pos, Token::Number(n))),
+                    Err(_) => {
+                        return Err(CalcError::InvalidNumber {
+                            pos,
+                            text: text.to_string(),
+                        })
+                    }
+                }
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            _ => return Err(CalcError::UnexpectedChar { pos, ch }),
+        };
+        tokens.push((pos, token));
+        chars.next();
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    current: usize,
+    // How many '(' and unary '-' we are inside of right now
+    nesting: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.current).map(|&(_, token)| token)
+    }
+
+    fn next(&mut self) -> Option<(usize, Token)> {
+        let next = self.tokens.get(self.current).cloned();
+        self.current += 1;
+        next
+    }
+
+    // One level further down, for the '(' or '-' at pos
+    fn enter(&mut self, pos: usize) -> Result<(), CalcError> {
+        if self.nesting == MAX_DEPTH {
+            return Err(CalcError::TooDeep { pos });
+        }
+        self.nesting += 1;
+        Ok(())
+    }
+
+    fn expr(&mut self) -> Result<Expr, CalcError> {
+        let mut left = self.term()?;
+
+        loop {
+            let build = match self.peek() {
+                Some(Token::Plus) => Expr::Add,
+                Some(Token::Minus) => Expr::Sub,
+                _ => return Ok(left),
+            };
+            self.next();
+            let right = self.term()?;
+            left = build(Box::new(left), Box::new(right));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, CalcError> {
+        let mut left = self.unary()?;
+
+        loop {
+            let build = match self.peek() {
+                Some(Token::Star) => Expr::Mul,
+                Some(Token::Slash) => Expr::Div,
+                _ => return Ok(left),
+            };
+            self.next();
+            let right = self.unary()?;
+            left = build(Box::new(left), Box::new(right));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, CalcError> {
+        if self.peek() == Some(Token::Minus) {
+            let (pos, _) = self.next().unwrap();
+            self.enter(pos)?;
+            let inner = self.unary()?;
+            self.nesting -= 1;
+            return Ok(Expr::Neg(Box::new(inner)));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Expr, CalcError> {
+        match self.next() {
+            Some((_, Token::Number(n))) => Ok(Expr::Number(n)),
+            Some((open, Token::LParen)) => {
+                self.enter(open)?;
+                let inner = self.expr()?;
+                self.nesting -= 1;
+                match self.next() {
+                    Some((_, Token::RParen)) => Ok(inner),
+                    Some((pos, token)) => Err(CalcError::UnexpectedToken { pos, token }),
+                    None => Err(CalcError::UnclosedParen { pos: open }),
+                }
+            }
+            Some((pos, token)) => Err(CalcError::UnexpectedToken { pos, token }),
+            None => Err(CalcError::UnexpectedEnd),
+        }
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::calc::{parse, Expr};
+///
+/// let number = |n| Box::new(Expr::Number(n));
+/// assert_eq!(
+///     parse("1 - 2 * 3"),
+///     Ok(Expr::Sub(number(1.0), Box::new(Expr::Mul(number(2.0), number(3.0)))))
+/// );
+/// ```
+pub fn parse(input: &str) -> Result<Expr, CalcError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        current: 0,
+        nesting: 0,
+    };
+    let expr = parser.expr()?;
+
+    // Anything left over, like the ')' in "1)", is an error
+    match parser.next() {
+        Some((pos, token)) => Err(CalcError::UnexpectedToken { pos, token }),
+        None => Ok(expr),
+    }
+}
+
+/*
+ * Walks down the left side of a chain like 1+2+3 in a loop, then applies
+ * the operators back up from its first number. Only the right-hand sides
+ * and negations recurse, and those are as deep as the nesting.
+ * */
+pub fn evaluate(expr: &Expr) -> Result<f64, CalcError> {
+    let mut chain = Vec::new();
+    let mut first = expr;
+    while let Expr::Add(ref a, _)
+    | Expr::Sub(ref a, _)
+    | Expr::Mul(ref a, _)
+    | Expr::Div(ref a, _) = *first
+    {
+        chain.push(first);
+        first = a;
+    }
+
+    let mut value = match *first {
+        Expr::Number(n) => n,
+        Expr::Neg(ref inner) => -evaluate(inner)?,
+        _ => unreachable!("the loop above stops at numbers and negations"),
+    };
+    for op in chain.into_iter().rev() {
+        value = match *op {
+            Expr::Add(_, ref b) => value + evaluate(b)?,
+            Expr::Sub(_, ref b) => value - evaluate(b)?,
+            Expr::Mul(_, ref b) => value * evaluate(b)?,
+            Expr::Div(_, ref b) => {
+                let divisor = evaluate(b)?;
+                if divisor == 0.0 {
+                    return Err(CalcError::DivisionByZero);
+                }
+                value / divisor
+            }
+            _ => unreachable!("only binary operators are in the chain"),
+        };
+    }
+    Ok(value)
+}
+
+/*
+ * Parses and evaluates in one go: calc("2 * (3 + 4)") == Ok(14.0)
+ * */
+/// ```
+/// use rust_exercises::extras::calc::{calc, CalcError};
+///
+/// assert_eq!(calc("2 * (3 + 4)"), Ok(14.0));
+/// assert_eq!(calc("-2 - -3 / 2"), Ok(-0.5));
+/// assert_eq!(calc("1 / (2 - 2)"), Err(CalcError::DivisionByZero));
+/// assert_eq!(calc("2 +"), Err(CalcError::UnexpectedEnd));
+/// ```
+pub fn calc(input: &str) -> Result<f64, CalcError> {
+    evaluate(&parse(input)?)
+}
@@ -0,0 +1,239 @@
+/*
+ * An unbounded multi-producer single-consumer channel, behaving like
+ * std::sync::mpsc::channel, from a Mutex<VecDeque<T>> and a Condvar.
+ *
+ * Disconnection works like std's too:
+ *   - recv() returns Err once every Sender is gone and the queue is empty,
+ *     items sent before that are still received
+ *   - send() returns the item in an Err once the Receiver is gone
+ *
+ * Also like std's, the Receiver can move to another thread but not be
+ * shared by several, which is what makes it single-consumer.
+ * */
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::{Arc, Condvar, Mutex};
+
+struct Inner<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    receiver_alive: bool,
+}
+
+struct Shared<T> {
+    inner: Mutex<Inner<T>>,
+    available: Condvar,
+}
+
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// Not Sync, so only one thread at a time can be receiving:
+///
+/// ```compile_fail,E0277
+/// use rust_exercises::extras::channel::channel;
+/// use std::thread;
+///
+/// let (sender, receiver) = channel::<i32>();
+/// thread::scope(|s| {
+///     s.spawn(|| receiver.recv());
+///     s.spawn(|| receiver.recv());
+/// });
+/// ```
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    // Cell is Send and not Sync, which the Receiver then is too
+    marker: PhantomData<Cell<()>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    Empty,
+    Disconnected,
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "sending on a channel whose receiver is gone")
+    }
+}
+
+impl<T: fmt::Debug> Error for SendError<T> {}
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "receiving on a channel with no senders left")
+    }
+}
+
+impl Error for RecvError {}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryRecvError::Empty => write!(f, "the channel is empty"),
+            TryRecvError::Disconnected => write!(f, "{}", RecvError),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+/// ```
+/// use rust_exercises::extras::channel::{channel, RecvError, TryRecvError};
+/// use std::thread;
+///
+/// let (sender, receiver) = channel();
+/// let producers: Vec<_> = (0..3)
+///     .map(|id| {
+///         let sender = sender.clone();
+///         thread::spawn(move || sender.send(id).unwrap())
+///     })
+///     .collect();
+/// drop(sender);
+/// for producer in producers {
+///     producer.join().unwrap();
+/// }
+///
+/// let mut received: Vec<i32> = receiver.iter().collect();
+/// received.sort();
+/// assert_eq!(received, [0, 1, 2]);
+/// // Every sender is gone and nothing is left
+/// assert_eq!(receiver.recv(), Err(RecvError));
+/// assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+/// ```
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        inner: Mutex::new(Inner {
+            queue: VecDeque::new(),
+            senders: 1,
+            receiver_alive: true,
+        }),
+        available: Condvar::new(),
+    });
+
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver {
+            shared,
+            marker: PhantomData,
+        },
+    )
+}
+
+impl<T> Sender<T> {
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        if !inner.receiver_alive {
+            return Err(SendError(item));
+        }
+        inner.queue.push_back(item);
+        drop(inner);
+
+        // Only one thread can be waiting, the one holding the Receiver
+        self.shared.available.notify_one();
+        Ok(())
+    }
+}
+
+/*
+ * Cloning and dropping keep count of the senders, #[derive(Clone)]
+ * would also require T: Clone and wouldn't count
+ * */
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Sender<T> {
+        self.shared.inner.lock().unwrap().senders += 1;
+        Sender {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.senders -= 1;
+        let last = inner.senders == 0;
+        drop(inner);
+
+        // Wake the receiver so it notices it will never get anything else
+        if last {
+            self.shared.available.notify_one();
+        }
+    }
+}
+
+impl<T> Receiver<T> {
+    // Blocks until an item arrives or every sender is gone
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        loop {
+            if let Some(item) = inner.queue.pop_front() {
+                return Ok(item);
+            }
+            if inner.senders == 0 {
+                return Err(RecvError);
+            }
+            inner = self.shared.available.wait(inner).unwrap();
+        }
+    }
+
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut inner = self.shared.inner.lock().unwrap();
+        match inner.queue.pop_front() {
+            Some(item) => Ok(item),
+            None if inner.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        inner.receiver_alive = false;
+        // Nobody will read them, drop them now rather than with the last sender
+        inner.queue.clear();
+    }
+}
+
+/*
+ * Blocking iterator ending when the senders are gone, so that
+ * `for item in receiver.iter()` works like with std's channel
+ * */
+pub struct Iter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -0,0 +1,229 @@
+/*
+ * Line-based chat room over TCP, try it with a few `nc localhost 8000`.
+ *
+ * The first line a client sends is its nickname, every line after that
+ * goes to everyone else as "nick: line". Two commands:
+ *   /nick <name>  change nickname
+ *   /who          list who is connected
+ *
+ * Every client has its own task. Messages go through a broadcast channel
+ * that all client tasks subscribe to, and nicknames live in a map shared
+ * between the tasks.
+ * */
+use crate::extras::shutdown::ShutdownFlag;
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::time;
+
+// How many messages a slow client can fall behind before missing some
+const CHANNEL_CAPACITY: usize = 100;
+// How often serve_until looks at the shutdown flag
+const SHUTDOWN_POLL: Duration = Duration::from_millis(50);
+// Clients still connected after this are dropped without goodbye
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone)]
+struct Message {
+    // None for server notices, which go to the sender too
+    from: Option<SocketAddr>,
+    text: String,
+    // The server is shutting down, clients disconnect after this one
+    last: bool,
+}
+
+type Nicknames = Arc<Mutex<HashMap<SocketAddr, String>>>;
+
+/// ```
+/// use rust_exercises::extras::chat::ChatRoom;
+/// use rust_exercises::extras::shutdown::ShutdownFlag;
+/// use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+/// use tokio::net::{TcpListener, TcpStream};
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     let listener = TcpListener::bind("127.0.0.1:0").await?;
+///     let addr = listener.local_addr()?;
+///     let (room, shutdown) = (ChatRoom::new(), ShutdownFlag::new());
+///     let server = tokio::spawn({
+///         let (room, shutdown) = (room.clone(), shutdown.clone());
+///         async move { room.serve_until(listener, shutdown).await }
+///     });
+///
+///     let mut client = BufReader::new(TcpStream::connect(addr).await?).lines();
+///     assert_eq!(client.next_line().await?.as_deref(), Some("nickname?"));
+///     client.get_mut().get_mut().write_all(b"ferris\n").await?;
+///     assert_eq!(client.next_line().await?.as_deref(), Some("* ferris joined"));
+///     client.get_mut().get_mut().write_all(b"/who\n").await?;
+///     assert_eq!(client.next_line().await?.as_deref(), Some("* here: ferris"));
+///     assert_eq!(room.nicknames(), ["ferris"]);
+///
+///     shutdown.trigger();
+///     assert_eq!(client.next_line().await?.as_deref(), Some("* server shutting down"));
+///     server.await.unwrap()
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ChatRoom {
+    sender: broadcast::Sender<Message>,
+    nicknames: Nicknames,
+}
+
+impl Default for ChatRoom {
+    fn default() -> ChatRoom {
+        ChatRoom::new()
+    }
+}
+
+impl ChatRoom {
+    pub fn new() -> ChatRoom {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        ChatRoom {
+            sender,
+            nicknames: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Sorted so /who output doesn't depend on HashMap order
+    pub fn nicknames(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.nicknames.lock().unwrap().values().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn announce(&self, text: String) {
+        // Err only means nobody is listening
+        let _ = self.sender.send(Message {
+            from: None,
+            text,
+            last: false,
+        });
+    }
+
+    /*
+     * Accepts clients forever, each one handled on its own task
+     * */
+    pub async fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        self.serve_until(listener, ShutdownFlag::new()).await
+    }
+
+    async fn triggered(shutdown: &ShutdownFlag) {
+        while !shutdown.is_triggered() {
+            time::sleep(SHUTDOWN_POLL).await;
+        }
+    }
+
+    /*
+     * Stops accepting once the flag is set, tells every client the
+     * server is going away and waits a little for them to disconnect
+     * */
+    pub async fn serve_until(&self, listener: TcpListener, shutdown: ShutdownFlag) -> io::Result<()> {
+        let mut clients = Vec::new();
+
+        loop {
+            let (stream, addr) = tokio::select! {
+                accepted = listener.accept() => accepted?,
+                _ = ChatRoom::triggered(&shutdown) => break,
+            };
+            let room = self.clone();
+            clients.push(tokio::spawn(async move {
+                if let Err(e) = room.handle_client(stream, addr).await {
+                    eprintln!("chat: {}: {}", addr, e);
+                }
+                room.leave(addr);
+            }));
+            clients.retain(|client| !client.is_finished());
+        }
+
+        let _ = self.sender.send(Message {
+            from: None,
+            text: String::from("* server shutting down"),
+            last: true,
+        });
+        let all_gone = async {
+            for client in clients.iter_mut() {
+                let _ = client.await;
+            }
+        };
+        if time::timeout(SHUTDOWN_GRACE, all_gone).await.is_err() {
+            for client in clients {
+                client.abort();
+            }
+        }
+        Ok(())
+    }
+
+    fn leave(&self, addr: SocketAddr) {
+        let nickname = self.nicknames.lock().unwrap().remove(&addr);
+        if let Some(nickname) = nickname {
+            self.announce(format!("* {} left", nickname));
+        }
+    }
+
+    async fn handle_client(&self, stream: TcpStream, addr: SocketAddr) -> io::Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        writer.write_all(b"nickname?\n").await?;
+        let mut nickname = match lines.next_line().await? {
+            Some(line) if !line.trim().is_empty() => line.trim().to_string(),
+            _ => return Ok(()),
+        };
+
+        // Subscribe before announcing so the client sees its own join
+        let mut receiver = self.sender.subscribe();
+        self.nicknames.lock().unwrap().insert(addr, nickname.clone());
+        self.announce(format!("* {} joined", nickname));
+
+        loop {
+            tokio::select! {
+                line = lines.next_line() => {
+                    let line = match line? {
+                        Some(line) => line,
+                        // The client disconnected
+                        None => return Ok(()),
+                    };
+
+                    if let Some(name) = line.strip_prefix("/nick ") {
+                        let name = name.trim().to_string();
+                        self.nicknames.lock().unwrap().insert(addr, name.clone());
+                        self.announce(format!("* {} is now {}", nickname, name));
+                        nickname = name;
+                    } else if line.trim() == "/who" {
+                        let who = format!("* here: {}\n", self.nicknames().join(", "));
+                        writer.write_all(who.as_bytes()).await?;
+                    } else if !line.trim().is_empty() {
+                        let _ = self.sender.send(Message {
+                            from: Some(addr),
+                            text: format!("{}: {}", nickname, line),
+                            last: false,
+                        });
+                    }
+                }
+                message = receiver.recv() => {
+                    let message = match message {
+                        Ok(message) => message,
+                        Err(broadcast::error::RecvError::Lagged(missed)) => Message {
+                            from: None,
+                            text: format!("* missed {} messages", missed),
+                            last: false,
+                        },
+                        Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                    };
+                    if message.from != Some(addr) {
+                        writer.write_all(message.text.as_bytes()).await?;
+                        writer.write_all(b"\n").await?;
+                    }
+                    if message.last {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+}
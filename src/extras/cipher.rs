@@ -0,0 +1,158 @@
+/*
+ * Caesar and Vigenère ciphers on ASCII letters. Case is kept and
+ * everything that isn't a letter passes through unchanged.
+ * */
+use std::error::Error;
+use std::fmt;
+
+// Shifts one char within its case, shift may be negative or above 26
+fn shift_char(c: char, shift: i32) -> char {
+    let base = if c.is_ascii_lowercase() {
+        b'a'
+    } else if c.is_ascii_uppercase() {
+        b'A'
+    } else {
+        return c;
+    };
+
+    let offset = (c as u8 - base) as i32;
+    (base + (offset + shift).rem_euclid(26) as u8) as char
+}
+
+/// ```
+/// use rust_exercises::extras::cipher::{caesar_decrypt, caesar_encrypt};
+///
+/// assert_eq!(caesar_encrypt("Hello, World!", 3), "Khoor, Zruog!");
+/// assert_eq!(caesar_encrypt("abc", -1), "zab");
+/// assert_eq!(caesar_decrypt("Khoor", 29), "Hello");
+/// ```
+pub fn caesar_encrypt(text: &str, shift: i32) -> String {
+    text.chars().map(|c| shift_char(c, shift)).collect()
+}
+
+pub fn caesar_decrypt(text: &str, shift: i32) -> String {
+    caesar_encrypt(text, -shift)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyError {
+    // The key has to be made of ASCII letters, and at least one
+    Empty,
+    InvalidChar(char),
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            KeyError::Empty => write!(f, "the key is empty"),
+            KeyError::InvalidChar(c) => write!(f, "'{}' is not a letter", c),
+        }
+    }
+}
+
+impl Error for KeyError {}
+
+// "LEMON" becomes the shifts [11, 4, 12, 14, 13]
+fn key_shifts(key: &str) -> Result<Vec<i32>, KeyError> {
+    if key.is_empty() {
+        return Err(KeyError::Empty);
+    }
+
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() {
+                Ok((c.to_ascii_lowercase() as u8 - b'a') as i32)
+            } else {
+                Err(KeyError::InvalidChar(c))
+            }
+        })
+        .collect()
+}
+
+/*
+ * Every letter is shifted by the next letter of the key, repeating it as
+ * needed. Non-letters don't use up a key letter, so
+ * "ATTACK AT DAWN" with "LEMON" gives "LXFOPV EF RNHR".
+ * */
+fn vigenere(text: &str, key: &str, direction: i32) -> Result<String, KeyError> {
+    let shifts = key_shifts(key)?;
+    let mut shifts = shifts.iter().cycle();
+
+    Ok(text
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() {
+                shift_char(c, direction * shifts.next().unwrap())
+            } else {
+                c
+            }
+        })
+        .collect())
+}
+
+/// ```
+/// use rust_exercises::extras::cipher::{vigenere_decrypt, vigenere_encrypt, KeyError};
+///
+/// assert_eq!(vigenere_encrypt("ATTACK AT DAWN", "LEMON").unwrap(), "LXFOPV EF RNHR");
+/// assert_eq!(vigenere_decrypt("LXFOPV EF RNHR", "lemon").unwrap(), "ATTACK AT DAWN");
+/// assert_eq!(vigenere_encrypt("x", ""), Err(KeyError::Empty));
+/// assert_eq!(vigenere_encrypt("x", "k3y"), Err(KeyError::InvalidChar('3')));
+/// ```
+pub fn vigenere_encrypt(text: &str, key: &str) -> Result<String, KeyError> {
+    vigenere(text, key, 1)
+}
+
+pub fn vigenere_decrypt(text: &str, key: &str) -> Result<String, KeyError> {
+    vigenere(text, key, -1)
+}
+
+// Relative frequency of a to z in English text, in percent
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    8.2, 1.5, 2.8, 4.3, 12.7, 2.2, 2.0, 6.1, 7.0, 0.15, 0.77, 4.0, 2.4, 6.7, 7.5, 1.9, 0.095, 6.0,
+    6.3, 9.1, 2.8, 0.98, 2.4, 0.15, 2.0, 0.074,
+];
+
+/*
+ * Chi-squared distance between the letters of text and English,
+ * the lower the more it looks like English.
+ * */
+fn english_score(text: &str) -> f64 {
+    let mut counts = [0usize; 26];
+    let mut total = 0;
+    for c in text.chars().filter(|c| c.is_ascii_alphabetic()) {
+        counts[(c.to_ascii_lowercase() as u8 - b'a') as usize] += 1;
+        total += 1;
+    }
+    if total == 0 {
+        return f64::MAX;
+    }
+
+    counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES.iter())
+        .map(|(&count, &frequency)| {
+            let expected = total as f64 * frequency / 100.0;
+            (count as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+/*
+ * Tries all 26 shifts and keeps the one whose output looks the most like
+ * English. Returns the shift and the decrypted text, short texts can fool it.
+ * */
+/// ```
+/// use rust_exercises::extras::cipher::{caesar_crack, caesar_encrypt};
+///
+/// let secret = caesar_encrypt("Meet me at the usual place at ten rather than eight", 11);
+/// assert_eq!(
+///     caesar_crack(&secret),
+///     (11, String::from("Meet me at the usual place at ten rather than eight"))
+/// );
+/// ```
+pub fn caesar_crack(ciphertext: &str) -> (i32, String) {
+    (0..26)
+        .map(|shift| (shift, caesar_decrypt(ciphertext, shift)))
+        .min_by(|a, b| english_score(&a.1).total_cmp(&english_score(&b.1)))
+        .unwrap()
+}
@@ -0,0 +1,159 @@
+/*
+ * The same small tasks written twice over Option and Result: once with
+ * match, spelling out every case, and once with the combinators,
+ * https://doc.rust-lang.org/std/option/enum.Option.html#method.and_then
+ *
+ *   with_match::host_port("db:5432")        Ok(("db", 5432))
+ *   with_combinators::host_port("db:5432")  the same, in one chain
+ *
+ * Both modules have the same functions with the same results, reading
+ * one next to the other shows what each combinator stands for:
+ *
+ *   map            Some(x) => Some(f(x)), None => None
+ *   and_then       the same when f itself may give None
+ *   filter         Some(x) if test(x) => Some(x), _ => None
+ *   ok_or          None becomes an Err
+ *   unwrap_or_else None becomes a value, computed only then
+ * */
+use std::fmt;
+use std::num::ParseIntError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddrError {
+    MissingPort,
+    EmptyHost,
+    BadPort(ParseIntError),
+}
+
+impl fmt::Display for AddrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddrError::MissingPort => write!(f, "expected host:port"),
+            AddrError::EmptyHost => write!(f, "no host before the ':'"),
+            AddrError::BadPort(e) => write!(f, "bad port: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AddrError {}
+
+/*
+ * Settings where every level may be missing, like a config file that
+ * only sets what it needs
+ * */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub server: Option<Server>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Server {
+    pub tls: Option<Tls>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tls {
+    pub cert: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct User {
+    pub id: u32,
+    pub nickname: Option<String>,
+}
+
+/// ```
+/// use rust_exercises::extras::combinators::{with_combinators, with_match, AddrError};
+///
+/// assert_eq!(with_match::host_port("[::1]:8080"), Ok(("[::1]", 8080)));
+/// assert_eq!(with_match::host_port(":80"), Err(AddrError::EmptyHost));
+/// assert_eq!(with_combinators::host_port("localhost"), Err(AddrError::MissingPort));
+/// ```
+pub mod with_match {
+    use super::{AddrError, Config, User};
+
+    /*
+     * Splits at the last ':' so the host part may contain colons, the
+     * port has to parse as a u16
+     * */
+    pub fn host_port(s: &str) -> Result<(&str, u16), AddrError> {
+        let (host, port) = match s.rsplit_once(':') {
+            Some(parts) => parts,
+            None => return Err(AddrError::MissingPort),
+        };
+        if host.is_empty() {
+            return Err(AddrError::EmptyHost);
+        }
+        match port.parse() {
+            Ok(port) => Ok((host, port)),
+            Err(e) => Err(AddrError::BadPort(e)),
+        }
+    }
+
+    // server.tls.cert, None when any of them isn't set
+    pub fn cert_path(config: &Config) -> Option<&str> {
+        match config.server {
+            Some(ref server) => match server.tls {
+                Some(ref tls) => match tls.cert {
+                    Some(ref cert) => Some(cert.as_str()),
+                    None => None,
+                },
+                None => None,
+            },
+            None => None,
+        }
+    }
+
+    // The nickname unless it's missing or blank, "user <id>" otherwise
+    pub fn display_name(user: &User) -> String {
+        match user.nickname {
+            Some(ref nickname) if !nickname.trim().is_empty() => nickname.trim().to_string(),
+            _ => format!("user {}", user.id),
+        }
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::combinators::{with_combinators, Config, Server, Tls, User};
+///
+/// let config = Config {
+///     server: Some(Server {
+///         tls: Some(Tls { cert: Some(String::from("cert.pem")) }),
+///     }),
+/// };
+/// assert_eq!(with_combinators::cert_path(&config), Some("cert.pem"));
+/// assert_eq!(with_combinators::cert_path(&Config::default()), None);
+///
+/// let user = User { id: 7, nickname: Some(String::from("  ")) };
+/// assert_eq!(with_combinators::display_name(&user), "user 7");
+/// ```
+pub mod with_combinators {
+    use super::{AddrError, Config, User};
+
+    pub fn host_port(s: &str) -> Result<(&str, u16), AddrError> {
+        let (host, port) = s.rsplit_once(':').ok_or(AddrError::MissingPort)?;
+        let host = Some(host)
+            .filter(|host| !host.is_empty())
+            .ok_or(AddrError::EmptyHost)?;
+        port.parse()
+            .map(|port| (host, port))
+            .map_err(AddrError::BadPort)
+    }
+
+    pub fn cert_path(config: &Config) -> Option<&str> {
+        config
+            .server
+            .as_ref()
+            .and_then(|server| server.tls.as_ref())
+            .and_then(|tls| tls.cert.as_deref())
+    }
+
+    pub fn display_name(user: &User) -> String {
+        user.nickname
+            .as_deref()
+            .map(str::trim)
+            .filter(|nickname| !nickname.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("user {}", user.id))
+    }
+}
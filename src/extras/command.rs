@@ -0,0 +1,253 @@
+/*
+ * Command pattern: every edit to a Document is a value that knows how to
+ * apply itself and how to take itself back, so an UndoStack can keep the
+ * history as Box<dyn Command> and walk it both ways.
+ *
+ *   let mut history = UndoStack::new();
+ *   history.execute(&mut doc, Box::new(Insert::new(0, "hello")))?;
+ *   history.undo(&mut doc);
+ *   history.redo(&mut doc)?;
+ *
+ * A new edit after some undos throws the undone ones away, like every
+ * editor does: they were made on a text that no longer exists.
+ *
+ * Offsets are in bytes, like String::insert_str, and have to fall on a
+ * char boundary.
+ * */
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    text: String,
+}
+
+impl Document {
+    pub fn new() -> Document {
+        Document::default()
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn check(&self, at: usize) -> Result<(), EditError> {
+        if at > self.text.len() {
+            Err(EditError::OutOfBounds {
+                at,
+                len: self.text.len(),
+            })
+        } else if !self.text.is_char_boundary(at) {
+            Err(EditError::NotCharBoundary(at))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl From<&str> for Document {
+    fn from(text: &str) -> Document {
+        Document {
+            text: text.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditError {
+    OutOfBounds { at: usize, len: usize },
+    NotCharBoundary(usize),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditError::OutOfBounds { at, len } => {
+                write!(f, "offset {} is past the end of a {} byte text", at, len)
+            }
+            EditError::NotCharBoundary(at) => write!(f, "offset {} is inside a char", at),
+        }
+    }
+}
+
+impl Error for EditError {}
+
+/*
+ * apply() may refuse and then leaves the document as it was. revert() is
+ * only called right after apply() succeeded, or after a redo did, on the
+ * document apply() left, so it can't fail.
+ * */
+pub trait Command {
+    fn apply(&mut self, doc: &mut Document) -> Result<(), EditError>;
+
+    fn revert(&mut self, doc: &mut Document);
+
+    // For showing the history, "insert \"hello\" at 0"
+    fn describe(&self) -> String;
+}
+
+#[derive(Debug, Clone)]
+pub struct Insert {
+    at: usize,
+    text: String,
+}
+
+impl Insert {
+    pub fn new<S: Into<String>>(at: usize, text: S) -> Insert {
+        Insert {
+            at,
+            text: text.into(),
+        }
+    }
+}
+
+impl Command for Insert {
+    fn apply(&mut self, doc: &mut Document) -> Result<(), EditError> {
+        doc.check(self.at)?;
+        doc.text.insert_str(self.at, &self.text);
+        Ok(())
+    }
+
+    fn revert(&mut self, doc: &mut Document) {
+        doc.text
+            .replace_range(self.at..self.at + self.text.len(), "");
+    }
+
+    fn describe(&self) -> String {
+        format!("insert {:?} at {}", self.text, self.at)
+    }
+}
+
+/*
+ * Only knows what it deleted once it's applied, revert() puts that back
+ * */
+#[derive(Debug, Clone)]
+pub struct Delete {
+    at: usize,
+    len: usize,
+    deleted: String,
+}
+
+impl Delete {
+    pub fn new(at: usize, len: usize) -> Delete {
+        Delete {
+            at,
+            len,
+            deleted: String::new(),
+        }
+    }
+}
+
+impl Command for Delete {
+    fn apply(&mut self, doc: &mut Document) -> Result<(), EditError> {
+        doc.check(self.at)?;
+        doc.check(self.at + self.len)?;
+        self.deleted = doc.text.drain(self.at..self.at + self.len).collect();
+        Ok(())
+    }
+
+    fn revert(&mut self, doc: &mut Document) {
+        doc.text.insert_str(self.at, &self.deleted);
+    }
+
+    fn describe(&self) -> String {
+        format!("delete {} bytes at {}", self.len, self.at)
+    }
+}
+
+/*
+ * done holds what has been applied, the last one on top. undo() moves
+ * the top of done to undone, redo() moves it back.
+ * */
+/// ```
+/// use rust_exercises::extras::command::{Delete, Document, Insert, UndoStack};
+///
+/// let mut doc = Document::from("hello");
+/// let mut history = UndoStack::new();
+/// history.execute(&mut doc, Box::new(Insert::new(5, " world"))).unwrap();
+/// history.execute(&mut doc, Box::new(Delete::new(0, 1))).unwrap();
+/// assert_eq!(doc.text(), "ello world");
+///
+/// assert!(history.undo(&mut doc));
+/// assert_eq!(doc.text(), "hello world");
+/// assert_eq!(history.redo(&mut doc), Ok(true));
+/// assert_eq!(doc.text(), "ello world");
+///
+/// // A refused command leaves the document and the history alone
+/// assert!(history.execute(&mut doc, Box::new(Delete::new(20, 1))).is_err());
+/// assert_eq!(history.history().len(), 2);
+/// ```
+#[derive(Default)]
+pub struct UndoStack {
+    done: Vec<Box<dyn Command>>,
+    undone: Vec<Box<dyn Command>>,
+}
+
+impl UndoStack {
+    pub fn new() -> UndoStack {
+        UndoStack::default()
+    }
+
+    // Nothing is recorded, nor thrown away, when the command refuses
+    pub fn execute(
+        &mut self,
+        doc: &mut Document,
+        mut command: Box<dyn Command>,
+    ) -> Result<(), EditError> {
+        command.apply(doc)?;
+        self.done.push(command);
+        self.undone.clear();
+        Ok(())
+    }
+
+    // false if there was nothing to undo
+    pub fn undo(&mut self, doc: &mut Document) -> bool {
+        match self.done.pop() {
+            Some(mut command) => {
+                command.revert(doc);
+                self.undone.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /*
+     * false if there was nothing to redo. A document edited behind the
+     * stack's back can make the command refuse, it then stays first in
+     * line to be redone.
+     * */
+    pub fn redo(&mut self, doc: &mut Document) -> Result<bool, EditError> {
+        let mut command = match self.undone.pop() {
+            Some(command) => command,
+            None => return Ok(false),
+        };
+        if let Err(e) = command.apply(doc) {
+            self.undone.push(command);
+            return Err(e);
+        }
+        self.done.push(command);
+        Ok(true)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    // Oldest first
+    pub fn history(&self) -> Vec<String> {
+        self.done.iter().map(|command| command.describe()).collect()
+    }
+}
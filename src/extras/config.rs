@@ -0,0 +1,233 @@
+/*
+ * Application settings merged from three layers, each one overriding
+ * the one before:
+ *
+ *   1. defaults written in the code
+ *   2. an optional INI file, the one APP_CONFIG points to
+ *   3. APP_* environment variables, APP_PORT=9000 sets port
+ *
+ * Every value remembers which layer it came from, so a surprising
+ * setting can be traced back.
+ * */
+use crate::extras::ini::{self, GetError};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const ENV_PREFIX: &str = "APP_";
+const FILE_VAR: &str = "APP_CONFIG";
+
+// Keys and their defaults, in the order explain() lists them
+const DEFAULTS: [(&str, &str); 5] = [
+    ("host", "127.0.0.1"),
+    ("port", "8080"),
+    ("workers", "4"),
+    ("verbose", "false"),
+    ("log_level", "info"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    // The variable it was read from
+    Env(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "file {}", path.display()),
+            Source::Env(var) => write!(f, "env {}", var),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, ini::ParseError),
+    UnknownKey {
+        key: String,
+        source: Source,
+    },
+    Invalid {
+        key: String,
+        value: String,
+        source: Source,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::Parse(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::UnknownKey { key, source } => {
+                write!(f, "unknown setting {:?} from {}", key, source)
+            }
+            LoadError::Invalid { key, value, source } => {
+                write!(f, "invalid {} {:?} from {}", key, value, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// ```
+/// use rust_exercises::extras::config::{Config, Source};
+///
+/// let vars = vec![
+///     (String::from("APP_PORT"), String::from("9000")),
+///     (String::from("HOME"), String::from("/root")),
+/// ];
+/// let config = Config::load_from(None, vars).unwrap();
+/// assert_eq!(config.port, 9000);
+/// assert_eq!(config.source("port"), Some(&Source::Env(String::from("APP_PORT"))));
+/// assert_eq!(config.host, "127.0.0.1");
+/// assert_eq!(config.source("host"), Some(&Source::Default));
+///
+/// let unknown = vec![(String::from("APP_COLOUR"), String::from("blue"))];
+/// assert!(Config::load_from(None, unknown).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub host: String,
+    pub port: u16,
+    pub workers: usize,
+    pub verbose: bool,
+    pub log_level: String,
+    sources: BTreeMap<&'static str, Source>,
+}
+
+fn known_key(key: &str) -> Option<&'static str> {
+    DEFAULTS
+        .iter()
+        .map(|&(known, _)| known)
+        .find(|&known| known == key)
+}
+
+impl Config {
+    // Reads the file APP_CONFIG names, if set, and the process environment
+    pub fn load() -> Result<Config, LoadError> {
+        let file = env::var_os(FILE_VAR).map(PathBuf::from);
+        Config::load_from(file.as_deref(), env::vars())
+    }
+
+    /*
+     * Same as load() with the file and the variables given, which keeps
+     * tests away from the real environment. Variables without the APP_
+     * prefix are ignored, unknown APP_ ones are an error like unknown
+     * keys in the file, APP_CONFIG aside.
+     * */
+    pub fn load_from<I>(file: Option<&Path>, vars: I) -> Result<Config, LoadError>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut merged = ini::Config::new();
+        let mut sources = BTreeMap::new();
+
+        for &(key, value) in DEFAULTS.iter() {
+            merged
+                .set("", key, value)
+                .expect("the defaults can be written as INI");
+            sources.insert(key, Source::Default);
+        }
+
+        if let Some(path) = file {
+            let text =
+                fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+            let parsed =
+                ini::Config::parse(&text).map_err(|e| LoadError::Parse(path.to_path_buf(), e))?;
+
+            for section in parsed.sections() {
+                for (key, value) in parsed.entries(section) {
+                    let source = Source::File(path.to_path_buf());
+                    let known = match (section, known_key(key)) {
+                        ("", Some(known)) => known,
+                        _ => {
+                            let key = if section.is_empty() {
+                                key.to_string()
+                            } else {
+                                format!("{}.{}", section, key)
+                            };
+                            return Err(LoadError::UnknownKey { key, source });
+                        }
+                    };
+                    merged
+                        .set("", known, value)
+                        .expect("what was parsed can be set again");
+                    sources.insert(known, source);
+                }
+            }
+        }
+
+        // Sorted so that an error always names the same variable
+        let mut vars: Vec<(String, String)> = vars
+            .into_iter()
+            .filter(|(var, _)| var.starts_with(ENV_PREFIX) && var != FILE_VAR)
+            .collect();
+        vars.sort();
+
+        for (var, value) in vars {
+            let key = var[ENV_PREFIX.len()..].to_lowercase();
+            let source = Source::Env(var.clone());
+            let known = known_key(&key).ok_or(LoadError::UnknownKey {
+                key,
+                source: source.clone(),
+            })?;
+            // A line break or spaces around it, what INI text can't hold
+            merged
+                .set("", known, &value)
+                .map_err(|_| LoadError::Invalid {
+                    key: known.to_string(),
+                    value: value.clone(),
+                    source: source.clone(),
+                })?;
+            sources.insert(known, source);
+        }
+
+        let invalid = |e: GetError| match e {
+            GetError::Invalid { key, value, .. } => {
+                let source = sources[key.as_str()].clone();
+                LoadError::Invalid { key, value, source }
+            }
+            GetError::Missing { .. } => unreachable!("every key has a default"),
+        };
+
+        Ok(Config {
+            host: merged.get("", "host").unwrap_or_default().to_string(),
+            port: merged.get_parsed("", "port").map_err(invalid)?,
+            workers: merged.get_parsed("", "workers").map_err(invalid)?,
+            verbose: merged.get_bool("", "verbose").map_err(invalid)?,
+            log_level: merged.get("", "log_level").unwrap_or_default().to_string(),
+            sources,
+        })
+    }
+
+    pub fn source(&self, key: &str) -> Option<&Source> {
+        self.sources.get(key)
+    }
+
+    // One "key = value (source)" line per setting
+    pub fn explain(&self) -> String {
+        let values = [
+            self.host.clone(),
+            self.port.to_string(),
+            self.workers.to_string(),
+            self.verbose.to_string(),
+            self.log_level.clone(),
+        ];
+
+        DEFAULTS
+            .iter()
+            .zip(values.iter())
+            .map(|(&(key, _), value)| format!("{} = {} ({})\n", key, value, self.sources[key]))
+            .collect()
+    }
+}
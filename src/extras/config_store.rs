@@ -0,0 +1,97 @@
+/*
+ * Configuration shared between threads behind a RwLock: any number of
+ * readers at once, or a single writer.
+ * https://doc.rust-lang.org/std/sync/struct.RwLock.html
+ *
+ * Multi-key updates go through one write lock, and snapshot() copies
+ * under one read lock, so a reader never sees half of an update.
+ * */
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+/// ```
+/// use rust_exercises::extras::config_store::ConfigStore;
+/// use std::thread;
+///
+/// let store = ConfigStore::new();
+/// store.set("mode", "dark");
+/// thread::scope(|s| {
+///     s.spawn(|| store.update(&[("host", "example.com"), ("port", "443")]));
+///     // Either both keys are there or neither is
+///     let pair = store.get_many(&["host", "port"]);
+///     assert_eq!(pair[0].is_some(), pair[1].is_some());
+/// });
+/// assert_eq!(store.get("port").as_deref(), Some("443"));
+/// assert_eq!(store.snapshot().len(), 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigStore {
+    values: RwLock<HashMap<String, String>>,
+}
+
+impl ConfigStore {
+    pub fn new() -> ConfigStore {
+        ConfigStore {
+            values: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.values.read().unwrap().get(key).cloned()
+    }
+
+    pub fn set(&self, key: &str, value: &str) {
+        self.values
+            .write()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    /*
+     * All the pairs are applied under a single write lock
+     * */
+    pub fn update(&self, pairs: &[(&str, &str)]) {
+        let mut values = self.values.write().unwrap();
+        for &(key, value) in pairs {
+            values.insert(key.to_string(), value.to_string());
+        }
+    }
+
+    pub fn snapshot(&self) -> HashMap<String, String> {
+        self.values.read().unwrap().clone()
+    }
+
+    // Reads several keys consistently without copying the whole map
+    pub fn get_many(&self, keys: &[&str]) -> Vec<Option<String>> {
+        let values = self.values.read().unwrap();
+        keys.iter().map(|&key| values.get(key).cloned()).collect()
+    }
+}
+
+/*
+ * Same store with a Mutex, readers wait for each other too. Only there
+ * to compare against in benches/config_store.rs.
+ * */
+#[derive(Debug, Default)]
+pub struct MutexConfigStore {
+    values: Mutex<HashMap<String, String>>,
+}
+
+impl MutexConfigStore {
+    pub fn new() -> MutexConfigStore {
+        MutexConfigStore {
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.values.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn set(&self, key: &str, value: &str) {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+    }
+}
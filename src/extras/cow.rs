@@ -0,0 +1,106 @@
+/*
+ * Clone-on-write text processing with std::borrow::Cow,
+ * https://doc.rust-lang.org/std/borrow/enum.Cow.html
+ *
+ * Most inputs need no change, so each function first looks for something
+ * to fix and hands back the input as Cow::Borrowed when there is nothing,
+ * only allocating a String (Cow::Owned) when it really has to.
+ * */
+use std::borrow::Cow;
+
+/*
+ * Replaces control characters by spaces, except newlines which are kept
+ * */
+/// ```
+/// use rust_exercises::extras::cow::sanitize;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(sanitize("fine\nas is"), Cow::Borrowed(_)));
+/// assert_eq!(sanitize("bell\x07 and tab\t"), "bell  and tab ");
+/// ```
+pub fn sanitize(input: &str) -> Cow<'_, str> {
+    let is_unwanted = |c: char| c.is_control() && c != '\n';
+
+    if !input.chars().any(is_unwanted) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(
+        input
+            .chars()
+            .map(|c| if is_unwanted(c) { ' ' } else { c })
+            .collect(),
+    )
+}
+
+/*
+ * Trims both ends and collapses every run of whitespace to one space.
+ * Trimming alone never allocates, the trimmed &str borrows from input.
+ * */
+/// ```
+/// use rust_exercises::extras::cow::normalize_whitespace;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(normalize_whitespace("  trimmed only "), Cow::Borrowed("trimmed only")));
+/// assert_eq!(normalize_whitespace("a \t b\n\nc"), "a b c");
+/// ```
+pub fn normalize_whitespace(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim();
+
+    let mut previous_was_space = false;
+    let needs_collapsing = trimmed.chars().any(|c| {
+        let bad = c.is_whitespace() && (c != ' ' || previous_was_space);
+        previous_was_space = c.is_whitespace();
+        bad
+    });
+
+    if !needs_collapsing {
+        return Cow::Borrowed(trimmed);
+    }
+
+    Cow::Owned(trimmed.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+/*
+ * Escapes the five characters that are special in HTML
+ * */
+/// ```
+/// use rust_exercises::extras::cow::escape_html;
+/// use std::borrow::Cow;
+///
+/// assert!(matches!(escape_html("plain text"), Cow::Borrowed(_)));
+/// assert_eq!(escape_html("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+/// ```
+pub fn escape_html(input: &str) -> Cow<'_, str> {
+    let special = ['&', '<', '>', '"', '\''];
+
+    // Everything before the first special character is copied at once
+    let first = match input.find(&special[..]) {
+        Some(first) => first,
+        None => return Cow::Borrowed(input),
+    };
+
+    let mut escaped = String::with_capacity(input.len() + 16);
+    escaped.push_str(&input[..first]);
+    for c in input[first..].chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/*
+ * Cows chain well: each step only allocates if it changes something,
+ * and into_owned() at the end is free when the last step already did.
+ * */
+pub fn clean_for_html(input: &str) -> String {
+    let sanitized = sanitize(input);
+    let normalized = normalize_whitespace(&sanitized);
+    escape_html(&normalized).into_owned()
+}
@@ -0,0 +1,5 @@
+/*
+ * Lives in core_utils now so it builds without std, still reachable
+ * from here for the code that was written against extras::crc32
+ * */
+pub use crate::core_utils::crc32::*;
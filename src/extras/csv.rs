@@ -0,0 +1,259 @@
+/*
+ * CSV following https://datatracker.ietf.org/doc/html/rfc4180: fields are
+ * separated by commas, and a field in double quotes may contain commas,
+ * newlines and quotes written twice ("" is one ").
+ * */
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    // Lines start at 1, for a record over several lines it's where it started
+    UnterminatedQuote { line: usize },
+    // A quote in the middle of an unquoted field, or text right after a closing one
+    UnexpectedQuote { line: usize, column: usize },
+    UnexpectedChar { line: usize, column: usize, ch: char },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CsvError::Io(ref e) => write!(f, "io error: {}", e),
+            CsvError::UnterminatedQuote { line } => {
+                write!(f, "quoted field starting on line {} is never closed", line)
+            }
+            CsvError::UnexpectedQuote { line, column } => {
+                write!(f, "unexpected quote at line {} column {}", line, column)
+            }
+            CsvError::UnexpectedChar { line, column, ch } => write!(
+                f,
+                "unexpected '{}' after a closing quote at line {} column {}",
+                ch, line, column
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CsvError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> CsvError {
+        CsvError::Io(e)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    // At the start of a field, nothing read yet
+    FieldStart,
+    Unquoted,
+    Quoted,
+    // Just read a quote inside a quoted field, either "" or the closing one
+    QuoteInQuoted,
+}
+
+// What read_line leaves at the end of a line
+fn at_line_end(rest: &str) -> bool {
+    matches!(rest, "\n" | "\r\n" | "\r")
+}
+
+/*
+ * Iterator over the records of a reader, one Vec<String> per record.
+ * Blank lines are skipped. After an error the iterator keeps going with
+ * the next line.
+ * */
+/// ```
+/// use rust_exercises::extras::csv::{CsvError, Reader};
+///
+/// let input = "name,notes\r\nferris,\"likes \"\"rust\"\", a lot\"\r\n\r\nbad\"quote\r\n";
+/// let mut records = Reader::new(input.as_bytes());
+/// assert_eq!(records.next().unwrap().unwrap(), ["name", "notes"]);
+/// assert_eq!(
+///     records.next().unwrap().unwrap(),
+///     ["ferris", "likes \"rust\", a lot"]
+/// );
+/// // The blank line is skipped
+/// assert!(matches!(
+///     records.next(),
+///     Some(Err(CsvError::UnexpectedQuote { line: 4, .. }))
+/// ));
+/// assert!(records.next().is_none());
+/// ```
+pub struct Reader<R: BufRead> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn new(reader: R) -> Reader<R> {
+        Reader {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    // false at the end of the input
+    fn next_line(&mut self) -> io::Result<bool> {
+        self.line.clear();
+        let n = self.reader.read_line(&mut self.line)?;
+        self.line_number += 1;
+        Ok(n > 0)
+    }
+
+    fn read_record(&mut self) -> Result<Option<Vec<String>>, CsvError> {
+        // Skip blank lines between records
+        loop {
+            if !self.next_line()? {
+                return Ok(None);
+            }
+            if !self.line.trim_end_matches(['\r', '\n']).is_empty() {
+                break;
+            }
+        }
+
+        let start_line = self.line_number;
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut state = State::FieldStart;
+
+        loop {
+            for (i, c) in self.line.char_indices() {
+                let column = i + 1;
+                state = match (state, c) {
+                    (State::Quoted, '"') => State::QuoteInQuoted,
+                    (State::Quoted, c) => {
+                        field.push(c);
+                        State::Quoted
+                    }
+                    // "" inside quotes is an escaped quote
+                    (State::QuoteInQuoted, '"') => {
+                        field.push('"');
+                        State::Quoted
+                    }
+                    (State::FieldStart, '"') => State::Quoted,
+                    (State::Unquoted, '"') => {
+                        return Err(CsvError::UnexpectedQuote {
+                            line: self.line_number,
+                            column,
+                        })
+                    }
+                    (_, ',') => {
+                        record.push(field);
+                        field = String::new();
+                        State::FieldStart
+                    }
+                    // The end of the record, the rest of the line is the "\n".
+                    // A '\r' anywhere else is just a character.
+                    (_, '\r') | (_, '\n') if at_line_end(&self.line[i..]) => {
+                        record.push(field);
+                        return Ok(Some(record));
+                    }
+                    (State::QuoteInQuoted, c) => {
+                        return Err(CsvError::UnexpectedChar {
+                            line: self.line_number,
+                            column,
+                            ch: c,
+                        })
+                    }
+                    (_, c) => {
+                        field.push(c);
+                        State::Unquoted
+                    }
+                };
+            }
+
+            // The line ran out while inside quotes, the field goes on
+            if state != State::Quoted {
+                break;
+            }
+            if !self.next_line()? {
+                return Err(CsvError::UnterminatedQuote { line: start_line });
+            }
+        }
+
+        // Last record of a file that doesn't end with a newline
+        record.push(field);
+        Ok(Some(record))
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<Vec<String>, CsvError>;
+
+    fn next(&mut self) -> Option<Result<Vec<String>, CsvError>> {
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/*
+ * Quotes a field only when it has to: when it contains a comma, a quote
+ * or a line break, or has leading or trailing spaces.
+ * */
+pub fn escape_field(field: &str) -> String {
+    let needs_quotes = field.contains([',', '"', '\n', '\r'])
+        || field.starts_with(' ')
+        || field.ends_with(' ');
+
+    if needs_quotes {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::csv::{escape_field, Writer};
+///
+/// assert_eq!(escape_field("plain"), "plain");
+/// assert_eq!(escape_field("a, b"), "\"a, b\"");
+///
+/// let mut writer = Writer::new(Vec::new());
+/// writer.write_record(&["id", "say \"hi\""]).unwrap();
+/// assert_eq!(writer.into_inner(), b"id,\"say \"\"hi\"\"\"\r\n");
+/// ```
+pub struct Writer<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(writer: W) -> Writer<W> {
+        Writer { writer }
+    }
+
+    // Records end with "\r\n" as the RFC asks
+    pub fn write_record<S: AsRef<str>>(&mut self, fields: &[S]) -> io::Result<()> {
+        let line: Vec<String> = fields
+            .iter()
+            .map(|field| escape_field(field.as_ref()))
+            .collect();
+        // A lone empty field would be a blank line, which readers skip
+        if line.len() == 1 && line[0].is_empty() {
+            return self.writer.write_all(b"\"\"\r\n");
+        }
+        self.writer.write_all(line.join(",").as_bytes())?;
+        self.writer.write_all(b"\r\n")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
@@ -0,0 +1,282 @@
+/*
+ * Calendar dates in the proleptic Gregorian calendar, the one ISO 8601
+ * uses, extended backwards before 1582.
+ *
+ * Arithmetic converts to a day count since 1970-01-01 and back with
+ * Howard Hinnant's days_from_civil / civil_from_days,
+ * https://howardhinnant.github.io/date_algorithms.html
+ * */
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    InvalidMonth(u8),
+    InvalidDay { year: i32, month: u8, day: u8 },
+    InvalidFormat(String),
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateError::InvalidMonth(month) => write!(f, "invalid month {}", month),
+            DateError::InvalidDay { year, month, day } => {
+                write!(f, "{:04}-{:02} has no day {}", year, month, day)
+            }
+            DateError::InvalidFormat(s) => write!(f, "expected YYYY-MM-DD, got {:?}", s),
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+// Field order makes the derived Ord chronological
+/// ```
+/// use rust_exercises::extras::datetime::{Date, DateError, Weekday};
+///
+/// let date: Date = "2024-02-28".parse().unwrap();
+/// assert_eq!((date + 1).to_string(), "2024-02-29");
+/// assert_eq!((date + 2).to_string(), "2024-03-01");
+/// assert_eq!(date.weekday(), Weekday::Wednesday);
+/// assert_eq!(date - Date::new(2024, 1, 1).unwrap(), 58);
+/// assert_eq!(date.ordinal(), 59);
+///
+/// assert_eq!(
+///     Date::new(2023, 2, 29),
+///     Err(DateError::InvalidDay { year: 2023, month: 2, day: 29 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+// Every 4 years, except centuries, except every 400 years
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+impl Date {
+    pub const UNIX_EPOCH: Date = Date {
+        year: 1970,
+        month: 1,
+        day: 1,
+    };
+
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Date, DateError> {
+        let last_day = days_in_month(year, month).ok_or(DateError::InvalidMonth(month))?;
+        if day == 0 || day > last_day {
+            return Err(DateError::InvalidDay { year, month, day });
+        }
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /*
+     * Days since 1970-01-01, negative before. The year is shifted to
+     * start in March so the leap day is the last day of its year, then
+     * split into 400 year eras of exactly 146097 days.
+     * */
+    pub fn days_since_epoch(&self) -> i64 {
+        let month = self.month as i64;
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month_from_march = (month + 9) % 12;
+        let day_of_year = (153 * month_from_march + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    // The inverse of days_since_epoch
+    pub fn from_days_since_epoch(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    pub fn add_days(&self, days: i64) -> Date {
+        Date::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    // 1970-01-01 was a Thursday
+    pub fn weekday(&self) -> Weekday {
+        let index = (self.days_since_epoch() + 3).rem_euclid(7);
+        WEEKDAYS[index as usize]
+    }
+
+    // 1 for January 1st
+    pub fn ordinal(&self) -> u16 {
+        let start = Date {
+            year: self.year,
+            month: 1,
+            day: 1,
+        };
+        (self.days_since_epoch() - start.days_since_epoch() + 1) as u16
+    }
+}
+
+impl Add<i64> for Date {
+    type Output = Date;
+
+    fn add(self, days: i64) -> Date {
+        self.add_days(days)
+    }
+}
+
+impl Sub<i64> for Date {
+    type Output = Date;
+
+    fn sub(self, days: i64) -> Date {
+        self.add_days(-days)
+    }
+}
+
+// Days between two dates, negative when rhs is later
+impl Sub<Date> for Date {
+    type Output = i64;
+
+    fn sub(self, rhs: Date) -> i64 {
+        self.days_since_epoch() - rhs.days_since_epoch()
+    }
+}
+
+// ISO 8601 calendar dates, the year needs at least four digits
+impl FromStr for Date {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Date, DateError> {
+        let invalid = || DateError::InvalidFormat(s.to_string());
+
+        let mut parts = s.rsplitn(3, '-');
+        let (day, month, year) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(day), Some(month), Some(year)) => (day, month, year),
+            _ => return Err(invalid()),
+        };
+
+        let digits =
+            |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+        let unsigned_year = year.strip_prefix('-').unwrap_or(year);
+        if !digits(day, 2)
+            || !digits(month, 2)
+            || unsigned_year.len() < 4
+            || !digits(unsigned_year, unsigned_year.len())
+        {
+            return Err(invalid());
+        }
+
+        let year = year.parse().map_err(|_| invalid())?;
+        Date::new(year, month.parse().unwrap(), day.parse().unwrap())
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.year < 0 {
+            write!(
+                f,
+                "-{:04}-{:02}-{:02}",
+                -(self.year as i64),
+                self.month,
+                self.day
+            )
+        } else {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+}
+
+/*
+ * Short human readable form like "1d 2h 3m 4s", leaving out zero
+ * units, and milliseconds below one second
+ * */
+/// ```
+/// use rust_exercises::extras::datetime::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(93_784)), "1d 2h 3m 4s");
+/// assert_eq!(format_duration(Duration::from_secs(3_600)), "1h");
+/// assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let total = duration.as_secs();
+    if total == 0 {
+        return format!("{}ms", duration.subsec_millis());
+    }
+
+    let units = [
+        (total / 86_400, "d"),
+        (total % 86_400 / 3_600, "h"),
+        (total % 3_600 / 60, "m"),
+        (total % 60, "s"),
+    ];
+    units
+        .iter()
+        .filter(|&&(value, _)| value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
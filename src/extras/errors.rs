@@ -0,0 +1,181 @@
+/*
+ * How a library designs its error type: one enum for everything the
+ * crate can fail with, each variant wrapping the lower level error
+ * instead of turning it into a string,
+ * https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+ *
+ * Display says what went wrong at this level only, source() hands out
+ * the error underneath, so the whole story is a chain:
+ *
+ *   can't load limits.txt, line 3
+ *     caused by: not a whole number
+ *     caused by: invalid digit found in string
+ *
+ * render_error_chain() prints it like that. The From impls let ? turn an
+ * io::Error or ParseIntError into an AppError on the way out.
+ *
+ * load_limits() is the example using all of it: a file with one limit
+ * per line, each a whole number from MIN_LIMIT to MAX_LIMIT.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+use std::path::Path;
+
+pub const MIN_LIMIT: u32 = 1;
+pub const MAX_LIMIT: u32 = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub value: u32,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is outside {}..={}", self.value, self.min, self.max)
+    }
+}
+
+// The bottom of its chain, nothing caused it
+impl Error for ValidationError {}
+
+#[derive(Debug)]
+pub enum AppError {
+    Io(io::Error),
+    Parse(ParseIntError),
+    Validation(ValidationError),
+    // Where it happened, wrapped around the error itself
+    Context {
+        context: String,
+        source: Box<AppError>,
+    },
+}
+
+impl AppError {
+    pub fn context<C: Into<String>>(self, context: C) -> AppError {
+        AppError::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+}
+
+/*
+ * Only this level. Writing the source in here too would print it twice
+ * once something walks the chain.
+ * */
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AppError::Io(_) => write!(f, "I/O error"),
+            AppError::Parse(_) => write!(f, "not a whole number"),
+            AppError::Validation(_) => write!(f, "value out of range"),
+            AppError::Context { context, .. } => write!(f, "{}", context),
+        }
+    }
+}
+
+impl Error for AppError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AppError::Io(e) => Some(e),
+            AppError::Parse(e) => Some(e),
+            AppError::Validation(e) => Some(e),
+            AppError::Context { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(e: io::Error) -> AppError {
+        AppError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(e: ParseIntError) -> AppError {
+        AppError::Parse(e)
+    }
+}
+
+impl From<ValidationError> for AppError {
+    fn from(e: ValidationError) -> AppError {
+        AppError::Validation(e)
+    }
+}
+
+/*
+ * The error and every cause under it, one per line, each cause indented
+ * under the one it explains
+ * */
+pub fn render_error_chain(error: &dyn Error) -> String {
+    let mut out = error.to_string();
+    let mut cause = error.source();
+    while let Some(e) = cause {
+        out.push_str("\n  caused by: ");
+        out.push_str(&e.to_string());
+        cause = e.source();
+    }
+    out
+}
+
+/// ```
+/// use rust_exercises::extras::errors::{parse_limit, AppError, ValidationError};
+///
+/// assert_eq!(parse_limit(" 42 ").unwrap(), 42);
+/// assert!(matches!(
+///     parse_limit("0"),
+///     Err(AppError::Validation(ValidationError { value: 0, min: 1, max: 10_000 }))
+/// ));
+/// assert!(matches!(parse_limit("-1"), Err(AppError::Parse(_))));
+/// ```
+pub fn parse_limit(text: &str) -> Result<u32, AppError> {
+    let value: u32 = text.trim().parse()?;
+    if !(MIN_LIMIT..=MAX_LIMIT).contains(&value) {
+        return Err(ValidationError {
+            value,
+            min: MIN_LIMIT,
+            max: MAX_LIMIT,
+        }
+        .into());
+    }
+    Ok(value)
+}
+
+// Blank lines are skipped, line numbers still count them
+/// ```
+/// use rust_exercises::extras::errors::{load_limits, render_error_chain};
+/// use std::fs;
+///
+/// let path = std::env::temp_dir().join(format!("limits-{}.txt", std::process::id()));
+/// fs::write(&path, "10\n\nten\n").unwrap();
+/// let error = load_limits(&path).unwrap_err();
+/// assert_eq!(
+///     render_error_chain(&error),
+///     format!(
+///         "can't load {}, line 3\n  caused by: not a whole number\n  caused by: invalid digit found in string",
+///         path.display()
+///     )
+/// );
+/// fs::remove_file(&path).unwrap();
+/// ```
+pub fn load_limits<P: AsRef<Path>>(path: P) -> Result<Vec<u32>, AppError> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)
+        .map_err(|e| AppError::from(e).context(format!("can't read {}", path.display())))?;
+
+    let mut limits = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let limit = parse_limit(line)
+            .map_err(|e| e.context(format!("can't load {}, line {}", path.display(), i + 1)))?;
+        limits.push(limit);
+    }
+    Ok(limits)
+}
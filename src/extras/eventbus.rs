@@ -0,0 +1,112 @@
+/*
+ * An event bus for any number of event types at once, where observer's
+ * EventBus<E> carries a single one. Handlers subscribe to one type and
+ * are only called for events of that type:
+ *
+ *   bus.subscribe(|login: &UserLoggedIn| println!("hello {}", login.name));
+ *   bus.subscribe(|order: &OrderPlaced| total += order.amount);
+ *   bus.publish(&UserLoggedIn { name: "ferris".into() });
+ *
+ * The handlers of every type share one map, so they have to be stored
+ * as the same type: Box<dyn Any>, keyed by the TypeId of their event.
+ * publish() looks up the TypeId of what it was given and downcasts each
+ * handler back to Box<dyn FnMut(&E)>, which can't fail because only
+ * handlers for E are stored under E's TypeId,
+ * https://doc.rust-lang.org/std/any/index.html
+ * */
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+type Handler<E> = Box<dyn FnMut(&E)>;
+// A Handler<E> for some E, which one is in the TypeId it's filed under
+type AnyHandler = Box<dyn Any>;
+
+/// ```
+/// use rust_exercises::extras::eventbus::EventBus;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// struct UserLoggedIn {
+///     name: String,
+/// }
+/// struct OrderPlaced {
+///     amount: u32,
+/// }
+///
+/// let total = Rc::new(Cell::new(0));
+/// let mut bus = EventBus::new();
+/// bus.subscribe(|login: &UserLoggedIn| assert_eq!(login.name, "ferris"));
+/// let counting = {
+///     let total = total.clone();
+///     bus.subscribe(move |order: &OrderPlaced| total.set(total.get() + order.amount))
+/// };
+///
+/// assert_eq!(bus.publish(&UserLoggedIn { name: "ferris".into() }), 1);
+/// bus.publish(&OrderPlaced { amount: 5 });
+/// assert!(bus.unsubscribe(counting));
+/// assert_eq!(bus.publish(&OrderPlaced { amount: 5 }), 0);
+/// assert_eq!(total.get(), 5);
+/// ```
+#[derive(Default)]
+pub struct EventBus {
+    handlers: HashMap<TypeId, Vec<(SubscriptionId, AnyHandler)>>,
+    next_id: u64,
+}
+
+impl EventBus {
+    pub fn new() -> EventBus {
+        EventBus::default()
+    }
+
+    // The event type is taken from the handler's argument
+    pub fn subscribe<E, F>(&mut self, handler: F) -> SubscriptionId
+    where
+        E: 'static,
+        F: FnMut(&E) + 'static,
+    {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        let handler: Handler<E> = Box::new(handler);
+        self.handlers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push((id, Box::new(handler)));
+        id
+    }
+
+    // false if the subscription was already gone
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        for handlers in self.handlers.values_mut() {
+            if let Some(position) = handlers
+                .iter()
+                .position(|&(handler_id, _)| handler_id == id)
+            {
+                handlers.remove(position);
+                return true;
+            }
+        }
+        false
+    }
+
+    pub fn subscribers<E: 'static>(&self) -> usize {
+        self.handlers.get(&TypeId::of::<E>()).map_or(0, Vec::len)
+    }
+
+    // Calls every handler of E in the order they subscribed, returns how many
+    pub fn publish<E: 'static>(&mut self, event: &E) -> usize {
+        let handlers = match self.handlers.get_mut(&TypeId::of::<E>()) {
+            Some(handlers) => handlers,
+            None => return 0,
+        };
+        for (_, handler) in handlers.iter_mut() {
+            let handler = handler
+                .downcast_mut::<Handler<E>>()
+                .expect("handler stored under another event's TypeId");
+            handler(event);
+        }
+        handlers.len()
+    }
+}
@@ -0,0 +1,186 @@
+/*
+ * A chain of line transforms for the filter binary, cut and grep-lite.
+ * Steps run in the order given on the command line, a step can drop the
+ * line, which skips the rest of the chain.
+ *
+ *   -d <c>      field delimiter for the -f steps after it, default tab
+ *   -f <list>   keep these 1-based fields, like 1,3 or 2-4
+ *   -m <text>   keep lines containing text
+ *   -v <text>   drop lines containing text
+ *   -u / -l     upper / lower case
+ * */
+use std::fmt;
+use std::io::{self, BufRead, ErrorKind, Write};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Fields { delimiter: char, fields: Vec<usize> },
+    Contains(String),
+    Excludes(String),
+    Upper,
+    Lower,
+}
+
+impl Step {
+    pub fn apply(&self, line: String) -> Option<String> {
+        match self {
+            Step::Fields { delimiter, fields } => {
+                let columns: Vec<&str> = line.split(*delimiter).collect();
+                let selected: Vec<&str> = fields
+                    .iter()
+                    .filter_map(|&field| columns.get(field - 1).copied())
+                    .collect();
+                Some(selected.join(&delimiter.to_string()))
+            }
+            Step::Contains(text) => Some(line).filter(|line| line.contains(text.as_str())),
+            Step::Excludes(text) => Some(line).filter(|line| !line.contains(text.as_str())),
+            Step::Upper => Some(line.to_uppercase()),
+            Step::Lower => Some(line.to_lowercase()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    MissingValue(String),
+    InvalidFields(String),
+    InvalidDelimiter(String),
+    UnknownFlag(String),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgError::MissingValue(flag) => write!(f, "{} needs a value", flag),
+            ArgError::InvalidFields(list) => write!(f, "invalid field list {:?}", list),
+            ArgError::InvalidDelimiter(d) => {
+                write!(f, "the delimiter must be one character, got {:?}", d)
+            }
+            ArgError::UnknownFlag(flag) => write!(f, "unknown flag {}", flag),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+// "1,3,5-7" to [1, 3, 5, 6, 7], fields start at 1
+/// ```
+/// use rust_exercises::extras::filter::parse_fields;
+///
+/// assert_eq!(parse_fields("1,3,5-7"), Ok(vec![1, 3, 5, 6, 7]));
+/// assert!(parse_fields("0").is_err());
+/// assert!(parse_fields("4-2").is_err());
+/// ```
+pub fn parse_fields(list: &str) -> Result<Vec<usize>, ArgError> {
+    let invalid = || ArgError::InvalidFields(list.to_string());
+    let number = |s: &str| match s.trim().parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(invalid()),
+    };
+
+    let mut fields = Vec::new();
+    for part in list.split(',') {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (number(start)?, number(end)?);
+                if start > end {
+                    return Err(invalid());
+                }
+                fields.extend(start..=end);
+            }
+            None => fields.push(number(part)?),
+        }
+    }
+    Ok(fields)
+}
+
+/// ```
+/// use rust_exercises::extras::filter::{ArgError, Chain};
+///
+/// let args = ["-d", ":", "-f", "1,7", "-v", "nologin", "-u"];
+/// let chain = Chain::from_args(args.iter().map(|arg| arg.to_string())).unwrap();
+/// assert_eq!(chain.apply("root:x:0:0:root:/root:/bin/bash").as_deref(), Some("ROOT:/BIN/BASH"));
+/// assert_eq!(chain.apply("daemon:x:1:1::/:/usr/sbin/nologin"), None);
+///
+/// let mut output = Vec::new();
+/// assert_eq!(chain.run(&b"a:b:c:d:e:f:g\n"[..], &mut output).unwrap(), 1);
+/// assert_eq!(output, b"A:G\n");
+///
+/// assert_eq!(
+///     Chain::from_args(vec![String::from("-x")]).unwrap_err(),
+///     ArgError::UnknownFlag(String::from("-x"))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Chain {
+    steps: Vec<Step>,
+}
+
+impl Chain {
+    pub fn new(steps: Vec<Step>) -> Chain {
+        Chain { steps }
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Chain, ArgError> {
+        let mut steps = Vec::new();
+        let mut delimiter = '\t';
+        let mut args = args.into_iter();
+
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| ArgError::MissingValue(flag.clone()))
+            };
+            match flag.as_str() {
+                "-d" => {
+                    let d = value()?;
+                    let mut chars = d.chars();
+                    delimiter = match (chars.next(), chars.next()) {
+                        (Some(c), None) => c,
+                        _ => return Err(ArgError::InvalidDelimiter(d)),
+                    };
+                }
+                "-f" => steps.push(Step::Fields {
+                    delimiter,
+                    fields: parse_fields(&value()?)?,
+                }),
+                "-m" => steps.push(Step::Contains(value()?)),
+                "-v" => steps.push(Step::Excludes(value()?)),
+                "-u" => steps.push(Step::Upper),
+                "-l" => steps.push(Step::Lower),
+                _ => return Err(ArgError::UnknownFlag(flag)),
+            }
+        }
+        Ok(Chain { steps })
+    }
+
+    pub fn apply(&self, line: &str) -> Option<String> {
+        self.steps
+            .iter()
+            .try_fold(line.to_string(), |line, step| step.apply(line))
+    }
+
+    /*
+     * Writes every line as soon as it's transformed, so the output keeps
+     * flowing in a pipeline that never ends, like tail -f | filter.
+     * A closed output (`| head`) ends the run without an error.
+     * Returns how many lines were written.
+     * */
+    pub fn run<R: BufRead, W: Write>(&self, input: R, mut output: W) -> io::Result<usize> {
+        let mut written = 0;
+        for line in input.lines() {
+            if let Some(line) = self.apply(&line?) {
+                match writeln!(output, "{}", line).and_then(|()| output.flush()) {
+                    Ok(()) => written += 1,
+                    Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(written),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(written)
+    }
+}
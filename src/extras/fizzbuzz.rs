@@ -0,0 +1,100 @@
+/*
+ * FizzBuzz where every rule is a trait object, so a new rule like
+ * "Bazz on 7" is added with with_rule() instead of editing the loop.
+ * */
+use std::ops::RangeInclusive;
+
+pub trait Rule {
+    // Label to print for n, or None when the rule doesn't apply
+    fn label(&self, n: u32) -> Option<&str>;
+}
+
+/*
+ * The classic kind of rule: print a label when n is a multiple of divisor
+ * */
+pub struct Divisor {
+    divisor: u32,
+    label: String,
+}
+
+impl Divisor {
+    pub fn new(divisor: u32, label: &str) -> Divisor {
+        Divisor {
+            divisor,
+            label: label.to_string(),
+        }
+    }
+}
+
+impl Rule for Divisor {
+    fn label(&self, n: u32) -> Option<&str> {
+        // A divisor of 0 never matches, 0 is only a multiple of itself
+        if self.divisor != 0 && n.is_multiple_of(self.divisor) {
+            Some(&self.label)
+        } else {
+            None
+        }
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::fizzbuzz::{Divisor, FizzBuzzer};
+///
+/// assert_eq!(
+///     FizzBuzzer::classic().run(1..=5),
+///     ["1", "2", "Fizz", "4", "Buzz"]
+/// );
+///
+/// let bazz = FizzBuzzer::classic().with_rule(Box::new(Divisor::new(7, "Bazz")));
+/// assert_eq!(bazz.line(105), "FizzBuzzBazz");
+/// assert_eq!(bazz.line(14), "Bazz");
+/// ```
+#[derive(Default)]
+pub struct FizzBuzzer {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl FizzBuzzer {
+    pub fn new() -> FizzBuzzer {
+        FizzBuzzer { rules: Vec::new() }
+    }
+
+    /*
+     * Fizz on 3 and Buzz on 5
+     * */
+    pub fn classic() -> FizzBuzzer {
+        FizzBuzzer::new()
+            .with_rule(Box::new(Divisor::new(3, "Fizz")))
+            .with_rule(Box::new(Divisor::new(5, "Buzz")))
+    }
+
+    // Rules are applied in the order they were added
+    pub fn with_rule(mut self, rule: Box<dyn Rule>) -> FizzBuzzer {
+        self.rules.push(rule);
+        self
+    }
+
+    /*
+     * Concatenates the labels of every matching rule, or falls back to
+     * the number itself when none of them match.
+     * */
+    pub fn line(&self, n: u32) -> String {
+        let mut line = String::new();
+
+        for rule in self.rules.iter() {
+            if let Some(label) = rule.label(n) {
+                line.push_str(label);
+            }
+        }
+
+        if line.is_empty() {
+            line = n.to_string();
+        }
+
+        line
+    }
+
+    pub fn run(&self, range: RangeInclusive<u32>) -> Vec<String> {
+        range.map(|n| self.line(n)).collect()
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * A vending machine as a finite state machine: the current State and the
+ * incoming Event are matched together, so every legal transition is one
+ * match arm and everything else falls through to an error.
+ *
+ * Chapter 17 encodes states as trait objects instead,
+ * https://doc.rust-lang.org/book/ch17-03-oo-design-patterns.html
+ * */
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Idle,
+    Collecting { credit: u32 },
+    Dispensing { change: u32 },
+    OutOfStock,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    InsertCoin(u32),
+    Select,
+    Cancel,
+    TakeItem,
+    Restock(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionError {
+    // The event makes no sense in this state, e.g. Select while Idle
+    Invalid { state: State, event: Event },
+    InsufficientCredit { credit: u32, price: u32 },
+    // The credit or the stock would go past u32::MAX
+    Overflow { state: State, event: Event },
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransitionError::Invalid { state, event } => {
+                write!(f, "cannot handle {:?} while {:?}", event, state)
+            }
+            TransitionError::InsufficientCredit { credit, price } => {
+                write!(f, "inserted {} but the price is {}", credit, price)
+            }
+            TransitionError::Overflow { state, event } => {
+                write!(f, "{:?} while {:?} overflows", event, state)
+            }
+        }
+    }
+}
+
+impl Error for TransitionError {}
+
+/// ```
+/// use rust_exercises::extras::fsm::{Event, State, TransitionError, VendingMachine};
+///
+/// let mut machine = VendingMachine::new(150, 1);
+/// machine.handle(Event::InsertCoin(100)).unwrap();
+/// assert_eq!(
+///     machine.handle(Event::Select),
+///     Err(TransitionError::InsufficientCredit { credit: 100, price: 150 })
+/// );
+/// machine.handle(Event::InsertCoin(100)).unwrap();
+/// assert_eq!(machine.handle(Event::Select), Ok(State::Dispensing { change: 50 }));
+/// // That was the last one
+/// assert_eq!(machine.handle(Event::TakeItem), Ok(State::OutOfStock));
+/// assert!(matches!(
+///     machine.handle(Event::InsertCoin(100)),
+///     Err(TransitionError::Invalid { .. })
+/// ));
+/// ```
+pub struct VendingMachine {
+    state: State,
+    price: u32,
+    stock: u32,
+}
+
+impl VendingMachine {
+    pub fn new(price: u32, stock: u32) -> VendingMachine {
+        VendingMachine {
+            state: if stock == 0 {
+                State::OutOfStock
+            } else {
+                State::Idle
+            },
+            price,
+            stock,
+        }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn stock(&self) -> u32 {
+        self.stock
+    }
+
+    /*
+     * Moves to the next state and returns it. On error the machine
+     * stays in the state it was in.
+     * */
+    pub fn handle(&mut self, event: Event) -> Result<State, TransitionError> {
+        let overflow = TransitionError::Overflow {
+            state: self.state,
+            event,
+        };
+        let next = match (self.state, event) {
+            (State::Idle, Event::InsertCoin(coin)) => State::Collecting { credit: coin },
+            (State::Idle, Event::Restock(count)) => {
+                self.stock = self.stock.checked_add(count).ok_or(overflow)?;
+                State::Idle
+            }
+            (State::Collecting { credit }, Event::InsertCoin(coin)) => State::Collecting {
+                credit: credit.checked_add(coin).ok_or(overflow)?,
+            },
+            (State::Collecting { credit }, Event::Select) => {
+                if credit < self.price {
+                    return Err(TransitionError::InsufficientCredit {
+                        credit,
+                        price: self.price,
+                    });
+                }
+                self.stock -= 1;
+                State::Dispensing {
+                    change: credit - self.price,
+                }
+            }
+            // The credit is handed back
+            (State::Collecting { .. }, Event::Cancel) => State::Idle,
+            (State::Dispensing { .. }, Event::TakeItem) => {
+                if self.stock == 0 {
+                    State::OutOfStock
+                } else {
+                    State::Idle
+                }
+            }
+            (State::OutOfStock, Event::Restock(count)) if count > 0 => {
+                self.stock = count;
+                State::Idle
+            }
+            (state, event) => return Err(TransitionError::Invalid { state, event }),
+        };
+
+        self.state = next;
+        Ok(next)
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * Shell-style wildcard matching:
+ *
+ *   *       any run of characters, including none
+ *   ?       exactly one character
+ *   [abc]   one of the listed characters, ranges like [a-z] work too
+ *   [!abc]  one character not listed, [^abc] is the same
+ *   \*      a literal *, any character can be escaped
+ *
+ * An unclosed [ matches itself, as in most shells.
+ *
+ * matches() doesn't recurse: it remembers the last * seen and, when the
+ * rest fails to match, goes back to it and lets it swallow one more
+ * character. That is O(pattern * text) at worst, where the naive
+ * recursive version is exponential on patterns like a*a*a*a*b.
+ * */
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Literal(char),
+    AnyOne,
+    AnyRun,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl Token {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Literal(l) => *l == c,
+            Token::AnyOne => true,
+            Token::AnyRun => unreachable!("handled by the matching loop"),
+            Token::Class { negated, ranges } => {
+                ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != *negated
+            }
+        }
+    }
+}
+
+// The class starting after a '[', None if it is never closed
+fn parse_class(chars: &[char]) -> Option<(Token, usize)> {
+    let mut i = 0;
+    let negated = matches!(chars.first(), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut ranges = Vec::new();
+    // A ] right at the start is a member, not the end
+    let mut first = true;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == ']' && !first {
+            return Some((Token::Class { negated, ranges }, i + 1));
+        }
+        first = false;
+
+        if i + 2 < chars.len() && chars[i + 1] == '-' && chars[i + 2] != ']' {
+            ranges.push((c, chars[i + 2]));
+            i += 3;
+        } else {
+            ranges.push((c, c));
+            i += 1;
+        }
+    }
+    None
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                // Consecutive stars mean the same as one
+                if tokens.last() != Some(&Token::AnyRun) {
+                    tokens.push(Token::AnyRun);
+                }
+                i += 1;
+            }
+            '?' => {
+                tokens.push(Token::AnyOne);
+                i += 1;
+            }
+            '\\' if i + 1 < chars.len() => {
+                tokens.push(Token::Literal(chars[i + 1]));
+                i += 2;
+            }
+            '[' => match parse_class(&chars[i + 1..]) {
+                Some((class, len)) => {
+                    tokens.push(class);
+                    i += 1 + len;
+                }
+                None => {
+                    tokens.push(Token::Literal('['));
+                    i += 1;
+                }
+            },
+            c => {
+                tokens.push(Token::Literal(c));
+                i += 1;
+            }
+        }
+    }
+    tokens
+}
+
+/// ```
+/// use rust_exercises::extras::glob::matches;
+///
+/// assert!(matches("*.rs", "main.rs"));
+/// assert!(matches("file?.[ch]", "file1.c"));
+/// assert!(matches("[!a-c]*", "delta"));
+/// assert!(!matches("[!a-c]*", "alpha"));
+/// assert!(matches("\\*", "*"));
+/// assert!(!matches("a*a*a*a*b", &"a".repeat(50)));
+/// ```
+pub fn matches(pattern: &str, text: &str) -> bool {
+    let tokens = tokenize(pattern);
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut t, mut p) = (0, 0);
+    // Token after the last *, and where in the text it started matching
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match tokens.get(p) {
+            Some(Token::AnyRun) => {
+                p += 1;
+                backtrack = Some((p, t));
+            }
+            Some(token) if token.matches(text[t]) => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the star take one more character and retry
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    backtrack = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    // Only stars can match the empty rest of the text
+    tokens[p..].iter().all(|token| *token == Token::AnyRun)
+}
+
+/*
+ * Names of the entries in `dir` matching the pattern, sorted. Like a
+ * shell, names starting with '.' only match a pattern starting with '.'.
+ * */
+/// ```
+/// use rust_exercises::extras::glob::filter_dir;
+/// use std::fs;
+///
+/// let dir = std::env::temp_dir().join(format!("glob-doc-{}", std::process::id()));
+/// fs::create_dir_all(&dir).unwrap();
+/// for name in ["b.txt", "a.txt", "c.md", ".hidden.txt"] {
+///     fs::write(dir.join(name), "").unwrap();
+/// }
+/// assert_eq!(filter_dir(&dir, "*.txt").unwrap(), ["a.txt", "b.txt"]);
+/// assert_eq!(filter_dir(&dir, ".*").unwrap(), [".hidden.txt"]);
+/// fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub fn filter_dir<P: AsRef<Path>>(dir: P, pattern: &str) -> io::Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') && !pattern.starts_with('.') {
+            continue;
+        }
+        if matches(pattern, &name) {
+            names.push(name);
+        }
+    }
+    names.sort();
+    Ok(names)
+}
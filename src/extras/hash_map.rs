@@ -0,0 +1,136 @@
+/*
+ * A hash map from scratch, with separate chaining: a Vec of buckets,
+ * each a small Vec of the (key, value) pairs whose hash lands there.
+ *
+ *   bucket  hash(key) % buckets.len()
+ *   grow    doubles the buckets once there are more entries than
+ *           buckets, every pair is rehashed into the new ones
+ *
+ * Keys are hashed with std's RandomState, the SipHash std::HashMap uses
+ * too, so benches/collections.rs compares the tables and not the hash
+ * functions. std's HashMap is a SwissTable with open addressing,
+ * https://abseil.io/about/design/swisstables
+ * */
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+
+const INITIAL_BUCKETS: usize = 8;
+
+/// ```
+/// use rust_exercises::extras::hash_map::MyHashMap;
+///
+/// let mut ages = MyHashMap::new();
+/// assert_eq!(ages.insert("ferris", 7), None);
+/// assert_eq!(ages.insert("ferris", 8), Some(7));
+/// assert_eq!(ages.get("ferris"), Some(&8));
+/// assert_eq!(ages.remove("ferris"), Some(8));
+/// assert!(ages.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MyHashMap<K, V> {
+    buckets: Vec<Vec<(K, V)>>,
+    len: usize,
+    hasher: RandomState,
+}
+
+impl<K: Hash + Eq, V> Default for MyHashMap<K, V> {
+    fn default() -> MyHashMap<K, V> {
+        MyHashMap::new()
+    }
+}
+
+impl<K: Hash + Eq, V> MyHashMap<K, V> {
+    pub fn new() -> MyHashMap<K, V> {
+        MyHashMap {
+            buckets: Vec::new(),
+            len: 0,
+            hasher: RandomState::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn bucket<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        (self.hasher.hash_one(key) % self.buckets.len() as u64) as usize
+    }
+
+    // The old value if the key was there, the key given is then dropped
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.len >= self.buckets.len() {
+            self.grow();
+        }
+        let bucket = self.bucket(&key);
+        let pairs = &mut self.buckets[bucket];
+        if let Some((_, old)) = pairs.iter_mut().find(|(k, _)| *k == key) {
+            return Some(std::mem::replace(old, value));
+        }
+        pairs.push((key, value));
+        self.len += 1;
+        None
+    }
+
+    fn grow(&mut self) {
+        let buckets = (self.buckets.len() * 2).max(INITIAL_BUCKETS);
+        let old = std::mem::replace(
+            &mut self.buckets,
+            (0..buckets).map(|_| Vec::new()).collect(),
+        );
+        for (key, value) in old.into_iter().flatten() {
+            let bucket = self.bucket(&key);
+            self.buckets[bucket].push((key, value));
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.len == 0 {
+            return None;
+        }
+        self.buckets[self.bucket(key)]
+            .iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    // Order within a bucket doesn't matter, so the last pair fills the hole
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.len == 0 {
+            return None;
+        }
+        let bucket = self.bucket(key);
+        let pairs = &mut self.buckets[bucket];
+        let index = pairs.iter().position(|(k, _)| k.borrow() == key)?;
+        self.len -= 1;
+        Some(pairs.swap_remove(index).1)
+    }
+
+    // In no particular order, like std's
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.buckets
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key, value))
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * Same output as `xxd`: the offset, 16 bytes in groups of two, then the
+ * bytes again as ASCII with '.' for anything not printable.
+ *
+ *   00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a       Hello, World!.
+ * */
+use std::io::{self, Read, Write};
+
+const BYTES_PER_LINE: usize = 16;
+
+// 8 groups of 4 hex digits with a space between each
+const HEX_WIDTH: usize = 39;
+
+fn format_line(offset: usize, bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(HEX_WIDTH);
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 && i % 2 == 0 {
+            hex.push(' ');
+        }
+        hex.push_str(&format!("{:02x}", byte));
+    }
+
+    let ascii: String = bytes
+        .iter()
+        .map(|&byte| {
+            if (0x20..0x7f).contains(&byte) {
+                byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+
+    format!("{:08x}: {:<width$}  {}\n", offset, hex, ascii, width = HEX_WIDTH)
+}
+
+/*
+ * Fills buf as much as the reader allows, read() alone may stop short
+ * on pipes and sockets. Less than buf.len() means the input ended.
+ * */
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+pub fn dump<R: Read, W: Write>(mut reader: R, mut writer: W) -> io::Result<()> {
+    let mut buf = [0; BYTES_PER_LINE];
+    let mut offset = 0;
+
+    loop {
+        let n = read_full(&mut reader, &mut buf)?;
+        if n == 0 {
+            break;
+        }
+        writer.write_all(format_line(offset, &buf[..n]).as_bytes())?;
+        offset += n;
+    }
+
+    writer.flush()
+}
+
+/// ```
+/// use rust_exercises::extras::hexdump::dump_to_string;
+///
+/// assert_eq!(
+///     dump_to_string(b"Hello, World!\n"),
+///     "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a       Hello, World!.\n"
+/// );
+/// assert_eq!(dump_to_string(b""), "");
+/// ```
+pub fn dump_to_string(bytes: &[u8]) -> String {
+    let mut output = Vec::new();
+    // Writing to a Vec can't fail
+    dump(bytes, &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
@@ -0,0 +1,285 @@
+/*
+ * HTTP/1.1 GET over a plain TcpStream, no TLS and no external crates,
+ * https://www.rfc-editor.org/rfc/rfc9112
+ *
+ * The request asks the server to close the connection afterwards, so
+ * a body without Content-Length simply runs until EOF. Chunked bodies
+ * aren't supported and are reported as such.
+ * */
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub enum HttpError {
+    InvalidUrl(String),
+    UnsupportedScheme(String),
+    Io(io::Error),
+    InvalidStatusLine(String),
+    InvalidHeader(String),
+    InvalidContentLength(String),
+    UnsupportedTransferEncoding(String),
+    // The connection closed before Content-Length bytes arrived
+    TruncatedBody { expected: usize, received: usize },
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HttpError::InvalidUrl(url) => write!(f, "invalid URL {:?}", url),
+            HttpError::UnsupportedScheme(s) => write!(f, "unsupported scheme {:?}, only http", s),
+            HttpError::Io(e) => write!(f, "{}", e),
+            HttpError::InvalidStatusLine(line) => write!(f, "invalid status line {:?}", line),
+            HttpError::InvalidHeader(line) => write!(f, "invalid header {:?}", line),
+            HttpError::InvalidContentLength(v) => write!(f, "invalid Content-Length {:?}", v),
+            HttpError::UnsupportedTransferEncoding(v) => {
+                write!(f, "unsupported Transfer-Encoding {:?}", v)
+            }
+            HttpError::TruncatedBody { expected, received } => {
+                write!(f, "body truncated, got {} of {} bytes", received, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> HttpError {
+        HttpError::Io(e)
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::http_client::{HttpError, Url};
+///
+/// let url = Url::parse("http://localhost:8080/search?q=rust#results").unwrap();
+/// assert_eq!((url.host.as_str(), url.port, url.path.as_str()), ("localhost", 8080, "/search?q=rust"));
+/// assert_eq!(Url::parse("http://example.com").unwrap().path, "/");
+/// assert!(matches!(Url::parse("https://example.com"), Err(HttpError::UnsupportedScheme(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    // Always starts with '/', includes the query string
+    pub path: String,
+}
+
+impl Url {
+    // http://host[:port][/path], the fragment is dropped
+    pub fn parse(url: &str) -> Result<Url, HttpError> {
+        let invalid = || HttpError::InvalidUrl(url.to_string());
+
+        let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(HttpError::UnsupportedScheme(scheme.to_string()));
+        }
+
+        let rest = rest.split('#').next().unwrap_or("");
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        let path = if path.starts_with('?') {
+            format!("/{}", path)
+        } else {
+            path.to_string()
+        };
+
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() || host.contains('@') {
+            return Err(invalid());
+        }
+
+        Ok(Url {
+            host: host.to_string(),
+            port,
+            path,
+        })
+    }
+
+    // Host header value, the port only when it isn't the default
+    fn host_header(&self) -> String {
+        if self.port == 80 {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub reason: String,
+    // In the order received, names as the server wrote them
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    // Header names are case-insensitive
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+// One line without its \r\n, None at EOF
+fn read_line<R: BufRead>(reader: &mut R) -> Result<Option<String>, HttpError> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    let trimmed = line.trim_end_matches(['\r', '\n']).len();
+    line.truncate(trimmed);
+    Ok(Some(line))
+}
+
+fn parse_status_line(line: &str) -> Result<(u16, String), HttpError> {
+    let invalid = || HttpError::InvalidStatusLine(line.to_string());
+
+    let mut parts = line.splitn(3, ' ');
+    let version = parts.next().ok_or_else(invalid)?;
+    if !version.starts_with("HTTP/1.") {
+        return Err(invalid());
+    }
+    let status = parts.next().ok_or_else(invalid)?;
+    if status.len() != 3 {
+        return Err(invalid());
+    }
+    let status = status.parse().map_err(|_| invalid())?;
+    let reason = parts.next().unwrap_or("").to_string();
+    Ok((status, reason))
+}
+
+/// ```
+/// use rust_exercises::extras::http_client::{parse_response, HttpError};
+///
+/// let raw = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nhello";
+/// let response = parse_response(raw.as_bytes()).unwrap();
+/// assert_eq!((response.status, response.reason.as_str()), (200, "OK"));
+/// assert_eq!(response.header("content-type"), Some("text/plain"));
+/// assert_eq!(response.text(), "hello");
+///
+/// let short = "HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nhello";
+/// assert!(matches!(
+///     parse_response(short.as_bytes()),
+///     Err(HttpError::TruncatedBody { expected: 10, received: 5 })
+/// ));
+/// ```
+pub fn parse_response<R: BufRead>(mut reader: R) -> Result<Response, HttpError> {
+    let status_line =
+        read_line(&mut reader)?.ok_or_else(|| HttpError::InvalidStatusLine(String::new()))?;
+    let (status, reason) = parse_status_line(&status_line)?;
+
+    let mut headers = Vec::new();
+    loop {
+        let line =
+            read_line(&mut reader)?.ok_or_else(|| HttpError::InvalidHeader(String::new()))?;
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| HttpError::InvalidHeader(line.clone()))?;
+        if name.is_empty() || name.contains(' ') {
+            return Err(HttpError::InvalidHeader(line.clone()));
+        }
+        headers.push((name.to_string(), value.trim().to_string()));
+    }
+
+    let mut response = Response {
+        status,
+        reason,
+        headers,
+        body: Vec::new(),
+    };
+
+    if let Some(encoding) = response.header("Transfer-Encoding") {
+        if !encoding.eq_ignore_ascii_case("identity") {
+            return Err(HttpError::UnsupportedTransferEncoding(encoding.to_string()));
+        }
+    }
+
+    // These never have a body, whatever the headers say
+    if (100..200).contains(&status) || status == 204 || status == 304 {
+        return Ok(response);
+    }
+
+    match response.header("Content-Length") {
+        Some(length) => {
+            let expected: usize = length
+                .parse()
+                .map_err(|_| HttpError::InvalidContentLength(length.to_string()))?;
+            let mut body = Vec::with_capacity(expected.min(1 << 20));
+            reader.take(expected as u64).read_to_end(&mut body)?;
+            if body.len() < expected {
+                return Err(HttpError::TruncatedBody {
+                    expected,
+                    received: body.len(),
+                });
+            }
+            response.body = body;
+        }
+        None => {
+            reader.read_to_end(&mut response.body)?;
+        }
+    }
+    Ok(response)
+}
+
+/// ```
+/// use rust_exercises::extras::http_client::get;
+/// use std::io::{BufRead, BufReader, Write};
+/// use std::net::TcpListener;
+/// use std::thread;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+/// let port = listener.local_addr().unwrap().port();
+/// let server = thread::spawn(move || {
+///     let (stream, _) = listener.accept().unwrap();
+///     let mut request = BufReader::new(stream);
+///     let mut line = String::new();
+///     // Up to the blank line ending the headers
+///     while request.read_line(&mut line).unwrap() > 2 {
+///         line.clear();
+///     }
+///     request.get_mut().write_all(b"HTTP/1.1 404 Not Found\r\n\r\nnothing here").unwrap();
+/// });
+///
+/// let response = get(&format!("http://127.0.0.1:{}/missing", port)).unwrap();
+/// assert_eq!(response.status, 404);
+/// assert_eq!(response.text(), "nothing here");
+/// server.join().unwrap();
+/// ```
+pub fn get(url: &str) -> Result<Response, HttpError> {
+    let url = Url::parse(url)?;
+
+    let mut stream = TcpStream::connect((url.host.as_str(), url.port))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: rust-exercises\r\nAccept: */*\r\nConnection: close\r\n\r\n",
+        url.path,
+        url.host_header()
+    )?;
+    stream.flush()?;
+
+    parse_response(BufReader::new(stream))
+}
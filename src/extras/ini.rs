@@ -0,0 +1,308 @@
+/*
+ * INI config files:
+ *
+ *   ; comments start with ; or #
+ *   name = global value
+ *
+ *   [server]
+ *   port = 8080
+ *   verbose = yes
+ *
+ * Keys before the first [section] belong to the unnamed section "".
+ * Sections and keys keep the order of the file, comments are dropped.
+ *
+ * set() refuses what the text form can't hold, so that printing a Config
+ * and parsing it again always gives it back: line breaks anywhere, spaces
+ * around a name or value (parsing trims them), and keys with an = or that
+ * start like a comment or a section header.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    UnclosedSection,
+    EmptySectionName,
+    MissingEquals,
+    EmptyKey,
+}
+
+/// ```
+/// use rust_exercises::extras::ini::{Config, ParseErrorKind};
+///
+/// let error = Config::parse("[server]\nport 8080\n").unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: expected key = value");
+/// assert_eq!((error.line, error.kind), (2, ParseErrorKind::MissingEquals));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self.kind {
+            ParseErrorKind::UnclosedSection => "missing ']' after the section name",
+            ParseErrorKind::EmptySectionName => "empty section name",
+            ParseErrorKind::MissingEquals => "expected key = value",
+            ParseErrorKind::EmptyKey => "empty key",
+        };
+        write!(f, "line {}: {}", self.line, what)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetError {
+    Missing { section: String, key: String },
+    Invalid { section: String, key: String, value: String },
+}
+
+impl fmt::Display for GetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GetError::Missing {
+                ref section,
+                ref key,
+            } => write!(f, "[{}] {} is not set", section, key),
+            GetError::Invalid {
+                ref section,
+                ref key,
+                ref value,
+            } => write!(f, "[{}] {} has an invalid value '{}'", section, key, value),
+        }
+    }
+}
+
+impl Error for GetError {}
+
+// What set() was given, as it was given
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetError {
+    InvalidSection(String),
+    InvalidKey(String),
+    InvalidValue(String),
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetError::InvalidSection(name) => write!(f, "invalid section name {:?}", name),
+            SetError::InvalidKey(key) => write!(f, "invalid key {:?}", key),
+            SetError::InvalidValue(value) => write!(f, "invalid value {:?}", value),
+        }
+    }
+}
+
+impl Error for SetError {}
+
+// On one line and without spaces parsing would trim
+fn is_writable(text: &str) -> bool {
+    !text.contains(['\n', '\r']) && text.trim() == text
+}
+
+/// ```
+/// use rust_exercises::extras::ini::{Config, GetError};
+///
+/// let config = Config::parse("name = demo\n\n[server]\n; local only\nport = 8080\nverbose = yes\n").unwrap();
+/// assert_eq!(config.sections(), ["", "server"]);
+/// assert_eq!(config.get("", "name"), Some("demo"));
+/// assert_eq!(config.get_int("server", "port"), Ok(8080));
+/// assert_eq!(config.get_bool("server", "verbose"), Ok(true));
+/// assert!(matches!(config.get_bool("server", "port"), Err(GetError::Invalid { .. })));
+///
+/// // Printing and parsing again gives the same config back
+/// assert_eq!(Config::parse(&config.to_string()).unwrap(), config);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Config {
+    sections: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl Config {
+    pub fn new() -> Config {
+        Config {
+            sections: Vec::new(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Config, ParseError> {
+        let mut config = Config::new();
+        let mut section = String::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |kind| ParseError {
+                line: line_number,
+                kind,
+            };
+
+            if let Some(header) = line.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(ParseErrorKind::UnclosedSection))?
+                    .trim();
+                if name.is_empty() {
+                    return Err(error(ParseErrorKind::EmptySectionName));
+                }
+                section = name.to_string();
+                // So that empty sections survive a round trip
+                config.section_mut(&section);
+                continue;
+            }
+
+            let eq = line
+                .find('=')
+                .ok_or_else(|| error(ParseErrorKind::MissingEquals))?;
+            let key = line[..eq].trim();
+            if key.is_empty() {
+                return Err(error(ParseErrorKind::EmptyKey));
+            }
+            config.insert(&section, key, line[eq + 1..].trim());
+        }
+
+        Ok(config)
+    }
+
+    fn section_mut(&mut self, name: &str) -> &mut Vec<(String, String)> {
+        let index = match self.sections.iter().position(|(section, _)| section == name) {
+            Some(index) => index,
+            // The unnamed section goes first however late it's made, as in a file
+            None if name.is_empty() => {
+                self.sections.insert(0, (String::new(), Vec::new()));
+                0
+            }
+            None => {
+                self.sections.push((name.to_string(), Vec::new()));
+                self.sections.len() - 1
+            }
+        };
+        &mut self.sections[index].1
+    }
+
+    pub fn sections(&self) -> Vec<&str> {
+        self.sections.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    // Key value pairs of a section in file order, empty if it doesn't exist
+    pub fn entries(&self, section: &str) -> Vec<(&str, &str)> {
+        self.sections
+            .iter()
+            .find(|(name, _)| name == section)
+            .map(|(_, entries)| {
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // A key set twice keeps its first position but the last value
+    pub fn set(&mut self, section: &str, key: &str, value: &str) -> Result<(), SetError> {
+        if !is_writable(section) {
+            return Err(SetError::InvalidSection(section.to_string()));
+        }
+        if key.is_empty()
+            || !is_writable(key)
+            || key.contains('=')
+            || key.starts_with([';', '#', '['])
+        {
+            return Err(SetError::InvalidKey(key.to_string()));
+        }
+        if !is_writable(value) {
+            return Err(SetError::InvalidValue(value.to_string()));
+        }
+        self.insert(section, key, value);
+        Ok(())
+    }
+
+    // set() without the checks, for what parse() already knows is fine
+    fn insert(&mut self, section: &str, key: &str, value: &str) {
+        let entries = self.section_mut(section);
+        match entries.iter_mut().find(|(existing, _)| existing == key) {
+            Some(entry) => entry.1 = value.to_string(),
+            None => entries.push((key.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.sections
+            .iter()
+            .find(|(name, _)| name == section)
+            .and_then(|(_, entries)| entries.iter().find(|(existing, _)| existing == key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn get_required(&self, section: &str, key: &str) -> Result<&str, GetError> {
+        self.get(section, key).ok_or_else(|| GetError::Missing {
+            section: section.to_string(),
+            key: key.to_string(),
+        })
+    }
+
+    fn invalid(section: &str, key: &str, value: &str) -> GetError {
+        GetError::Invalid {
+            section: section.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /*
+     * Accepts true/false, yes/no, on/off and 1/0 in any case
+     * */
+    pub fn get_bool(&self, section: &str, key: &str) -> Result<bool, GetError> {
+        let value = self.get_required(section, key)?;
+        match value.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Ok(true),
+            "false" | "no" | "off" | "0" => Ok(false),
+            _ => Err(Config::invalid(section, key, value)),
+        }
+    }
+
+    pub fn get_int(&self, section: &str, key: &str) -> Result<i64, GetError> {
+        self.get_parsed(section, key)
+    }
+
+    // Any type implementing FromStr, like get_parsed::<f64>
+    pub fn get_parsed<T: FromStr>(&self, section: &str, key: &str) -> Result<T, GetError> {
+        let value = self.get_required(section, key)?;
+        value
+            .parse()
+            .map_err(|_| Config::invalid(section, key, value))
+    }
+}
+
+/*
+ * Writes the config back as INI text that parses to the same Config,
+ * set() made sure every name and value can be written as it is
+ * */
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The unnamed section has no header, section_mut() keeps it first
+        let mut first = true;
+        for (name, entries) in self.sections.iter() {
+            if !name.is_empty() {
+                if !first {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}]", name)?;
+            }
+            for (key, value) in entries.iter() {
+                writeln!(f, "{} = {}", key, value)?;
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Interior mutability three ways, the same counter and cache written with
+ * Cell, RefCell and Mutex. All of them change their contents through &self.
+ * https://doc.rust-lang.org/book/ch15-05-interior-mutability.html
+ *
+ *   Cell     Copy values only, get() and set() copy in and out, no borrows
+ *   RefCell  any value, borrows are checked at runtime and break by panicking
+ *   Mutex    any value, also works across threads, pays for a lock
+ *
+ * Only the Mutex versions are Sync, so sharing the others between threads
+ * doesn't compile:
+ *
+ *   let counter = Arc::new(CellCounter::new());
+ *   thread::spawn(move || counter.increment());
+ *   // error[E0277]: `Cell<u64>` cannot be shared between threads safely
+ * */
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
+/// ```
+/// use rust_exercises::extras::interior::CellCounter;
+///
+/// let counter = CellCounter::new();
+/// counter.increment();
+/// assert_eq!(counter.increment(), 2);
+/// assert_eq!(counter.get(), 2);
+/// ```
+///
+/// Only the Mutex versions can be shared between threads:
+///
+/// ```compile_fail,E0277
+/// use rust_exercises::extras::interior::CellCounter;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let counter = Arc::new(CellCounter::new());
+/// thread::spawn(move || counter.increment());
+/// ```
+#[derive(Debug, Default)]
+pub struct CellCounter {
+    count: Cell<u64>,
+}
+
+impl CellCounter {
+    pub fn new() -> CellCounter {
+        CellCounter {
+            count: Cell::new(0),
+        }
+    }
+
+    pub fn increment(&self) -> u64 {
+        self.count.set(self.count.get() + 1);
+        self.count.get()
+    }
+
+    pub fn get(&self) -> u64 {
+        self.count.get()
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::interior::MutexCounter;
+/// use std::thread;
+///
+/// let counter = MutexCounter::new();
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| counter.increment());
+///     }
+/// });
+/// assert_eq!(counter.get(), 4);
+/// ```
+#[derive(Debug, Default)]
+pub struct MutexCounter {
+    count: Mutex<u64>,
+}
+
+impl MutexCounter {
+    pub fn new() -> MutexCounter {
+        MutexCounter {
+            count: Mutex::new(0),
+        }
+    }
+
+    pub fn increment(&self) -> u64 {
+        let mut count = self.count.lock().unwrap();
+        *count += 1;
+        *count
+    }
+
+    pub fn get(&self) -> u64 {
+        *self.count.lock().unwrap()
+    }
+}
+
+/*
+ * A HashMap isn't Copy so Cell can't hold it, RefCell lends it out instead.
+ * The closure computing a missing value must not touch the cache itself.
+ * */
+/// ```
+/// use rust_exercises::extras::interior::RefCellCache;
+///
+/// let cache = RefCellCache::new();
+/// assert_eq!(cache.get_or_insert_with(3, || 9), 9);
+/// // Already there, the closure isn't called
+/// assert_eq!(cache.get_or_insert_with(3, || unreachable!()), 9);
+/// assert_eq!(cache.len(), 1);
+/// assert!(cache.double_borrow_panics());
+/// ```
+#[derive(Debug, Default)]
+pub struct RefCellCache {
+    values: RefCell<HashMap<u64, u64>>,
+}
+
+impl RefCellCache {
+    pub fn new() -> RefCellCache {
+        RefCellCache {
+            values: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> u64>(&self, key: u64, compute: F) -> u64 {
+        if let Some(&value) = self.values.borrow().get(&key) {
+            return value;
+        }
+        // The borrow above ended with the if, so this one is allowed
+        let value = compute();
+        self.values.borrow_mut().insert(key, value);
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.borrow().is_empty()
+    }
+
+    /*
+     * Borrows mutably while a shared borrow is alive. This compiles fine,
+     * the conflict is only noticed when it runs, where borrow_mut panics
+     * with "already borrowed". Returns whether that panic happened.
+     * */
+    pub fn double_borrow_panics(&self) -> bool {
+        let reading = self.values.borrow();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.values.borrow_mut().clear();
+        }));
+        drop(reading);
+        result.is_err()
+    }
+
+    // try_borrow_mut reports the same conflict as an Err instead
+    pub fn try_clear(&self) -> bool {
+        match self.values.try_borrow_mut() {
+            Ok(mut values) => {
+                values.clear();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MutexCache {
+    values: Mutex<HashMap<u64, u64>>,
+}
+
+impl MutexCache {
+    pub fn new() -> MutexCache {
+        MutexCache {
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /*
+     * The lock is released while computing so other threads aren't
+     * blocked on a slow value, two threads may both compute the same key.
+     * */
+    pub fn get_or_insert_with<F: FnOnce() -> u64>(&self, key: u64, compute: F) -> u64 {
+        if let Some(&value) = self.values.lock().unwrap().get(&key) {
+            return value;
+        }
+        let value = compute();
+        *self.values.lock().unwrap().entry(key).or_insert(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.lock().unwrap().is_empty()
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Half-open intervals [start, end), merged, checked for overlap and
+ * searched for the holes between them.
+ *
+ * Half-open like Rust's ranges: [1, 3) and [3, 5) touch without
+ * overlapping, they share no point, but merging them leaves no gap and
+ * gives [1, 5). An interval with start == end is empty, it overlaps
+ * nothing and merge drops it.
+ *
+ * Anything Ord works as an endpoint: integers, times, even strings.
+ * */
+use std::fmt;
+
+/// ```
+/// use rust_exercises::extras::intervals::Interval;
+///
+/// let morning = Interval::new(9, 12).unwrap();
+/// assert!(morning.contains(&9) && !morning.contains(&12));
+/// assert!(!morning.overlaps(&Interval::new(12, 14).unwrap()));
+/// assert_eq!(morning.to_string(), "[9, 12)");
+/// assert_eq!(Interval::new(5, 1), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Ord> Interval<T> {
+    // None when start is after end
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        if start > end {
+            return None;
+        }
+        Some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        self.start <= *point && *point < self.end
+    }
+
+    // At least one point in common, so touching ends don't count
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/*
+ * The same points covered by as few intervals as possible, sorted by
+ * start. After sorting, an interval either continues the last merged
+ * one (it starts at or before that one's end) or begins a new one.
+ * */
+/// ```
+/// use rust_exercises::extras::intervals::{merge, Interval};
+///
+/// let interval = |start, end| Interval::new(start, end).unwrap();
+/// assert_eq!(
+///     merge(vec![interval(5, 8), interval(1, 3), interval(3, 4), interval(7, 9)]),
+///     [interval(1, 4), interval(5, 9)]
+/// );
+/// ```
+pub fn merge<T: Ord>(mut intervals: Vec<Interval<T>>) -> Vec<Interval<T>> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_by(|a, b| a.start.cmp(&b.start));
+
+    let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => {
+                if interval.end > last.end {
+                    last.end = interval.end;
+                }
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/*
+ * Whether any two of the intervals overlap. Sorted by start, an
+ * interval overlaps an earlier one exactly when it starts before the
+ * furthest end seen so far, no need to compare every pair.
+ * */
+/// ```
+/// use rust_exercises::extras::intervals::{any_overlap, Interval};
+///
+/// let interval = |start, end| Interval::new(start, end).unwrap();
+/// assert!(!any_overlap(&[interval(1, 3), interval(3, 5)]));
+/// assert!(any_overlap(&[interval(4, 6), interval(1, 3), interval(2, 5)]));
+/// ```
+pub fn any_overlap<T: Ord>(intervals: &[Interval<T>]) -> bool {
+    let mut sorted: Vec<&Interval<T>> = intervals.iter().filter(|i| !i.is_empty()).collect();
+    sorted.sort_by(|a, b| a.start.cmp(&b.start));
+
+    let mut furthest: Option<&T> = None;
+    for interval in sorted {
+        if let Some(end) = furthest {
+            if interval.start < *end {
+                return true;
+            }
+        }
+        if furthest.is_none_or(|end| interval.end > *end) {
+            furthest = Some(&interval.end);
+        }
+    }
+    false
+}
+
+/*
+ * What isn't covered between the first start and the last end, sorted.
+ * Touching intervals leave no gap.
+ * */
+/// ```
+/// use rust_exercises::extras::intervals::{gaps, Interval};
+///
+/// let interval = |start, end| Interval::new(start, end).unwrap();
+/// assert_eq!(
+///     gaps(&[interval(8, 10), interval(1, 3), interval(3, 5)]),
+///     [interval(5, 8)]
+/// );
+/// ```
+pub fn gaps<T: Ord + Clone>(intervals: &[Interval<T>]) -> Vec<Interval<T>> {
+    let merged = merge(intervals.to_vec());
+    merged
+        .windows(2)
+        .map(|pair| Interval {
+            start: pair[0].end.clone(),
+            end: pair[1].start.clone(),
+        })
+        .collect()
+}
@@ -0,0 +1,96 @@
+/*
+ * Slices have windows() and chunks(), these adapters do the same for any
+ * iterator, including ranges and iterators that never end.
+ *
+ * Items are handed out as Vec since they can't borrow from an iterator
+ * that has already moved on. The inner iterator is fused, once it
+ * returned None it isn't called again, even if the adapter is.
+ * */
+use std::collections::VecDeque;
+use std::iter::Fuse;
+
+pub struct WindowsIter<I: Iterator> {
+    iter: Fuse<I>,
+    size: usize,
+    window: VecDeque<I::Item>,
+}
+
+impl<I> Iterator for WindowsIter<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        // Fill the first window, then slide by one item per call
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(self.window.iter().cloned().collect())
+    }
+}
+
+pub struct ChunksIter<I: Iterator> {
+    iter: Fuse<I>,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksIter<I> {
+    type Item = Vec<I::Item>;
+
+    /*
+     * The last chunk is shorter when the items don't divide evenly,
+     * same as slice::chunks
+     * */
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+/*
+ * Extension trait so the adapters can be chained like the std ones:
+ * (1..6).sliding_windows(3) yields [1, 2, 3], [2, 3, 4], [3, 4, 5]
+ * */
+/// ```
+/// use rust_exercises::extras::iter_ext::IterExt;
+///
+/// let windows: Vec<Vec<u32>> = (1..6).sliding_windows(3).collect();
+/// assert_eq!(windows, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+///
+/// // Works on iterators that never end too
+/// let chunks: Vec<Vec<u32>> = (1..).chunks_of(2).take(2).collect();
+/// assert_eq!(chunks, [[1, 2], [3, 4]]);
+/// ```
+pub trait IterExt: Iterator + Sized {
+    // Panics if size is 0, like slice::windows
+    fn sliding_windows(self, size: usize) -> WindowsIter<Self> {
+        assert!(size != 0, "window size must be non-zero");
+        WindowsIter {
+            iter: self.fuse(),
+            size,
+            window: VecDeque::with_capacity(size),
+        }
+    }
+
+    // Panics if size is 0, like slice::chunks
+    fn chunks_of(self, size: usize) -> ChunksIter<Self> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksIter {
+            iter: self.fuse(),
+            size,
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
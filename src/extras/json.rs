@@ -0,0 +1,476 @@
+/*
+ * JSON parser written by hand, following the grammar on https://www.json.org
+ * Errors point at the line and column where parsing stopped.
+ *
+ * Arrays and objects nest at most MAX_DEPTH deep, the parser recurses
+ * once per level and "[[[[..." would otherwise overflow the stack.
+ * */
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+pub const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    InvalidNumber,
+    InvalidEscape,
+    InvalidUnicode,
+    ControlCharInString,
+    TooDeep,
+    TrailingCharacters,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    // Both start at 1, like editors count them
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self.kind {
+            ErrorKind::UnexpectedChar(c) => format!("unexpected character '{}'", c),
+            ErrorKind::UnexpectedEnd => "unexpected end of input".to_string(),
+            ErrorKind::InvalidNumber => "invalid number".to_string(),
+            ErrorKind::InvalidEscape => "invalid escape sequence".to_string(),
+            ErrorKind::InvalidUnicode => "invalid unicode escape".to_string(),
+            ErrorKind::ControlCharInString => "control character in string".to_string(),
+            ErrorKind::TooDeep => format!("nested deeper than {}", MAX_DEPTH),
+            ErrorKind::TrailingCharacters => "trailing characters".to_string(),
+        };
+        write!(f, "{} at line {} column {}", what, self.line, self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    // Arrays and objects we are inside of
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, kind: ErrorKind) -> ParseError {
+        let before = &self.input[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = match before.rfind('\n') {
+            Some(newline) => before[newline + 1..].chars().count() + 1,
+            None => before.chars().count() + 1,
+        };
+        ParseError { kind, line, column }
+    }
+
+    // Error for whatever is at the current position
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ErrorKind::UnexpectedChar(c)),
+            None => self.error(ErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek() {
+            self.bump();
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", Value::Null),
+            Some('t') => self.literal("true", Value::Bool(true)),
+            Some('f') => self.literal("false", Value::Bool(false)),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some(c @ '[') | Some(c @ '{') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error(ErrorKind::TooDeep));
+                }
+                self.depth += 1;
+                let value = if c == '[' { self.array() } else { self.object() };
+                self.depth -= 1;
+                value
+            }
+            Some('-') | Some('0'..='9') => self.number(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, ParseError> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn digits(&mut self) -> usize {
+        let mut count = 0;
+        while let Some('0'..='9') = self.peek() {
+            self.bump();
+            count += 1;
+        }
+        count
+    }
+
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+
+        // Leading zeros are not allowed, "0" must stand alone
+        match self.peek() {
+            Some('0') => {
+                self.bump();
+            }
+            Some('1'..='9') => {
+                self.digits();
+            }
+            _ => return Err(self.error(ErrorKind::InvalidNumber)),
+        }
+
+        if self.peek() == Some('.') {
+            self.bump();
+            if self.digits() == 0 {
+                return Err(self.error(ErrorKind::InvalidNumber));
+            }
+        }
+
+        if let Some('e') | Some('E') = self.peek() {
+            self.bump();
+            if let Some('+') | Some('-') = self.peek() {
+                self.bump();
+            }
+            if self.digits() == 0 {
+                return Err(self.error(ErrorKind::InvalidNumber));
+            }
+        }
+
+        // Too big for an f64 like 1e400 comes out as infinity, which
+        // couldn't be written back as JSON
+        match self.input[start..self.pos].parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(Value::Number(n)),
+            _ => Err(self.error(ErrorKind::InvalidNumber)),
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, ParseError> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = match self.peek().and_then(|c| c.to_digit(16)) {
+                Some(digit) => digit,
+                None => return Err(self.error(ErrorKind::InvalidUnicode)),
+            };
+            self.bump();
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+
+    /*
+     * \uXXXX escapes outside the basic plane come in surrogate pairs,
+     * e.g. "\ud83e\udd80" is a single crab.
+     * */
+    fn unicode_escape(&mut self) -> Result<char, ParseError> {
+        let high = self.hex4()?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            if self.bump() != Some('\\') || self.bump() != Some('u') {
+                return Err(self.error(ErrorKind::InvalidUnicode));
+            }
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error(ErrorKind::InvalidUnicode));
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        match ::std::char::from_u32(code) {
+            Some(c) => Ok(c),
+            None => Err(self.error(ErrorKind::InvalidUnicode)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut s = String::new();
+
+        loop {
+            match self.peek() {
+                None => return Err(self.error(ErrorKind::UnexpectedEnd)),
+                Some('"') => {
+                    self.bump();
+                    return Ok(s);
+                }
+                Some('\\') => {
+                    self.bump();
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.bump();
+                            s.push(self.unicode_escape()?);
+                            continue;
+                        }
+                        _ => return Err(self.error(ErrorKind::InvalidEscape)),
+                    };
+                    self.bump();
+                    s.push(escaped);
+                }
+                Some(c) if c < ' ' => return Err(self.error(ErrorKind::ControlCharInString)),
+                Some(c) => {
+                    self.bump();
+                    s.push(c);
+                }
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(']') => {
+                    self.bump();
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.expect('{')?;
+        let mut members = BTreeMap::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value()?;
+            members.insert(key, value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some('}') => {
+                    self.bump();
+                    return Ok(Value::Object(members));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::json::{parse, ErrorKind, Value};
+///
+/// let value = parse(r#"{"name": "ferris", "tags": ["crab", true, null], "age": 7}"#).unwrap();
+/// match value {
+///     Value::Object(ref members) => assert_eq!(members["age"], Value::Number(7.0)),
+///     _ => unreachable!(),
+/// }
+/// // Compact output parses back to the same value
+/// assert_eq!(parse(&value.to_string()), Ok(value));
+///
+/// let error = parse("[1,\n 2,,]").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::UnexpectedChar(','));
+/// assert_eq!((error.line, error.column), (2, 4));
+/// ```
+pub fn parse(input: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(parser.error(ErrorKind::TrailingCharacters));
+    }
+
+    Ok(value)
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_number(out: &mut String, n: f64) {
+    // JSON has no NaN or infinity, null is the usual stand-in
+    if n.is_finite() {
+        out.push_str(&n.to_string());
+    } else {
+        out.push_str("null");
+    }
+}
+
+impl Value {
+    /*
+     * Multi-line output indented by two spaces per level,
+     * empty arrays and objects stay on one line.
+     * */
+    pub fn to_pretty(&self) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, depth: usize) {
+        let indent = |out: &mut String, depth: usize| {
+            for _ in 0..depth {
+                out.push_str("  ");
+            }
+        };
+
+        match *self {
+            Value::Array(ref items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    indent(out, depth + 1);
+                    item.write_pretty(out, depth + 1);
+                    if i + 1 < items.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                indent(out, depth);
+                out.push(']');
+            }
+            Value::Object(ref members) if !members.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in members.iter().enumerate() {
+                    indent(out, depth + 1);
+                    write_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, depth + 1);
+                    if i + 1 < members.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                indent(out, depth);
+                out.push('}');
+            }
+            _ => out.push_str(&self.to_string()),
+        }
+    }
+}
+
+/*
+ * Compact output on a single line, parse(&v.to_string()) gives back v
+ * */
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::new();
+        match *self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if b { "true" } else { "false" }),
+            Value::Number(n) => write_number(&mut out, n),
+            Value::String(ref s) => write_string(&mut out, s),
+            Value::Array(ref items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push_str(&item.to_string());
+                }
+                out.push(']');
+            }
+            Value::Object(ref members) => {
+                out.push('{');
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_string(&mut out, key);
+                    out.push(':');
+                    out.push_str(&value.to_string());
+                }
+                out.push('}');
+            }
+        }
+        f.write_str(&out)
+    }
+}
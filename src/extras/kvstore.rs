@@ -0,0 +1,326 @@
+/*
+ * Persistent key-value store in the style of Bitcask: every set and
+ * remove is appended to a log file, an in-memory HashMap says where the
+ * current value of each key is in that file.
+ *
+ * The log is a binfmt record file, one record per operation:
+ *
+ *   u8      0 set, 1 remove
+ *   u32     key length, little endian
+ *   [u8]    key, UTF-8
+ *   [u8]    value, the rest of the record, empty for a remove
+ *
+ * Overwritten values and removes stay in the log as garbage. Once there
+ * is more garbage than the threshold, the live entries are written to a
+ * new log which replaces the old one.
+ * */
+use crate::extras::binfmt::{self, FormatError, Reader, Writer};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+const SET: u8 = 0;
+const REMOVE: u8 = 1;
+pub const DEFAULT_COMPACTION_THRESHOLD: u64 = 1024 * 1024;
+
+#[derive(Debug)]
+pub enum KvError {
+    Io(io::Error),
+    Format(FormatError),
+    // A record that passed its checksum but isn't a valid operation
+    InvalidEntry(String),
+}
+
+impl fmt::Display for KvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KvError::Io(e) => write!(f, "{}", e),
+            KvError::Format(e) => write!(f, "corrupted log: {}", e),
+            KvError::InvalidEntry(why) => write!(f, "invalid log entry: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for KvError {}
+
+impl From<io::Error> for KvError {
+    fn from(e: io::Error) -> KvError {
+        KvError::Io(e)
+    }
+}
+
+impl From<FormatError> for KvError {
+    fn from(e: FormatError) -> KvError {
+        KvError::Format(e)
+    }
+}
+
+// Where a value is in the log
+#[derive(Debug, Clone, Copy)]
+struct Location {
+    offset: u64,
+    len: u32,
+    // The whole record, what becomes garbage when it's replaced
+    record_len: u64,
+}
+
+enum Entry<'a> {
+    Set(&'a str, &'a [u8]),
+    Remove(&'a str),
+}
+
+fn encode(entry: &Entry) -> Vec<u8> {
+    let (op, key, value): (u8, &str, &[u8]) = match *entry {
+        Entry::Set(key, value) => (SET, key, value),
+        Entry::Remove(key) => (REMOVE, key, &[]),
+    };
+    let mut payload = Vec::with_capacity(5 + key.len() + value.len());
+    payload.push(op);
+    payload.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    payload.extend_from_slice(key.as_bytes());
+    payload.extend_from_slice(value);
+    payload
+}
+
+// Returns the entry and where its value starts in the payload
+fn decode(payload: &[u8]) -> Result<(Entry<'_>, usize), KvError> {
+    let invalid = |why: &str| KvError::InvalidEntry(why.to_string());
+    if payload.len() < 5 {
+        return Err(invalid("too short"));
+    }
+
+    let key_len = u32::from_le_bytes([payload[1], payload[2], payload[3], payload[4]]) as usize;
+    let value_start = 5usize
+        .checked_add(key_len)
+        .filter(|&end| end <= payload.len())
+        .ok_or_else(|| invalid("key runs past the record"))?;
+    let key =
+        std::str::from_utf8(&payload[5..value_start]).map_err(|_| invalid("key isn't UTF-8"))?;
+
+    match payload[0] {
+        SET => Ok((Entry::Set(key, &payload[value_start..]), value_start)),
+        REMOVE if value_start == payload.len() => Ok((Entry::Remove(key), value_start)),
+        REMOVE => Err(invalid("remove with a value")),
+        op => Err(KvError::InvalidEntry(format!("unknown operation {}", op))),
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::kvstore::KvStore;
+///
+/// let path = std::env::temp_dir().join(format!("kvstore-doc-{}.log", std::process::id()));
+/// {
+///     let mut store = KvStore::open(&path).unwrap();
+///     store.set("language", b"rust").unwrap();
+///     store.set("mascot", b"ferris").unwrap();
+///     store.set("mascot", b"ferris the crab").unwrap();
+///     assert!(store.remove("language").unwrap());
+///     store.sync().unwrap();
+/// }
+/// // Reopening replays the log
+/// let mut store = KvStore::open(&path).unwrap();
+/// assert_eq!(store.keys(), ["mascot"]);
+/// assert_eq!(store.get("mascot").unwrap().as_deref(), Some(&b"ferris the crab"[..]));
+/// assert!(store.garbage() > 0);
+/// store.compact().unwrap();
+/// assert_eq!(store.garbage(), 0);
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct KvStore {
+    path: PathBuf,
+    index: HashMap<String, Location>,
+    writer: Writer<File>,
+    reader: File,
+    // End of the log, where the next record goes
+    end: u64,
+    garbage: u64,
+    compaction_threshold: u64,
+}
+
+impl KvStore {
+    /*
+     * Opens or creates the log and replays it to build the index. A last
+     * record cut short, by a crash in the middle of a write, is dropped
+     * and the file truncated to the last complete record.
+     * */
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<KvStore, KvError> {
+        let path = path.as_ref().to_path_buf();
+        if !path.exists() || fs::metadata(&path)?.len() == 0 {
+            Writer::new(File::create(&path)?)?.finish()?.sync_all()?;
+        }
+
+        let mut index = HashMap::new();
+        let mut garbage = 0;
+        let mut end = binfmt::HEADER_LEN;
+
+        let mut log = Reader::new(BufReader::new(File::open(&path)?))?;
+        while let Some(record) = log.next_record() {
+            let payload = match record {
+                Ok(payload) => payload,
+                Err(FormatError::Truncated { .. }) => {
+                    OpenOptions::new().write(true).open(&path)?.set_len(end)?;
+                    break;
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let record_len = binfmt::record_len(payload.len());
+            let (entry, value_start) = decode(&payload)?;
+            let replaced = match entry {
+                Entry::Set(key, value) => index.insert(
+                    key.to_string(),
+                    Location {
+                        // Past the record's 4 length bytes
+                        offset: end + 4 + value_start as u64,
+                        len: value.len() as u32,
+                        record_len,
+                    },
+                ),
+                Entry::Remove(key) => {
+                    // The remove record itself is garbage right away
+                    garbage += record_len;
+                    index.remove(key)
+                }
+            };
+            if let Some(old) = replaced {
+                garbage += old.record_len;
+            }
+            end += record_len;
+        }
+
+        let file = OpenOptions::new().append(true).open(&path)?;
+        Ok(KvStore {
+            reader: File::open(&path)?,
+            writer: Writer::append(file),
+            path,
+            index,
+            end,
+            garbage,
+            compaction_threshold: DEFAULT_COMPACTION_THRESHOLD,
+        })
+    }
+
+    pub fn with_compaction_threshold(mut self, bytes: u64) -> KvStore {
+        self.compaction_threshold = bytes;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    // Sorted, HashMap order would change from one run to the next
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.index.keys().map(String::as_str).collect();
+        keys.sort();
+        keys
+    }
+
+    // Bytes of the log no longer needed
+    pub fn garbage(&self) -> u64 {
+        self.garbage
+    }
+
+    fn append(&mut self, entry: &Entry) -> Result<(u64, u64), KvError> {
+        let payload = encode(entry);
+        let start = self.end;
+        self.writer.write_record(&payload)?;
+        let record_len = binfmt::record_len(payload.len());
+        self.end += record_len;
+        Ok((start, record_len))
+    }
+
+    pub fn set(&mut self, key: &str, value: &[u8]) -> Result<(), KvError> {
+        let (start, record_len) = self.append(&Entry::Set(key, value))?;
+        let location = Location {
+            offset: start + 4 + 5 + key.len() as u64,
+            len: value.len() as u32,
+            record_len,
+        };
+        if let Some(old) = self.index.insert(key.to_string(), location) {
+            self.garbage += old.record_len;
+        }
+        self.maybe_compact()
+    }
+
+    pub fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, KvError> {
+        let location = match self.index.get(key) {
+            Some(&location) => location,
+            None => return Ok(None),
+        };
+
+        let mut value = vec![0; location.len as usize];
+        self.reader.seek(SeekFrom::Start(location.offset))?;
+        self.reader.read_exact(&mut value)?;
+        Ok(Some(value))
+    }
+
+    // False if the key wasn't there, nothing is written then
+    pub fn remove(&mut self, key: &str) -> Result<bool, KvError> {
+        if !self.index.contains_key(key) {
+            return Ok(false);
+        }
+        let (_, record_len) = self.append(&Entry::Remove(key))?;
+        let old = self.index.remove(key).expect("checked above");
+        self.garbage += old.record_len + record_len;
+        self.maybe_compact()?;
+        Ok(true)
+    }
+
+    // Waits for the log to be on disk, not just handed to the OS
+    pub fn sync(&self) -> Result<(), KvError> {
+        self.writer.get_ref().sync_data()?;
+        Ok(())
+    }
+
+    fn maybe_compact(&mut self) -> Result<(), KvError> {
+        if self.garbage > self.compaction_threshold {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /*
+     * Writes the live entries to a new file next to the log and renames
+     * it over the log. The rename is atomic, a crash leaves either the
+     * old log or the new one, never half of each.
+     * */
+    pub fn compact(&mut self) -> Result<(), KvError> {
+        let compacted_path = self.path.with_extension("compact");
+        let mut writer = Writer::new(File::create(&compacted_path)?)?;
+        let mut index = HashMap::new();
+        let mut end = binfmt::HEADER_LEN;
+
+        let mut keys: Vec<String> = self.index.keys().cloned().collect();
+        keys.sort();
+        for key in keys {
+            let value = self.get(&key)?.expect("key from the index");
+            let payload = encode(&Entry::Set(&key, &value));
+            writer.write_record(&payload)?;
+
+            let record_len = binfmt::record_len(payload.len());
+            let location = Location {
+                offset: end + 4 + 5 + key.len() as u64,
+                len: value.len() as u32,
+                record_len,
+            };
+            index.insert(key, location);
+            end += record_len;
+        }
+        writer.finish()?.sync_all()?;
+        fs::rename(&compacted_path, &self.path)?;
+
+        self.writer = Writer::append(OpenOptions::new().append(true).open(&self.path)?);
+        self.reader = File::open(&self.path)?;
+        self.index = index;
+        self.end = end;
+        self.garbage = 0;
+        Ok(())
+    }
+}
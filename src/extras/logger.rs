@@ -0,0 +1,360 @@
+/*
+ * A logger in the style of the log crate's facade, kept dependency free
+ * with a crate-local Log trait,
+ * https://docs.rs/log/latest/log/trait.Log.html
+ *
+ * Levels are filtered with RUST_LOG-style strings:
+ *
+ *   info                         everything at info and above
+ *   warn,rust_exercises::net=debug   debug for one module tree only
+ *   off                          nothing
+ *
+ * Code logs through the log_error! ... log_trace! macros, which go to
+ * whatever logger was installed with set_logger(), if any. The runner
+ * binary installs a stderr one, see its -v and --log options.
+ * */
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Most severe first, so `level <= max` means enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        // Padding applies, so levels can line up in columns
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLevelError(pub String);
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown log level {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLevelError {}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Level, ParseLevelError> {
+        match s.trim().to_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" | "warning" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+}
+
+/*
+ * A maximum level per target prefix, the longest matching prefix wins.
+ * None means off.
+ * */
+/// ```
+/// use rust_exercises::extras::logger::{Filter, Level};
+///
+/// let filter: Filter = "warn,rust_exercises::net=debug".parse().unwrap();
+/// assert_eq!(filter.max_level("rust_exercises::net::tcp"), Some(Level::Debug));
+/// assert!(filter.enabled("rust_exercises::kvstore", Level::Error));
+/// assert!(!filter.enabled("rust_exercises::kvstore", Level::Info));
+/// assert_eq!("off".parse::<Filter>().unwrap().max_level("anything"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    directives: Vec<(String, Option<Level>)>,
+}
+
+fn parse_max(s: &str) -> Result<Option<Level>, ParseLevelError> {
+    if s.trim().eq_ignore_ascii_case("off") {
+        Ok(None)
+    } else {
+        s.parse().map(Some)
+    }
+}
+
+impl Filter {
+    pub fn new(default: Option<Level>) -> Filter {
+        Filter {
+            default,
+            directives: Vec::new(),
+        }
+    }
+
+    // From RUST_LOG, error only when it isn't set or fails to parse
+    pub fn from_env() -> Filter {
+        std::env::var("RUST_LOG")
+            .ok()
+            .and_then(|spec| spec.parse().ok())
+            .unwrap_or_else(|| Filter::new(Some(Level::Error)))
+    }
+
+    // A target matches its own name and the modules under it
+    fn matches(prefix: &str, target: &str) -> bool {
+        target == prefix || (target.starts_with(prefix) && target[prefix.len()..].starts_with("::"))
+    }
+
+    pub fn max_level(&self, target: &str) -> Option<Level> {
+        self.directives
+            .iter()
+            .filter(|(prefix, _)| Filter::matches(prefix, target))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        self.max_level(target).is_some_and(|max| level <= max)
+    }
+}
+
+// Anything not mentioned in the spec is off
+impl FromStr for Filter {
+    type Err = ParseLevelError;
+
+    fn from_str(spec: &str) -> Result<Filter, ParseLevelError> {
+        let mut filter = Filter::new(None);
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let level = parse_max(level)?;
+                    filter.directives.push((target.trim().to_string(), level));
+                }
+                None => filter.default = parse_max(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+pub struct Record<'a> {
+    pub level: Level,
+    pub target: &'a str,
+    pub args: fmt::Arguments<'a>,
+}
+
+pub trait Log: Send + Sync {
+    fn enabled(&self, level: Level, target: &str) -> bool;
+
+    fn log(&self, record: &Record);
+
+    fn flush(&self) {}
+}
+
+type Clock = fn() -> SystemTime;
+
+/*
+ * Writes "<secs>.<millis> LEVEL target: message" lines, the timestamp
+ * being seconds since the Unix epoch
+ * */
+/// ```
+/// use rust_exercises::extras::logger::{Filter, Level, Log, Logger, Record};
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// let (logger, sink) = Logger::memory(Filter::new(Some(Level::Info)));
+/// let logger = logger.with_clock(|| UNIX_EPOCH + Duration::from_millis(1_500));
+/// for &level in [Level::Warn, Level::Debug].iter() {
+///     logger.log(&Record {
+///         level,
+///         target: "demo",
+///         args: format_args!("{} disk space left", "10%"),
+///     });
+/// }
+/// assert_eq!(sink.lines(), ["1.500 WARN  demo: 10% disk space left"]);
+/// ```
+pub struct Logger {
+    filter: Filter,
+    sink: Mutex<Box<dyn Write + Send>>,
+    clock: Clock,
+}
+
+impl Logger {
+    pub fn new(filter: Filter, sink: Box<dyn Write + Send>) -> Logger {
+        Logger {
+            filter,
+            sink: Mutex::new(sink),
+            clock: SystemTime::now,
+        }
+    }
+
+    pub fn stderr(filter: Filter) -> Logger {
+        Logger::new(filter, Box::new(io::stderr()))
+    }
+
+    // Lines end up in the returned MemorySink
+    pub fn memory(filter: Filter) -> (Logger, MemorySink) {
+        let sink = MemorySink::default();
+        (Logger::new(filter, Box::new(sink.clone())), sink)
+    }
+
+    // A fixed clock makes the output predictable
+    pub fn with_clock(mut self, clock: Clock) -> Logger {
+        self.clock = clock;
+        self
+    }
+
+    pub fn format(&self, record: &Record) -> String {
+        let since_epoch = (self.clock)()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        format!(
+            "{}.{:03} {:<5} {}: {}",
+            since_epoch.as_secs(),
+            since_epoch.subsec_millis(),
+            record.level,
+            record.target,
+            record.args
+        )
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, level: Level, target: &str) -> bool {
+        self.filter.enabled(target, level)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.level, record.target) {
+            return;
+        }
+        let line = self.format(record);
+        let mut sink = self.sink.lock().unwrap();
+        // Logging must never take the program down
+        let _ = writeln!(sink, "{}", line);
+    }
+
+    fn flush(&self) {
+        let _ = self.sink.lock().unwrap().flush();
+    }
+}
+
+// Collects written lines, clones share the same buffer
+#[derive(Clone, Default)]
+pub struct MemorySink {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl MemorySink {
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.buffer.lock().unwrap()).into_owned()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.contents().lines().map(String::from).collect()
+    }
+}
+
+impl Write for MemorySink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+static LOGGER: OnceLock<Box<dyn Log>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetLoggerError;
+
+impl fmt::Display for SetLoggerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a logger is already installed")
+    }
+}
+
+impl std::error::Error for SetLoggerError {}
+
+// Like log::set_boxed_logger, only the first call wins
+/// ```
+/// use rust_exercises::extras::logger::{self, Filter, Level, Logger};
+/// use rust_exercises::{log_debug, log_warn};
+///
+/// let (memory, sink) = Logger::memory(Filter::new(Some(Level::Warn)));
+/// logger::set_logger(Box::new(memory)).unwrap();
+/// log_warn!("{} retries left", 2);
+/// log_debug!("not enabled, never formatted");
+/// assert_eq!(sink.lines().len(), 1);
+/// assert!(sink.contents().ends_with("2 retries left\n"));
+///
+/// // Only the first logger is kept
+/// let (second, _) = Logger::memory(Filter::new(None));
+/// assert!(logger::set_logger(Box::new(second)).is_err());
+/// ```
+pub fn set_logger(logger: Box<dyn Log>) -> Result<(), SetLoggerError> {
+    LOGGER.set(logger).map_err(|_| SetLoggerError)
+}
+
+pub fn logger() -> Option<&'static dyn Log> {
+    LOGGER.get().map(|logger| logger.as_ref())
+}
+
+// What the macros call, the arguments are only formatted when enabled
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    if let Some(logger) = logger() {
+        if logger.enabled(level, target) {
+            logger.log(&Record {
+                level,
+                target,
+                args,
+            });
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)+) => {
+        $crate::extras::logger::log($level, module_path!(), format_args!($($arg)+))
+    };
+}
+
+#[macro_export]
+macro_rules! log_error {
+    ($($arg:tt)+) => { $crate::log_at!($crate::extras::logger::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_warn {
+    ($($arg:tt)+) => { $crate::log_at!($crate::extras::logger::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_info {
+    ($($arg:tt)+) => { $crate::log_at!($crate::extras::logger::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { $crate::log_at!($crate::extras::logger::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! log_trace {
+    ($($arg:tt)+) => { $crate::log_at!($crate::extras::logger::Level::Trace, $($arg)+) };
+}
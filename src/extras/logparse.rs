@@ -0,0 +1,176 @@
+/*
+ * Parser for the Apache Common Log Format,
+ * https://httpd.apache.org/docs/current/logs.html#common
+ *
+ *   127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /index.html HTTP/1.0" 200 2326
+ *
+ * LogLine borrows every field from the input so parsing allocates nothing,
+ * OwnedLogLine is the same with Strings to compare the cost (see
+ * benches/logparse.rs).
+ * */
+use std::error::Error;
+use std::fmt;
+
+/// ```
+/// use rust_exercises::extras::logparse::{LogLine, ParseError};
+///
+/// let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /index.html HTTP/1.0" 200 2326"#;
+/// let parsed = LogLine::parse(line).unwrap();
+/// assert_eq!((parsed.user, parsed.method, parsed.path), ("frank", "GET", "/index.html"));
+/// assert_eq!((parsed.status, parsed.size), (200, Some(2326)));
+///
+/// assert!(matches!(LogLine::parse("127.0.0.1 -"), Err(ParseError::MissingField(_))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogLine<'a> {
+    pub host: &'a str,
+    pub ident: &'a str,
+    pub user: &'a str,
+    // Left as text, e.g. "10/Oct/2000:13:55:36 -0700"
+    pub timestamp: &'a str,
+    pub method: &'a str,
+    pub path: &'a str,
+    pub protocol: &'a str,
+    pub status: u16,
+    // "-" in the log when nothing was sent
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    MissingField(&'static str),
+    InvalidField(&'static str),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::MissingField(name) => write!(f, "missing {}", name),
+            ParseError::InvalidField(name) => write!(f, "invalid {}", name),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/*
+ * Splits off everything up to the first delimiter and returns it along
+ * with what follows the delimiter. Both borrow from s.
+ * */
+fn take_until<'a>(
+    s: &'a str,
+    delimiter: char,
+    field: &'static str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    match s.find(delimiter) {
+        Some(end) => Ok((&s[..end], &s[end + delimiter.len_utf8()..])),
+        None => Err(ParseError::MissingField(field)),
+    }
+}
+
+fn expect_prefix<'a>(s: &'a str, prefix: char, field: &'static str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or(ParseError::MissingField(field))
+}
+
+impl<'a> LogLine<'a> {
+    pub fn parse(line: &'a str) -> Result<LogLine<'a>, ParseError> {
+        let (host, rest) = take_until(line, ' ', "host")?;
+        let (ident, rest) = take_until(rest, ' ', "ident")?;
+        let (user, rest) = take_until(rest, ' ', "user")?;
+
+        let rest = expect_prefix(rest, '[', "timestamp")?;
+        let (timestamp, rest) = take_until(rest, ']', "timestamp")?;
+
+        let rest = expect_prefix(rest.trim_start(), '"', "request")?;
+        let (request, rest) = take_until(rest, '"', "request")?;
+        let mut request_parts = request.splitn(3, ' ');
+        let method = request_parts.next().filter(|m| !m.is_empty());
+        let method = method.ok_or(ParseError::MissingField("method"))?;
+        let path = request_parts.next().ok_or(ParseError::MissingField("path"))?;
+        let protocol = request_parts.next().ok_or(ParseError::MissingField("protocol"))?;
+
+        let mut numbers = rest.split_whitespace();
+        let status = numbers
+            .next()
+            .ok_or(ParseError::MissingField("status"))?
+            .parse()
+            .map_err(|_| ParseError::InvalidField("status"))?;
+        let size = match numbers.next() {
+            Some("-") => None,
+            Some(size) => Some(size.parse().map_err(|_| ParseError::InvalidField("size"))?),
+            None => return Err(ParseError::MissingField("size")),
+        };
+
+        Ok(LogLine {
+            host,
+            ident,
+            user,
+            timestamp,
+            method,
+            path,
+            protocol,
+            status,
+            size,
+        })
+    }
+
+    pub fn to_owned_line(&self) -> OwnedLogLine {
+        OwnedLogLine {
+            host: self.host.to_string(),
+            ident: self.ident.to_string(),
+            user: self.user.to_string(),
+            timestamp: self.timestamp.to_string(),
+            method: self.method.to_string(),
+            path: self.path.to_string(),
+            protocol: self.protocol.to_string(),
+            status: self.status,
+            size: self.size,
+        }
+    }
+}
+
+/*
+ * Owns its fields, it can outlive the buffer it was parsed from
+ * but costs seven allocations per line.
+ * */
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedLogLine {
+    pub host: String,
+    pub ident: String,
+    pub user: String,
+    pub timestamp: String,
+    pub method: String,
+    pub path: String,
+    pub protocol: String,
+    pub status: u16,
+    pub size: Option<u64>,
+}
+
+impl OwnedLogLine {
+    pub fn parse(line: &str) -> Result<OwnedLogLine, ParseError> {
+        LogLine::parse(line).map(|line| line.to_owned_line())
+    }
+}
+
+/*
+ * Every non-empty line of a whole log file, with its line number
+ * (starting at 1) so errors can be reported.
+ * */
+/// ```
+/// use rust_exercises::extras::logparse::parse_all;
+///
+/// let log = "1.2.3.4 - - [01/Jan/2024:00:00:00 +0000] \"GET / HTTP/1.1\" 304 -\n\ngarbage\n";
+/// let results: Vec<_> = parse_all(log).collect();
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].1.as_ref().unwrap().size, None);
+/// // Blank lines are skipped, but still counted
+/// assert_eq!(results[1].0, 3);
+/// assert!(results[1].1.is_err());
+/// ```
+pub fn parse_all(buffer: &str) -> impl Iterator<Item = (usize, Result<LogLine<'_>, ParseError>)> {
+    buffer
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (index + 1, LogLine::parse(line)))
+}
@@ -0,0 +1,222 @@
+/*
+ * Dense f64 matrices, row-major in one Vec, and three ways to multiply
+ * them that give bit-for-bit the same result:
+ *
+ *   mul           the textbook triple loop. The innermost loop walks down
+ *                 a column of the right matrix, a new cache line per step.
+ *   mul_blocked   the same sums over BLOCK x BLOCK tiles small enough to
+ *                 stay in cache, and rows walked left to right
+ *   mul_parallel  bands of rows on scoped threads, each band computed
+ *                 like mul_blocked, every thread writing only its rows
+ *
+ * Every entry of the result adds up its products in the same order, k
+ * from 0 up, in all three, so rounding is the same too and the results
+ * can be compared with ==.
+ *
+ *   cargo bench --bench matrix
+ * */
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::thread;
+
+// 64 x 64 f64 is 32KB, a tile of each operand fits in L2 together
+pub const BLOCK: usize = 64;
+
+/// ```
+/// use rust_exercises::extras::matrix::{Matrix, ShapeError};
+///
+/// let a = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+/// let b = Matrix::from_fn(2, 2, |i, j| (i * 2 + j) as f64);
+/// let product = a.mul(&b).unwrap();
+/// assert_eq!(product.row(2), [12.0, 23.0]);
+/// assert_eq!(a.mul_blocked(&b), Ok(product.clone()));
+/// assert_eq!(a.mul_parallel(&b, 2), Ok(product));
+/// assert_eq!(a.mul(&Matrix::identity(2)), Ok(a.clone()));
+///
+/// assert_eq!(a.mul(&a), Err(ShapeError { left: (3, 2), right: (3, 2) }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeError {
+    pub left: (usize, usize),
+    pub right: (usize, usize),
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "can't multiply {}x{} by {}x{}, the inner sizes differ",
+            self.left.0, self.left.1, self.right.0, self.right.1
+        )
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    pub fn identity(n: usize) -> Matrix {
+        let mut matrix = Matrix::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    // None when the rows don't all have the same length
+    pub fn from_rows(rows: &[Vec<f64>]) -> Option<Matrix> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.concat(),
+        })
+    }
+
+    // Fills in every entry from its (row, column)
+    pub fn from_fn<F: FnMut(usize, usize) -> f64>(rows: usize, cols: usize, mut f: F) -> Matrix {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Matrix { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[f64] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    fn check_shape(&self, other: &Matrix) -> Result<(), ShapeError> {
+        if self.cols != other.rows {
+            return Err(ShapeError {
+                left: (self.rows, self.cols),
+                right: (other.rows, other.cols),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn mul(&self, other: &Matrix) -> Result<Matrix, ShapeError> {
+        self.check_shape(other)?;
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let mut sum = 0.0;
+                for k in 0..self.cols {
+                    sum += self[(i, k)] * other[(k, j)];
+                }
+                out[(i, j)] = sum;
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn mul_blocked(&self, other: &Matrix) -> Result<Matrix, ShapeError> {
+        self.check_shape(other)?;
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        self.mul_rows_blocked(other, 0, &mut out.data);
+        Ok(out)
+    }
+
+    /*
+     * Up to `threads` bands of consecutive rows, one scoped thread each.
+     * The bands are disjoint slices of the result, chunks_mut hands
+     * them out, so no locking.
+     * */
+    pub fn mul_parallel(&self, other: &Matrix, threads: usize) -> Result<Matrix, ShapeError> {
+        self.check_shape(other)?;
+        let mut out = Matrix::zeros(self.rows, other.cols);
+        if out.data.is_empty() {
+            return Ok(out);
+        }
+
+        let band_rows = self.rows.div_ceil(threads.max(1));
+        thread::scope(|scope| {
+            for (band, rows) in out.data.chunks_mut(band_rows * other.cols).enumerate() {
+                scope.spawn(move || self.mul_rows_blocked(other, band * band_rows, rows));
+            }
+        });
+        Ok(out)
+    }
+
+    /*
+     * Rows first_row.. of self times other, into out, which holds
+     * exactly those rows of the result and starts zeroed. Tiles go k
+     * before j so each row of other is read left to right, and the tiles
+     * along k are taken in order to keep the sums in k order.
+     * */
+    fn mul_rows_blocked(&self, other: &Matrix, first_row: usize, out: &mut [f64]) {
+        let n = other.cols;
+        let rows = out.len() / n.max(1);
+
+        for kk in (0..self.cols).step_by(BLOCK) {
+            let k_end = (kk + BLOCK).min(self.cols);
+            for jj in (0..n).step_by(BLOCK) {
+                let j_end = (jj + BLOCK).min(n);
+                for i in 0..rows {
+                    let left = self.row(first_row + i);
+                    let out_row = &mut out[i * n..(i + 1) * n];
+                    for (k, &a) in left.iter().enumerate().take(k_end).skip(kk) {
+                        let right = &other.row(k)[jj..j_end];
+                        for (cell, &b) in out_row[jj..j_end].iter_mut().zip(right) {
+                            *cell += a * b;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "({}, {}) out of bounds",
+            i,
+            j
+        );
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "({}, {}) out of bounds",
+            i,
+            j
+        );
+        &mut self.data[i * self.cols + j]
+    }
+}
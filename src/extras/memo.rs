@@ -0,0 +1,132 @@
+/*
+ * Generalization of the Cacher from chapter 13,
+ * https://doc.rust-lang.org/book/ch13-01-closures.html
+ *
+ * The book's version remembers a single u32 and ignores the argument of
+ * later calls, here every argument gets its own entry in a HashMap.
+ * */
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// ```
+/// use rust_exercises::extras::memo::Memoized;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let mut square = Memoized::new(|&n: &u64| {
+///     calls.set(calls.get() + 1);
+///     n * n
+/// });
+/// assert_eq!(square.get(4), 16);
+/// assert_eq!(square.get(4), 16);
+/// assert_eq!(square.get(5), 25);
+/// assert_eq!((square.cached(), calls.get()), (2, 2));
+/// ```
+pub struct Memoized<A, R, F>
+where
+    A: Hash + Eq,
+    R: Clone,
+    F: Fn(&A) -> R,
+{
+    func: F,
+    cache: HashMap<A, R>,
+}
+
+impl<A, R, F> Memoized<A, R, F>
+where
+    A: Hash + Eq,
+    R: Clone,
+    F: Fn(&A) -> R,
+{
+    pub fn new(func: F) -> Memoized<A, R, F> {
+        Memoized {
+            func,
+            cache: HashMap::new(),
+        }
+    }
+
+    /*
+     * Calls the wrapped function only the first time arg is seen and
+     * hands out a clone of the cached result afterwards.
+     * */
+    pub fn get(&mut self, arg: A) -> R {
+        if let Some(result) = self.cache.get(&arg) {
+            return result.clone();
+        }
+
+        let result = (self.func)(&arg);
+        self.cache.insert(arg, result.clone());
+        result
+    }
+
+    pub fn cached(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/*
+ * A closure can't call a Memoized that owns it, so recursive functions
+ * receive the memoizer as their first argument and recurse through it:
+ *
+ *   let mut fib = RecursiveMemoized::new(|fib, &n: &u64| {
+ *       if n < 2 { n } else { fib.get(n - 1) + fib.get(n - 2) }
+ *   });
+ *
+ * A plain fn pointer is stored instead of a generic F because the type
+ * of F would otherwise have to mention itself.
+ * */
+/// ```
+/// use rust_exercises::extras::memo::RecursiveMemoized;
+///
+/// let mut fib = RecursiveMemoized::new(|fib, &n: &u64| {
+///     if n < 2 {
+///         n
+///     } else {
+///         fib.get(n - 1) + fib.get(n - 2)
+///     }
+/// });
+/// // Without the cache this would take about 2^90 calls
+/// assert_eq!(fib.get(90), 2_880_067_194_370_816_120);
+/// assert_eq!(fib.cached(), 91);
+/// ```
+pub struct RecursiveMemoized<A, R>
+where
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    func: fn(&mut RecursiveMemoized<A, R>, &A) -> R,
+    cache: HashMap<A, R>,
+}
+
+impl<A, R> RecursiveMemoized<A, R>
+where
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    pub fn new(func: fn(&mut RecursiveMemoized<A, R>, &A) -> R) -> RecursiveMemoized<A, R> {
+        RecursiveMemoized {
+            func,
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn get(&mut self, arg: A) -> R {
+        if let Some(result) = self.cache.get(&arg) {
+            return result.clone();
+        }
+
+        // Copy the fn pointer out so self can be lent to it mutably
+        let func = self.func;
+        let result = func(self, &arg);
+        self.cache.insert(arg, result.clone());
+        result
+    }
+
+    pub fn cached(&self) -> usize {
+        self.cache.len()
+    }
+}
@@ -0,0 +1,74 @@
+pub mod fizzbuzz;
+pub mod calc;
+pub mod json;
+pub mod bf;
+pub mod fsm;
+pub mod memo;
+pub mod iter_ext;
+pub mod builder;
+pub mod visitor;
+pub mod observer;
+pub mod argparse;
+pub mod units;
+pub mod strings;
+pub mod cipher;
+pub mod base64;
+pub mod hexdump;
+pub mod wc;
+pub mod csv;
+pub mod ini;
+pub mod rle;
+pub mod crc32;
+pub mod rng;
+pub mod cow;
+pub mod interior;
+pub mod typed_id;
+pub mod str_split;
+pub mod temp_dir;
+pub mod logparse;
+#[cfg(feature = "async-rt")]
+pub mod chat;
+pub mod atomics;
+pub mod config_store;
+pub mod bounded_queue;
+pub mod thread_pool;
+pub mod channel;
+pub mod actor;
+pub mod par_wc;
+pub mod simulation;
+pub mod net;
+pub mod http_client;
+pub mod proc;
+pub mod config;
+pub mod logger;
+pub mod glob;
+pub mod datetime;
+pub mod shutdown;
+pub mod filter;
+pub mod binfmt;
+pub mod kvstore;
+pub mod hash_map;
+pub mod bst;
+pub mod quiz;
+pub mod playground;
+pub mod attempts;
+pub mod term;
+pub mod combinators;
+pub mod errors;
+pub mod stats;
+pub mod ordered_float;
+pub mod intervals;
+pub mod algos;
+pub mod matrix;
+pub mod top_k;
+pub mod rope;
+pub mod bitset;
+pub mod raii;
+pub mod retry;
+pub mod eventbus;
+pub mod plugins;
+pub mod command;
+pub mod pipeline;
+#[cfg(feature = "alloc")]
+pub mod scheduler;
+pub mod update;
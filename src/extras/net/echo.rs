@@ -0,0 +1,137 @@
+/*
+ * Echo server: every byte a client sends comes straight back.
+ *
+ * Each connection gets its own thread. std's accept() and read() can't
+ * be interrupted, so the listener is non-blocking and polled, and the
+ * connections use a read timeout, both checking the shutdown flag in
+ * between. serve() returns once the flag is set and every connection
+ * thread has finished.
+ * */
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// How long a shutdown can go unnoticed
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+fn is_timeout(e: &io::Error) -> bool {
+    matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut)
+}
+
+fn echo(mut stream: TcpStream, shutdown: &AtomicBool) -> io::Result<()> {
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+    let mut buf = [0; 4096];
+
+    while !shutdown.load(Ordering::SeqCst) {
+        match stream.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => stream.write_all(&buf[..n])?,
+            Err(e) if is_timeout(&e) || e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+pub fn serve(listener: TcpListener, shutdown: Arc<AtomicBool>) -> io::Result<()> {
+    listener.set_nonblocking(true)?;
+    let mut connections = Vec::new();
+
+    while !shutdown.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Accepted sockets may inherit non-blocking mode
+                stream.set_nonblocking(false)?;
+                let shutdown = shutdown.clone();
+                connections.push(thread::spawn(move || {
+                    // A client going away mid-echo only ends its own thread
+                    let _ = echo(stream, &shutdown);
+                }));
+            }
+            Err(e) if is_timeout(&e) => thread::sleep(POLL_INTERVAL),
+            Err(e) => return Err(e),
+        }
+        connections.retain(|handle| !handle.is_finished());
+    }
+
+    for handle in connections {
+        let _ = handle.join();
+    }
+    Ok(())
+}
+
+/*
+ * Binds, then serves on a background thread. Setting the returned flag
+ * stops the server, join the handle to wait for it.
+ * */
+/// ```
+/// use rust_exercises::extras::net::echo::{spawn, Client};
+/// use std::sync::atomic::Ordering;
+///
+/// let (addr, shutdown, handle) = spawn("127.0.0.1:0").unwrap();
+/// let mut client = Client::connect(addr).unwrap();
+/// assert_eq!(client.send("hello").unwrap(), "hello");
+/// assert!(client.send("two\nlines").is_err());
+///
+/// shutdown.store(true, Ordering::SeqCst);
+/// drop(client);
+/// handle.join().unwrap().unwrap();
+/// ```
+pub fn spawn<A: ToSocketAddrs>(
+    addr: A,
+) -> io::Result<(
+    SocketAddr,
+    Arc<AtomicBool>,
+    thread::JoinHandle<io::Result<()>>,
+)> {
+    let listener = TcpListener::bind(addr)?;
+    let local_addr = listener.local_addr()?;
+    let shutdown = Arc::new(AtomicBool::new(false));
+
+    let signal = shutdown.clone();
+    let handle = thread::spawn(move || serve(listener, signal));
+    Ok((local_addr, shutdown, handle))
+}
+
+/*
+ * Line-oriented client, send() writes one line and waits for the same
+ * line to come back
+ * */
+pub struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Client> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        Ok(Client {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+        })
+    }
+
+    pub fn send(&mut self, message: &str) -> io::Result<String> {
+        if message.contains('\n') {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "message spans several lines",
+            ));
+        }
+        writeln!(self.writer, "{}", message)?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "server closed the connection",
+            ));
+        }
+        line.pop();
+        Ok(line)
+    }
+}
@@ -0,0 +1,6 @@
+/*
+ * Blocking networking with std::net, the groundwork for chapter 20's
+ * web server, https://doc.rust-lang.org/std/net/index.html
+ * */
+pub mod echo;
+pub mod udp;
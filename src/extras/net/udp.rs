@@ -0,0 +1,211 @@
+/*
+ * Time and statistics server over UDP. Each request and each answer is
+ * a single datagram with no connection in between, so nothing tells the
+ * client its request got lost: it waits for a timeout and sends again.
+ *
+ *   TIME   ->  TIME <seconds since the epoch>.<millis>
+ *   STATS  ->  STATS requests=<n> time=<n> stats=<n> unknown=<n>
+ *   other  ->  ERROR unknown command
+ * */
+use std::fmt;
+use std::io::{self, ErrorKind};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const MAX_DATAGRAM: usize = 512;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub requests: u64,
+    pub time: u64,
+    pub stats: u64,
+    pub unknown: u64,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "requests={} time={} stats={} unknown={}",
+            self.requests, self.time, self.stats, self.unknown
+        )
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::net::udp::Server;
+///
+/// let mut server = Server::bind("127.0.0.1:0").unwrap();
+/// assert!(server.answer("TIME").starts_with("TIME "));
+/// assert_eq!(server.answer("HELLO"), "ERROR unknown command");
+/// assert_eq!(
+///     server.answer("STATS"),
+///     "STATS requests=3 time=1 stats=1 unknown=1"
+/// );
+/// ```
+pub struct Server {
+    socket: UdpSocket,
+    stats: Stats,
+    // Ignore every nth datagram, to watch the client retry
+    drop_every: Option<u64>,
+    received: u64,
+}
+
+impl Server {
+    pub fn new(socket: UdpSocket) -> Server {
+        Server {
+            socket,
+            stats: Stats::default(),
+            drop_every: None,
+            received: 0,
+        }
+    }
+
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Server> {
+        Ok(Server::new(UdpSocket::bind(addr)?))
+    }
+
+    pub fn with_loss(mut self, drop_every: u64) -> Server {
+        assert!(drop_every > 0, "can't drop every 0th datagram");
+        self.drop_every = Some(drop_every);
+        self
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    pub fn answer(&mut self, request: &str) -> String {
+        self.stats.requests += 1;
+        match request.trim() {
+            "TIME" => {
+                self.stats.time += 1;
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                format!("TIME {}.{:03}", now.as_secs(), now.subsec_millis())
+            }
+            "STATS" => {
+                self.stats.stats += 1;
+                format!("STATS {}", self.stats)
+            }
+            _ => {
+                self.stats.unknown += 1;
+                String::from("ERROR unknown command")
+            }
+        }
+    }
+
+    // Answers datagrams until the flag is set, then returns the totals
+    pub fn serve(mut self, shutdown: Arc<AtomicBool>) -> io::Result<Stats> {
+        self.socket.set_read_timeout(Some(POLL_INTERVAL))?;
+        let mut buf = [0; MAX_DATAGRAM];
+
+        while !shutdown.load(Ordering::SeqCst) {
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    continue
+                }
+                Err(e) => return Err(e),
+            };
+
+            self.received += 1;
+            if let Some(n) = self.drop_every {
+                if self.received.is_multiple_of(n) {
+                    continue;
+                }
+            }
+
+            let answer = self.answer(&String::from_utf8_lossy(&buf[..len]));
+            self.socket.send_to(answer.as_bytes(), from)?;
+        }
+        Ok(self.stats)
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    // No answer after this many attempts
+    NoResponse(u32),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Io(e) => write!(f, "{}", e),
+            ClientError::NoResponse(attempts) => {
+                write!(f, "no response after {} attempts", attempts)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> ClientError {
+        ClientError::Io(e)
+    }
+}
+
+pub struct Client {
+    socket: UdpSocket,
+    server: SocketAddr,
+    timeout: Duration,
+    attempts: u32,
+}
+
+impl Client {
+    // Binds an ephemeral port, of the same address family as the server
+    pub fn new(server: SocketAddr) -> io::Result<Client> {
+        let any: IpAddr = if server.is_ipv4() {
+            Ipv4Addr::UNSPECIFIED.into()
+        } else {
+            Ipv6Addr::UNSPECIFIED.into()
+        };
+
+        Ok(Client {
+            socket: UdpSocket::bind((any, 0))?,
+            server,
+            timeout: Duration::from_millis(200),
+            attempts: 3,
+        })
+    }
+
+    pub fn with_retries(mut self, timeout: Duration, attempts: u32) -> Client {
+        assert!(attempts > 0, "need at least one attempt");
+        self.timeout = timeout;
+        self.attempts = attempts;
+        self
+    }
+
+    /*
+     * Sends the request and waits up to the timeout for an answer, as
+     * many times as allowed. Datagrams from anyone but the server are
+     * ignored.
+     * */
+    pub fn request(&self, command: &str) -> Result<String, ClientError> {
+        self.socket.set_read_timeout(Some(self.timeout))?;
+        let mut buf = [0; MAX_DATAGRAM];
+
+        for _ in 0..self.attempts {
+            self.socket.send_to(command.as_bytes(), self.server)?;
+            match self.socket.recv_from(&mut buf) {
+                Ok((len, from)) if from == self.server => {
+                    return Ok(String::from_utf8_lossy(&buf[..len]).into_owned());
+                }
+                Ok(_) => continue,
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                    continue
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(ClientError::NoResponse(self.attempts))
+    }
+}
@@ -0,0 +1,107 @@
+/*
+ * Observer pattern: an EventBus fans every published event out to its
+ * subscribers, in the order they subscribed.
+ *
+ * A subscriber is either called directly on publish() or gets a clone of
+ * the event over an mpsc channel, so it can be consumed on another thread,
+ * https://doc.rust-lang.org/book/ch16-02-message-passing.html
+ * */
+use std::sync::mpsc::{self, Receiver, Sender};
+
+pub trait Subscriber<E> {
+    fn notify(&mut self, event: &E);
+}
+
+// Any closure taking the event works as a subscriber
+impl<E, F: FnMut(&E)> Subscriber<E> for F {
+    fn notify(&mut self, event: &E) {
+        self(event)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+enum Listener<E> {
+    Direct(Box<dyn Subscriber<E>>),
+    Channel(Sender<E>),
+}
+
+/// ```
+/// use rust_exercises::extras::observer::EventBus;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let seen = Rc::new(RefCell::new(Vec::new()));
+/// let mut bus = EventBus::new();
+/// let log = seen.clone();
+/// let id = bus.subscribe(Box::new(move |event: &u32| log.borrow_mut().push(*event)));
+/// let (_, events) = bus.subscribe_channel();
+///
+/// bus.publish(&1);
+/// assert!(bus.unsubscribe(id));
+/// bus.publish(&2);
+/// assert_eq!(*seen.borrow(), [1]);
+/// assert_eq!(events.try_iter().collect::<Vec<_>>(), [1, 2]);
+/// ```
+pub struct EventBus<E> {
+    listeners: Vec<(SubscriptionId, Listener<E>)>,
+    next_id: u64,
+}
+
+impl<E: Clone> Default for EventBus<E> {
+    fn default() -> EventBus<E> {
+        EventBus::new()
+    }
+}
+
+impl<E: Clone> EventBus<E> {
+    pub fn new() -> EventBus<E> {
+        EventBus {
+            listeners: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    fn add(&mut self, listener: Listener<E>) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.listeners.push((id, listener));
+        id
+    }
+
+    pub fn subscribe(&mut self, subscriber: Box<dyn Subscriber<E>>) -> SubscriptionId {
+        self.add(Listener::Direct(subscriber))
+    }
+
+    /*
+     * The receiver gets every event published from now on, dropping it
+     * is the same as unsubscribing.
+     * */
+    pub fn subscribe_channel(&mut self) -> (SubscriptionId, Receiver<E>) {
+        let (sender, receiver) = mpsc::channel();
+        (self.add(Listener::Channel(sender)), receiver)
+    }
+
+    // false if the subscription was already gone
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.listeners.len();
+        self.listeners.retain(|&(listener_id, _)| listener_id != id);
+        self.listeners.len() != before
+    }
+
+    pub fn subscribers(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub fn publish(&mut self, event: &E) {
+        // Channels whose receiver was dropped are removed along the way
+        self.listeners.retain_mut(|&mut (_, ref mut listener)| match *listener {
+            Listener::Direct(ref mut subscriber) => {
+                subscriber.notify(event);
+                true
+            }
+            Listener::Channel(ref sender) => sender.send(event.clone()).is_ok(),
+        });
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * f64 with a total order, so floats can go where Ord is required:
+ * BTreeMap keys, BinaryHeap, sort() and max() without unwrap.
+ *
+ * f64 is only PartialOrd because NaN compares false to everything, even
+ * itself. Here every NaN is equal to every other and greater than any
+ * number, infinity included. -0.0 and 0.0 stay equal like they are for
+ * f64, and hash the same, which Eq needs from Hash.
+ *
+ * It's the idea of the ordered-float crate's OrderedFloat,
+ * https://docs.rs/ordered-float
+ * */
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// ```
+/// use rust_exercises::extras::ordered_float::OrderedF64;
+///
+/// let mut values: Vec<OrderedF64> = [2.5, f64::NAN, -1.0, f64::INFINITY]
+///     .iter()
+///     .map(|&x| OrderedF64(x))
+///     .collect();
+/// values.sort();
+/// assert_eq!(values[0], OrderedF64(-1.0));
+/// assert!(values[3].get().is_nan());
+/// assert_eq!(OrderedF64(-0.0), OrderedF64(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderedF64(pub f64);
+
+impl OrderedF64 {
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    // The bits Hash sees: one NaN for all of them, and 0.0 for -0.0
+    fn canonical_bits(self) -> u64 {
+        if self.0.is_nan() {
+            f64::NAN.to_bits()
+        } else if self.0 == 0.0 {
+            0.0f64.to_bits()
+        } else {
+            self.0.to_bits()
+        }
+    }
+}
+
+impl From<f64> for OrderedF64 {
+    fn from(value: f64) -> OrderedF64 {
+        OrderedF64(value)
+    }
+}
+
+impl From<OrderedF64> for f64 {
+    fn from(value: OrderedF64) -> f64 {
+        value.0
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &OrderedF64) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            // Neither is NaN, so partial_cmp always has an answer
+            (false, false) => self.0.partial_cmp(&other.0).unwrap(),
+        }
+    }
+}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &OrderedF64) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equal exactly when cmp says so, NaN included
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &OrderedF64) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl Hash for OrderedF64 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_bits().hash(state);
+    }
+}
+
+impl fmt::Display for OrderedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
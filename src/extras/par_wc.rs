@@ -0,0 +1,126 @@
+/*
+ * Word frequencies over a whole directory tree, counted either on the
+ * current thread or split across the chapter 20 ThreadPool.
+ *
+ * Every pool job counts its share of the files into its own HashMap and
+ * sends it back over a channel, the maps are only merged at the end so
+ * the workers never contend on a shared one.
+ * */
+use crate::extras::thread_pool::ThreadPool;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+pub type Frequencies = HashMap<String, usize>;
+
+// Every file under `root`, sorted so the split between jobs is stable
+pub fn collect_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+// Words are runs of alphanumeric characters, lowercased
+pub fn count_words(text: &str, frequencies: &mut Frequencies) {
+    for word in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        *frequencies.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+}
+
+// Invalid UTF-8 is replaced rather than failing the whole count
+fn count_file(path: &Path, frequencies: &mut Frequencies) -> io::Result<()> {
+    let bytes = fs::read(path)?;
+    count_words(&String::from_utf8_lossy(&bytes), frequencies);
+    Ok(())
+}
+
+pub fn merge(into: &mut Frequencies, from: Frequencies) {
+    for (word, count) in from {
+        *into.entry(word).or_insert(0) += count;
+    }
+}
+
+pub fn count_sequential(files: &[PathBuf]) -> io::Result<Frequencies> {
+    let mut frequencies = Frequencies::new();
+    for path in files {
+        count_file(path, &mut frequencies)?;
+    }
+    Ok(frequencies)
+}
+
+/// ```
+/// use rust_exercises::extras::par_wc::{count_parallel, count_sequential, top};
+/// use rust_exercises::extras::temp_dir::TempDir;
+/// use std::fs;
+///
+/// let dir = TempDir::new("par_wc_doc").unwrap();
+/// fs::write(dir.join("a.txt"), "the cat and the hat").unwrap();
+/// fs::write(dir.join("b.txt"), "The end").unwrap();
+/// let files = vec![dir.join("a.txt"), dir.join("b.txt")];
+///
+/// let frequencies = count_parallel(&files, 2).unwrap();
+/// assert_eq!(frequencies, count_sequential(&files).unwrap());
+/// assert_eq!(top(&frequencies, 2), [("the", 3), ("and", 1)]);
+/// ```
+pub fn count_parallel(files: &[PathBuf], threads: usize) -> io::Result<Frequencies> {
+    assert!(threads > 0, "need at least one thread");
+
+    // Round robin so large neighbouring files end up in different jobs
+    let jobs = threads.min(files.len()).max(1);
+    let mut shares = vec![Vec::new(); jobs];
+    for (i, path) in files.iter().enumerate() {
+        shares[i % jobs].push(path.clone());
+    }
+
+    let pool = ThreadPool::new(threads);
+    let (tx, rx) = mpsc::channel();
+    for share in shares {
+        let tx = tx.clone();
+        pool.execute(move || {
+            let mut local = Frequencies::new();
+            let result = share
+                .iter()
+                .try_for_each(|path| count_file(path, &mut local))
+                .map(|()| local);
+            tx.send(result).unwrap();
+        });
+    }
+    // Only the jobs' senders left, so the loop ends after the last one
+    drop(tx);
+
+    let mut frequencies = Frequencies::new();
+    for result in rx {
+        merge(&mut frequencies, result?);
+    }
+    Ok(frequencies)
+}
+
+// Most frequent first, ties in alphabetical order
+pub fn top(frequencies: &Frequencies, n: usize) -> Vec<(&str, usize)> {
+    let mut table: Vec<(&str, usize)> = frequencies
+        .iter()
+        .map(|(word, &count)| (word.as_str(), count))
+        .collect();
+    table.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    table.truncate(n);
+    table
+}
@@ -0,0 +1,205 @@
+/*
+ * Chain of responsibility: an item goes through the stages one after
+ * the other, and each stage decides what happens to it next. It can
+ *
+ *   Step::Next(item)  hand on the item, changed or as it came
+ *   Step::Skip        drop it, the stages after it never see it
+ *   Err(e)            stop everything, run() returns the error
+ *
+ * Stages are Box<dyn Stage<T>>, and closures are stages too, so a
+ * pipeline is usually put together from the builder's map/filter/try_map:
+ *
+ *   let pipeline = Pipeline::builder()
+ *       .map("trim", |line: String| line.trim().to_string())
+ *       .filter("not empty", |line| !line.is_empty())
+ *       .try_map("parse", |line| line.parse::<i64>().map(|n| n.to_string()))
+ *       .build();
+ *
+ * grep() is minigrep's search rebuilt that way, lines in, matching lines
+ * out, the same result as chapters::minigrep::search.
+ * */
+use std::error::Error;
+use std::fmt;
+
+pub enum Step<T> {
+    Next(T),
+    Skip,
+}
+
+pub trait Stage<T> {
+    fn process(&self, item: T) -> Result<Step<T>, Box<dyn Error>>;
+}
+
+impl<T, F> Stage<T> for F
+where
+    F: Fn(T) -> Result<Step<T>, Box<dyn Error>>,
+{
+    fn process(&self, item: T) -> Result<Step<T>, Box<dyn Error>> {
+        self(item)
+    }
+}
+
+/*
+ * Which stage failed, by the name it was added with, and why. The
+ * stage's own error is the source().
+ * */
+#[derive(Debug)]
+pub struct PipelineError {
+    pub stage: String,
+    pub source: Box<dyn Error>,
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "stage {} failed: {}", self.stage, self.source)
+    }
+}
+
+impl Error for PipelineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::pipeline::Pipeline;
+///
+/// let pipeline = Pipeline::builder()
+///     .map("trim", |line: String| line.trim().to_string())
+///     .filter("non empty", |line: &String| !line.is_empty())
+///     .try_map("parse", |line: String| line.parse::<u32>().map(|n| (n * 2).to_string()))
+///     .build();
+/// assert_eq!(pipeline.stage_names(), ["trim", "non empty", "parse"]);
+///
+/// let lines = vec![" 1".to_string(), "".to_string(), "21 ".to_string()];
+/// assert_eq!(pipeline.run_all(lines).unwrap(), ["2", "42"]);
+/// assert_eq!(pipeline.run(" ".to_string()).unwrap(), None);
+///
+/// let error = pipeline.run("x".to_string()).unwrap_err();
+/// assert_eq!(error.stage, "parse");
+/// ```
+pub struct Pipeline<T> {
+    stages: Vec<(String, Box<dyn Stage<T>>)>,
+}
+
+impl<T: 'static> Pipeline<T> {
+    pub fn builder() -> PipelineBuilder<T> {
+        PipelineBuilder { stages: Vec::new() }
+    }
+
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    // None if a stage skipped the item
+    pub fn run(&self, item: T) -> Result<Option<T>, PipelineError> {
+        let mut item = item;
+        for (name, stage) in self.stages.iter() {
+            match stage.process(item) {
+                Ok(Step::Next(next)) => item = next,
+                Ok(Step::Skip) => return Ok(None),
+                Err(source) => {
+                    return Err(PipelineError {
+                        stage: name.clone(),
+                        source,
+                    })
+                }
+            }
+        }
+        Ok(Some(item))
+    }
+
+    // What came out the other end, in order. The first error ends it all.
+    pub fn run_all<I: IntoIterator<Item = T>>(&self, items: I) -> Result<Vec<T>, PipelineError> {
+        let mut out = Vec::new();
+        for item in items {
+            if let Some(item) = self.run(item)? {
+                out.push(item);
+            }
+        }
+        Ok(out)
+    }
+}
+
+pub struct PipelineBuilder<T> {
+    stages: Vec<(String, Box<dyn Stage<T>>)>,
+}
+
+impl<T: 'static> PipelineBuilder<T> {
+    // Stages run in the order they're added
+    pub fn stage<S: Stage<T> + 'static>(mut self, name: &str, stage: S) -> PipelineBuilder<T> {
+        self.stages.push((name.to_string(), Box::new(stage)));
+        self
+    }
+
+    pub fn map<F>(self, name: &str, f: F) -> PipelineBuilder<T>
+    where
+        F: Fn(T) -> T + 'static,
+    {
+        self.stage(name, move |item| Ok(Step::Next(f(item))))
+    }
+
+    // Items the predicate says no to are skipped
+    pub fn filter<F>(self, name: &str, keep: F) -> PipelineBuilder<T>
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.stage(name, move |item| {
+            Ok(if keep(&item) {
+                Step::Next(item)
+            } else {
+                Step::Skip
+            })
+        })
+    }
+
+    pub fn try_map<F, E>(self, name: &str, f: F) -> PipelineBuilder<T>
+    where
+        F: Fn(T) -> Result<T, E> + 'static,
+        E: Into<Box<dyn Error>>,
+    {
+        self.stage(name, move |item| {
+            f(item).map(Step::Next).map_err(Into::into)
+        })
+    }
+
+    pub fn build(self) -> Pipeline<T> {
+        Pipeline {
+            stages: self.stages,
+        }
+    }
+}
+
+/*
+ * minigrep's search and search_case_insensitive as one pipeline over
+ * owned lines: a single filter, with the query lowercased once up front
+ * when case doesn't matter
+ * */
+pub fn grep(query: &str, ignore_case: bool) -> Pipeline<String> {
+    if ignore_case {
+        let query = query.to_lowercase();
+        Pipeline::builder()
+            .filter("match ignoring case", move |line: &String| {
+                line.to_lowercase().contains(&query)
+            })
+            .build()
+    } else {
+        let query = query.to_string();
+        Pipeline::builder()
+            .filter("match", move |line: &String| line.contains(&query))
+            .build()
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::pipeline::grep_lines;
+///
+/// let contents = "Rust:\nsafe, fast, productive.\nTrust me.";
+/// assert_eq!(grep_lines("rust", false, contents), ["Trust me."]);
+/// assert_eq!(grep_lines("rust", true, contents), ["Rust:", "Trust me."]);
+/// ```
+pub fn grep_lines(query: &str, ignore_case: bool, contents: &str) -> Vec<String> {
+    grep(query, ignore_case)
+        .run_all(contents.lines().map(String::from))
+        .expect("grep's stages never fail")
+}
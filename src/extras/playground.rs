@@ -0,0 +1,89 @@
+/*
+ * Runs a throwaway main.rs the way a learner would in a project of
+ * their own: a scratch cargo project in a temp dir, built and run with
+ * `cargo run`. Compiler errors and the program's output go straight to
+ * our stdout and stderr while they happen, nothing is buffered.
+ * */
+use crate::extras::temp_dir::TempDir;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+// Its own [workspace] so a parent directory's workspace can't claim it
+const MANIFEST: &str = r#"[package]
+name = "playground"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[workspace]
+"#;
+
+/// ```no_run
+/// use rust_exercises::extras::playground::Playground;
+///
+/// let playground = Playground::new().unwrap();
+/// let status = playground
+///     .run("fn main() { println!(\"hello\"); }")
+///     .unwrap();
+/// assert!(status.success());
+/// ```
+pub struct Playground {
+    dir: TempDir,
+    cargo: String,
+}
+
+impl Playground {
+    /*
+     * $CARGO is set by cargo for whatever it runs, so `cargo run --bin
+     * runner` builds snippets with the toolchain building this crate
+     * */
+    pub fn new() -> io::Result<Playground> {
+        let dir = TempDir::new("playground")?;
+        fs::create_dir(dir.join("src"))?;
+        fs::write(dir.join("Cargo.toml"), MANIFEST)?;
+        Ok(Playground {
+            dir,
+            cargo: env::var("CARGO").unwrap_or_else(|_| String::from("cargo")),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    pub fn write(&self, source: &str) -> io::Result<()> {
+        fs::write(self.dir.join("src").join("main.rs"), source)
+    }
+
+    // Left to the caller to pick where the output goes
+    pub fn command(&self) -> Command {
+        let mut command = Command::new(&self.cargo);
+        command
+            .args(["run", "--quiet", "--manifest-path"])
+            .arg(self.dir.join("Cargo.toml"));
+        command
+    }
+
+    /*
+     * Builds and runs source, inheriting our stdio. The status is the
+     * program's own when it ran, cargo's when the build failed.
+     * */
+    pub fn run(&self, source: &str) -> io::Result<ExitStatus> {
+        self.write(source)?;
+        self.command().status()
+    }
+}
+
+// A path, or "-" for stdin
+pub fn read_source(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut source = String::new();
+        io::stdin().read_to_string(&mut source)?;
+        Ok(source)
+    } else {
+        fs::read_to_string(path)
+    }
+}
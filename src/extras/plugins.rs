@@ -0,0 +1,175 @@
+/*
+ * Plugins as trait objects: everything that implements Plugin can sit in
+ * the same Registry as a Box<dyn Plugin>, and is found by its name when
+ * it's time to run it.
+ *
+ *   cargo run --bin runner -- plugins run rot13 hello
+ *   echo 'a b c' | cargo run --bin runner -- plugins run wc
+ *
+ * The inventory crate lets each plugin register itself from wherever it's
+ * defined, gathered at link time, https://docs.rs/inventory
+ * register_plugins! is the part of that std can do: one list of
+ * constructors in a static, which Registry::from_list() turns into
+ * plugins. Adding one is a line in that list, nothing else changes.
+ * */
+use crate::extras::cipher;
+use crate::extras::wc;
+use std::error::Error;
+use std::fmt;
+
+pub trait Plugin {
+    // What `plugins run <name>` looks for, unique within a registry
+    fn name(&self) -> &str;
+
+    fn execute(&self, input: &str) -> Result<String, PluginError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginError {
+    Unknown(String),
+    Duplicate(String),
+    // The plugin ran and had a problem with the input
+    Failed { plugin: String, message: String },
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PluginError::Unknown(name) => write!(f, "no plugin named {}", name),
+            PluginError::Duplicate(name) => {
+                write!(f, "a plugin named {} is already registered", name)
+            }
+            PluginError::Failed { plugin, message } => write!(f, "{}: {}", plugin, message),
+        }
+    }
+}
+
+impl Error for PluginError {}
+
+// How register_plugins! lists them, one function per plugin
+pub type Constructor = fn() -> Box<dyn Plugin>;
+
+/*
+ * Each entry is an expression building the plugin, it's run every time a
+ * registry is made from the list.
+ * */
+/// ```
+/// use rust_exercises::extras::plugins::{Registry, Rot13};
+/// use rust_exercises::register_plugins;
+///
+/// register_plugins! {
+///     static MINE = [Rot13];
+/// }
+/// let registry = Registry::from_list(MINE).unwrap();
+/// assert_eq!(registry.names(), ["rot13"]);
+/// ```
+#[macro_export]
+macro_rules! register_plugins {
+    ($vis:vis static $name:ident = [$($plugin:expr),* $(,)?];) => {
+        $vis static $name: &[$crate::extras::plugins::Constructor] = &[
+            $(|| -> ::std::boxed::Box<dyn $crate::extras::plugins::Plugin> {
+                ::std::boxed::Box::new($plugin)
+            }),*
+        ];
+    };
+}
+
+// Kept in the order they were registered, for listing them
+/// ```
+/// use rust_exercises::extras::plugins::{PluginError, Registry, Rot13};
+///
+/// let registry = Registry::builtin();
+/// assert_eq!(registry.names(), ["rot13", "wc"]);
+/// assert_eq!(registry.run("rot13", "hello").unwrap(), "uryyb");
+/// assert_eq!(
+///     registry.run("nope", ""),
+///     Err(PluginError::Unknown("nope".to_string()))
+/// );
+///
+/// let mut registry = Registry::new();
+/// registry.register(Box::new(Rot13)).unwrap();
+/// assert!(registry.register(Box::new(Rot13)).is_err());
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn from_list(list: &[Constructor]) -> Result<Registry, PluginError> {
+        let mut registry = Registry::new();
+        for constructor in list {
+            registry.register(constructor())?;
+        }
+        Ok(registry)
+    }
+
+    // The plugins that come with the crate, BUILTIN below
+    pub fn builtin() -> Registry {
+        Registry::from_list(BUILTIN).expect("two builtin plugins with the same name")
+    }
+
+    pub fn register(&mut self, plugin: Box<dyn Plugin>) -> Result<(), PluginError> {
+        if self.get(plugin.name()).is_some() {
+            return Err(PluginError::Duplicate(plugin.name().to_string()));
+        }
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Plugin> {
+        self.plugins
+            .iter()
+            .find(|plugin| plugin.name() == name)
+            .map(|plugin| plugin.as_ref())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    pub fn run(&self, name: &str, input: &str) -> Result<String, PluginError> {
+        match self.get(name) {
+            Some(plugin) => plugin.execute(input),
+            None => Err(PluginError::Unknown(name.to_string())),
+        }
+    }
+}
+
+// Caesar with a shift of 13, running it twice gives the input back
+pub struct Rot13;
+
+impl Plugin for Rot13 {
+    fn name(&self) -> &str {
+        "rot13"
+    }
+
+    fn execute(&self, input: &str) -> Result<String, PluginError> {
+        Ok(cipher::caesar_encrypt(input, 13))
+    }
+}
+
+// The same columns as `wc`: lines, words and bytes
+pub struct WordCount;
+
+impl Plugin for WordCount {
+    fn name(&self) -> &str {
+        "wc"
+    }
+
+    fn execute(&self, input: &str) -> Result<String, PluginError> {
+        let counts = wc::count(input.as_bytes()).map_err(|e| PluginError::Failed {
+            plugin: self.name().to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(counts.to_string())
+    }
+}
+
+register_plugins! {
+    pub static BUILTIN = [Rot13, WordCount];
+}
@@ -0,0 +1,206 @@
+/*
+ * Running other programs with std::process::Command,
+ * https://doc.rust-lang.org/std/process/index.html
+ *
+ *   run             wait for it and capture stdout and stderr
+ *   run_checked     same, but a non-zero exit is an error
+ *   pipe            first | second, without a shell
+ *   run_with_timeout  kill it if it takes too long
+ * */
+use std::fmt;
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captured {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Captured {
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+}
+
+#[derive(Debug)]
+pub enum ProcError {
+    // Couldn't start it, or lost the pipes to it
+    Io(io::Error),
+    Failed { status: ExitStatus, stderr: String },
+    TimedOut(Duration),
+}
+
+impl fmt::Display for ProcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProcError::Io(e) => write!(f, "{}", e),
+            ProcError::Failed { status, stderr } => {
+                write!(f, "{}", describe_status(*status))?;
+                if !stderr.trim().is_empty() {
+                    write!(f, ": {}", stderr.trim())?;
+                }
+                Ok(())
+            }
+            ProcError::TimedOut(after) => write!(f, "killed after {:?}", after),
+        }
+    }
+}
+
+impl std::error::Error for ProcError {}
+
+impl From<io::Error> for ProcError {
+    fn from(e: io::Error) -> ProcError {
+        ProcError::Io(e)
+    }
+}
+
+/*
+ * A process either exits with a code or, on Unix, is killed by a signal
+ * and has no code at all
+ * */
+pub fn describe_status(status: ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return format!("exited with code {}", code);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return format!("killed by signal {}", signal);
+        }
+    }
+
+    String::from("terminated")
+}
+
+fn lossy(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
+// output() sets up the pipes, waits and reads both streams for us
+pub fn run(program: &str, args: &[&str]) -> io::Result<Captured> {
+    let output = Command::new(program).args(args).output()?;
+    Ok(Captured {
+        status: output.status,
+        stdout: lossy(output.stdout),
+        stderr: lossy(output.stderr),
+    })
+}
+
+/// ```no_run
+/// use rust_exercises::extras::proc::run_checked;
+///
+/// let version = run_checked("rustc", &["--version"]).unwrap();
+/// assert!(version.starts_with("rustc "));
+/// assert!(run_checked("rustc", &["--no-such-flag"]).is_err());
+/// ```
+pub fn run_checked(program: &str, args: &[&str]) -> Result<String, ProcError> {
+    let captured = run(program, args)?;
+    if captured.success() {
+        Ok(captured.stdout)
+    } else {
+        Err(ProcError::Failed {
+            status: captured.status,
+            stderr: captured.stderr,
+        })
+    }
+}
+
+/*
+ * The first child's stdout handle becomes the second one's stdin, the
+ * data goes from one process to the other without passing through us.
+ * Fails if either command fails.
+ * */
+/// ```no_run
+/// use rust_exercises::extras::proc::pipe;
+///
+/// let sorted = pipe(("printf", &["b\\na\\n"]), ("sort", &[])).unwrap();
+/// assert_eq!(sorted, "a\nb\n");
+/// ```
+pub fn pipe(first: (&str, &[&str]), second: (&str, &[&str])) -> Result<String, ProcError> {
+    let mut producer = Command::new(first.0)
+        .args(first.1)
+        .stdout(Stdio::piped())
+        .spawn()?;
+    let producer_stdout = producer.stdout.take().expect("stdout was piped");
+
+    let consumer = Command::new(second.0)
+        .args(second.1)
+        .stdin(Stdio::from(producer_stdout))
+        .output();
+    // Wait on the producer even if the consumer didn't start, no zombies
+    let producer_status = producer.wait()?;
+    let consumer = consumer?;
+
+    if !producer_status.success() {
+        return Err(ProcError::Failed {
+            status: producer_status,
+            stderr: String::new(),
+        });
+    }
+    if !consumer.status.success() {
+        return Err(ProcError::Failed {
+            status: consumer.status,
+            stderr: lossy(consumer.stderr),
+        });
+    }
+    Ok(lossy(consumer.stdout))
+}
+
+/*
+ * Reads a pipe to the end on its own thread. Reading stdout and then
+ * stderr on one thread can deadlock: the child blocks writing to a full
+ * stderr pipe while we wait for stdout to end.
+ * */
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
+
+fn kill_and_reap(child: &mut Child) -> io::Result<()> {
+    // Fails only if it already exited, wait() reaps it either way
+    let _ = child.kill();
+    child.wait().map(|_| ())
+}
+
+/*
+ * Polls try_wait() until the child exits or the time is up, then kills
+ * it. std has no wait with a timeout.
+ * */
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> Result<Captured, ProcError> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if start.elapsed() >= timeout {
+            kill_and_reap(&mut child)?;
+            return Err(ProcError::TimedOut(timeout));
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    Ok(Captured {
+        status,
+        stdout: lossy(stdout.join().unwrap_or_default()),
+        stderr: lossy(stderr.join().unwrap_or_default()),
+    })
+}
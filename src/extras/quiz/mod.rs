@@ -0,0 +1,103 @@
+/*
+ * Guess the output: shows a snippet, reads what the learner thinks it
+ * prints, then compiles and runs it to reveal what it really prints.
+ * A guess ends at the first empty line, or at the end of the input.
+ * */
+pub mod snippets;
+
+use snippets::{Runner, Snippet, SnippetError};
+use std::io::{BufRead, Write};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Score {
+    pub right: usize,
+    pub asked: usize,
+}
+
+// Trailing spaces and blank lines at the end don't count against a guess
+fn normalize(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    lines
+}
+
+/// ```
+/// use rust_exercises::extras::quiz::is_right;
+///
+/// assert!(is_right("1\n2  \n\n", "1\n2\n"));
+/// assert!(!is_right("1\n\n2\n", "1\n2\n"));
+/// ```
+pub fn is_right(guess: &str, actual: &str) -> bool {
+    normalize(guess) == normalize(actual)
+}
+
+fn read_guess<R: BufRead>(input: &mut R) -> std::io::Result<String> {
+    let mut guess = String::new();
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            return Ok(guess);
+        }
+        guess.push_str(&line);
+    }
+}
+
+/*
+ * One question, returns whether the guess was right. The snippet runs
+ * after the guess is in, and its real output is what's shown and
+ * compared against, even if the stored answer has gone stale.
+ * */
+pub fn ask<R: BufRead, W: Write>(
+    runner: &Runner,
+    snippet: &Snippet,
+    input: &mut R,
+    output: &mut W,
+) -> Result<bool, SnippetError> {
+    writeln!(output, "--- {} ---", snippet.name)?;
+    write!(output, "{}", snippet.code)?;
+    writeln!(output, "--- what does it print? ---")?;
+    output.flush()?;
+
+    let guess = read_guess(input)?;
+    let actual = runner.run(snippet)?;
+    let right = is_right(&guess, &actual);
+
+    writeln!(output, "--- it prints ---")?;
+    write!(output, "{}", actual)?;
+    if actual != snippet.expected {
+        writeln!(
+            output,
+            "(the stored answer for {} is out of date)",
+            snippet.name
+        )?;
+    }
+    writeln!(output, "{}\n", if right { "right!" } else { "not quite" })?;
+    Ok(right)
+}
+
+/// ```no_run
+/// use rust_exercises::extras::quiz::{self, snippets};
+///
+/// let snippet = snippets::find("shadowing").unwrap();
+/// let mut input = "6\n12\n\n".as_bytes();
+/// let score = quiz::run(&[snippet], &mut input, &mut std::io::stdout()).unwrap();
+/// assert_eq!(score.asked, 1);
+/// ```
+pub fn run<R: BufRead, W: Write>(
+    snippets: &[&Snippet],
+    input: &mut R,
+    output: &mut W,
+) -> Result<Score, SnippetError> {
+    let runner = Runner::new()?;
+    let mut score = Score::default();
+
+    for snippet in snippets {
+        if ask(&runner, snippet, input, output)? {
+            score.right += 1;
+        }
+        score.asked += 1;
+    }
+    Ok(score)
+}
@@ -0,0 +1,230 @@
+/*
+ * Small programs with the output they print. The answers aren't trusted:
+ * every snippet is compiled with rustc and run in a temp dir when it's
+ * asked, so a stale expected output shows up instead of being taught.
+ * */
+use crate::extras::temp_dir::TempDir;
+use crate::extras::proc::{self, ProcError};
+use std::env;
+use std::fmt;
+use std::io;
+use std::process::Command;
+use std::time::Duration;
+
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(60);
+// Snippets are tiny, anything slower than this is stuck in a loop
+const RUN_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snippet {
+    // Also the file name, so letters, digits and _ only
+    pub name: &'static str,
+    pub code: &'static str,
+    pub expected: &'static str,
+}
+
+pub const SNIPPETS: &[Snippet] = &[
+    Snippet {
+        name: "shadowing",
+        code: r#"fn main() {
+    let x = 5;
+    let x = x + 1;
+    {
+        let x = x * 2;
+        println!("{}", x);
+    }
+    println!("{}", x);
+}
+"#,
+        expected: "12\n6\n",
+    },
+    Snippet {
+        name: "drop_order",
+        code: r#"struct Noisy(&'static str);
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        println!("drop {}", self.0);
+    }
+}
+
+fn main() {
+    let _a = Noisy("a");
+    let _b = Noisy("b");
+    let _ = Noisy("c");
+    println!("end of main");
+}
+"#,
+        expected: "drop c\nend of main\ndrop b\ndrop a\n",
+    },
+    Snippet {
+        name: "lazy_iterators",
+        code: r#"fn main() {
+    let v = vec![1, 2, 3];
+    let doubled = v.iter().map(|x| {
+        println!("map {}", x);
+        x * 2
+    });
+    println!("nothing yet");
+    let total: i32 = doubled.take(2).sum();
+    println!("{}", total);
+}
+"#,
+        expected: "nothing yet\nmap 1\nmap 2\n6\n",
+    },
+    Snippet {
+        name: "string_length",
+        code: r#"fn main() {
+    let s = "héllo";
+    println!("{} {}", s.len(), s.chars().count());
+}
+"#,
+        expected: "6 5\n",
+    },
+    Snippet {
+        name: "integer_arithmetic",
+        code: r#"fn main() {
+    let a: u8 = 250;
+    println!("{}", a.wrapping_add(10));
+    println!("{}", -7 / 2);
+    println!("{}", -7 % 2);
+}
+"#,
+        expected: "4\n-3\n-1\n",
+    },
+    Snippet {
+        name: "split_spaces",
+        code: r#"fn main() {
+    let parts: Vec<&str> = "a b  c".split(' ').collect();
+    println!("{:?}", parts);
+}
+"#,
+        expected: "[\"a\", \"b\", \"\", \"c\"]\n",
+    },
+];
+
+pub fn find(name: &str) -> Option<&'static Snippet> {
+    SNIPPETS.iter().find(|snippet| snippet.name == name)
+}
+
+#[derive(Debug)]
+pub enum SnippetError {
+    Io(io::Error),
+    Compile {
+        name: String,
+        stderr: String,
+    },
+    Run {
+        name: String,
+        error: ProcError,
+    },
+    // The snippet ran but its stored answer is out of date
+    Mismatch {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for SnippetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnippetError::Io(e) => write!(f, "{}", e),
+            SnippetError::Compile { name, stderr } => {
+                write!(f, "{} doesn't compile:\n{}", name, stderr.trim_end())
+            }
+            SnippetError::Run { name, error } => write!(f, "{} failed: {}", name, error),
+            SnippetError::Mismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} is expected to print {:?} but prints {:?}",
+                name, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnippetError {}
+
+impl From<io::Error> for SnippetError {
+    fn from(e: io::Error) -> SnippetError {
+        SnippetError::Io(e)
+    }
+}
+
+/*
+ * Compiles snippets in its own temp dir, removed with it. Uses $RUSTC
+ * when set, like cargo does, or the rustc on the PATH.
+ * */
+/// ```no_run
+/// use rust_exercises::extras::quiz::snippets::{self, Runner};
+///
+/// let runner = Runner::new().unwrap();
+/// for snippet in snippets::SNIPPETS {
+///     runner.verify(snippet).unwrap();
+/// }
+/// ```
+pub struct Runner {
+    dir: TempDir,
+    rustc: String,
+}
+
+impl Runner {
+    pub fn new() -> io::Result<Runner> {
+        Ok(Runner {
+            dir: TempDir::new("quiz")?,
+            rustc: env::var("RUSTC").unwrap_or_else(|_| String::from("rustc")),
+        })
+    }
+
+    // What the snippet prints today, whatever its expected output says
+    pub fn run(&self, snippet: &Snippet) -> Result<String, SnippetError> {
+        let source = self.dir.join(format!("{}.rs", snippet.name));
+        let binary = self.dir.join(snippet.name);
+        std::fs::write(&source, snippet.code)?;
+
+        let failed = |error| SnippetError::Run {
+            name: snippet.name.to_string(),
+            error,
+        };
+
+        let mut rustc = Command::new(&self.rustc);
+        rustc
+            .args(["--edition", "2021", "-o"])
+            .arg(&binary)
+            .arg(&source);
+        let compiled = proc::run_with_timeout(&mut rustc, COMPILE_TIMEOUT).map_err(failed)?;
+        if !compiled.success() {
+            return Err(SnippetError::Compile {
+                name: snippet.name.to_string(),
+                stderr: compiled.stderr,
+            });
+        }
+
+        let ran =
+            proc::run_with_timeout(&mut Command::new(&binary), RUN_TIMEOUT).map_err(failed)?;
+        if !ran.success() {
+            return Err(failed(ProcError::Failed {
+                status: ran.status,
+                stderr: ran.stderr,
+            }));
+        }
+        Ok(ran.stdout)
+    }
+
+    // Runs the snippet and checks it still prints its expected output
+    pub fn verify(&self, snippet: &Snippet) -> Result<String, SnippetError> {
+        let actual = self.run(snippet)?;
+        if actual != snippet.expected {
+            return Err(SnippetError::Mismatch {
+                name: snippet.name.to_string(),
+                expected: snippet.expected.to_string(),
+                actual,
+            });
+        }
+        Ok(actual)
+    }
+}
@@ -0,0 +1,113 @@
+/*
+ * Guards whose Drop does the cleanup, so it happens on every way out of
+ * the scope: the end of the block, an early return, a ? and a panic
+ * unwinding through it,
+ * https://doc.rust-lang.org/rust-by-example/scope/raii.html
+ *
+ *   FileLockGuard  a lock file that exists exactly as long as the guard
+ *   ScopedTimer    hands how long its scope took to a callback
+ *
+ * A panic with panic = "abort", or std::process::exit, runs no
+ * destructors at all, the lock file stays behind then.
+ * */
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+/*
+ * Creating the file with create_new is the lock: the OS makes it fail
+ * for everyone but the first, across processes too. The file holds the
+ * owner's pid, for whoever finds a stale one.
+ * */
+/// ```
+/// use rust_exercises::extras::raii::FileLockGuard;
+/// use rust_exercises::extras::temp_dir::TempDir;
+/// use std::io::ErrorKind;
+///
+/// let dir = TempDir::new("lock_doc").unwrap();
+/// let path = dir.join("app.lock");
+/// {
+///     let _guard = FileLockGuard::acquire(&path).unwrap();
+///     let taken = FileLockGuard::acquire(&path).unwrap_err();
+///     assert_eq!(taken.kind(), ErrorKind::AlreadyExists);
+/// }
+/// assert!(!path.exists());
+/// ```
+#[derive(Debug)]
+pub struct FileLockGuard {
+    path: PathBuf,
+}
+
+impl FileLockGuard {
+    // An AlreadyExists error means someone else holds it
+    pub fn acquire<P: AsRef<Path>>(path: P) -> io::Result<FileLockGuard> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        let guard = FileLockGuard { path };
+        // From here on a failure still removes the file, through the guard
+        writeln!(file, "{}", process::id())?;
+        Ok(guard)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for FileLockGuard {
+    fn drop(&mut self) {
+        // Nowhere to report an error from a destructor, and panicking in
+        // one during a panic aborts the process
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/*
+ * Calls record(label, elapsed) when dropped. The callback decides where
+ * timings go: a Vec behind a Mutex, a log line, a histogram.
+ * */
+/// ```
+/// use rust_exercises::extras::raii::ScopedTimer;
+/// use std::cell::RefCell;
+///
+/// let timings = RefCell::new(Vec::new());
+/// {
+///     let _timer = ScopedTimer::new("load", |label, elapsed| {
+///         timings.borrow_mut().push((label.to_string(), elapsed))
+///     });
+/// }
+/// assert_eq!(timings.borrow()[0].0, "load");
+/// ```
+pub struct ScopedTimer<F: FnOnce(&str, Duration)> {
+    label: String,
+    start: Instant,
+    // Taken out in drop(), FnOnce can only be called by value
+    record: Option<F>,
+}
+
+impl<F: FnOnce(&str, Duration)> ScopedTimer<F> {
+    pub fn new<S: Into<String>>(label: S, record: F) -> ScopedTimer<F> {
+        ScopedTimer {
+            label: label.into(),
+            start: Instant::now(),
+            record: Some(record),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl<F: FnOnce(&str, Duration)> Drop for ScopedTimer<F> {
+    fn drop(&mut self) {
+        if let Some(record) = self.record.take() {
+            record(&self.label, self.start.elapsed());
+        }
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Calls an operation until it succeeds or the attempts run out, waiting
+ * longer and longer in between:
+ *
+ *   let policy = RetryPolicy::exponential(Duration::from_millis(100))
+ *       .max_attempts(5)
+ *       .max_delay(Duration::from_secs(2));
+ *   let page = retry(policy, || fetch(url))?;
+ *
+ *   fixed        the same delay every time
+ *   linear       initial, initial + step, initial + 2 * step...
+ *   exponential  initial, then doubled each time
+ *
+ * retry() sleeps with thread::sleep. retry_with_sleep() takes the sleep
+ * as a closure, for tests that shouldn't wait and want to see the
+ * delays.
+ * */
+use std::error::Error;
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    Fixed(Duration),
+    Linear { initial: Duration, step: Duration },
+    Exponential { initial: Duration },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    backoff: Backoff,
+    max_attempts: u32,
+    max_delay: Option<Duration>,
+}
+
+impl RetryPolicy {
+    // Three attempts unless max_attempts says otherwise
+    pub fn new(backoff: Backoff) -> RetryPolicy {
+        RetryPolicy {
+            backoff,
+            max_attempts: 3,
+            max_delay: None,
+        }
+    }
+
+    pub fn fixed(delay: Duration) -> RetryPolicy {
+        RetryPolicy::new(Backoff::Fixed(delay))
+    }
+
+    pub fn linear(initial: Duration, step: Duration) -> RetryPolicy {
+        RetryPolicy::new(Backoff::Linear { initial, step })
+    }
+
+    pub fn exponential(initial: Duration) -> RetryPolicy {
+        RetryPolicy::new(Backoff::Exponential { initial })
+    }
+
+    // Counting the first call. 0 still makes that first call.
+    pub fn max_attempts(mut self, attempts: u32) -> RetryPolicy {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    // No single wait is longer than this
+    pub fn max_delay(mut self, delay: Duration) -> RetryPolicy {
+        self.max_delay = Some(delay);
+        self
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /*
+     * How long to wait after the n-th failed attempt, n from 1.
+     * Arithmetic saturates, a huge exponent gives Duration::MAX and then
+     * max_delay, not an overflow panic.
+     * */
+    pub fn delay(&self, failed: u32) -> Duration {
+        let steps = failed.saturating_sub(1);
+        let delay = match self.backoff {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Linear { initial, step } => {
+                initial.saturating_add(step.checked_mul(steps).unwrap_or(Duration::MAX))
+            }
+            Backoff::Exponential { initial } => 2u32
+                .checked_pow(steps)
+                .and_then(|factor| initial.checked_mul(factor))
+                .unwrap_or(Duration::MAX),
+        };
+        match self.max_delay {
+            Some(max) => delay.min(max),
+            None => delay,
+        }
+    }
+}
+
+/*
+ * Every attempt failed. The last error is kept, and is the source().
+ * */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryError<E> {
+    pub attempts: u32,
+    pub last: E,
+}
+
+impl<E: fmt::Display> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "gave up after {} attempts", self.attempts)
+    }
+}
+
+impl<E: Error + 'static> Error for RetryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.last)
+    }
+}
+
+pub fn retry<T, E, F>(policy: RetryPolicy, op: F) -> Result<T, RetryError<E>>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    retry_with_sleep(policy, op, thread::sleep)
+}
+
+/// ```
+/// use rust_exercises::extras::retry::{retry_with_sleep, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::exponential(Duration::from_millis(100))
+///     .max_attempts(4)
+///     .max_delay(Duration::from_millis(300));
+/// let mut calls = 0;
+/// let mut waits = Vec::new();
+/// let result = retry_with_sleep(
+///     policy,
+///     || {
+///         calls += 1;
+///         if calls < 4 { Err("busy") } else { Ok(calls) }
+///     },
+///     |delay| waits.push(delay.as_millis()),
+/// );
+/// assert_eq!(result, Ok(4));
+/// assert_eq!(waits, [100, 200, 300]);
+///
+/// let error = retry_with_sleep(policy, || Err::<(), _>("down"), |_| {}).unwrap_err();
+/// assert_eq!((error.attempts, error.last), (4, "down"));
+/// ```
+pub fn retry_with_sleep<T, E, F, S>(
+    policy: RetryPolicy,
+    mut op: F,
+    mut sleep: S,
+) -> Result<T, RetryError<E>>
+where
+    F: FnMut() -> Result<T, E>,
+    S: FnMut(Duration),
+{
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(last) if attempt >= policy.max_attempts => {
+                return Err(RetryError {
+                    attempts: attempt,
+                    last,
+                })
+            }
+            // No sleep after the last attempt, nothing comes after it
+            Err(_) => sleep(policy.delay(attempt)),
+        }
+        attempt += 1;
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * Run-length encoding in the style of PackBits, every block starts with
+ * a control byte n:
+ *
+ *   n in 0..=127    the next n + 1 bytes are copied as is (a literal run)
+ *   n in 128..=255  the next byte is repeated n - 126 times (2 to 129)
+ *
+ * Literal runs keep data without repetitions from doubling in size,
+ * the worst case grows by one byte every 128.
+ *
+ * Both directions stream from a reader to a writer and never hold more
+ * than one block in memory. core_utils::rle does the same between
+ * slices, without std.
+ * */
+use crate::core_utils::rle::{MAX_LITERAL, MAX_REPEAT, MIN_REPEAT};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+struct Encoder<W: Write> {
+    writer: W,
+    literals: Vec<u8>,
+    // The byte being repeated and how many times so far
+    run: Option<(u8, usize)>,
+}
+
+impl<W: Write> Encoder<W> {
+    fn flush_literals(&mut self) -> io::Result<()> {
+        if !self.literals.is_empty() {
+            self.writer.write_all(&[(self.literals.len() - 1) as u8])?;
+            self.writer.write_all(&self.literals)?;
+            self.literals.clear();
+        }
+        Ok(())
+    }
+
+    // A run of a single byte is cheaper as part of the literals around it
+    fn flush_run(&mut self) -> io::Result<()> {
+        match self.run.take() {
+            Some((byte, count)) if count >= MIN_REPEAT => {
+                self.flush_literals()?;
+                self.writer.write_all(&[(count + 126) as u8, byte])
+            }
+            Some((byte, _)) => {
+                self.literals.push(byte);
+                if self.literals.len() == MAX_LITERAL {
+                    self.flush_literals()?;
+                }
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn push(&mut self, byte: u8) -> io::Result<()> {
+        if let Some((current, ref mut count)) = self.run {
+            if current == byte && *count < MAX_REPEAT {
+                *count += 1;
+                return Ok(());
+            }
+        }
+        self.flush_run()?;
+        self.run = Some((byte, 1));
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        self.flush_run()?;
+        self.flush_literals()?;
+        self.writer.flush()
+    }
+}
+
+pub fn compress<R: Read, W: Write>(reader: R, writer: W) -> io::Result<()> {
+    let mut encoder = Encoder {
+        writer: BufWriter::new(writer),
+        literals: Vec::with_capacity(MAX_LITERAL),
+        run: None,
+    };
+
+    for byte in BufReader::new(reader).bytes() {
+        encoder.push(byte?)?;
+    }
+
+    encoder.finish()
+}
+
+/*
+ * Input cut in the middle of a block fails with ErrorKind::UnexpectedEof
+ * */
+pub fn decompress<R: Read, W: Write>(reader: R, writer: W) -> io::Result<()> {
+    let mut reader = BufReader::new(reader);
+    let mut writer = BufWriter::new(writer);
+    let mut control = [0];
+    let mut block = [0; MAX_REPEAT];
+
+    loop {
+        if reader.read(&mut control)? == 0 {
+            break;
+        }
+
+        let n = control[0] as usize;
+        if n < MAX_LITERAL {
+            reader.read_exact(&mut block[..n + 1])?;
+            writer.write_all(&block[..n + 1])?;
+        } else {
+            let mut byte = [0];
+            reader.read_exact(&mut byte)?;
+            let count = n - 126;
+            for slot in block[..count].iter_mut() {
+                *slot = byte[0];
+            }
+            writer.write_all(&block[..count])?;
+        }
+    }
+
+    writer.flush()
+}
+
+// In-memory shortcuts, writing to a Vec can't fail
+/// ```
+/// use rust_exercises::extras::rle::{compress_bytes, decompress_bytes};
+///
+/// let packed = compress_bytes(b"aaaaaabc");
+/// assert_eq!(packed, [132, b'a', 1, b'b', b'c']);
+/// assert_eq!(decompress_bytes(&packed).unwrap(), b"aaaaaabc");
+/// ```
+pub fn compress_bytes(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    compress(input, &mut output).unwrap();
+    output
+}
+
+pub fn decompress_bytes(input: &[u8]) -> io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    decompress(input, &mut output)?;
+    Ok(output)
+}
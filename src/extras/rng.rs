@@ -0,0 +1,111 @@
+/*
+ * Seedable pseudo-random numbers without the rand crate, for exercises
+ * that need reproducible randomness (games, shuffles, property checks).
+ * Not suitable for anything related to security.
+ * */
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/*
+ * Only next_u64 has to be implemented, the rest is built on it
+ * */
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+
+    /*
+     * Uniform in range, panics when it's empty. A plain % would favor
+     * small values, so draws falling in the last incomplete
+     * multiple of the range's length are thrown away and retried.
+     * */
+    fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty range");
+        let span = range.end - range.start;
+        let zone = u64::MAX - (u64::MAX % span);
+
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return range.start + value % span;
+            }
+        }
+    }
+
+    // Uniform in [0, 1), from the top 53 bits which is all an f64 can hold
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn gen_bool(&mut self, probability: f64) -> bool {
+        self.gen_f64() < probability
+    }
+
+    /*
+     * Fisher-Yates, every permutation is equally likely
+     * */
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_range(0..i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/*
+ * Marsaglia's xorshift, https://www.jstatsoft.org/article/view/v008i14
+ * The same seed always gives the same sequence.
+ * */
+/// ```
+/// use rust_exercises::extras::rng::{Rng, XorShift64};
+///
+/// let mut a = XorShift64::new(42);
+/// let mut b = XorShift64::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+///
+/// let roll = a.gen_range(1..7);
+/// assert!((1..7).contains(&roll));
+/// let mut deck: Vec<u32> = (0..10).collect();
+/// a.shuffle(&mut deck);
+/// deck.sort();
+/// assert_eq!(deck, (0..10).collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    // A zero state would only ever produce zeros, so 0 is replaced
+    pub fn new(seed: u64) -> XorShift64 {
+        XorShift64 {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+
+    // Different every run, for when reproducibility doesn't matter
+    pub fn from_time() -> XorShift64 {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or(0);
+        XorShift64::new(nanos)
+    }
+}
+
+impl Rng for XorShift64 {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+impl Iterator for XorShift64 {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        Some(self.next_u64())
+    }
+}
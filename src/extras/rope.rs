@@ -0,0 +1,124 @@
+/*
+ * Builds a long string out of many pieces without copying the ones
+ * already added each time another comes, which is what
+ * `text = format!("{}{}", text, piece)` in a loop does.
+ *
+ * A real rope is a tree of pieces and can also insert in the middle,
+ * https://en.wikipedia.org/wiki/Rope_(data_structure)
+ * This one only appends, so a Vec of pieces is enough: append is a
+ * push, and the running char count of every piece's end turns finding
+ * the n-th char into a binary search. The pieces are joined into one
+ * String only when someone asks for it, and only once.
+ *
+ *   cargo bench --bench rope
+ * */
+use std::fmt;
+use std::iter::FromIterator;
+
+/// ```
+/// use rust_exercises::extras::rope::Rope;
+///
+/// let mut rope = Rope::new();
+/// rope.append("héllo");
+/// rope.append(String::from(", "));
+/// rope.extend(["wor", "ld"]);
+/// assert_eq!((rope.len(), rope.char_len(), rope.pieces()), (13, 12, 4));
+/// assert_eq!(rope.char_at(1), Some('é'));
+/// assert_eq!(rope.char_at(12), None);
+///
+/// assert_eq!(rope.flatten(), "héllo, world");
+/// assert_eq!(rope.pieces(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Rope {
+    pieces: Vec<String>,
+    // Chars up to the end of each piece, ends[i] counts pieces[..=i]
+    ends: Vec<usize>,
+    bytes: usize,
+}
+
+impl Rope {
+    pub fn new() -> Rope {
+        Rope::default()
+    }
+
+    pub fn append<S: Into<String>>(&mut self, piece: S) {
+        let piece = piece.into();
+        if piece.is_empty() {
+            return;
+        }
+        self.bytes += piece.len();
+        self.ends.push(self.char_len() + piece.chars().count());
+        self.pieces.push(piece);
+    }
+
+    // In bytes, like String::len
+    pub fn len(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes == 0
+    }
+
+    pub fn char_len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    pub fn pieces(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /*
+     * The char at a char offset. The first piece ending past the offset
+     * holds it, then it's a walk within that piece only.
+     * */
+    pub fn char_at(&self, offset: usize) -> Option<char> {
+        let piece = self.ends.partition_point(|&end| end <= offset);
+        let start = if piece == 0 { 0 } else { self.ends[piece - 1] };
+        self.pieces.get(piece)?.chars().nth(offset - start)
+    }
+
+    /*
+     * The whole text as one &str, joining the pieces the first time and
+     * keeping the result as the only piece, so asking again is free
+     * */
+    pub fn flatten(&mut self) -> &str {
+        if self.pieces.len() > 1 {
+            let joined = self.pieces.concat();
+            self.pieces = vec![joined];
+            self.ends = vec![self.char_len()];
+        }
+        self.pieces.first().map_or("", String::as_str)
+    }
+
+    pub fn into_string(mut self) -> String {
+        self.flatten();
+        self.pieces.pop().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for piece in self.pieces.iter() {
+            f.write_str(piece)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: Into<String>> Extend<S> for Rope {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, pieces: I) {
+        for piece in pieces {
+            self.append(piece);
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Rope {
+    fn from_iter<I: IntoIterator<Item = S>>(pieces: I) -> Rope {
+        let mut rope = Rope::new();
+        rope.extend(pieces);
+        rope
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ * Jobs with a priority and an optional time to run at, handed to a
+ * ThreadPool once they're due, the most important first:
+ *
+ *   let mut scheduler = Scheduler::new(ThreadPool::new(4));
+ *   scheduler.submit(Job::new(|| backup()).priority(1));
+ *   let report = scheduler.submit(
+ *       Job::new(|| send_report()).run_at(Instant::now() + Duration::from_secs(60)),
+ *   );
+ *   loop {
+ *       scheduler.tick();
+ *       match scheduler.next_run_at() {
+ *           Some(at) => thread::sleep(at.saturating_duration_since(Instant::now())),
+ *           None => break,
+ *       }
+ *   }
+ *   scheduler.shutdown();
+ *
+ * Nothing runs by itself: tick() is what looks at the clock and sends
+ * the due jobs to the pool. Waiting jobs sit in two of core_utils' heaps,
+ * the delayed ones by the earliest run_at, the due ones by the highest
+ * priority, and equal priorities by submission order.
+ *
+ * The clock is a parameter so tests can use a ManualClock and move time
+ * forward themselves instead of sleeping.
+ * */
+use crate::core_utils::heap::BinaryHeap;
+use crate::extras::thread_pool::ThreadPool;
+use std::cmp::{Ordering, Reverse};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/*
+ * Stands still until advance() is called. Clones share the same time,
+ * keep one and give the other to the scheduler.
+ * */
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<Instant>>,
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> ManualClock {
+        ManualClock::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JobId(u64);
+
+// Higher runs first, 0 unless priority() says otherwise
+pub struct Job {
+    run: Box<dyn FnOnce() + Send>,
+    priority: u8,
+    run_at: Option<Instant>,
+}
+
+impl Job {
+    pub fn new<F: FnOnce() + Send + 'static>(run: F) -> Job {
+        Job {
+            run: Box::new(run),
+            priority: 0,
+            run_at: None,
+        }
+    }
+
+    pub fn priority(mut self, priority: u8) -> Job {
+        self.priority = priority;
+        self
+    }
+
+    // Not before this. Without it the job is due as soon as it's submitted.
+    pub fn run_at(mut self, at: Instant) -> Job {
+        self.run_at = Some(at);
+        self
+    }
+}
+
+/*
+ * A job in one of the heaps. The heaps only compare keys: (priority,
+ * earlier id) for the due ones, (earlier run_at, earlier id) for the
+ * delayed ones, both turned around with Reverse where smaller has to
+ * come out first.
+ * */
+struct Queued<K> {
+    key: K,
+    id: JobId,
+    job: Job,
+}
+
+impl<K: Ord> PartialEq for Queued<K> {
+    fn eq(&self, other: &Queued<K>) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord> Eq for Queued<K> {}
+
+impl<K: Ord> PartialOrd for Queued<K> {
+    fn partial_cmp(&self, other: &Queued<K>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord> Ord for Queued<K> {
+    fn cmp(&self, other: &Queued<K>) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+type DueKey = (u8, Reverse<u64>);
+type DelayedKey = Reverse<(Instant, u64)>;
+
+/// ```
+/// use rust_exercises::extras::scheduler::{Job, ManualClock, Scheduler};
+/// use rust_exercises::extras::thread_pool::ThreadPool;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::{Duration, Instant};
+///
+/// let ran = Arc::new(Mutex::new(Vec::new()));
+/// let job = |name: &'static str| {
+///     let ran = ran.clone();
+///     Job::new(move || ran.lock().unwrap().push(name))
+/// };
+///
+/// let clock = ManualClock::new();
+/// let mut scheduler = Scheduler::with_clock(ThreadPool::new(1), clock.clone());
+/// scheduler.submit(job("low").priority(1));
+/// scheduler.submit(job("high").priority(9));
+/// scheduler.submit(job("later").run_at(Instant::now() + Duration::from_secs(60)));
+///
+/// assert_eq!(scheduler.tick(), 2);
+/// assert_eq!(scheduler.len(), 1);
+/// clock.advance(Duration::from_secs(61));
+/// assert_eq!(scheduler.tick(), 1);
+/// scheduler.shutdown();
+/// assert_eq!(*ran.lock().unwrap(), ["high", "low", "later"]);
+/// ```
+pub struct Scheduler<C: Clock = SystemClock> {
+    pool: ThreadPool,
+    clock: C,
+    due: BinaryHeap<Queued<DueKey>>,
+    delayed: BinaryHeap<Queued<DelayedKey>>,
+    next_id: u64,
+}
+
+impl Scheduler<SystemClock> {
+    pub fn new(pool: ThreadPool) -> Scheduler<SystemClock> {
+        Scheduler::with_clock(pool, SystemClock)
+    }
+}
+
+impl<C: Clock> Scheduler<C> {
+    pub fn with_clock(pool: ThreadPool, clock: C) -> Scheduler<C> {
+        Scheduler {
+            pool,
+            clock,
+            due: BinaryHeap::new(),
+            delayed: BinaryHeap::new(),
+            next_id: 0,
+        }
+    }
+
+    // Waits for the next tick(), even if it's already due
+    pub fn submit(&mut self, job: Job) -> JobId {
+        let id = JobId(self.next_id);
+        self.next_id += 1;
+        match job.run_at {
+            Some(at) => self.delayed.push(Queued {
+                key: Reverse((at, id.0)),
+                id,
+                job,
+            }),
+            None => self.push_due(id, job),
+        }
+        id
+    }
+
+    fn push_due(&mut self, id: JobId, job: Job) {
+        self.due.push(Queued {
+            key: (job.priority, Reverse(id.0)),
+            id,
+            job,
+        });
+    }
+
+    /*
+     * false if the job isn't waiting anymore: it was already handed to
+     * the pool, cancelled before, or never submitted here
+     * */
+    pub fn cancel(&mut self, id: JobId) -> bool {
+        let waiting = self.len();
+        self.due.retain(|queued| queued.id != id);
+        self.delayed.retain(|queued| queued.id != id);
+        self.len() < waiting
+    }
+
+    // Jobs waiting, due or not
+    pub fn len(&self) -> usize {
+        self.due.len() + self.delayed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // When the earliest delayed job is due, the ones already due don't count
+    pub fn next_run_at(&self) -> Option<Instant> {
+        self.delayed.peek().map(|queued| (queued.key.0).0)
+    }
+
+    /*
+     * Hands every job that's due now to the pool, highest priority first,
+     * and returns how many. On a pool of one thread they also run in that
+     * order.
+     * */
+    pub fn tick(&mut self) -> usize {
+        let now = self.clock.now();
+        while self
+            .delayed
+            .peek()
+            .is_some_and(|queued| (queued.key.0).0 <= now)
+        {
+            let queued = self.delayed.pop().unwrap();
+            self.push_due(queued.id, queued.job);
+        }
+        self.dispatch()
+    }
+
+    fn dispatch(&mut self) -> usize {
+        let mut dispatched = 0;
+        while let Some(queued) = self.due.pop() {
+            self.pool.execute(queued.job.run);
+            dispatched += 1;
+        }
+        dispatched
+    }
+
+    /*
+     * Graceful stop: every job still waiting runs, the delayed ones
+     * without waiting for their time, by priority like on a tick. Returns
+     * how many that was once the pool has finished all of them, along with
+     * whatever earlier ticks had sent it.
+     *
+     * Dropping the scheduler instead throws the waiting jobs away, and
+     * still waits for the pool.
+     * */
+    pub fn shutdown(mut self) -> usize {
+        while let Some(queued) = self.delayed.pop() {
+            self.push_due(queued.id, queued.job);
+        }
+        // The pool is dropped with self, which joins its workers
+        self.dispatch()
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Clean shutdown for long running binaries. A ShutdownFlag is shared by
+ * everything that needs to stop, install_ctrl_c() makes Ctrl-C (SIGINT)
+ * and SIGTERM set it instead of killing the process on the spot.
+ *
+ * A signal handler may only do async-signal-safe things, storing to an
+ * atomic is one of them while locking a Mutex or notifying a Condvar is
+ * not. So the flag is a bare AtomicBool and waiting on it polls.
+ *
+ * A second Ctrl-C while shutting down exits right away, for when the
+ * clean shutdown hangs.
+ *
+ * Out of scope: the chapter 20 web server isn't in this tree, so nothing
+ * wires it to the flag. The chat server does, and net::echo::serve stops
+ * on as_atomic().
+ * */
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+// Clones share the same flag
+/// ```
+/// use rust_exercises::extras::shutdown::ShutdownFlag;
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// let flag = ShutdownFlag::new();
+/// assert!(!flag.wait_timeout(Duration::from_millis(10)));
+///
+/// let worker = {
+///     let flag = flag.clone();
+///     thread::spawn(move || flag.wait_timeout(Duration::from_secs(5)))
+/// };
+/// flag.trigger();
+/// assert!(worker.join().unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownFlag {
+    triggered: Arc<AtomicBool>,
+}
+
+impl ShutdownFlag {
+    pub fn new() -> ShutdownFlag {
+        ShutdownFlag::default()
+    }
+
+    // What the signal handler does, and what tests call to simulate one
+    pub fn trigger(&self) {
+        self.triggered.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.triggered.load(Ordering::SeqCst)
+    }
+
+    // True if triggered before the timeout ran out
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let start = Instant::now();
+        while !self.is_triggered() {
+            let left = timeout.saturating_sub(start.elapsed());
+            if left.is_zero() {
+                return false;
+            }
+            thread::sleep(left.min(POLL_INTERVAL));
+        }
+        true
+    }
+
+    // For code taking a plain Arc<AtomicBool>, like net::echo::serve
+    pub fn as_atomic(&self) -> Arc<AtomicBool> {
+        self.triggered.clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallError {
+    // install_ctrl_c() was called before, only one flag can get signals
+    AlreadyInstalled,
+    // signal() refused the handler
+    Failed,
+    // No signal support here, the flag can still be triggered by hand
+    Unsupported,
+}
+
+impl fmt::Display for InstallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InstallError::AlreadyInstalled => write!(f, "a Ctrl-C handler is already installed"),
+            InstallError::Failed => write!(f, "couldn't install the signal handler"),
+            InstallError::Unsupported => write!(f, "signals aren't supported on this platform"),
+        }
+    }
+}
+
+impl std::error::Error for InstallError {}
+
+#[cfg(unix)]
+mod unix {
+    use std::os::raw::c_int;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, OnceLock};
+
+    pub static SIGNAL_FLAG: OnceLock<Arc<AtomicBool>> = OnceLock::new();
+
+    pub const SIGINT: c_int = 2;
+    pub const SIGTERM: c_int = 15;
+    // SIG_ERR is (void (*)(int)) -1
+    pub const SIG_ERR: usize = usize::MAX;
+
+    extern "C" {
+        pub fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+        fn _exit(status: c_int) -> !;
+    }
+
+    pub extern "C" fn handler(signum: c_int) {
+        if let Some(flag) = SIGNAL_FLAG.get() {
+            if flag.swap(true, Ordering::SeqCst) {
+                // SAFETY: _exit is async-signal-safe, unlike exit
+                unsafe { _exit(128 + signum) }
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+pub fn install_ctrl_c(flag: &ShutdownFlag) -> Result<(), InstallError> {
+    unix::SIGNAL_FLAG
+        .set(flag.as_atomic())
+        .map_err(|_| InstallError::AlreadyInstalled)?;
+
+    for &signum in &[unix::SIGINT, unix::SIGTERM] {
+        // SAFETY: the handler only touches an atomic that lives forever
+        let previous = unsafe { unix::signal(signum, unix::handler) };
+        if previous == unix::SIG_ERR {
+            return Err(InstallError::Failed);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn install_ctrl_c(_flag: &ShutdownFlag) -> Result<(), InstallError> {
+    Err(InstallError::Unsupported)
+}
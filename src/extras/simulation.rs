@@ -0,0 +1,220 @@
+/*
+ * Conway's Game of Life, stepped either on one thread or on N threads
+ * that each own a band of rows and meet at a std::sync::Barrier after
+ * every generation,
+ * https://doc.rust-lang.org/std/sync/struct.Barrier.html
+ *
+ * Cells outside the grid count as dead, nothing wraps around.
+ * */
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Barrier;
+use std::thread;
+
+/// ```
+/// use rust_exercises::extras::simulation::{run_parallel, run_sequential, Grid};
+///
+/// // A blinker turns from a row into a column and back
+/// let blinker: Grid = ".....\n.###.\n.....".parse().unwrap();
+/// let next = run_sequential(&blinker, 1);
+/// assert_eq!(next.to_string(), "..#..\n..#..\n..#..\n");
+/// assert_eq!(run_parallel(&blinker, 2, 3), blinker);
+/// assert_eq!(next.alive(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    RaggedRow(usize),
+    InvalidCell(char),
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GridError::Empty => write!(f, "the grid has no cells"),
+            GridError::RaggedRow(row) => write!(f, "row {} has a different width", row),
+            GridError::InvalidCell(c) => write!(f, "invalid cell {:?}, expected '.' or '#'", c),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Grid {
+        Grid {
+            width,
+            height,
+            cells: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) {
+        self.cells[y * self.width + x] = alive;
+    }
+
+    pub fn alive(&self) -> usize {
+        self.cells.iter().filter(|&&alive| alive).count()
+    }
+}
+
+// Rows of '.' (dead) and '#' (alive)
+impl FromStr for Grid {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Grid, GridError> {
+        let rows: Vec<&str> = s.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(GridError::RaggedRow(y));
+            }
+            for c in row.chars() {
+                match c {
+                    '.' => cells.push(false),
+                    '#' => cells.push(true),
+                    _ => return Err(GridError::InvalidCell(c)),
+                }
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width) {
+            let line: String = row
+                .iter()
+                .map(|&alive| if alive { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+// Next state of cell (x, y) given a way to read the current generation
+fn next_cell(
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+    alive: impl Fn(usize) -> bool,
+) -> bool {
+    let mut neighbours = 0;
+    for ny in y.saturating_sub(1)..=(y + 1).min(height - 1) {
+        for nx in x.saturating_sub(1)..=(x + 1).min(width - 1) {
+            if (nx, ny) != (x, y) && alive(ny * width + nx) {
+                neighbours += 1;
+            }
+        }
+    }
+
+    matches!((alive(y * width + x), neighbours), (true, 2) | (_, 3))
+}
+
+// The reference implementation
+pub fn step(grid: &Grid) -> Grid {
+    let mut next = Grid::new(grid.width, grid.height);
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let alive = next_cell(grid.width, grid.height, x, y, |i| grid.cells[i]);
+            next.set(x, y, alive);
+        }
+    }
+    next
+}
+
+pub fn run_sequential(grid: &Grid, generations: usize) -> Grid {
+    let mut grid = grid.clone();
+    for _ in 0..generations {
+        grid = step(&grid);
+    }
+    grid
+}
+
+/*
+ * Two buffers of atomics, generation g reads buffers[g % 2] and writes
+ * buffers[(g + 1) % 2]. Only the owner of a row writes it, and the
+ * barrier orders every write of a generation before any read of the
+ * next one, so Relaxed loads and stores are enough.
+ * */
+pub fn run_parallel(grid: &Grid, generations: usize, threads: usize) -> Grid {
+    assert!(threads > 0, "need at least one thread");
+    let (width, height) = (grid.width, grid.height);
+
+    let buffers: [Vec<AtomicBool>; 2] = [
+        grid.cells
+            .iter()
+            .map(|&alive| AtomicBool::new(alive))
+            .collect(),
+        grid.cells.iter().map(|_| AtomicBool::new(false)).collect(),
+    ];
+    let band = height.div_ceil(threads).max(1);
+    let bands = height.div_ceil(band);
+    let barrier = Barrier::new(bands);
+
+    thread::scope(|scope| {
+        for first_row in (0..height).step_by(band) {
+            let (buffers, barrier) = (&buffers, &barrier);
+            let rows = first_row..(first_row + band).min(height);
+
+            scope.spawn(move || {
+                for generation in 0..generations {
+                    let current = &buffers[generation % 2];
+                    let next = &buffers[(generation + 1) % 2];
+                    let alive = |i: usize| current[i].load(Ordering::Relaxed);
+
+                    for y in rows.clone() {
+                        for x in 0..width {
+                            let cell = next_cell(width, height, x, y, alive);
+                            next[y * width + x].store(cell, Ordering::Relaxed);
+                        }
+                    }
+                    barrier.wait();
+                }
+            });
+        }
+    });
+
+    let result = &buffers[generations % 2];
+    Grid {
+        width,
+        height,
+        cells: result
+            .iter()
+            .map(|cell| cell.load(Ordering::Relaxed))
+            .collect(),
+    }
+}
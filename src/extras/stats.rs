@@ -0,0 +1,198 @@
+/*
+ * Descriptive statistics over a slice of numbers,
+ * https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+ *
+ * Mean and variance come from Welford's algorithm: one pass, and no
+ * sum of squares that loses every digit when the values are large and
+ * close together. Percentiles need the values in order, so they sort a
+ * copy and interpolate between the two closest ranks, like numpy's
+ * default.
+ *
+ * Any input that converts to f64 without loss works: f32, and the
+ * integers up to 32 bits. i64 and u64 don't implement Into<f64>, past
+ * 2^53 they would be rounded.
+ *
+ * Everything returns None for an empty slice, there is no mean of
+ * nothing.
+ * */
+use std::fmt;
+
+/*
+ * Running mean and variance, values can be pushed one at a time
+ * without keeping them
+ * */
+/// ```
+/// use rust_exercises::extras::stats::Welford;
+///
+/// let mut running = Welford::new();
+/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     running.push(value);
+/// }
+/// assert_eq!(running.count(), 8);
+/// assert_eq!(running.mean(), Some(5.0));
+/// assert_eq!(running.std_dev(), Some(2.0));
+/// assert_eq!(Welford::new().mean(), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Welford {
+    count: usize,
+    mean: f64,
+    // Sum of squared distances from the current mean
+    m2: f64,
+}
+
+impl Welford {
+    pub fn new() -> Welford {
+        Welford::default()
+    }
+
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.mean)
+    }
+
+    // Population variance, dividing by n
+    pub fn variance(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.m2 / self.count as f64)
+    }
+
+    // Sample variance, dividing by n - 1, needs two values
+    pub fn sample_variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.m2 / (self.count - 1) as f64)
+    }
+
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+}
+
+fn welford<T: Into<f64> + Copy>(data: &[T]) -> Welford {
+    let mut running = Welford::new();
+    for &value in data {
+        running.push(value.into());
+    }
+    running
+}
+
+pub fn mean<T: Into<f64> + Copy>(data: &[T]) -> Option<f64> {
+    welford(data).mean()
+}
+
+pub fn variance<T: Into<f64> + Copy>(data: &[T]) -> Option<f64> {
+    welford(data).variance()
+}
+
+pub fn std_dev<T: Into<f64> + Copy>(data: &[T]) -> Option<f64> {
+    welford(data).std_dev()
+}
+
+fn sorted<T: Into<f64> + Copy>(data: &[T]) -> Vec<f64> {
+    let mut values: Vec<f64> = data.iter().map(|&value| value.into()).collect();
+    // total_cmp puts NaN after everything, sort_by(partial_cmp) would panic
+    values.sort_by(f64::total_cmp);
+    values
+}
+
+// p from 0 to 100 over values already sorted
+fn percentile_of_sorted(values: &[f64], p: f64) -> Option<f64> {
+    if values.is_empty() || !(0.0..=100.0).contains(&p) {
+        return None;
+    }
+    let rank = p / 100.0 * (values.len() - 1) as f64;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    let fraction = rank - below as f64;
+    Some(values[below] + (values[above] - values[below]) * fraction)
+}
+
+/*
+ * The value p percent of the data is below, p from 0 to 100. Between two
+ * values it's interpolated: the 50th percentile of [1, 2, 3, 4] is 2.5.
+ * */
+pub fn percentile<T: Into<f64> + Copy>(data: &[T], p: f64) -> Option<f64> {
+    percentile_of_sorted(&sorted(data), p)
+}
+
+pub fn median<T: Into<f64> + Copy>(data: &[T]) -> Option<f64> {
+    percentile(data, 50.0)
+}
+
+/// ```
+/// use rust_exercises::extras::stats::{percentile, Summary};
+///
+/// let data = [3u8, 1, 4, 1, 5, 9, 2, 6];
+/// let summary = Summary::of(&data).unwrap();
+/// assert_eq!(summary.count, 8);
+/// assert_eq!((summary.min, summary.median, summary.max), (1.0, 3.5, 9.0));
+/// assert_eq!(percentile(&[1, 2, 3, 4], 50.0), Some(2.5));
+/// assert_eq!(Summary::of::<f64>(&[]), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+impl Summary {
+    pub fn of<T: Into<f64> + Copy>(data: &[T]) -> Option<Summary> {
+        let running = welford(data);
+        let values = sorted(data);
+        Some(Summary {
+            count: running.count(),
+            mean: running.mean()?,
+            std_dev: running.std_dev()?,
+            min: *values.first()?,
+            median: percentile_of_sorted(&values, 50.0)?,
+            max: *values.last()?,
+        })
+    }
+}
+
+/*
+ * One line, "n=8 mean=5.00 sd=2.00 min=2.00 median=4.50 max=9.00". The
+ * precision applies to every number, "{:.1}" gives one decimal, two by
+ * default.
+ * */
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        write!(
+            f,
+            "n={} mean={:.*} sd={:.*} min={:.*} median={:.*} max={:.*}",
+            self.count,
+            precision,
+            self.mean,
+            precision,
+            self.std_dev,
+            precision,
+            self.min,
+            precision,
+            self.median,
+            precision,
+            self.max
+        )
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * str::split written from scratch, as in Crust of Rust's lifetimes
+ * episode, https://www.youtube.com/watch?v=rAl-9HwD858
+ *
+ * The pieces borrow from the haystack only, never from the delimiter,
+ * which is why the two get their own lifetimes. With a single lifetime
+ * until_char below couldn't compile: its delimiter lives in a local
+ * buffer, and the returned &str would be tied to that buffer too.
+ * */
+/// ```
+/// use rust_exercises::extras::str_split::{until_char, StrSplit};
+///
+/// let pieces: Vec<&str> = StrSplit::new("a, b,, c", ", ").collect();
+/// assert_eq!(pieces, ["a", "b,", "c"]);
+/// assert_eq!(until_char("hello world", 'o'), "hell");
+/// ```
+pub struct StrSplit<'haystack, 'delim> {
+    // None once the last piece was handed out
+    remainder: Option<&'haystack str>,
+    delimiter: &'delim str,
+}
+
+impl<'haystack, 'delim> StrSplit<'haystack, 'delim> {
+    // An empty delimiter panics. str::split accepts one and splits around
+    // every char, "ab" gives "", "a", "b", "", which this doesn't copy
+    pub fn new(haystack: &'haystack str, delimiter: &'delim str) -> StrSplit<'haystack, 'delim> {
+        assert!(!delimiter.is_empty(), "empty delimiter");
+        StrSplit {
+            remainder: Some(haystack),
+            delimiter,
+        }
+    }
+}
+
+/*
+ * Same pieces as str::split, empty ones included:
+ * "a,,b," split on "," gives "a", "", "b", ""
+ * */
+impl<'haystack, 'delim> Iterator for StrSplit<'haystack, 'delim> {
+    type Item = &'haystack str;
+
+    fn next(&mut self) -> Option<&'haystack str> {
+        // as_mut gives a &mut &'haystack str so the remainder can be moved forward
+        let remainder = self.remainder.as_mut()?;
+
+        match remainder.find(self.delimiter) {
+            Some(start) => {
+                let until_delimiter = &remainder[..start];
+                *remainder = &remainder[start + self.delimiter.len()..];
+                Some(until_delimiter)
+            }
+            None => self.remainder.take(),
+        }
+    }
+}
+
+/*
+ * Everything before the first c, or the whole string if there is none
+ * */
+pub fn until_char(s: &str, c: char) -> &str {
+    let mut buffer = [0; 4];
+    let delimiter = c.encode_utf8(&mut buffer);
+
+    StrSplit::new(s, delimiter)
+        .next()
+        .expect("StrSplit always yields at least one piece")
+}
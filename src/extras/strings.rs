@@ -0,0 +1,86 @@
+/*
+ * String exercises past chapter 8,
+ * https://doc.rust-lang.org/book/ch08-02-strings.html
+ *
+ * All of these work on chars, which are Unicode scalar values and not
+ * what a reader sees as one character (a grapheme). "é" can be written
+ * as the single char U+00E9 or as 'e' followed by the combining accent
+ * U+0301, and the two spellings don't compare equal. Handling graphemes
+ * properly needs the unicode-segmentation crate, see each function for
+ * how it behaves without it.
+ * */
+
+// Letters and digits only, lowercased, e.g. "Ça va?" gives "çava"
+fn normalized_chars(s: &str) -> Vec<char> {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/*
+ * Ignores case, spaces and punctuation:
+ * "A man, a plan, a canal: Panama!" is a palindrome.
+ *
+ * Combining marks aren't alphanumeric so they are dropped, a decomposed
+ * "é" counts as a plain 'e' while the precomposed one doesn't.
+ * */
+/// ```
+/// use rust_exercises::extras::strings::is_palindrome;
+///
+/// assert!(is_palindrome("A man, a plan, a canal: Panama!"));
+/// assert!(!is_palindrome("rust"));
+/// ```
+pub fn is_palindrome(s: &str) -> bool {
+    let chars = normalized_chars(s);
+    chars.iter().eq(chars.iter().rev())
+}
+
+/*
+ * Same letters the same number of times, ignoring case, spaces and
+ * punctuation: "Dormitory" and "dirty room!" are anagrams.
+ *
+ * Some lowercase forms are longer than one char ('İ' becomes 'i' and
+ * U+0307), which is why the chars are collected instead of mapped 1 to 1.
+ * */
+/// ```
+/// use rust_exercises::extras::strings::are_anagrams;
+///
+/// assert!(are_anagrams("Dormitory", "dirty room!"));
+/// assert!(!are_anagrams("abc", "abd"));
+/// ```
+pub fn are_anagrams(a: &str, b: &str) -> bool {
+    let mut a = normalized_chars(a);
+    let mut b = normalized_chars(b);
+    a.sort();
+    b.sort();
+    a == b
+}
+
+/*
+ * "hello big world" gives "world big hello". Runs of whitespace collapse
+ * to a single space and the words themselves are left untouched, so
+ * graphemes inside a word are never split.
+ * */
+/// ```
+/// use rust_exercises::extras::strings::reverse_words;
+///
+/// assert_eq!(reverse_words("  the quick\tfox "), "fox quick the");
+/// ```
+pub fn reverse_words(s: &str) -> String {
+    s.split_whitespace().rev().collect::<Vec<&str>>().join(" ")
+}
+
+/*
+ * Reverses char by char. For text with combining marks this moves the
+ * accent onto the wrong letter: "e\u{301}a" (éa) comes back as
+ * "a\u{301}e" (áe).
+ * */
+/// ```
+/// use rust_exercises::extras::strings::reverse_chars;
+///
+/// assert_eq!(reverse_chars("héllo"), "olléh");
+/// ```
+pub fn reverse_chars(s: &str) -> String {
+    s.chars().rev().collect()
+}
@@ -0,0 +1,57 @@
+/*
+ * Scratch directories for whatever needs one, from the file_io chapter's
+ * exercises to the quiz, the playground and the tests. Lives here rather
+ * than in a chapter so it's there whichever chapters are built.
+ * */
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/*
+ * Directory under env::temp_dir(), unique per process and per TempDir so
+ * threads and concurrent runs don't step on each other
+ * */
+/// ```
+/// use rust_exercises::extras::temp_dir::TempDir;
+/// use std::fs;
+///
+/// let path = {
+///     let dir = TempDir::new("doc").unwrap();
+///     fs::write(dir.join("notes.txt"), "hi").unwrap();
+///     assert!(dir.join("notes.txt").is_file());
+///     dir.path().to_path_buf()
+/// };
+/// assert!(!path.exists());
+/// ```
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(prefix: &str) -> io::Result<TempDir> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("{}-{}-{}", prefix, process::id(), n));
+
+        fs::create_dir_all(&path)?;
+        Ok(TempDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn join<P: AsRef<Path>>(&self, name: P) -> PathBuf {
+        self.path.join(name)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        // Nothing useful to do about a failure in drop
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * Colored terminal output with ANSI escape codes, no dependencies,
+ * https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+ *
+ *   println!("{} {}", style("ok").green().bold(), name);
+ *
+ * style() wraps anything that implements Display and every method
+ * returns the wrapper again, so styles chain like a builder. Its own
+ * Display implementation adds the escape codes around the value's.
+ *
+ * Colors are left out when stdout isn't a terminal (a pipe or a file
+ * would end up full of escape codes) or when NO_COLOR is set,
+ * https://no-color.org
+ * */
+use std::env;
+use std::fmt;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Styled<T> {
+    value: T,
+    color: Option<Color>,
+    bold: bool,
+    dim: bool,
+    underline: bool,
+}
+
+/// ```
+/// use rust_exercises::extras::term::{set_enabled, style, Color};
+///
+/// set_enabled(true);
+/// assert_eq!(style("ok").green().bold().to_string(), "\x1b[1;32mok\x1b[0m");
+/// assert_eq!(format!("{:<4}|", style(7).color(Color::Red)), "\x1b[31m7   \x1b[0m|");
+///
+/// set_enabled(false);
+/// assert_eq!(style("ok").green().to_string(), "ok");
+/// ```
+pub fn style<T: fmt::Display>(value: T) -> Styled<T> {
+    Styled {
+        value,
+        color: None,
+        bold: false,
+        dim: false,
+        underline: false,
+    }
+}
+
+impl<T> Styled<T> {
+    pub fn color(mut self, color: Color) -> Styled<T> {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn red(self) -> Styled<T> {
+        self.color(Color::Red)
+    }
+
+    pub fn green(self) -> Styled<T> {
+        self.color(Color::Green)
+    }
+
+    pub fn yellow(self) -> Styled<T> {
+        self.color(Color::Yellow)
+    }
+
+    pub fn blue(self) -> Styled<T> {
+        self.color(Color::Blue)
+    }
+
+    pub fn cyan(self) -> Styled<T> {
+        self.color(Color::Cyan)
+    }
+
+    pub fn bold(mut self) -> Styled<T> {
+        self.bold = true;
+        self
+    }
+
+    pub fn dim(mut self) -> Styled<T> {
+        self.dim = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Styled<T> {
+        self.underline = true;
+        self
+    }
+
+    // The parameters of the escape sequence, "1;32" for bold green
+    fn codes(&self) -> String {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(1);
+        }
+        if self.dim {
+            codes.push(2);
+        }
+        if self.underline {
+            codes.push(4);
+        }
+        if let Some(color) = self.color {
+            codes.push(color.code());
+        }
+        codes
+            .iter()
+            .map(|code| code.to_string())
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+/*
+ * The value is formatted with the caller's formatter, so "{:<10}" pads
+ * the text itself and the padding doesn't count the escape codes
+ * */
+impl<T: fmt::Display> fmt::Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let codes = self.codes();
+        if codes.is_empty() || !enabled() {
+            return self.value.fmt(f);
+        }
+        write!(f, "\x1b[{}m", codes)?;
+        self.value.fmt(f)?;
+        f.write_str("\x1b[0m")
+    }
+}
+
+const UNKNOWN: u8 = 0;
+const OFF: u8 = 1;
+const ON: u8 = 2;
+
+static ENABLED: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+fn detect() -> bool {
+    // Any value counts except empty, as no-color.org asks
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !no_color && io::stdout().is_terminal()
+}
+
+/*
+ * Whether styles are written out. Detected the first time it's asked
+ * and remembered after that.
+ * */
+pub fn enabled() -> bool {
+    match ENABLED.load(Ordering::Relaxed) {
+        OFF => false,
+        ON => true,
+        _ => {
+            let on = detect();
+            set_enabled(on);
+            on
+        }
+    }
+}
+
+// Overrides the detection, for a --color flag or tests
+pub fn set_enabled(on: bool) {
+    ENABLED.store(if on { ON } else { OFF }, Ordering::Relaxed);
+}
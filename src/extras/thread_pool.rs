@@ -0,0 +1,234 @@
+/*
+ * The thread pool from chapter 20,
+ * https://doc.rust-lang.org/book/ch20-02-multithreaded.html
+ * with a second way of handing jobs to the workers.
+ *
+ *   SharedQueue   the book's version, one channel all workers receive from
+ *   WorkStealing  every worker has its own queue and, once it's empty,
+ *                 takes jobs from the back of the others' queues
+ *
+ * Dropping the pool waits for every job already submitted to finish.
+ * */
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    SharedQueue,
+    WorkStealing,
+}
+
+/*
+ * Shared between the work-stealing workers. pending counts the jobs
+ * submitted and not yet taken, it's raised before a job is queued and
+ * lowered after one is taken, so it never undercounts and a worker only
+ * sleeps when there is really nothing left.
+ * */
+struct Stealing {
+    queues: Vec<Mutex<VecDeque<Job>>>,
+    pending: Mutex<usize>,
+    available: Condvar,
+    shutdown: Mutex<bool>,
+}
+
+impl Stealing {
+    // Own queue first from the front, then the others from the back
+    fn find_job(&self, id: usize) -> Option<Job> {
+        if let Some(job) = self.queues[id].lock().unwrap().pop_front() {
+            return Some(job);
+        }
+
+        let count = self.queues.len();
+        (1..count)
+            .map(|offset| (id + offset) % count)
+            .find_map(|victim| self.queues[victim].lock().unwrap().pop_back())
+    }
+
+    fn run_worker(&self, id: usize) {
+        loop {
+            if let Some(job) = self.find_job(id) {
+                *self.pending.lock().unwrap() -= 1;
+                job();
+                continue;
+            }
+
+            let mut pending = self.pending.lock().unwrap();
+            while *pending == 0 {
+                if *self.shutdown.lock().unwrap() {
+                    return;
+                }
+                pending = self.available.wait(pending).unwrap();
+            }
+            // A job is pending but may not be queued yet, go look again
+        }
+    }
+}
+
+enum Queue {
+    // None once the pool is being dropped, which closes the channel
+    Shared(Option<mpsc::Sender<Job>>),
+    Stealing {
+        shared: Arc<Stealing>,
+        // Round robin over the workers' queues
+        next: AtomicUsize,
+    },
+}
+
+struct Worker {
+    id: usize,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+/// ```
+/// use rust_exercises::extras::thread_pool::{Strategy, ThreadPool};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// let done = Arc::new(AtomicUsize::new(0));
+/// let pool = ThreadPool::with_strategy(4, Strategy::WorkStealing);
+/// for _ in 0..100 {
+///     let done = done.clone();
+///     pool.execute(move || {
+///         done.fetch_add(1, Ordering::SeqCst);
+///     });
+/// }
+/// // Dropping the pool waits for every job
+/// drop(pool);
+/// assert_eq!(done.load(Ordering::SeqCst), 100);
+/// ```
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    queue: Queue,
+}
+
+impl ThreadPool {
+    /*
+     * Create a new ThreadPool with the book's shared queue.
+     *
+     * The size is the number of threads in the pool.
+     *
+     * # Panics
+     *
+     * The `new` function will panic if the size is zero.
+     * */
+    pub fn new(size: usize) -> ThreadPool {
+        ThreadPool::with_strategy(size, Strategy::SharedQueue)
+    }
+
+    pub fn with_strategy(size: usize, strategy: Strategy) -> ThreadPool {
+        assert!(size > 0);
+
+        match strategy {
+            Strategy::SharedQueue => {
+                let (sender, receiver) = mpsc::channel::<Job>();
+                let receiver = Arc::new(Mutex::new(receiver));
+
+                let workers = (0..size)
+                    .map(|id| {
+                        let receiver = Arc::clone(&receiver);
+                        Worker::spawn(id, move || loop {
+                            // The lock is released as soon as recv returns
+                            let job = receiver.lock().unwrap().recv();
+                            match job {
+                                Ok(job) => job(),
+                                // Channel closed, the pool is shutting down
+                                Err(_) => break,
+                            }
+                        })
+                    })
+                    .collect();
+
+                ThreadPool {
+                    workers,
+                    queue: Queue::Shared(Some(sender)),
+                }
+            }
+            Strategy::WorkStealing => {
+                let shared = Arc::new(Stealing {
+                    queues: (0..size).map(|_| Mutex::new(VecDeque::new())).collect(),
+                    pending: Mutex::new(0),
+                    available: Condvar::new(),
+                    shutdown: Mutex::new(false),
+                });
+
+                let workers = (0..size)
+                    .map(|id| {
+                        let shared = Arc::clone(&shared);
+                        Worker::spawn(id, move || shared.run_worker(id))
+                    })
+                    .collect();
+
+                ThreadPool {
+                    workers,
+                    queue: Queue::Stealing {
+                        shared,
+                        next: AtomicUsize::new(0),
+                    },
+                }
+            }
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let job: Job = Box::new(f);
+
+        match self.queue {
+            Queue::Shared(ref sender) => {
+                sender.as_ref().unwrap().send(job).unwrap();
+            }
+            Queue::Stealing {
+                ref shared,
+                ref next,
+            } => {
+                let target = next.fetch_add(1, Ordering::Relaxed) % shared.queues.len();
+                *shared.pending.lock().unwrap() += 1;
+                shared.queues[target].lock().unwrap().push_back(job);
+                shared.available.notify_one();
+            }
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        match self.queue {
+            Queue::Shared(ref mut sender) => drop(sender.take()),
+            Queue::Stealing { ref shared, .. } => {
+                *shared.shutdown.lock().unwrap() = true;
+                // Taking pending makes sure no worker is between its
+                // shutdown check and wait(), where it would miss this
+                let _pending = shared.pending.lock().unwrap();
+                shared.available.notify_all();
+            }
+        }
+
+        for worker in &mut self.workers {
+            if let Some(thread) = worker.thread.take() {
+                if thread.join().is_err() {
+                    eprintln!("worker {} panicked", worker.id);
+                }
+            }
+        }
+    }
+}
+
+impl Worker {
+    fn spawn<F: FnOnce() + Send + 'static>(id: usize, run: F) -> Worker {
+        Worker {
+            id,
+            thread: Some(thread::spawn(run)),
+        }
+    }
+}
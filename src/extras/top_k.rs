@@ -0,0 +1,143 @@
+/*
+ * The k largest items, largest first.
+ *
+ * top_k sorts a copy of a slice, simple but it needs all the items in
+ * memory at once. top_k_streaming takes any iterator and only ever
+ * holds k items: a min-heap of the best so far, whose smallest is
+ * pushed out whenever something bigger comes along. Lines of a file
+ * too big to load, by length:
+ *
+ *   let lines = BufReader::new(file).lines().map_while(Result::ok);
+ *   let longest = top_k_streaming_by_key(lines, 10, |line| line.len());
+ *
+ * Among equal items the ones that came first win, in both versions, the
+ * same way a stable sort would order them.
+ * */
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// ```
+/// use rust_exercises::extras::top_k::{top_k, top_k_by_key};
+///
+/// assert_eq!(top_k(&[3, 1, 4, 1, 5, 9, 2, 6], 3), [9, 6, 5]);
+/// let words = ["pear", "fig", "banana", "kiwi"];
+/// assert_eq!(top_k_by_key(&words, 2, |word| word.len()), ["banana", "pear"]);
+/// ```
+pub fn top_k<T: Ord + Clone>(items: &[T], k: usize) -> Vec<T> {
+    top_k_by_key(items, k, |item| item.clone())
+}
+
+pub fn top_k_by_key<T, K, F>(items: &[T], k: usize, mut key: F) -> Vec<T>
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut sorted = items.to_vec();
+    // Stable, equal keys keep their order
+    sorted.sort_by_cached_key(|item| Reverse(key(item)));
+    sorted.truncate(k);
+    sorted
+}
+
+/*
+ * Heap entry, the smallest key on top, and of equal keys the one that
+ * came last, it's the first to give up its place
+ * */
+struct Entry<K, T> {
+    key: K,
+    index: usize,
+    item: T,
+}
+
+impl<K: Ord, T> Ord for Entry<K, T> {
+    fn cmp(&self, other: &Entry<K, T>) -> Ordering {
+        // Reversed because BinaryHeap keeps the greatest on top
+        other
+            .key
+            .cmp(&self.key)
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+impl<K: Ord, T> PartialOrd for Entry<K, T> {
+    fn partial_cmp(&self, other: &Entry<K, T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> PartialEq for Entry<K, T> {
+    fn eq(&self, other: &Entry<K, T>) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, T> Eq for Entry<K, T> {}
+
+fn bounded<K, T, I>(pairs: I, k: usize) -> Vec<(K, T)>
+where
+    K: Ord,
+    I: Iterator<Item = (K, T)>,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap: BinaryHeap<Entry<K, T>> = BinaryHeap::with_capacity(k + 1);
+    for (index, (key, item)) in pairs.enumerate() {
+        if heap.len() == k {
+            // Only a strictly bigger key than the smallest kept one gets in
+            match heap.peek() {
+                Some(smallest) if key > smallest.key => {}
+                _ => continue,
+            }
+            heap.pop();
+        }
+        heap.push(Entry { key, index, item });
+    }
+
+    // Smallest on top, so ascending into_sorted_vec() order is largest first
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|entry| (entry.key, entry.item))
+        .collect()
+}
+
+/// ```
+/// use rust_exercises::extras::top_k::top_k_streaming;
+///
+/// // Only 3 items are ever held, whatever the length of the input
+/// assert_eq!(top_k_streaming((1..=1_000_000u64).map(|n| n % 1000), 3), [999, 999, 999]);
+/// assert!(top_k_streaming(Vec::<u32>::new(), 3).is_empty());
+/// ```
+pub fn top_k_streaming<I>(iter: I, k: usize) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Ord,
+{
+    bounded(iter.into_iter().map(|item| (item, ())), k)
+        .into_iter()
+        .map(|(item, ())| item)
+        .collect()
+}
+
+/// ```
+/// use rust_exercises::extras::top_k::top_k_streaming_by_key;
+///
+/// let lines = "a\nlongest line\nmid line\nlonger line".lines();
+/// assert_eq!(
+///     top_k_streaming_by_key(lines, 2, |line| line.len()),
+///     ["longest line", "longer line"]
+/// );
+/// ```
+pub fn top_k_streaming_by_key<I, K, F>(iter: I, k: usize, mut key: F) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    K: Ord,
+    F: FnMut(&I::Item) -> K,
+{
+    bounded(iter.into_iter().map(|item| (key(&item), item)), k)
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect()
+}
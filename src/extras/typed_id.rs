@@ -0,0 +1,120 @@
+/*
+ * Ids tagged with the type they identify, so an Id<User> is never
+ * accidentally passed where an Id<Order> is expected.
+ * https://doc.rust-lang.org/std/marker/struct.PhantomData.html
+ *
+ * PhantomData<T> takes no space, an Id<T> is a plain u64 at runtime.
+ * */
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// Ids of the same type compare by their number:
+///
+/// ```
+/// use rust_exercises::extras::typed_id::Id;
+///
+/// struct User;
+///
+/// let a: Id<User> = Id::from(1);
+/// assert_eq!(a, Id::new(1));
+/// ```
+///
+/// An `Id<User>` and an `Id<Order>` can't be compared:
+///
+/// ```compile_fail,E0308
+/// use rust_exercises::extras::typed_id::Id;
+///
+/// struct User;
+/// struct Order;
+///
+/// let user: Id<User> = Id::from(1);
+/// let order: Id<Order> = Id::from(1);
+/// let same = user == order;
+/// ```
+///
+/// nor passed where the other is expected:
+///
+/// ```compile_fail,E0308
+/// use rust_exercises::extras::typed_id::Id;
+///
+/// struct User;
+/// struct Order;
+///
+/// fn cancel(order: Id<Order>) {}
+///
+/// let user: Id<User> = Id::from(7);
+/// cancel(user);
+/// ```
+pub struct Id<T>(u64, PhantomData<T>);
+
+impl<T> Id<T> {
+    pub fn new(id: u64) -> Id<T> {
+        Id(id, PhantomData)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/*
+ * #[derive] would only implement these when T implements them too, but
+ * T is just a tag and an Id<User> is copyable even if User isn't.
+ * So they are written by hand, only looking at the u64.
+ * */
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Id<T> {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Id<T>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Id<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Id<T>) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T> From<u64> for Id<T> {
+    fn from(id: u64) -> Id<T> {
+        Id::new(id)
+    }
+}
+
+impl<T> fmt::Display for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+// Includes the tag, e.g. Id<User>(42), without needing T: Debug
+impl<T> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = ::std::any::type_name::<T>();
+        let short = name.rsplit("::").next().unwrap_or(name);
+        write!(f, "Id<{}>({})", short, self.0)
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * Newtype pattern for units of measure,
+ * https://doc.rust-lang.org/book/ch19-04-advanced-types.html
+ *
+ * Each unit wraps a plain f64, but only values of the same unit can be
+ * added or compared, anything else has to go through From/Into first.
+ * The doc examples on Meters and Celsius are the mixes that don't compile.
+ * */
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/*
+ * Same derives and operators for every unit: + and - between two values
+ * of the unit, * and / by a plain scalar.
+ * */
+macro_rules! unit {
+    ($(#[$meta:meta])* $name:ident, $suffix:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        pub struct $name(pub f64);
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, factor: f64) -> $name {
+                $name(self.0 * factor)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = $name;
+
+            fn div(self, divisor: f64) -> $name {
+                $name(self.0 / divisor)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                // Forwarding to f64 keeps precision flags like {:.1}
+                fmt::Display::fmt(&self.0, f)?;
+                f.write_str($suffix)
+            }
+        }
+    };
+}
+
+unit!(
+    /// Compared with another temperature only once it's in Celsius too:
+    ///
+    /// ```
+    /// use rust_exercises::extras::units::{Celsius, Fahrenheit};
+    ///
+    /// assert!(Celsius(20.0) > Celsius::from(Fahrenheit(60.0)));
+    /// ```
+    ///
+    /// ```compile_fail,E0308
+    /// use rust_exercises::extras::units::{Celsius, Fahrenheit};
+    ///
+    /// let warm = Celsius(20.0) > Fahrenheit(60.0);
+    /// ```
+    Celsius,
+    "°C"
+);
+unit!(Fahrenheit, "°F");
+unit!(Kelvin, "K");
+unit!(
+    /// Feet are added once they're converted:
+    ///
+    /// ```
+    /// use rust_exercises::extras::units::{Feet, Meters};
+    ///
+    /// let total = Meters(1.0) + Meters::from(Feet(10.0));
+    /// assert!((total.0 - 4.048).abs() < 1e-9);
+    /// ```
+    ///
+    /// ```compile_fail,E0308
+    /// use rust_exercises::extras::units::{Feet, Meters};
+    ///
+    /// let total = Meters(1.0) + Feet(3.0);
+    /// ```
+    Meters,
+    "m"
+);
+unit!(Feet, "ft");
+
+const ABSOLUTE_ZERO_CELSIUS: f64 = -273.15;
+const METERS_PER_FOOT: f64 = 0.3048;
+
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Celsius {
+        Celsius((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+impl From<Kelvin> for Celsius {
+    fn from(k: Kelvin) -> Celsius {
+        Celsius(k.0 + ABSOLUTE_ZERO_CELSIUS)
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Fahrenheit {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+// Going through Celsius for the two conversions left
+impl From<Kelvin> for Fahrenheit {
+    fn from(k: Kelvin) -> Fahrenheit {
+        Fahrenheit::from(Celsius::from(k))
+    }
+}
+
+impl From<Celsius> for Kelvin {
+    fn from(c: Celsius) -> Kelvin {
+        Kelvin(c.0 - ABSOLUTE_ZERO_CELSIUS)
+    }
+}
+
+impl From<Fahrenheit> for Kelvin {
+    fn from(f: Fahrenheit) -> Kelvin {
+        Kelvin::from(Celsius::from(f))
+    }
+}
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Meters {
+        Meters(feet.0 * METERS_PER_FOOT)
+    }
+}
+
+impl From<Meters> for Feet {
+    fn from(meters: Meters) -> Feet {
+        Feet(meters.0 / METERS_PER_FOOT)
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ * Brings in exercises added upstream without losing the learner's work.
+ *
+ * Upstream publishes catalog.txt at the root of the repository, one path
+ * per line. Every file in it is fetched and compared with the local one:
+ *
+ *   new        missing here, installed
+ *   unchanged  same content already
+ *   updated    still exactly what the last update installed, nobody
+ *              touched it, so it's replaced
+ *   modified   changed here since then, or never installed by an update
+ *              at all, and left alone
+ *
+ * What each update installed is recorded in .runner/installed as
+ * "<crc32> <path>" lines, that's how a file the learner edited is told
+ * apart from one that is only out of date.
+ * */
+use crate::core_utils::crc32;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+pub const DEFAULT_UPSTREAM: &str =
+    "https://raw.githubusercontent.com/gabriel-letarte/rust-exercises/master";
+pub const CATALOG: &str = "catalog.txt";
+const INSTALLED: &str = ".runner/installed";
+
+#[derive(Debug)]
+pub enum UpdateError {
+    Io(io::Error),
+    Fetch { path: String, message: String },
+    // A catalog entry that would write outside the repository
+    UnsafePath(String),
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UpdateError::Io(e) => write!(f, "{}", e),
+            UpdateError::Fetch { path, message } => write!(f, "can't fetch {}: {}", path, message),
+            UpdateError::UnsafePath(path) => write!(f, "refusing to install {}", path),
+        }
+    }
+}
+
+impl std::error::Error for UpdateError {}
+
+impl From<io::Error> for UpdateError {
+    fn from(e: io::Error) -> UpdateError {
+        UpdateError::Io(e)
+    }
+}
+
+/*
+ * Where the files come from, paths are relative to the repository root
+ * */
+pub trait Source {
+    fn fetch(&self, path: &str) -> Result<Vec<u8>, UpdateError>;
+}
+
+/*
+ * Raw files over HTTPS. std has no TLS and the crate has no
+ * dependencies for it, so this hands the download to curl.
+ * */
+pub struct Remote {
+    base_url: String,
+}
+
+impl Remote {
+    pub fn new(base_url: &str) -> Remote {
+        Remote {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl Source for Remote {
+    fn fetch(&self, path: &str) -> Result<Vec<u8>, UpdateError> {
+        let url = format!("{}/{}", self.base_url, path);
+        let failed = |message: String| UpdateError::Fetch {
+            path: path.to_string(),
+            message,
+        };
+
+        // -f turns HTTP errors into a failed exit instead of saving the error page
+        let output = Command::new("curl")
+            .args(["--fail", "--silent", "--show-error", "--location", &url])
+            .output()
+            .map_err(|e| failed(format!("can't run curl: {}", e)))?;
+        if !output.status.success() {
+            return Err(failed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(output.stdout)
+    }
+}
+
+/*
+ * Another checkout on disk, for offline mirrors and for trying an
+ * update out before it's pushed
+ * */
+pub struct LocalDir {
+    root: PathBuf,
+}
+
+impl LocalDir {
+    pub fn new<P: AsRef<Path>>(root: P) -> LocalDir {
+        LocalDir {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl Source for LocalDir {
+    fn fetch(&self, path: &str) -> Result<Vec<u8>, UpdateError> {
+        fs::read(self.root.join(path)).map_err(|e| UpdateError::Fetch {
+            path: path.to_string(),
+            message: e.to_string(),
+        })
+    }
+}
+
+/*
+ * The catalog comes from the network, so a path has to stay inside the
+ * repository: relative, and no ".." anywhere
+ * */
+/// ```
+/// use rust_exercises::extras::update::{parse_catalog, UpdateError};
+///
+/// let paths = parse_catalog("# exercises\nsrc/a.rs\n\nsrc/b.rs\n").unwrap();
+/// assert_eq!(paths, ["src/a.rs", "src/b.rs"]);
+/// assert!(matches!(
+///     parse_catalog("../outside.rs"),
+///     Err(UpdateError::UnsafePath(_))
+/// ));
+/// ```
+pub fn parse_catalog(text: &str) -> Result<Vec<String>, UpdateError> {
+    let mut paths = Vec::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let safe = Path::new(line)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if !safe {
+            return Err(UpdateError::UnsafePath(line.to_string()));
+        }
+        paths.push(line.to_string());
+    }
+    Ok(paths)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    New,
+    Unchanged,
+    Updated,
+    Modified,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Action::New => "new",
+            Action::Unchanged => "unchanged",
+            Action::Updated => "updated",
+            Action::Modified => "modified, kept",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub path: String,
+    pub action: Action,
+    upstream: Vec<u8>,
+}
+
+// Checksums of what the previous updates installed, by path
+fn load_installed(root: &Path) -> io::Result<BTreeMap<String, u32>> {
+    let text = match fs::read_to_string(root.join(INSTALLED)) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(e),
+    };
+
+    // A line that doesn't parse is forgotten, its file counts as modified
+    let installed = text
+        .lines()
+        .filter_map(|line| {
+            let (checksum, path) = line.split_once(' ')?;
+            Some((path.to_string(), u32::from_str_radix(checksum, 16).ok()?))
+        })
+        .collect();
+    Ok(installed)
+}
+
+fn save_installed(root: &Path, installed: &BTreeMap<String, u32>) -> io::Result<()> {
+    let path = root.join(INSTALLED);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let lines: String = installed
+        .iter()
+        .map(|(path, checksum)| format!("{:08x} {}\n", checksum, path))
+        .collect();
+    fs::write(path, lines)
+}
+
+/*
+ * Fetches everything and works out what an update would do, without
+ * writing anything. This is all a --dry-run needs.
+ * */
+/// ```
+/// use rust_exercises::extras::temp_dir::TempDir;
+/// use rust_exercises::extras::update::{apply, plan, Action, LocalDir};
+/// use std::fs;
+///
+/// let upstream = TempDir::new("upstream_doc").unwrap();
+/// fs::write(upstream.join("catalog.txt"), "hello.rs\n").unwrap();
+/// fs::write(upstream.join("hello.rs"), "fn main() {}\n").unwrap();
+/// let local = TempDir::new("local_doc").unwrap();
+///
+/// let changes = plan(&LocalDir::new(upstream.path()), local.path()).unwrap();
+/// assert_eq!(changes[0].action, Action::New);
+/// apply(local.path(), &changes).unwrap();
+///
+/// // Edited here, so the next update leaves it alone
+/// fs::write(local.join("hello.rs"), "fn main() { todo!() }\n").unwrap();
+/// fs::write(upstream.join("hello.rs"), "fn main() { }\n").unwrap();
+/// let changes = plan(&LocalDir::new(upstream.path()), local.path()).unwrap();
+/// assert_eq!(changes[0].action, Action::Modified);
+/// ```
+pub fn plan<S: Source + ?Sized>(source: &S, root: &Path) -> Result<Vec<Change>, UpdateError> {
+    let catalog = String::from_utf8_lossy(&source.fetch(CATALOG)?).into_owned();
+    let installed = load_installed(root)?;
+    let mut changes = Vec::new();
+
+    for path in parse_catalog(&catalog)? {
+        let upstream = source.fetch(&path)?;
+        let action = match fs::read(root.join(&path)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Action::New,
+            Err(e) => return Err(e.into()),
+            Ok(local) if local == upstream => Action::Unchanged,
+            Ok(local) if installed.get(&path) == Some(&crc32::checksum(&local)) => Action::Updated,
+            Ok(_) => Action::Modified,
+        };
+        changes.push(Change {
+            path,
+            action,
+            upstream,
+        });
+    }
+    Ok(changes)
+}
+
+/*
+ * Writes the new and updated files and records them. Unchanged ones are
+ * recorded too: they match upstream, so the next update may replace them.
+ * */
+pub fn apply(root: &Path, changes: &[Change]) -> io::Result<()> {
+    let mut installed = load_installed(root)?;
+
+    for change in changes {
+        match change.action {
+            Action::New | Action::Updated => {
+                let path = root.join(&change.path);
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(path, &change.upstream)?;
+            }
+            Action::Unchanged => {}
+            Action::Modified => continue,
+        }
+        installed.insert(change.path.clone(), crc32::checksum(&change.upstream));
+    }
+
+    save_installed(root, &installed)
+}
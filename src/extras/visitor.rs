@@ -0,0 +1,255 @@
+/*
+ * Visitor pattern over an expression tree, next to the same two passes
+ * written with plain enum matching.
+ *
+ * accept() is the double dispatch: the node picks which visit_ method to
+ * call, the visitor picks what that method does. With enums the match in
+ * each pass does both at once, which is usually simpler in Rust, the
+ * visitor pays off when passes are added by code that can't edit Expr.
+ * */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+        }
+    }
+
+    pub fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Sub => 1,
+            Op::Mul | Op::Div => 2,
+        }
+    }
+
+    // None for a division by zero, which is left unfolded
+    pub fn apply(self, a: f64, b: f64) -> Option<f64> {
+        match self {
+            Op::Add => Some(a + b),
+            Op::Sub => Some(a - b),
+            Op::Mul => Some(a * b),
+            Op::Div if b == 0.0 => None,
+            Op::Div => Some(a / b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Var(String),
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+// Shorthands to build trees by hand
+pub fn num(n: f64) -> Expr {
+    Expr::Num(n)
+}
+
+pub fn var(name: &str) -> Expr {
+    Expr::Var(name.to_string())
+}
+
+pub fn neg(inner: Expr) -> Expr {
+    Expr::Neg(Box::new(inner))
+}
+
+pub fn binary(op: Op, left: Expr, right: Expr) -> Expr {
+    Expr::Binary(op, Box::new(left), Box::new(right))
+}
+
+pub trait Visitor {
+    type Output;
+
+    fn visit_num(&mut self, n: f64) -> Self::Output;
+    fn visit_var(&mut self, name: &str) -> Self::Output;
+    fn visit_neg(&mut self, inner: &Expr) -> Self::Output;
+    fn visit_binary(&mut self, op: Op, left: &Expr, right: &Expr) -> Self::Output;
+}
+
+impl Expr {
+    /*
+     * ?Sized so it also takes trait objects, like &mut dyn Visitor<Output = String>
+     * */
+    pub fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) -> V::Output {
+        match *self {
+            Expr::Num(n) => visitor.visit_num(n),
+            Expr::Var(ref name) => visitor.visit_var(name),
+            Expr::Neg(ref inner) => visitor.visit_neg(inner),
+            Expr::Binary(op, ref left, ref right) => visitor.visit_binary(op, left, right),
+        }
+    }
+}
+
+// Binding strength of a negation, tighter than any binary operator
+const NEG_PRECEDENCE: u8 = 3;
+const ATOM_PRECEDENCE: u8 = 4;
+
+fn parenthesize(text: String, precedence: u8, min: u8) -> String {
+    if precedence < min {
+        format!("({})", text)
+    } else {
+        text
+    }
+}
+
+/*
+ * Prints with as few parentheses as possible. Each visit returns the
+ * text along with the precedence of its outermost operator so the parent
+ * knows whether to wrap it. The right side of an operator needs them even
+ * at equal precedence: 1 - (2 - 3) isn't 1 - 2 - 3.
+ * */
+pub struct PrettyPrinter;
+
+impl Visitor for PrettyPrinter {
+    type Output = (String, u8);
+
+    fn visit_num(&mut self, n: f64) -> (String, u8) {
+        (n.to_string(), ATOM_PRECEDENCE)
+    }
+
+    fn visit_var(&mut self, name: &str) -> (String, u8) {
+        (name.to_string(), ATOM_PRECEDENCE)
+    }
+
+    fn visit_neg(&mut self, inner: &Expr) -> (String, u8) {
+        let (text, precedence) = inner.accept(self);
+        (
+            format!("-{}", parenthesize(text, precedence, NEG_PRECEDENCE)),
+            NEG_PRECEDENCE,
+        )
+    }
+
+    fn visit_binary(&mut self, op: Op, left: &Expr, right: &Expr) -> (String, u8) {
+        let (left, left_precedence) = left.accept(self);
+        let (right, right_precedence) = right.accept(self);
+        let text = format!(
+            "{} {} {}",
+            parenthesize(left, left_precedence, op.precedence()),
+            op.symbol(),
+            parenthesize(right, right_precedence, op.precedence() + 1)
+        );
+        (text, op.precedence())
+    }
+}
+
+/*
+ * Replaces every subtree without variables by its value,
+ * e.g. x * (2 + 3) becomes x * 5
+ * */
+pub struct ConstantFolder;
+
+impl Visitor for ConstantFolder {
+    type Output = Expr;
+
+    fn visit_num(&mut self, n: f64) -> Expr {
+        Expr::Num(n)
+    }
+
+    fn visit_var(&mut self, name: &str) -> Expr {
+        Expr::Var(name.to_string())
+    }
+
+    fn visit_neg(&mut self, inner: &Expr) -> Expr {
+        match inner.accept(self) {
+            Expr::Num(n) => Expr::Num(-n),
+            folded => neg(folded),
+        }
+    }
+
+    fn visit_binary(&mut self, op: Op, left: &Expr, right: &Expr) -> Expr {
+        let left = left.accept(self);
+        let right = right.accept(self);
+
+        if let (&Expr::Num(a), &Expr::Num(b)) = (&left, &right) {
+            if let Some(n) = op.apply(a, b) {
+                return Expr::Num(n);
+            }
+        }
+        binary(op, left, right)
+    }
+}
+
+/// ```
+/// use rust_exercises::extras::visitor::{binary, num, pretty_print, var, Op};
+///
+/// let expr = binary(Op::Mul, binary(Op::Add, var("x"), num(1.0)), num(2.0));
+/// assert_eq!(pretty_print(&expr), "(x + 1) * 2");
+/// ```
+pub fn pretty_print(expr: &Expr) -> String {
+    expr.accept(&mut PrettyPrinter).0
+}
+
+/// ```
+/// use rust_exercises::extras::visitor::{binary, fold_constants, num, var, Op};
+///
+/// let expr = binary(Op::Mul, var("x"), binary(Op::Add, num(2.0), num(3.0)));
+/// assert_eq!(fold_constants(&expr), binary(Op::Mul, var("x"), num(5.0)));
+/// ```
+pub fn fold_constants(expr: &Expr) -> Expr {
+    expr.accept(&mut ConstantFolder)
+}
+
+/*
+ * The same two passes with enum matching, each one is a single function
+ * that recurses on its own.
+ * */
+fn pretty_print_match_inner(expr: &Expr) -> (String, u8) {
+    match *expr {
+        Expr::Num(n) => (n.to_string(), ATOM_PRECEDENCE),
+        Expr::Var(ref name) => (name.clone(), ATOM_PRECEDENCE),
+        Expr::Neg(ref inner) => {
+            let (text, precedence) = pretty_print_match_inner(inner);
+            (
+                format!("-{}", parenthesize(text, precedence, NEG_PRECEDENCE)),
+                NEG_PRECEDENCE,
+            )
+        }
+        Expr::Binary(op, ref left, ref right) => {
+            let (left, left_precedence) = pretty_print_match_inner(left);
+            let (right, right_precedence) = pretty_print_match_inner(right);
+            let text = format!(
+                "{} {} {}",
+                parenthesize(left, left_precedence, op.precedence()),
+                op.symbol(),
+                parenthesize(right, right_precedence, op.precedence() + 1)
+            );
+            (text, op.precedence())
+        }
+    }
+}
+
+pub fn pretty_print_match(expr: &Expr) -> String {
+    pretty_print_match_inner(expr).0
+}
+
+pub fn fold_constants_match(expr: &Expr) -> Expr {
+    match *expr {
+        Expr::Num(n) => Expr::Num(n),
+        Expr::Var(ref name) => Expr::Var(name.clone()),
+        Expr::Neg(ref inner) => match fold_constants_match(inner) {
+            Expr::Num(n) => Expr::Num(-n),
+            folded => neg(folded),
+        },
+        Expr::Binary(op, ref left, ref right) => {
+            match (fold_constants_match(left), fold_constants_match(right)) {
+                (Expr::Num(a), Expr::Num(b)) => match op.apply(a, b) {
+                    Some(n) => Expr::Num(n),
+                    None => binary(op, Expr::Num(a), Expr::Num(b)),
+                },
+                (left, right) => binary(op, left, right),
+            }
+        }
+    }
+}
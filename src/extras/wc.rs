@@ -0,0 +1,78 @@
+/*
+ * Counts like `wc`: newlines, whitespace-separated words, chars and bytes.
+ * Chars are UTF-8 characters, so "é" is one char but two bytes.
+ * */
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::{Add, AddAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub chars: usize,
+    pub bytes: usize,
+}
+
+impl Add for Counts {
+    type Output = Counts;
+
+    fn add(self, other: Counts) -> Counts {
+        Counts {
+            lines: self.lines + other.lines,
+            words: self.words + other.words,
+            chars: self.chars + other.chars,
+            bytes: self.bytes + other.bytes,
+        }
+    }
+}
+
+impl AddAssign for Counts {
+    fn add_assign(&mut self, other: Counts) {
+        *self = *self + other;
+    }
+}
+
+/*
+ * Same columns as `wc` without options: lines, words and bytes
+ * */
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>7} {:>7} {:>7}", self.lines, self.words, self.bytes)
+    }
+}
+
+/*
+ * Reads line by line so big files aren't loaded at once.
+ * Like wc, a last line without '\n' adds its words but not a line,
+ * and invalid UTF-8 still counts bytes without failing.
+ * */
+/// ```
+/// use rust_exercises::extras::wc::count;
+///
+/// let counts = count("héllo world\nbye".as_bytes()).unwrap();
+/// assert_eq!((counts.lines, counts.words, counts.chars, counts.bytes), (1, 3, 15, 16));
+/// assert_eq!(counts.to_string(), "      1       3      16");
+/// ```
+pub fn count<R: BufRead>(mut reader: R) -> io::Result<Counts> {
+    let mut counts = Counts::default();
+    let mut line = Vec::new();
+
+    loop {
+        line.clear();
+        let n = reader.read_until(b'\n', &mut line)?;
+        if n == 0 {
+            break;
+        }
+
+        counts.bytes += n;
+        if line.last() == Some(&b'\n') {
+            counts.lines += 1;
+        }
+        // Every char starts with a byte that isn't 0b10xxxxxx
+        counts.chars += line.iter().filter(|&&b| b & 0xc0 != 0x80).count();
+        counts.words += String::from_utf8_lossy(&line).split_whitespace().count();
+    }
+
+    Ok(counts)
+}
@@ -0,0 +1,65 @@
+/*
+ * The other way around from chapters::ffi, Rust functions callable from
+ * C. The crate is also built as a cdylib (librust_exercises.so), the
+ * declarations are in include/rust_exercises.h.
+ *
+ * #[no_mangle] keeps the symbol name as written and extern "C" uses the
+ * C calling convention. Slices don't exist in C, so they cross as a
+ * pointer and a length, and results go through an out pointer so the
+ * return value can say whether there was one.
+ * */
+use crate::core_utils::largest::largest_copy;
+use std::slice;
+
+/*
+ * Builds the slice back and writes the largest item to out, returns
+ * false without touching out when there is nothing to compare.
+ * */
+unsafe fn largest_c<T: PartialOrd + Copy>(ptr: *const T, len: usize, out: *mut T) -> bool {
+    if ptr.is_null() || out.is_null() || len == 0 {
+        return false;
+    }
+
+    match largest_copy(slice::from_raw_parts(ptr, len)) {
+        Some(value) => {
+            *out = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must point to `len` initialized values and `out` must be
+/// valid for a write. Null pointers are
+/// rejected by returning false.
+#[no_mangle]
+pub unsafe extern "C" fn largest_i32_c(ptr: *const i32, len: usize, out: *mut i32) -> bool {
+    largest_c(ptr, len, out)
+}
+
+/// # Safety
+///
+/// Same as `largest_i32_c`. NaN values are never larger than anything,
+/// so they are skipped unless the first value is NaN.
+#[no_mangle]
+pub unsafe extern "C" fn largest_f64_c(ptr: *const f64, len: usize, out: *mut f64) -> bool {
+    largest_c(ptr, len, out)
+}
+
+/*
+ * A C char is a byte, this works on the bytes of a NUL terminated
+ * string and returns 0 for an empty or null one
+ * */
+/// # Safety
+///
+/// `s` must be null or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn largest_byte_c(s: *const std::os::raw::c_char) -> u8 {
+    if s.is_null() {
+        return 0;
+    }
+    let bytes = std::ffi::CStr::from_ptr(s).to_bytes();
+    largest_copy(bytes).unwrap_or(0)
+}
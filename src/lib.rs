@@ -0,0 +1,12 @@
+/*
+ * Exercises going past the book chapters, grouped in a library so they
+ * can build on one another.
+ * */
+// For the Vec returning functions of core_utils, see core_utils/mod.rs
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod chapters;
+pub mod core_utils;
+pub mod extras;
+pub mod ffi_exports;
@@ -0,0 +1,102 @@
+/*
+ * extras::actor: ping pong between two actors, messages handled in the
+ * order they were sent, and what stopping does to the mailbox
+ * */
+use rust_exercises::extras::actor::{
+    self, ping_pong, Actor, Pinger, PingerMsg, Ponger, Stopped, Supervisor,
+};
+use std::sync::mpsc::{self, Sender};
+
+#[test]
+fn ping_pong_rounds() {
+    for &rounds in [0, 1, 100].iter() {
+        assert_eq!(ping_pong(rounds), rounds);
+    }
+}
+
+#[test]
+fn ponger_answers_every_ping() {
+    let (done, finished) = mpsc::channel();
+    let ponger = actor::spawn(Ponger { pings: 0 });
+    let pinger = actor::spawn(Pinger::new(25, done));
+    let me = pinger.addr();
+    me.send(PingerMsg::Start {
+        me: me.clone(),
+        peer: ponger.addr(),
+    })
+    .unwrap();
+
+    assert_eq!(finished.recv(), Ok(25));
+    assert!(pinger.stop().is_ok());
+    assert_eq!(ponger.stop().unwrap().pings, 25);
+}
+
+// Keeps everything it's sent, and says when it stopped
+struct Recorder {
+    seen: Vec<u32>,
+    stopped: Sender<usize>,
+}
+
+impl Actor for Recorder {
+    type Msg = u32;
+
+    fn handle(&mut self, msg: u32) {
+        assert_ne!(msg, 13, "unlucky");
+        self.seen.push(msg);
+    }
+
+    fn stopped(&mut self) {
+        self.stopped.send(self.seen.len()).unwrap();
+    }
+}
+
+fn recorder() -> (Recorder, mpsc::Receiver<usize>) {
+    let (stopped, on_stop) = mpsc::channel();
+    let recorder = Recorder {
+        seen: Vec::new(),
+        stopped,
+    };
+    (recorder, on_stop)
+}
+
+#[test]
+fn messages_are_handled_in_order_before_the_stop() {
+    let (recorder, on_stop) = recorder();
+    let handle = actor::spawn(recorder);
+    let addr = handle.addr();
+    for i in 100..600 {
+        addr.send(i).unwrap();
+    }
+
+    let recorder = handle.stop().unwrap();
+    assert_eq!(recorder.seen, (100..600).collect::<Vec<_>>());
+    assert_eq!(on_stop.recv(), Ok(500));
+    // Nobody reads the mailbox anymore, the message comes back
+    assert_eq!(addr.send(7), Err(Stopped(7)));
+}
+
+#[test]
+fn supervisor_counts_panicked_actors() {
+    let mut supervisor = Supervisor::new();
+    let (fine, fine_stopped) = recorder();
+    let (unlucky, _) = recorder();
+    supervisor.spawn(fine).send(1).unwrap();
+    supervisor.spawn(unlucky).send(13).unwrap();
+    supervisor.spawn(Ponger { pings: 0 });
+    assert_eq!(supervisor.len(), 3);
+
+    let report = supervisor.shutdown();
+    assert_eq!((report.stopped, report.panicked), (2, 1));
+    assert_eq!(fine_stopped.recv(), Ok(1));
+}
+
+#[test]
+fn dropping_the_supervisor_stops_its_actors() {
+    let (recorder, on_stop) = recorder();
+    let mut supervisor = Supervisor::new();
+    let addr = supervisor.spawn(recorder);
+    addr.send(1).unwrap();
+    drop(supervisor);
+    assert_eq!(on_stop.recv(), Ok(1));
+    assert!(addr.send(2).is_err());
+}
@@ -0,0 +1,80 @@
+/*
+ * extras::algos' fast versions against their brute force ones, on the
+ * known examples and then on random input. TEST_SEED=<n> repeats a
+ * failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::algos::{
+    binary_gap, binary_gap_brute, max_subarray_sum, max_subarray_sum_brute, two_sum, two_sum_brute,
+};
+use rust_exercises::extras::rng::{Rng, XorShift64};
+
+fn random_nums(rng: &mut XorShift64) -> Vec<i64> {
+    let len = rng.gen_range(0..15) as usize;
+    (0..len).map(|_| rng.gen_range(0..41) as i64 - 20).collect()
+}
+
+#[test]
+fn known_answers() {
+    assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+    assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+    // The same element can't be used twice
+    assert_eq!(two_sum(&[3], 6), None);
+    assert_eq!(two_sum(&[i64::MIN, 1], i64::MAX), None);
+
+    assert_eq!(max_subarray_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), Some(6));
+    assert_eq!(max_subarray_sum(&[-3, -1, -2]), Some(-1));
+    assert_eq!(max_subarray_sum(&[]), None);
+
+    assert_eq!(binary_gap(0b1_0000_1001), 4);
+    assert_eq!(binary_gap(0b10100), 1);
+    assert_eq!(binary_gap(0b1000), 0);
+    assert_eq!(binary_gap(0), 0);
+    assert_eq!(binary_gap(u32::MAX), 0);
+    assert_eq!(binary_gap(0x8000_0001), 30);
+}
+
+#[test]
+fn two_sum_agrees_with_brute_force() {
+    let mut rng = rng();
+    for _ in 0..2000 {
+        let nums = random_nums(&mut rng);
+        let target = rng.gen_range(0..41) as i64 - 20;
+        assert_eq!(
+            two_sum(&nums, target),
+            two_sum_brute(&nums, target),
+            "{:?} target {}",
+            nums,
+            target
+        );
+    }
+}
+
+#[test]
+fn max_subarray_sum_agrees_with_brute_force() {
+    let mut rng = rng();
+    for _ in 0..2000 {
+        let nums = random_nums(&mut rng);
+        assert_eq!(
+            max_subarray_sum(&nums),
+            max_subarray_sum_brute(&nums),
+            "{:?}",
+            nums
+        );
+    }
+}
+
+#[test]
+fn binary_gap_agrees_with_brute_force() {
+    let mut rng = rng();
+    for _ in 0..10_000 {
+        // Sparse bits, dense ones rarely have gaps worth checking
+        let n = (rng.next_u64() & rng.next_u64() & rng.next_u64()) as u32;
+        assert_eq!(binary_gap(n), binary_gap_brute(n), "{:#b}", n);
+    }
+    for n in 0..=1024 {
+        assert_eq!(binary_gap(n), binary_gap_brute(n), "{:#b}", n);
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * One ArgSpec, many command lines: what each parses to, and the error
+ * each bad one gives
+ * */
+use rust_exercises::extras::argparse::{ArgSpec, Matches, ParseError};
+
+fn spec() -> ArgSpec {
+    ArgSpec::new("grep")
+        .about("Searches files")
+        .flag("verbose", Some('v'), "Say more")
+        .flag("quiet", Some('q'), "Say less")
+        .flag("count", None, "Only count")
+        .option("level", Some("1"), "How hard to look")
+        .option("color", None, "When to color")
+        .positional("pattern", "What to look for")
+        .positional("file", "Where to look")
+}
+
+fn parse(args: &[&str]) -> Result<Matches, ParseError> {
+    spec().parse(args.iter().copied())
+}
+
+#[test]
+fn valid_command_lines() {
+    // args, verbose, quiet, count, level, color, pattern, file
+    type Expected<'a> = (bool, bool, bool, &'a str, Option<&'a str>, &'a str, &'a str);
+    let cases: &[(&[&str], Expected)] = &[
+        (&["a", "b"], (false, false, false, "1", None, "a", "b")),
+        (&["-v", "a", "b"], (true, false, false, "1", None, "a", "b")),
+        (
+            &["a", "--verbose", "b"],
+            (true, false, false, "1", None, "a", "b"),
+        ),
+        (&["-vq", "a", "b"], (true, true, false, "1", None, "a", "b")),
+        (
+            &["-qv", "-v", "a", "b"],
+            (true, true, false, "1", None, "a", "b"),
+        ),
+        (
+            &["--count", "a", "b"],
+            (false, false, true, "1", None, "a", "b"),
+        ),
+        (
+            &["--level=3", "a", "b"],
+            (false, false, false, "3", None, "a", "b"),
+        ),
+        (
+            &["--level", "3", "a", "b"],
+            (false, false, false, "3", None, "a", "b"),
+        ),
+        // The last one given wins
+        (
+            &["--level", "3", "--level=4", "a", "b"],
+            (false, false, false, "4", None, "a", "b"),
+        ),
+        (
+            &["--color=", "a", "b"],
+            (false, false, false, "1", Some(""), "a", "b"),
+        ),
+        (
+            &["--color", "auto", "a", "b"],
+            (false, false, false, "1", Some("auto"), "a", "b"),
+        ),
+        // An option's value is taken as is, even if it looks like a flag
+        (
+            &["--color", "-v", "a", "b"],
+            (false, false, false, "1", Some("-v"), "a", "b"),
+        ),
+        (
+            &["--level=a=b", "a", "b"],
+            (false, false, false, "a=b", None, "a", "b"),
+        ),
+        // After -- and for a lone -, everything is positional
+        (
+            &["--", "-v", "--level"],
+            (false, false, false, "1", None, "-v", "--level"),
+        ),
+        (
+            &["-v", "--", "--", "b"],
+            (true, false, false, "1", None, "--", "b"),
+        ),
+        (&["-", "-"], (false, false, false, "1", None, "-", "-")),
+    ];
+
+    for &(args, (verbose, quiet, count, level, color, pattern, file)) in cases {
+        let matches = parse(args).unwrap_or_else(|e| panic!("{:?}: {}", args, e));
+        assert_eq!(matches.flag("verbose"), verbose, "{:?}", args);
+        assert_eq!(matches.flag("quiet"), quiet, "{:?}", args);
+        assert_eq!(matches.flag("count"), count, "{:?}", args);
+        assert_eq!(matches.value("level"), Some(level), "{:?}", args);
+        assert_eq!(matches.value("color"), color, "{:?}", args);
+        assert_eq!(matches.value("pattern"), Some(pattern), "{:?}", args);
+        assert_eq!(matches.value("file"), Some(file), "{:?}", args);
+    }
+}
+
+#[test]
+fn invalid_command_lines() {
+    let unknown = |arg: &str| ParseError::UnknownArgument(arg.to_string());
+    let cases: &[(&[&str], ParseError)] = &[
+        (&[], ParseError::MissingPositional("pattern".to_string())),
+        (&["a"], ParseError::MissingPositional("file".to_string())),
+        (
+            &["a", "b", "c"],
+            ParseError::TooManyPositionals("c".to_string()),
+        ),
+        (&["--nope", "a", "b"], unknown("--nope")),
+        (&["-x", "a", "b"], unknown("-x")),
+        (&["-vx", "a", "b"], unknown("-x")),
+        // Long names only with two dashes
+        (&["-verbose", "a", "b"], unknown("-e")),
+        (
+            &["--verbose=yes", "a", "b"],
+            ParseError::UnexpectedValue("verbose".to_string()),
+        ),
+        (
+            &["a", "b", "--level"],
+            ParseError::MissingValue("level".to_string()),
+        ),
+        // Unknown arguments are reported before missing positionals
+        (&["--nope"], unknown("--nope")),
+    ];
+
+    for (args, expected) in cases {
+        assert_eq!(parse(args).as_ref(), Err(expected), "{:?}", args);
+    }
+}
+
+#[test]
+fn help_wins_over_everything() {
+    for args in [
+        &["-h"][..],
+        &["--help"],
+        &["a", "b", "c", "--help"],
+        &["-v", "--help"],
+    ] {
+        match parse(args) {
+            Err(ParseError::HelpRequested(help)) => assert_eq!(help, spec().help()),
+            other => panic!("{:?}: {:?}", args, other),
+        }
+    }
+    // Positional after --
+    assert_eq!(
+        parse(&["--", "-h", "b"]).unwrap().value("pattern"),
+        Some("-h")
+    );
+}
+
+#[test]
+fn help_text() {
+    assert_eq!(
+        spec().help(),
+        "Searches files\n\
+         \n\
+         Usage: grep [OPTIONS] <pattern> <file>\n\
+         \n\
+         \x20 <pattern>            What to look for\n\
+         \x20 <file>               Where to look\n\
+         \x20 -v, --verbose        Say more\n\
+         \x20 -q, --quiet          Say less\n\
+         \x20     --count          Only count\n\
+         \x20     --level <value>  How hard to look [default: 1]\n\
+         \x20     --color <value>  When to color\n\
+         \x20 -h, --help           Print this help\n"
+    );
+}
+
+#[test]
+fn typed_values() {
+    let matches = parse(&["--level", "7", "a", "b"]).unwrap();
+    assert_eq!(matches.value_as::<u8>("level"), Ok(Some(7)));
+    assert_eq!(matches.value_as::<u8>("color"), Ok(None));
+    assert_eq!(
+        matches.value_as::<String>("pattern"),
+        Ok(Some("a".to_string()))
+    );
+
+    let matches = parse(&["--level=-1", "a", "b"]).unwrap();
+    let e = matches.value_as::<u8>("level").unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::InvalidValue {
+            name: "level".to_string(),
+            value: "-1".to_string()
+        }
+    );
+    assert_eq!(e.to_string(), "invalid value '-1' for level");
+    assert_eq!(matches.value_as::<i64>("level"), Ok(Some(-1)));
+}
+
+#[test]
+fn owned_arguments_like_env_args() {
+    let args: Vec<String> = vec!["-v".to_string(), "a".to_string(), "b".to_string()];
+    let matches = spec().parse(args).unwrap();
+    assert!(matches.flag("verbose"));
+    // No positionals at all, nothing to be missing
+    assert!(ArgSpec::new("x").parse(Vec::<String>::new()).is_ok());
+}
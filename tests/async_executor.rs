@@ -0,0 +1,193 @@
+/*
+ * The hand-written futures polled by hand with a waker that counts its
+ * wakes, and the Executor's order checked with yield_now instead of
+ * timers. Nothing here depends on how fast the machine is, Delay is only
+ * ever checked for having waited at least its duration.
+ * */
+#![cfg(feature = "async")]
+
+use rust_exercises::chapters::async_executor::{block_on, join, yield_now, Delay, Executor};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+    let count = Arc::new(CountingWaker::default());
+    (count.clone(), Waker::from(count))
+}
+
+struct ChannelWaker(Mutex<Sender<()>>);
+
+impl Wake for ChannelWaker {
+    fn wake(self: Arc<Self>) {
+        let _ = self.0.lock().unwrap().send(());
+    }
+}
+
+#[test]
+fn yield_now_is_pending_once_and_wakes_right_away() {
+    let (count, waker) = counting_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = yield_now();
+
+    assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+    assert_eq!(count.0.load(Ordering::SeqCst), 1);
+    assert!(Pin::new(&mut future).poll(&mut cx).is_ready());
+    assert_eq!(count.0.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn delay_wakes_the_last_waker_it_was_given() {
+    let (sender, receiver) = mpsc::channel();
+    let waker = Waker::from(Arc::new(ChannelWaker(Mutex::new(sender))));
+    let (unused, unused_waker) = counting_waker();
+    let mut delay = Delay::new(Duration::from_millis(20));
+
+    // Polled once with a waker that we then replace, only the second one
+    // gets called
+    assert!(Pin::new(&mut delay)
+        .poll(&mut Context::from_waker(&unused_waker))
+        .is_pending());
+    assert!(Pin::new(&mut delay)
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+    receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("never woken");
+
+    assert!(Pin::new(&mut delay)
+        .poll(&mut Context::from_waker(&waker))
+        .is_ready());
+    assert_eq!(unused.0.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn delay_waits_at_least_its_duration() {
+    let start = Instant::now();
+    block_on(Delay::new(Duration::from_millis(30)));
+    assert!(start.elapsed() >= Duration::from_millis(30));
+
+    // Zero is still one trip through the timer thread, but finishes
+    block_on(Delay::new(Duration::from_millis(0)));
+}
+
+#[test]
+fn join_polls_both_sides_every_time() {
+    let (count, waker) = counting_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut joined = join(yield_now(), yield_now());
+
+    // Both yielded on the first poll, so both woke the task
+    assert!(Pin::new(&mut joined).poll(&mut cx).is_pending());
+    assert_eq!(count.0.load(Ordering::SeqCst), 2);
+    assert_eq!(Pin::new(&mut joined).poll(&mut cx), Poll::Ready(((), ())));
+}
+
+#[test]
+fn join_keeps_outputs_in_argument_order() {
+    let log = Mutex::new(Vec::new());
+    let slow = async {
+        yield_now().await;
+        log.lock().unwrap().push("slow");
+        1
+    };
+    let fast = async {
+        log.lock().unwrap().push("fast");
+        "two"
+    };
+
+    assert_eq!(block_on(join(slow, fast)), (1, "two"));
+    // The first argument finished last without holding up the second
+    assert_eq!(*log.lock().unwrap(), ["fast", "slow"]);
+}
+
+#[test]
+fn block_on_a_ready_future() {
+    assert_eq!(block_on(async { 40 + 2 }), 42);
+    assert_eq!(block_on(join(async { 'a' }, async { 'b' })), ('a', 'b'));
+}
+
+#[test]
+fn executor_interleaves_tasks_at_each_yield() {
+    let executor = Executor::new();
+    let spawner = executor.spawner();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    for id in 0..3 {
+        let log = log.clone();
+        spawner.spawn(async move {
+            for step in 0..2 {
+                log.lock().unwrap().push((id, step));
+                yield_now().await;
+            }
+        });
+    }
+    drop(spawner);
+    executor.run();
+
+    // A yield puts the task at the back of the queue, so each round goes
+    // through every task in the order they were spawned
+    assert_eq!(
+        *log.lock().unwrap(),
+        [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+}
+
+#[test]
+fn tasks_can_spawn_tasks() {
+    let executor = Executor::new();
+    let spawner = executor.spawner();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let inner_log = log.clone();
+    let inner_spawner = spawner.clone();
+    spawner.spawn(async move {
+        inner_log.lock().unwrap().push("outer starts");
+        let child_log = inner_log.clone();
+        inner_spawner.spawn(async move {
+            child_log.lock().unwrap().push("child");
+        });
+        // The child was queued behind us, it runs while we're yielded
+        yield_now().await;
+        inner_log.lock().unwrap().push("outer ends");
+    });
+    drop(spawner);
+    executor.run();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["outer starts", "child", "outer ends"]
+    );
+}
+
+#[test]
+fn run_returns_once_woken_from_other_threads() {
+    let executor = Executor::new();
+    let spawner = executor.spawner();
+    let done = Arc::new(AtomicUsize::new(0));
+
+    for _ in 0..4 {
+        let done = done.clone();
+        spawner.spawn(async move {
+            Delay::new(Duration::from_millis(5)).await;
+            done.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    drop(spawner);
+    executor.run();
+
+    assert_eq!(done.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,80 @@
+/*
+ * What each ordering does and doesn't promise, run many times over.
+ *
+ * Relaxed keeps SequenceCounter's numbers unique. Release/Acquire is
+ * enough to hand data from one thread to another. Only SeqCst rules out
+ * both threads missing each other's flag in seq_cst_flags. The same
+ * code with Release/Acquire is allowed to give (false, false), but
+ * whether it ever does depends on the hardware, so no test here can
+ * check it either way.
+ * */
+use rust_exercises::extras::atomics::{seq_cst_flags, SequenceCounter};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+
+#[test]
+fn sequence_numbers_are_unique_across_threads() {
+    let counter = SequenceCounter::new();
+    let threads = 8;
+    let per_thread = 10_000;
+
+    let mut all: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| s.spawn(|| (0..per_thread).map(|_| counter.next()).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    });
+
+    // Relaxed still hands every number out exactly once
+    all.sort_unstable();
+    assert_eq!(all, (0..threads * per_thread).collect::<Vec<_>>());
+    assert_eq!(counter.current(), threads * per_thread);
+}
+
+#[test]
+fn each_thread_sees_its_own_numbers_increase() {
+    let counter = SequenceCounter::default();
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                let mut last = None;
+                for _ in 0..10_000 {
+                    let n = counter.next();
+                    assert!(last.is_none_or(|last| n > last));
+                    last = Some(n);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn seq_cst_never_misses_both_flags() {
+    for _ in 0..2_000 {
+        assert_ne!(seq_cst_flags(), (false, false));
+    }
+}
+
+#[test]
+fn release_acquire_hands_data_over() {
+    for _ in 0..500 {
+        let data = AtomicUsize::new(0);
+        let ready = AtomicBool::new(false);
+        thread::scope(|s| {
+            s.spawn(|| {
+                // Relaxed on its own, published by the Release below
+                data.store(42, Ordering::Relaxed);
+                ready.store(true, Ordering::Release);
+            });
+            s.spawn(|| {
+                while !ready.load(Ordering::Acquire) {
+                    std::hint::spin_loop();
+                }
+                assert_eq!(data.load(Ordering::Relaxed), 42);
+            });
+        });
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * extras::base64: the RFC 4648 test vectors both ways, bad input, and
+ * random byte strings that have to survive encode then decode.
+ * TEST_SEED=<n> repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::base64::{decode, encode, DecodeError};
+use rust_exercises::extras::rng::Rng;
+
+// https://datatracker.ietf.org/doc/html/rfc4648#section-10
+const VECTORS: &[(&str, &str)] = &[
+    ("", ""),
+    ("f", "Zg=="),
+    ("fo", "Zm8="),
+    ("foo", "Zm9v"),
+    ("foob", "Zm9vYg=="),
+    ("fooba", "Zm9vYmE="),
+    ("foobar", "Zm9vYmFy"),
+];
+
+#[test]
+fn rfc_4648_vectors() {
+    for &(plain, encoded) in VECTORS {
+        assert_eq!(encode(plain.as_bytes()), encoded);
+        assert_eq!(decode(encoded).unwrap(), plain.as_bytes(), "{}", encoded);
+    }
+}
+
+#[test]
+fn the_whole_alphabet() {
+    let all: Vec<u8> = (0..=255).collect();
+    let encoded = encode(&all);
+    assert!(encoded.starts_with("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8g"));
+    assert!(encoded.ends_with("+fr7/P3+/w=="));
+    assert_eq!(decode(&encoded).unwrap(), all);
+}
+
+#[test]
+fn invalid_input() {
+    assert_eq!(decode("Zm9"), Err(DecodeError::InvalidLength(3)));
+    assert_eq!(
+        decode("Zm9v!A=="),
+        Err(DecodeError::InvalidChar { pos: 4, ch: '!' })
+    );
+    assert_eq!(
+        decode("Zmé="),
+        Err(DecodeError::InvalidChar { pos: 2, ch: 'é' })
+    );
+    assert_eq!(decode("Zg==Zm9v"), Err(DecodeError::InvalidPadding(2)));
+    assert_eq!(decode("Z==="), Err(DecodeError::InvalidPadding(1)));
+    assert_eq!(decode("Z=g="), Err(DecodeError::InvalidPadding(1)));
+    assert_eq!(decode("===="), Err(DecodeError::InvalidPadding(0)));
+}
+
+#[test]
+fn random_round_trips() {
+    let mut rng = rng();
+    for _ in 0..500 {
+        let len = rng.gen_range(0..200) as usize;
+        let bytes: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+        let encoded = encode(&bytes);
+        assert_eq!(encoded.len(), len.div_ceil(3) * 4);
+        assert_eq!(decode(&encoded).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * extras::bf: the Wikipedia "Hello World!", bracket checks before
+ * anything runs, input through impl Read and a failing impl Write
+ * */
+use rust_exercises::extras::bf::{self, BfError, Instruction, Interpreter};
+use std::io::{self, Write};
+
+const HELLO_WORLD: &str = "++++++++[>++++[>++>+++>+++>+<<<<-]>+>+>->>+[<]<-]>>.>---.+++++++..+++.>>.<-.<.+++.------.--------.>>+.>++.";
+
+#[test]
+fn hello_world() {
+    let interpreter = Interpreter::new(HELLO_WORLD).unwrap();
+    assert_eq!(interpreter.run_to_string(b"").unwrap(), "Hello World!\n");
+}
+
+#[test]
+fn comments_are_skipped() {
+    let program = bf::parse("add one: + then [print .] it .").unwrap();
+    assert_eq!(
+        program,
+        [
+            Instruction::Increment,
+            Instruction::JumpIfZero(3),
+            Instruction::Output,
+            Instruction::JumpUnlessZero(1),
+            Instruction::Output
+        ]
+    );
+}
+
+#[test]
+fn unbalanced_brackets_fail_up_front() {
+    assert!(matches!(
+        bf::parse("+[.[-]"),
+        Err(BfError::UnmatchedOpen(1))
+    ));
+    assert!(matches!(bf::parse("+].["), Err(BfError::UnmatchedClose(1))));
+    // The '.' before the bad bracket never printed anything
+    assert!(Interpreter::new(".]").is_err());
+}
+
+#[test]
+fn cat_until_end_of_input() {
+    let cat = Interpreter::new(",[.,]").unwrap();
+    assert_eq!(cat.run_to_string(b"echo me").unwrap(), "echo me");
+    assert_eq!(cat.run_to_string(b"").unwrap(), "");
+}
+
+#[test]
+fn cells_wrap_and_the_tape_grows() {
+    // 0 - 1 wraps to 255, one cell far to the right is still 0
+    let mut output = Vec::new();
+    Interpreter::new("-.>>>>>>>>>>.")
+        .unwrap()
+        .run(&b""[..], &mut output)
+        .unwrap();
+    assert_eq!(output, [255, 0]);
+    assert!(matches!(
+        Interpreter::new("<").unwrap().run_to_string(b""),
+        Err(BfError::TapeUnderflow)
+    ));
+}
+
+struct Broken;
+
+impl Write for Broken {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn output_errors_stop_the_program() {
+    let error = Interpreter::new(HELLO_WORLD)
+        .unwrap()
+        .run(&b""[..], Broken)
+        .unwrap_err();
+    assert!(matches!(error, BfError::Io(ref e) if e.kind() == io::ErrorKind::BrokenPipe));
+}
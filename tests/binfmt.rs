@@ -0,0 +1,236 @@
+/*
+ * The record format written and read back, then cut at every byte and
+ * with every bit flipped, which must always come back as an error naming
+ * the right record, never as a panic or wrong data
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::binfmt::{
+    record_len, FormatError, Reader, Writer, HEADER_LEN, MAGIC, MAX_RECORD_LEN, VERSION,
+};
+use rust_exercises::extras::rng::Rng;
+use std::io::{self, Read};
+
+const PAYLOADS: [&[u8]; 4] = [b"first", b"", b"third record", &[0, 255, 10, 13]];
+
+fn file(payloads: &[&[u8]]) -> Vec<u8> {
+    let mut writer = Writer::new(Vec::new()).unwrap();
+    for payload in payloads {
+        writer.write_record(payload).unwrap();
+    }
+    assert_eq!(writer.records(), payloads.len());
+    writer.finish().unwrap()
+}
+
+fn read_all(bytes: &[u8]) -> Result<Vec<Vec<u8>>, FormatError> {
+    Reader::new(bytes)?.collect()
+}
+
+// Which record each byte of file(PAYLOADS) belongs to, None for the header
+fn record_at(offset: usize) -> Option<usize> {
+    let mut end = HEADER_LEN as usize;
+    if offset < end {
+        return None;
+    }
+    for (record, payload) in PAYLOADS.iter().enumerate() {
+        end += record_len(payload.len()) as usize;
+        if offset < end {
+            return Some(record);
+        }
+    }
+    panic!("offset {} is past the end", offset);
+}
+
+#[test]
+fn round_trip() {
+    let bytes = file(&PAYLOADS);
+    let lens: u64 = PAYLOADS.iter().map(|p| record_len(p.len())).sum();
+    assert_eq!(bytes.len() as u64, HEADER_LEN + lens);
+    assert_eq!(&bytes[..4], &MAGIC);
+
+    let reader = Reader::new(&bytes[..]).unwrap();
+    assert_eq!(reader.version(), VERSION);
+    assert_eq!(read_all(&bytes).unwrap(), PAYLOADS);
+
+    // Only a header is an empty file, not a broken one
+    assert_eq!(read_all(&file(&[])).unwrap(), Vec::<Vec<u8>>::new());
+}
+
+#[test]
+fn append_continues_a_file() {
+    let mut bytes = file(&PAYLOADS[..2]);
+    let mut writer = Writer::append(&mut bytes);
+    writer.write_record(PAYLOADS[2]).unwrap();
+    writer.write_record(PAYLOADS[3]).unwrap();
+    writer.finish().unwrap();
+    assert_eq!(read_all(&bytes).unwrap(), PAYLOADS);
+}
+
+#[test]
+fn random_round_trip() {
+    let mut rng = rng();
+    for _ in 0..50 {
+        let payloads: Vec<Vec<u8>> = (0..rng.gen_range(0..20))
+            .map(|_| {
+                (0..rng.gen_range(0..300))
+                    .map(|_| rng.next_u64() as u8)
+                    .collect()
+            })
+            .collect();
+        let refs: Vec<&[u8]> = payloads.iter().map(|p| p.as_slice()).collect();
+        assert_eq!(read_all(&file(&refs)).unwrap(), payloads);
+    }
+}
+
+#[test]
+fn bad_header() {
+    let bytes = file(&PAYLOADS);
+
+    let mut other = bytes.clone();
+    other[..4].copy_from_slice(b"PK\x03\x04");
+    assert!(matches!(
+        read_all(&other),
+        Err(FormatError::BadMagic(magic)) if &magic == b"PK\x03\x04"
+    ));
+
+    let mut newer = bytes.clone();
+    newer[4..6].copy_from_slice(&2u16.to_le_bytes());
+    assert!(matches!(
+        read_all(&newer),
+        Err(FormatError::UnsupportedVersion(2))
+    ));
+
+    for cut in 0..HEADER_LEN as usize {
+        assert!(matches!(
+            read_all(&bytes[..cut]),
+            Err(FormatError::BadMagic(_))
+        ));
+    }
+}
+
+#[test]
+fn truncated_at_every_byte() {
+    let bytes = file(&PAYLOADS);
+    let mut boundaries = vec![HEADER_LEN as usize];
+    for payload in PAYLOADS.iter() {
+        let last = *boundaries.last().unwrap();
+        boundaries.push(last + record_len(payload.len()) as usize);
+    }
+
+    for cut in HEADER_LEN as usize..bytes.len() {
+        let result = read_all(&bytes[..cut]);
+        if boundaries.contains(&cut) {
+            // Between two records: just fewer of them
+            let complete = boundaries.iter().position(|&b| b == cut).unwrap();
+            assert_eq!(result.unwrap(), &PAYLOADS[..complete], "cut at {}", cut);
+        } else {
+            let record = record_at(cut).unwrap();
+            match result {
+                Err(FormatError::Truncated { record: r }) => {
+                    assert_eq!(r, record, "cut at {}", cut)
+                }
+                other => panic!("cut at {}: {:?}", cut, other),
+            }
+        }
+    }
+}
+
+#[test]
+fn truncated_keeps_the_records_before() {
+    let bytes = file(&PAYLOADS);
+    let cut = bytes.len() - 2;
+    let mut reader = Reader::new(&bytes[..cut]).unwrap();
+    for payload in &PAYLOADS[..3] {
+        assert_eq!(reader.next().unwrap().unwrap(), *payload);
+    }
+    assert!(matches!(
+        reader.next(),
+        Some(Err(FormatError::Truncated { record: 3 }))
+    ));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn every_bit_flipped() {
+    let bytes = file(&PAYLOADS);
+    for offset in HEADER_LEN as usize..bytes.len() {
+        for bit in 0..8 {
+            let mut corrupted = bytes.clone();
+            corrupted[offset] ^= 1 << bit;
+            let record = record_at(offset).unwrap();
+            match read_all(&corrupted) {
+                // The payload or its checksum changed
+                Err(FormatError::ChecksumMismatch { record: r, .. }) => assert_eq!(r, record),
+                // The length changed: it now runs past the end, claims too
+                // much, or ends somewhere the checksum doesn't match
+                Err(FormatError::Truncated { record: r })
+                | Err(FormatError::TooLarge { record: r, .. }) => {
+                    assert_eq!(r, record)
+                }
+                other => panic!("bit {} of byte {}: {:?}", bit, offset, other),
+            }
+        }
+    }
+}
+
+#[test]
+fn huge_length_is_refused_without_allocating() {
+    let mut bytes = file(&[]);
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    assert!(matches!(
+        read_all(&bytes),
+        Err(FormatError::TooLarge {
+            record: 0,
+            len: u32::MAX
+        })
+    ));
+
+    let mut bytes = file(&[b"ok"]);
+    bytes.extend_from_slice(&(MAX_RECORD_LEN + 1).to_le_bytes());
+    assert!(matches!(
+        read_all(&bytes),
+        Err(FormatError::TooLarge { record: 1, .. })
+    ));
+
+    let too_large = vec![0; MAX_RECORD_LEN as usize + 1];
+    let mut writer = Writer::new(Vec::new()).unwrap();
+    let e = writer.write_record(&too_large).unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(writer.records(), 0);
+}
+
+// Hands out one byte per read() and fails now and then with Interrupted
+struct Trickle<'a> {
+    bytes: &'a [u8],
+    calls: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.calls += 1;
+        if self.calls.is_multiple_of(3) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        match self.bytes.split_first() {
+            Some((&first, rest)) if !buf.is_empty() => {
+                buf[0] = first;
+                self.bytes = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn short_reads() {
+    let bytes = file(&PAYLOADS);
+    let reader = Reader::new(Trickle {
+        bytes: &bytes,
+        calls: 0,
+    })
+    .unwrap();
+    let read: Result<Vec<_>, _> = reader.collect();
+    assert_eq!(read.unwrap(), PAYLOADS);
+}
@@ -0,0 +1,86 @@
+/*
+ * BitSet around the word boundaries, where an off-by-one in i / 64 or
+ * i % 64 shows, and a flags! type used from outside the crate
+ * */
+use rust_exercises::extras::bitset::BitSet;
+use rust_exercises::flags;
+
+flags! {
+    pub struct Permissions: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+#[test]
+fn set_clear_test_across_words() {
+    let mut set = BitSet::new();
+    for bit in [0, 63, 64, 65, 127, 128] {
+        assert!(!set.test(bit));
+        assert!(!set.set(bit));
+        assert!(set.test(bit));
+    }
+    // Setting twice reports it was already there
+    assert!(set.set(64));
+    assert_eq!(set.len(), 6);
+    assert!(!set.test(62) && !set.test(66) && !set.test(10_000));
+
+    assert!(set.clear(64));
+    assert!(!set.clear(64));
+    assert!(!set.clear(10_000));
+    assert!(set.test(63) && !set.test(64) && set.test(65));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 65, 127, 128]);
+}
+
+#[test]
+fn iterator_skips_empty_words() {
+    let set: BitSet = vec![1000, 3, 64].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [3, 64, 1000]);
+    assert_eq!(format!("{:?}", set), "{3, 64, 1000}");
+
+    let mut set = BitSet::with_capacity(256);
+    assert_eq!(set.iter().next(), None);
+    assert!(set.is_empty());
+    set.set(255);
+    assert_eq!((&set).into_iter().collect::<Vec<_>>(), [255]);
+}
+
+#[test]
+fn union_and_intersection() {
+    let small: BitSet = vec![1, 63, 64].into_iter().collect();
+    let large: BitSet = vec![63, 64, 200].into_iter().collect();
+
+    let union = &small | &large;
+    assert_eq!(union.iter().collect::<Vec<_>>(), [1, 63, 64, 200]);
+    let intersection = &small & &large;
+    assert_eq!(intersection.iter().collect::<Vec<_>>(), [63, 64]);
+    // Operands are borrowed, still usable
+    assert_eq!(&large & &small, intersection);
+
+    // Trailing empty words don't make sets different
+    let mut grown = BitSet::new();
+    grown.set(500);
+    grown.clear(500);
+    assert_eq!(grown, BitSet::new());
+}
+
+#[test]
+fn flags_macro() {
+    let mut permissions = Permissions::READ | Permissions::WRITE;
+    assert!(permissions.contains(Permissions::READ));
+    assert!(!permissions.contains(Permissions::READ | Permissions::EXECUTE));
+    assert_eq!(permissions.bits(), 0b011);
+    assert_eq!(format!("{:?}", permissions), "Permissions(READ | WRITE)");
+
+    permissions.remove(Permissions::READ);
+    permissions |= Permissions::EXECUTE;
+    assert_eq!(permissions, Permissions::WRITE | Permissions::EXECUTE);
+    assert_eq!(permissions & Permissions::WRITE, Permissions::WRITE);
+
+    assert_eq!(Permissions::all().bits(), 0b111);
+    assert!(Permissions::empty().is_empty());
+    assert_eq!(format!("{:?}", Permissions::empty()), "Permissions(empty)");
+    assert_eq!(Permissions::from_bits(0b1000), None);
+    assert_eq!(Permissions::from_bits_truncate(0b1001), Permissions::READ);
+}
@@ -0,0 +1,118 @@
+/*
+ * extras::bounded_queue: first in first out, and push/pop block until
+ * there's room or something to take. "Still blocked" is checked by
+ * waiting a little and seeing that nothing came out, "unblocked" with a
+ * long timeout so a slow machine doesn't fail it.
+ * */
+use rust_exercises::extras::bounded_queue::BoundedQueue;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const STILL_BLOCKED: Duration = Duration::from_millis(100);
+const UNBLOCKED: Duration = Duration::from_secs(5);
+
+#[test]
+fn first_in_first_out() {
+    let queue = BoundedQueue::new(3);
+    for i in 0..3 {
+        queue.push(i);
+    }
+    assert_eq!(queue.try_push(3), Err(3));
+    assert_eq!((queue.len(), queue.capacity()), (3, 3));
+    assert_eq!(queue.pop(), 0);
+    queue.push(3);
+    let popped: Vec<_> = (0..3).map(|_| queue.pop()).collect();
+    assert_eq!(popped, [1, 2, 3]);
+    assert_eq!(queue.try_pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "capacity must be at least 1")]
+fn zero_capacity_is_refused() {
+    BoundedQueue::<u8>::new(0);
+}
+
+#[test]
+fn push_blocks_while_full() {
+    let queue = Arc::new(BoundedQueue::new(1));
+    queue.push("first");
+    let (done, pushed) = mpsc::channel();
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            queue.push("second");
+            done.send(()).unwrap();
+        })
+    };
+
+    assert!(pushed.recv_timeout(STILL_BLOCKED).is_err());
+    assert_eq!(queue.pop(), "first");
+    pushed.recv_timeout(UNBLOCKED).unwrap();
+    producer.join().unwrap();
+    assert_eq!(queue.try_pop(), Some("second"));
+}
+
+#[test]
+fn pop_blocks_while_empty() {
+    let queue = Arc::new(BoundedQueue::new(2));
+    let (done, popped) = mpsc::channel();
+    let consumer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || done.send(queue.pop()).unwrap())
+    };
+
+    assert!(popped.recv_timeout(STILL_BLOCKED).is_err());
+    queue.push(42);
+    assert_eq!(popped.recv_timeout(UNBLOCKED), Ok(42));
+    consumer.join().unwrap();
+}
+
+#[test]
+fn producers_and_consumers() {
+    const PRODUCERS: usize = 4;
+    const ITEMS: usize = 1000;
+    let queue = Arc::new(BoundedQueue::new(8));
+
+    let producers: Vec<_> = (0..PRODUCERS)
+        .map(|producer| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 0..ITEMS {
+                    queue.push((producer, i));
+                }
+            })
+        })
+        .collect();
+    let consumers: Vec<_> = (0..2)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                (0..PRODUCERS * ITEMS / 2)
+                    .map(|_| queue.pop())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    let mut seen = vec![Vec::new(); PRODUCERS];
+    for consumer in consumers {
+        let mut last = [None; PRODUCERS];
+        for (producer, i) in consumer.join().unwrap() {
+            // What one producer pushed comes out in the same order
+            assert!(last[producer] < Some(i));
+            last[producer] = Some(i);
+            seen[producer].push(i);
+        }
+    }
+    for items in seen.iter_mut() {
+        items.sort_unstable();
+        assert_eq!(*items, (0..ITEMS).collect::<Vec<_>>());
+    }
+    assert!(queue.is_empty());
+}
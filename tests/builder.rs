@@ -0,0 +1,177 @@
+/*
+ * HttpRequestBuilder's defaults, every setting overriding its default,
+ * each way build() refuses, and the typestate builder agreeing with it
+ * */
+use rust_exercises::extras::builder::*;
+use std::time::Duration;
+
+const URL: &str = "https://example.com/api";
+
+#[test]
+fn defaults() {
+    let request = HttpRequest::builder().url(URL).build().unwrap();
+    assert_eq!(request.method(), Method::Get);
+    assert_eq!(request.url(), URL);
+    assert!(request.headers().is_empty());
+    assert_eq!(request.body(), None);
+    assert_eq!(request.timeout(), DEFAULT_TIMEOUT);
+    assert_eq!(DEFAULT_TIMEOUT, Duration::from_secs(30));
+
+    // Every way to get a builder starts from the same defaults
+    assert_eq!(
+        HttpRequestBuilder::default().url(URL).build(),
+        Ok(request.clone())
+    );
+    assert_eq!(HttpRequestBuilder::new().url(URL).build(), Ok(request));
+}
+
+#[test]
+fn overrides() {
+    let request = HttpRequest::builder()
+        .method(Method::Post)
+        .url("http://localhost:8080")
+        .header("Content-Type", "application/json")
+        .header("Accept", "*/*")
+        .body("{}")
+        .timeout(Duration::from_millis(1500))
+        .build()
+        .unwrap();
+
+    assert_eq!(request.method(), Method::Post);
+    assert_eq!(request.url(), "http://localhost:8080");
+    assert_eq!(
+        request.headers(),
+        [
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Accept".to_string(), "*/*".to_string()),
+        ]
+    );
+    assert_eq!(request.body(), Some("{}"));
+    assert_eq!(request.timeout(), Duration::from_millis(1500));
+}
+
+#[test]
+fn later_calls_replace_earlier_ones() {
+    let request = HttpRequest::builder()
+        .url("https://a.example")
+        .url(URL)
+        .method(Method::Post)
+        .method(Method::Put)
+        .body("first")
+        .body("second")
+        .timeout(Duration::from_secs(0))
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    assert_eq!(request.url(), URL);
+    assert_eq!(request.method(), Method::Put);
+    assert_eq!(request.body(), Some("second"));
+    assert_eq!(request.timeout(), Duration::from_secs(5));
+}
+
+#[test]
+fn headers_are_kept_in_order_and_looked_up_by_any_case() {
+    let request = HttpRequest::builder()
+        .url(URL)
+        .header("X-Id", "1")
+        .header("x-id", "2")
+        .build()
+        .unwrap();
+    assert_eq!(request.headers().len(), 2);
+    assert_eq!(request.header("X-ID"), Some("1"));
+    assert_eq!(request.header("x-other"), None);
+}
+
+#[test]
+fn missing_and_invalid_fields() {
+    assert_eq!(HttpRequest::builder().build(), Err(BuildError::MissingUrl));
+    // The url is checked first, whatever else is wrong
+    assert_eq!(
+        HttpRequest::builder()
+            .body("x")
+            .timeout(Duration::from_secs(0))
+            .build(),
+        Err(BuildError::MissingUrl)
+    );
+
+    for url in ["", "example.com", "ftp://example.com", "HTTP://example.com"] {
+        assert_eq!(
+            HttpRequest::builder().url(url).build(),
+            Err(BuildError::InvalidUrl(url.to_string())),
+            "{}",
+            url
+        );
+    }
+
+    for &method in [Method::Get, Method::Delete].iter() {
+        let built = HttpRequest::builder()
+            .method(method)
+            .url(URL)
+            .body("x")
+            .build();
+        assert_eq!(built, Err(BuildError::BodyNotAllowed(method)));
+    }
+    for &method in [Method::Post, Method::Put].iter() {
+        assert!(HttpRequest::builder()
+            .method(method)
+            .url(URL)
+            .body("x")
+            .build()
+            .is_ok());
+    }
+
+    assert_eq!(
+        HttpRequest::builder()
+            .url(URL)
+            .timeout(Duration::from_secs(0))
+            .build(),
+        Err(BuildError::ZeroTimeout)
+    );
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(BuildError::MissingUrl.to_string(), "a url is required");
+    assert_eq!(
+        BuildError::InvalidUrl("x".to_string()).to_string(),
+        "'x' is not an http:// or https:// url"
+    );
+    assert_eq!(
+        BuildError::BodyNotAllowed(Method::Get).to_string(),
+        "Get requests have no body"
+    );
+    assert_eq!(
+        BuildError::ZeroTimeout.to_string(),
+        "the timeout must be greater than zero"
+    );
+}
+
+#[test]
+fn typed_builder_builds_the_same_requests() {
+    let typed = TypedRequestBuilder::new()
+        .method(Method::Post)
+        .header("A", "1")
+        .url(URL)
+        .body("b")
+        .timeout(Duration::from_secs(2))
+        .build();
+    let plain = HttpRequest::builder()
+        .method(Method::Post)
+        .header("A", "1")
+        .url(URL)
+        .body("b")
+        .timeout(Duration::from_secs(2))
+        .build();
+    assert_eq!(typed, plain);
+
+    assert_eq!(
+        TypedRequestBuilder::default().url(URL).build(),
+        HttpRequest::builder().url(URL).build()
+    );
+    // Everything but the url is still checked when building
+    assert_eq!(
+        TypedRequestBuilder::new().url(URL).body("x").build(),
+        Err(BuildError::BodyNotAllowed(Method::Get))
+    );
+}
@@ -0,0 +1,135 @@
+/*
+ * extras::calc: precedence and associativity, unary minus, malformed
+ * input with where it went wrong, and the MAX_DEPTH limit
+ * */
+use rust_exercises::extras::calc::{self, calc, CalcError, Expr, Token, MAX_DEPTH};
+
+#[test]
+fn precedence_and_associativity() {
+    let cases = [
+        ("1 + 2 * 3", 7.0),
+        ("(1 + 2) * 3", 9.0),
+        ("10 - 4 - 3", 3.0),
+        ("48 / 4 / 2", 6.0),
+        ("2 * 3 + 4 * 5", 26.0),
+        ("8 - 2 * 3 + 1", 3.0),
+        ("1.5 * 4", 6.0),
+        ("((7))", 7.0),
+    ];
+    for &(input, expected) in cases.iter() {
+        assert_eq!(calc(input), Ok(expected), "{}", input);
+    }
+}
+
+#[test]
+fn unary_minus() {
+    assert_eq!(calc("-3"), Ok(-3.0));
+    assert_eq!(calc("--3"), Ok(3.0));
+    assert_eq!(calc("2 * -3"), Ok(-6.0));
+    assert_eq!(calc("-(2 + 3) * 2"), Ok(-10.0));
+    assert_eq!(calc("1 - -1"), Ok(2.0));
+}
+
+#[test]
+fn parse_builds_the_tree() {
+    let number = |n| Box::new(Expr::Number(n));
+    assert_eq!(
+        calc::parse("1 - 2 * 3").unwrap(),
+        Expr::Sub(number(1.0), Box::new(Expr::Mul(number(2.0), number(3.0))))
+    );
+}
+
+#[test]
+fn malformed_input() {
+    assert_eq!(
+        calc("1 + x"),
+        Err(CalcError::UnexpectedChar { pos: 4, ch: 'x' })
+    );
+    assert_eq!(calc("1 +"), Err(CalcError::UnexpectedEnd));
+    assert_eq!(calc(""), Err(CalcError::UnexpectedEnd));
+    assert_eq!(calc("(1 + 2"), Err(CalcError::UnclosedParen { pos: 0 }));
+    assert_eq!(
+        calc("1)"),
+        Err(CalcError::UnexpectedToken {
+            pos: 1,
+            token: Token::RParen
+        })
+    );
+    assert_eq!(
+        calc("* 2"),
+        Err(CalcError::UnexpectedToken {
+            pos: 0,
+            token: Token::Star
+        })
+    );
+    assert!(matches!(
+        calc("1.2.3"),
+        Err(CalcError::InvalidNumber { pos: 0, .. })
+    ));
+    assert_eq!(calc("1 / (2 - 2)"), Err(CalcError::DivisionByZero));
+}
+
+#[test]
+fn errors_say_where() {
+    assert_eq!(
+        calc("2 * (3 + ?)").unwrap_err().to_string(),
+        "unexpected character '?' at 9"
+    );
+    assert_eq!(
+        calc("(1").unwrap_err().to_string(),
+        "unclosed '(' opened at 0"
+    );
+}
+
+#[test]
+fn max_depth() {
+    let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+    assert_eq!(calc(&nested(MAX_DEPTH - 1)), Ok(1.0));
+    assert!(matches!(
+        calc(&nested(MAX_DEPTH + 1)),
+        Err(CalcError::TooDeep { .. })
+    ));
+
+    let negated = format!("{}1", "-".repeat(MAX_DEPTH + 1));
+    assert!(matches!(calc(&negated), Err(CalcError::TooDeep { .. })));
+
+    // A huge input doesn't overflow the stack while failing
+    let huge = "(".repeat(100_000);
+    assert!(matches!(calc(&huge), Err(CalcError::TooDeep { .. })));
+}
+
+#[test]
+fn long_flat_chains_are_not_nested() {
+    // Far more operators than MAX_DEPTH, evaluated and dropped without
+    // recursing once per operator
+    let terms = 100_000;
+    let sum = vec!["1"; terms].join("+");
+    assert_eq!(calc(&sum), Ok(terms as f64));
+    let difference = vec!["1"; terms].join(" - ");
+    assert_eq!(calc(&difference), Ok(2.0 - terms as f64));
+    let product = vec!["1"; terms].join("*");
+    assert_eq!(calc(&product), Ok(1.0));
+    let mixed = vec!["2*3/2"; terms].join("+");
+    assert_eq!(calc(&mixed), Ok(3.0 * terms as f64));
+    assert!(calc::parse(&sum).is_ok());
+
+    // Just past MAX_DEPTH of real nesting still fails, chains or not
+    let nested = format!(
+        "{}{}{}",
+        "(1+".repeat(MAX_DEPTH + 1),
+        sum,
+        ")".repeat(MAX_DEPTH + 1)
+    );
+    assert!(matches!(calc(&nested), Err(CalcError::TooDeep { .. })));
+    let nested = format!(
+        "{}{}{}",
+        "(1+".repeat(MAX_DEPTH),
+        sum,
+        ")".repeat(MAX_DEPTH)
+    );
+    assert_eq!(calc(&nested), Ok((terms + MAX_DEPTH) as f64));
+    assert_eq!(
+        calc(&format!("{}/0+1", sum)),
+        Err(CalcError::DivisionByZero)
+    );
+}
@@ -0,0 +1,117 @@
+/*
+ * extras::channel next to std::sync::mpsc: the same scenarios are run on
+ * both and have to give the same results. Each scenario is written once,
+ * in a module the macro stamps out for either channel.
+ * */
+use std::time::Duration;
+
+// Long enough for the receiving thread to be blocked in recv() by then
+const BLOCK: Duration = Duration::from_millis(50);
+
+macro_rules! scenarios {
+    ($name:ident, $($channel:ident)::+) => {
+        mod $name {
+            use super::BLOCK;
+            use std::thread;
+            use $($channel)::+::channel;
+
+            pub fn fifo() -> Vec<(usize, usize)> {
+                let (sender, receiver) = channel();
+                for i in 0..100 {
+                    sender.send((0, i)).unwrap();
+                }
+                let threads: Vec<_> = (1..4)
+                    .map(|producer| {
+                        let sender = sender.clone();
+                        thread::spawn(move || {
+                            for i in 0..1000 {
+                                sender.send((producer, i)).unwrap();
+                            }
+                        })
+                    })
+                    .collect();
+                drop(sender);
+
+                let received: Vec<_> = receiver.iter().collect();
+                for thread in threads {
+                    thread.join().unwrap();
+                }
+                // Interleaved differently every run, in order per producer
+                let mut next = [0; 4];
+                for &(producer, i) in received.iter() {
+                    assert_eq!(i, next[producer]);
+                    next[producer] += 1;
+                }
+                assert_eq!(next, [100, 1000, 1000, 1000]);
+                received.into_iter().take(100).collect()
+            }
+
+            pub fn disconnect() -> Vec<String> {
+                let mut events = Vec::new();
+                let (sender, receiver) = channel();
+                let second = sender.clone();
+                drop(sender);
+                events.push(format!("{:?}", receiver.try_recv()));
+                second.send("sent before the drop").unwrap();
+                drop(second);
+                events.push(format!("{:?}", receiver.recv()));
+                events.push(format!("{:?}", receiver.recv().is_err()));
+                events.push(format!("{:?}", receiver.try_recv()));
+
+                // A blocked recv() wakes up when the last sender goes
+                let (sender, receiver) = channel::<u8>();
+                let waiting = thread::spawn(move || receiver.recv().is_err());
+                thread::sleep(BLOCK);
+                drop(sender);
+                events.push(format!("{:?}", waiting.join().unwrap()));
+                events
+            }
+
+            pub fn receiver_gone() -> Vec<Result<(), String>> {
+                let (sender, receiver) = channel();
+                let other = sender.clone();
+                sender.send(String::from("queued")).unwrap();
+                drop(receiver);
+                vec![
+                    sender.send(String::from("first")).map_err(|e| e.0),
+                    other.send(String::from("second")).map_err(|e| e.0),
+                ]
+            }
+        }
+    };
+}
+
+scenarios!(ours, rust_exercises::extras::channel);
+scenarios!(theirs, std::sync::mpsc);
+
+#[test]
+fn first_in_first_out() {
+    assert_eq!(ours::fifo(), theirs::fifo());
+    assert_eq!(ours::fifo(), (0..100).map(|i| (0, i)).collect::<Vec<_>>());
+}
+
+#[test]
+fn disconnects_when_the_last_sender_drops() {
+    let events = ours::disconnect();
+    assert_eq!(events, theirs::disconnect());
+    assert_eq!(
+        events,
+        [
+            "Err(Empty)",
+            "Ok(\"sent before the drop\")",
+            "true",
+            "Err(Disconnected)",
+            "true"
+        ]
+    );
+}
+
+#[test]
+fn send_fails_once_the_receiver_is_gone() {
+    let results = ours::receiver_gone();
+    assert_eq!(results, theirs::receiver_gone());
+    assert_eq!(
+        results,
+        [Err("first".to_string()), Err("second".to_string())]
+    );
+}
@@ -0,0 +1,199 @@
+/*
+ * Runs the binaries the way a reader would and checks what they print,
+ * so moving code between the binaries and the library can't quietly
+ * break a demo. Cargo builds every binary before these tests and tells
+ * us where they are through CARGO_BIN_EXE_<name>.
+ *
+ * Expected outputs are snapshots in tests/snapshots, see snapshot/mod.rs
+ * */
+mod common;
+mod snapshot;
+
+#[cfg(unix)]
+use common::cdylib;
+use common::{fixture, fixture_path, stdout, temp_tree};
+#[cfg(feature = "async")]
+use rust_exercises::chapters::async_executor;
+use rust_exercises::chapters::CATALOG;
+use snapshot::assert_snapshot;
+#[cfg(all(unix, feature = "async-rt"))]
+use std::io::{BufRead, BufReader, Write};
+#[cfg(all(unix, feature = "async-rt"))]
+use std::net::TcpStream;
+#[cfg(all(unix, feature = "async-rt"))]
+use std::process::{Command, Stdio};
+#[cfg(feature = "async")]
+use std::time::Duration;
+
+#[cfg(feature = "ch10")]
+#[test]
+fn chapter_10_largest() {
+    let output = stdout(env!("CARGO_BIN_EXE_exercises"), &[], b"");
+    assert_snapshot("chapter_10", &output);
+}
+
+#[test]
+fn hexdump_matches_xxd() {
+    let input: Vec<u8> = (0..=40).collect();
+    let output = stdout(env!("CARGO_BIN_EXE_hexdump"), &[], &input);
+    assert_snapshot("hexdump", &output);
+}
+
+#[test]
+fn wc_counts_stdin() {
+    let output = stdout(env!("CARGO_BIN_EXE_wc"), &[], &fixture("wc_input.txt"));
+    assert_snapshot("wc", &output);
+}
+
+#[test]
+fn wc_counts_bytes_not_chars() {
+    let output = stdout(
+        env!("CARGO_BIN_EXE_wc"),
+        &[],
+        &fixture("wc_multibyte.txt"),
+    );
+    assert_snapshot("wc_multibyte", &output);
+}
+
+#[test]
+fn wc_totals_several_files() {
+    let ascii = fixture_path("wc_input.txt");
+    let multibyte = fixture_path("wc_multibyte.txt");
+    let (ascii, multibyte) = (ascii.to_str().unwrap(), multibyte.to_str().unwrap());
+
+    // The paths are absolute, so this one can't be a snapshot
+    let output = stdout(env!("CARGO_BIN_EXE_wc"), &[ascii, multibyte], b"");
+    assert_eq!(
+        output,
+        format!(
+            "      2       3      16 {}\n      4      16     108 {}\n      6      19     124 total\n",
+            ascii, multibyte
+        )
+    );
+}
+
+#[test]
+fn filter_chain() {
+    let args = ["-d", ":", "-f", "1,7", "-v", "nologin", "-u"];
+    let output = stdout(env!("CARGO_BIN_EXE_filter"), &args, &fixture("passwd"));
+    assert_snapshot("filter", &output);
+}
+
+#[test]
+fn par_wc_top_words() {
+    let dir = temp_tree(
+        "par_wc",
+        &[("a.txt", "the cat and the hat"), ("nested/b.txt", "The end")],
+    );
+
+    let root = dir.path().to_str().unwrap();
+    let output = stdout(env!("CARGO_BIN_EXE_par-wc"), &[root, "--top", "2"], b"");
+    assert_snapshot("par_wc", &output);
+}
+
+#[test]
+fn quiz_right_and_wrong_guesses() {
+    // shadowing guessed right, string_length counted in chars and wrong
+    let output = stdout(
+        env!("CARGO_BIN_EXE_quiz"),
+        &["shadowing", "string_length"],
+        b"12\n6\n\n5 5\n\n",
+    );
+    assert_snapshot("quiz", &output);
+}
+
+#[test]
+fn runner_plugins() {
+    let runner = env!("CARGO_BIN_EXE_runner");
+    let rot13 = stdout(runner, &["plugins", "run", "rot13", "Hello,", "world"], b"");
+    let wc = stdout(runner, &["plugins", "run", "wc"], &fixture("wc_input.txt"));
+    assert_snapshot("runner_plugins", &format!("{}{}", rot13, wc));
+}
+
+#[test]
+fn runner_lists_the_compiled_chapters() {
+    // Which chapters are there depends on the features, so no snapshot
+    let output = stdout(env!("CARGO_BIN_EXE_runner"), &["list"], b"");
+    let expected: String = CATALOG
+        .iter()
+        .map(|chapter| {
+            format!(
+                "{:<16} {:<10} {}\n",
+                chapter.module, chapter.difficulty, chapter.title
+            )
+        })
+        .collect();
+    assert_eq!(output, expected);
+    if cfg!(feature = "ch12") {
+        assert!(output.contains("file_io          intro      Reading and writing files\n"));
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn ffi_dlopen_calls_the_exports() {
+    let library = cdylib();
+    let output = stdout(
+        env!("CARGO_BIN_EXE_ffi-dlopen"),
+        &[library.to_str().unwrap()],
+        b"",
+    );
+    assert_snapshot("ffi_dlopen", &output);
+}
+
+/*
+ * A client joins and asks who is here, then SIGTERM, which the server
+ * turns into a notice to everyone and a clean exit
+ * */
+#[cfg(all(unix, feature = "async-rt"))]
+#[test]
+fn chat_server_session_and_shutdown() {
+    let mut server = Command::new(env!("CARGO_BIN_EXE_chat-server"))
+        .arg("127.0.0.1:0")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut server_out = BufReader::new(server.stdout.take().unwrap());
+    let mut line = String::new();
+    server_out.read_line(&mut line).unwrap();
+    let addr = line
+        .trim_end()
+        .strip_prefix("listening on ")
+        .unwrap_or_else(|| panic!("unexpected first line {:?}", line))
+        .to_string();
+
+    let mut client = TcpStream::connect(&addr).unwrap();
+    client.write_all(b"ferris\n/who\n").unwrap();
+    let mut client_in = BufReader::new(client.try_clone().unwrap());
+    let mut read_line = || {
+        let mut line = String::new();
+        client_in.read_line(&mut line).unwrap();
+        line
+    };
+    assert_eq!(read_line(), "nickname?\n");
+    assert_eq!(read_line(), "* ferris joined\n");
+    assert_eq!(read_line(), "* here: ferris\n");
+
+    let status = Command::new("kill")
+        .args(["-TERM", &server.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert_eq!(read_line(), "* server shutting down\n");
+    assert_eq!(read_line(), "");
+    drop(client);
+
+    let mut rest = String::new();
+    std::io::Read::read_to_string(&mut server_out, &mut rest).unwrap();
+    assert_eq!(rest, "shut down\n");
+    assert!(server.wait().unwrap().success());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_executor_demos() {
+    // How much less than 100ms depends on the machine, tests/async_executor.rs
+    // checks join without a clock
+    assert!(async_executor::demo_join() >= Duration::from_millis(50));
+    assert_eq!(async_executor::demo_executor(), vec![2, 1, 0]);
+}
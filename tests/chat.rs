@@ -0,0 +1,125 @@
+/*
+ * extras::chat with two real clients on a local port: joining, messages
+ * going to the other client only, /who, /nick and the shutdown notice.
+ * Every read has a timeout so a broken server fails the test instead of
+ * hanging it.
+ *
+ *   cargo test --features async-rt --test chat
+ * */
+#![cfg(feature = "async-rt")]
+
+use rust_exercises::extras::chat::ChatRoom;
+use rust_exercises::extras::shutdown::ShutdownFlag;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl Client {
+    // Connected and past the nickname question
+    async fn join(addr: SocketAddr, nickname: &str) -> Client {
+        let (reader, writer) = TcpStream::connect(addr).await.unwrap().into_split();
+        let mut client = Client {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        };
+        assert_eq!(client.line().await.as_deref(), Some("nickname?"));
+        client.send(nickname).await;
+        client
+    }
+
+    async fn send(&mut self, line: &str) {
+        self.writer
+            .write_all(format!("{}\n", line).as_bytes())
+            .await
+            .unwrap();
+    }
+
+    // None once the server has closed the connection
+    async fn line(&mut self) -> Option<String> {
+        time::timeout(TIMEOUT, self.lines.next_line())
+            .await
+            .expect("no line from the server in time")
+            .unwrap()
+    }
+
+    async fn expect(&mut self, expected: &str) {
+        assert_eq!(self.line().await.as_deref(), Some(expected));
+    }
+}
+
+#[tokio::test]
+async fn two_clients_talk() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let room = ChatRoom::new();
+    let shutdown = ShutdownFlag::new();
+    let server = tokio::spawn({
+        let (room, shutdown) = (room.clone(), shutdown.clone());
+        async move { room.serve_until(listener, shutdown).await }
+    });
+
+    let mut alice = Client::join(addr, "alice").await;
+    alice.expect("* alice joined").await;
+    let mut bob = Client::join(addr, " bob ").await;
+    bob.expect("* bob joined").await;
+    alice.expect("* bob joined").await;
+
+    // Nobody gets their own messages back
+    bob.send("hi alice").await;
+    alice.expect("bob: hi alice").await;
+    alice.send("hi bob").await;
+    bob.expect("alice: hi bob").await;
+
+    alice.send("/who").await;
+    alice.expect("* here: alice, bob").await;
+    assert_eq!(room.nicknames(), ["alice", "bob"]);
+
+    bob.send("/nick robert").await;
+    alice.expect("* bob is now robert").await;
+    bob.expect("* bob is now robert").await;
+    alice.send("still there?").await;
+    bob.expect("alice: still there?").await;
+
+    shutdown.trigger();
+    for client in [&mut alice, &mut bob] {
+        client.expect("* server shutting down").await;
+        assert_eq!(client.line().await, None);
+    }
+    time::timeout(TIMEOUT, server)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert!(room.nicknames().is_empty());
+}
+
+#[tokio::test]
+async fn leaving_is_announced() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let room = ChatRoom::new();
+    tokio::spawn({
+        let room = room.clone();
+        async move { room.serve(listener).await }
+    });
+
+    let mut alice = Client::join(addr, "alice").await;
+    alice.expect("* alice joined").await;
+    let mut bob = Client::join(addr, "bob").await;
+    alice.expect("* bob joined").await;
+    bob.expect("* bob joined").await;
+
+    drop(bob);
+    alice.expect("* bob left").await;
+    assert_eq!(room.nicknames(), ["alice"]);
+}
@@ -0,0 +1,156 @@
+/*
+ * Round trips of both ciphers on random text with random keys, what
+ * they leave alone, and the Caesar cracker on English sentences.
+ * TEST_SEED=<n> repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::cipher::*;
+use rust_exercises::extras::rng::{Rng, XorShift64};
+
+// Letters of both cases mixed with digits, spaces, punctuation and non-ASCII
+fn random_text(rng: &mut XorShift64) -> String {
+    let pool: Vec<char> = ('a'..='z')
+        .chain('A'..='Z')
+        .chain("0123456789 .,!?-\n\u{e9}\u{df}\u{391}\u{1f980}".chars())
+        .collect();
+    let len = rng.gen_range(0..200) as usize;
+    (0..len)
+        .map(|_| pool[rng.gen_range(0..pool.len() as u64) as usize])
+        .collect()
+}
+
+fn random_key(rng: &mut XorShift64) -> String {
+    let len = rng.gen_range(1..12) as usize;
+    (0..len)
+        .map(|_| {
+            let letter = (b'a' + rng.gen_range(0..26) as u8) as char;
+            if rng.gen_bool(0.5) {
+                letter.to_ascii_uppercase()
+            } else {
+                letter
+            }
+        })
+        .collect()
+}
+
+// Same case at every position, and non-letters at the same places
+fn same_shape(plain: &str, cipher: &str) -> bool {
+    plain.chars().count() == cipher.chars().count()
+        && plain.chars().zip(cipher.chars()).all(|(p, c)| {
+            if p.is_ascii_lowercase() {
+                c.is_ascii_lowercase()
+            } else if p.is_ascii_uppercase() {
+                c.is_ascii_uppercase()
+            } else {
+                p == c
+            }
+        })
+}
+
+#[test]
+fn caesar_round_trips() {
+    let mut rng = rng();
+    for _ in 0..500 {
+        let text = random_text(&mut rng);
+        // Negative and past 26 too
+        let shift = rng.gen_range(0..200) as i32 - 100;
+        let encrypted = caesar_encrypt(&text, shift);
+        assert!(same_shape(&text, &encrypted));
+        assert_eq!(caesar_decrypt(&encrypted, shift), text);
+        assert_eq!(caesar_encrypt(&text, shift + 26), encrypted);
+    }
+}
+
+#[test]
+fn vigenere_round_trips() {
+    let mut rng = rng();
+    for _ in 0..500 {
+        let text = random_text(&mut rng);
+        let key = random_key(&mut rng);
+        let encrypted = vigenere_encrypt(&text, &key).unwrap();
+        assert!(same_shape(&text, &encrypted));
+        assert_eq!(vigenere_decrypt(&encrypted, &key).unwrap(), text);
+        // The key's case doesn't matter
+        assert_eq!(
+            vigenere_encrypt(&text, &key.to_ascii_lowercase()).unwrap(),
+            encrypted
+        );
+    }
+}
+
+#[test]
+fn known_ciphertexts() {
+    assert_eq!(caesar_encrypt("Hello, World!", 3), "Khoor, Zruog!");
+    assert_eq!(caesar_encrypt("xyz XYZ", 3), "abc ABC");
+    assert_eq!(caesar_encrypt("abc", -1), "zab");
+    assert_eq!(
+        vigenere_encrypt("ATTACK AT DAWN", "LEMON").unwrap(),
+        "LXFOPV EF RNHR"
+    );
+    assert_eq!(
+        vigenere_encrypt("attack at dawn", "lemon").unwrap(),
+        "lxfopv ef rnhr"
+    );
+    // A one letter key is a Caesar shift
+    assert_eq!(
+        vigenere_encrypt("Hello, World!", "d").unwrap(),
+        caesar_encrypt("Hello, World!", 3)
+    );
+}
+
+#[test]
+fn non_letters_pass_through() {
+    let text = "123 \u{e9}\u{1f980} ... \u{391}\u{3b2}!";
+    assert_eq!(caesar_encrypt(text, 13), text);
+    assert_eq!(vigenere_encrypt(text, "key").unwrap(), text);
+    // And don't use up a key letter: the b still gets the first shift
+    assert_eq!(vigenere_encrypt("12 b", "bc").unwrap(), "12 c");
+}
+
+#[test]
+fn bad_keys() {
+    assert_eq!(vigenere_encrypt("text", ""), Err(KeyError::Empty));
+    assert_eq!(
+        vigenere_decrypt("text", "le mon"),
+        Err(KeyError::InvalidChar(' '))
+    );
+    assert_eq!(
+        vigenere_encrypt("text", "k\u{e9}y"),
+        Err(KeyError::InvalidChar('\u{e9}'))
+    );
+    assert_eq!(
+        KeyError::InvalidChar('3').to_string(),
+        "'3' is not a letter"
+    );
+}
+
+const SENTENCES: [&str; 4] = [
+    "The quick brown fox jumps over the lazy dog while the farmer watches from the porch.",
+    "It was the best of times, it was the worst of times, it was the age of wisdom.",
+    "Rust is a systems programming language that runs blazingly fast and prevents segfaults.",
+    "Call me Ishmael. Some years ago, never mind how long precisely, I thought I would sail about.",
+];
+
+#[test]
+fn crack_finds_every_shift_of_english() {
+    for sentence in SENTENCES.iter() {
+        for shift in 0..26 {
+            let encrypted = caesar_encrypt(sentence, shift);
+            assert_eq!(
+                caesar_crack(&encrypted),
+                (shift, sentence.to_string()),
+                "{}",
+                shift
+            );
+        }
+    }
+}
+
+#[test]
+fn crack_without_letters() {
+    // Every shift scores the same, the first one wins
+    assert_eq!(caesar_crack("1234 !?"), (0, String::from("1234 !?")));
+    assert_eq!(caesar_crack(""), (0, String::new()));
+}
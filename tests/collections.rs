@@ -0,0 +1,111 @@
+/*
+ * MyHashMap and Bst driven by the same random operations as std's
+ * HashMap and BTreeSet, which must agree on every answer. TEST_SEED=<n>
+ * repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::bst::Bst;
+use rust_exercises::extras::hash_map::MyHashMap;
+use rust_exercises::extras::rng::Rng;
+use std::collections::{BTreeSet, HashMap};
+
+#[test]
+fn hash_map_like_std() {
+    let mut rng = rng();
+    let mut ours = MyHashMap::new();
+    let mut std = HashMap::new();
+    for _ in 0..20_000 {
+        // Few keys, so inserts overwrite and removes hit
+        let key = rng.gen_range(0..500);
+        match rng.gen_range(0..3) {
+            0 => {
+                let value = rng.next_u64();
+                assert_eq!(ours.insert(key, value), std.insert(key, value));
+            }
+            1 => assert_eq!(ours.remove(&key), std.remove(&key)),
+            _ => assert_eq!(ours.get(&key), std.get(&key)),
+        }
+        assert_eq!(ours.len(), std.len());
+    }
+
+    let mut pairs: Vec<(u64, u64)> = ours.iter().map(|(&k, &v)| (k, v)).collect();
+    pairs.sort_unstable();
+    let mut expected: Vec<(u64, u64)> = std.into_iter().collect();
+    expected.sort_unstable();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn hash_map_borrowed_keys() {
+    let mut map = MyHashMap::new();
+    for word in "the quick brown fox jumps over the lazy dog".split(' ') {
+        let count = map.get(word).copied().unwrap_or(0);
+        map.insert(word.to_string(), count + 1);
+    }
+    assert_eq!(map.len(), 8);
+    assert_eq!(map.get("the"), Some(&2));
+    assert!(map.contains_key("fox"));
+    assert!(!map.contains_key("cat"));
+    assert_eq!(map.remove("dog"), Some(1));
+    assert_eq!(map.remove("dog"), None);
+
+    let empty: MyHashMap<String, u8> = MyHashMap::new();
+    assert_eq!(empty.get("anything"), None);
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+fn bst_like_btree_set() {
+    let mut rng = rng();
+    let mut ours = Bst::new();
+    let mut std = BTreeSet::new();
+    for _ in 0..20_000 {
+        let value = rng.gen_range(0..500);
+        match rng.gen_range(0..3) {
+            0 => assert_eq!(ours.insert(value), std.insert(value)),
+            1 => assert_eq!(ours.remove(&value), std.remove(&value)),
+            _ => assert_eq!(ours.contains(&value), std.contains(&value)),
+        }
+        assert_eq!(ours.len(), std.len());
+    }
+    assert!(ours.iter().eq(std.iter()));
+}
+
+#[test]
+fn bst_removes_every_shape_of_node() {
+    //        50
+    //     30     70
+    //   20  40  60  80
+    let mut tree: Bst<i32> = [50, 30, 70, 20, 40, 60, 80].iter().copied().collect();
+    // A leaf, a node with one child, then the root with two
+    assert!(tree.remove(&20));
+    assert!(tree.remove(&30));
+    assert!(tree.remove(&50));
+    assert!(!tree.remove(&50));
+    assert_eq!(tree.iter().copied().collect::<Vec<_>>(), [40, 60, 70, 80]);
+    assert_eq!(tree.len(), 4);
+
+    for value in [40, 60, 70, 80] {
+        assert!(tree.remove(&value));
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.iter().next(), None);
+}
+
+#[test]
+fn bst_sorted_input_is_a_list() {
+    let tree: Bst<u32> = (0..10_000).collect();
+    assert_eq!(tree.len(), 10_000);
+    assert!(tree.contains(&9_999));
+    assert!(tree.iter().copied().eq(0..10_000));
+
+    // As deep as it is long, dropping it mustn't recurse that deep
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(move || drop(tree))
+        .unwrap()
+        .join()
+        .unwrap();
+}
@@ -0,0 +1,106 @@
+/*
+ * The match and combinator versions in extras::combinators are meant to
+ * be interchangeable, every input here has to give both the same answer
+ * */
+use rust_exercises::extras::combinators::{
+    with_combinators, with_match, AddrError, Config, Server, Tls, User,
+};
+
+#[test]
+fn host_port_versions_agree() {
+    let inputs = [
+        "localhost:8080",
+        "db:5432",
+        "::1:443",
+        "no-port",
+        ":80",
+        "host:",
+        "host:65536",
+        "host:-1",
+        "",
+    ];
+    for input in inputs {
+        assert_eq!(
+            with_match::host_port(input),
+            with_combinators::host_port(input),
+            "{:?}",
+            input
+        );
+    }
+
+    assert_eq!(with_combinators::host_port("::1:443"), Ok(("::1", 443)));
+    assert_eq!(
+        with_combinators::host_port("no-port"),
+        Err(AddrError::MissingPort)
+    );
+    assert_eq!(
+        with_combinators::host_port(":80"),
+        Err(AddrError::EmptyHost)
+    );
+    assert!(matches!(
+        with_combinators::host_port("host:65536"),
+        Err(AddrError::BadPort(_))
+    ));
+}
+
+#[test]
+fn cert_path_versions_agree() {
+    let cert = |cert: Option<&str>| Config {
+        server: Some(Server {
+            tls: Some(Tls {
+                cert: cert.map(str::to_string),
+            }),
+        }),
+    };
+    let configs = [
+        Config::default(),
+        Config {
+            server: Some(Server::default()),
+        },
+        cert(None),
+        cert(Some("/etc/tls/server.pem")),
+    ];
+    for config in configs.iter() {
+        assert_eq!(
+            with_match::cert_path(config),
+            with_combinators::cert_path(config),
+            "{:?}",
+            config
+        );
+    }
+
+    assert_eq!(
+        with_combinators::cert_path(&configs[3]),
+        Some("/etc/tls/server.pem")
+    );
+    assert_eq!(with_combinators::cert_path(&configs[1]), None);
+}
+
+#[test]
+fn display_name_versions_agree() {
+    let users = [
+        (1, None),
+        (2, Some("")),
+        (3, Some("   ")),
+        (4, Some(" ferris ")),
+        (5, Some("corro")),
+    ];
+    for (id, nickname) in users {
+        let user = User {
+            id,
+            nickname: nickname.map(str::to_string),
+        };
+        assert_eq!(
+            with_match::display_name(&user),
+            with_combinators::display_name(&user),
+            "{:?}",
+            user
+        );
+    }
+
+    let user = User {
+        id: 3,
+        nickname: Some(String::from(" ")),
+    };
+    assert_eq!(with_combinators::display_name(&user), "user 3");
+}
@@ -0,0 +1,171 @@
+/*
+ * extras::command: undo and redo interleaved by hand, then long random
+ * runs checked against a plain list of every text the document went
+ * through. TEST_SEED=<n> repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::command::{Command, Delete, Document, EditError, Insert, UndoStack};
+use rust_exercises::extras::rng::Rng;
+
+#[test]
+fn undo_and_redo_walk_the_history() {
+    let mut doc = Document::new();
+    let mut history = UndoStack::new();
+    assert!(!history.can_undo());
+    assert!(!history.undo(&mut doc));
+    assert_eq!(history.redo(&mut doc), Ok(false));
+
+    history
+        .execute(&mut doc, Box::new(Insert::new(0, "world")))
+        .unwrap();
+    history
+        .execute(&mut doc, Box::new(Insert::new(0, "hello ")))
+        .unwrap();
+    history
+        .execute(&mut doc, Box::new(Delete::new(0, 1)))
+        .unwrap();
+    assert_eq!(doc.text(), "ello world");
+    assert_eq!(
+        history.history(),
+        [
+            "insert \"world\" at 0",
+            "insert \"hello \" at 0",
+            "delete 1 bytes at 0"
+        ]
+    );
+
+    assert!(history.undo(&mut doc));
+    assert_eq!(doc.text(), "hello world");
+    assert!(history.undo(&mut doc));
+    assert_eq!(doc.text(), "world");
+    assert_eq!(history.redo(&mut doc), Ok(true));
+    assert_eq!(doc.text(), "hello world");
+    assert!(history.undo(&mut doc));
+    assert!(history.undo(&mut doc));
+    assert_eq!(doc.text(), "");
+    assert!(!history.undo(&mut doc));
+
+    assert_eq!(history.redo(&mut doc), Ok(true));
+    assert_eq!(history.redo(&mut doc), Ok(true));
+    assert_eq!(history.redo(&mut doc), Ok(true));
+    assert_eq!(doc.text(), "ello world");
+    assert!(!history.can_redo());
+}
+
+#[test]
+fn a_new_edit_drops_what_was_undone() {
+    let mut doc = Document::from("abc");
+    let mut history = UndoStack::new();
+    history
+        .execute(&mut doc, Box::new(Delete::new(1, 1)))
+        .unwrap();
+    history
+        .execute(&mut doc, Box::new(Insert::new(2, "!")))
+        .unwrap();
+    history.undo(&mut doc);
+    history.undo(&mut doc);
+    assert!(history.can_redo());
+
+    history
+        .execute(&mut doc, Box::new(Insert::new(3, "d")))
+        .unwrap();
+    assert!(!history.can_redo());
+    assert_eq!(history.redo(&mut doc), Ok(false));
+    assert!(history.undo(&mut doc));
+    assert_eq!(doc.text(), "abc");
+    assert!(!history.can_undo());
+}
+
+#[test]
+fn refused_edits_change_nothing() {
+    let mut doc = Document::from("née");
+    let mut history = UndoStack::new();
+    history
+        .execute(&mut doc, Box::new(Insert::new(0, "x")))
+        .unwrap();
+    history.undo(&mut doc);
+
+    assert_eq!(
+        history.execute(&mut doc, Box::new(Insert::new(9, "?"))),
+        Err(EditError::OutOfBounds { at: 9, len: 4 })
+    );
+    // 'é' is two bytes, 2 is in the middle of it
+    assert_eq!(
+        history.execute(&mut doc, Box::new(Delete::new(0, 2))),
+        Err(EditError::NotCharBoundary(2))
+    );
+    assert_eq!(doc.text(), "née");
+    assert!(!history.can_undo());
+    // The undone insert is still there to redo
+    assert_eq!(history.redo(&mut doc), Ok(true));
+    assert_eq!(doc.text(), "xnée");
+}
+
+#[test]
+fn redo_on_a_document_edited_elsewhere() {
+    let mut doc = Document::from("abcdef");
+    let mut history = UndoStack::new();
+    history
+        .execute(&mut doc, Box::new(Delete::new(3, 3)))
+        .unwrap();
+    history.undo(&mut doc);
+
+    doc = Document::from("ab");
+    assert_eq!(
+        history.redo(&mut doc),
+        Err(EditError::OutOfBounds { at: 3, len: 2 })
+    );
+    assert!(history.can_redo());
+    assert_eq!(doc.text(), "ab");
+}
+
+/*
+ * The model: states[..=current] are the texts after each applied edit,
+ * states[current + 1..] are what redo would bring back
+ * */
+#[test]
+fn random_sequences_match_a_list_of_states() {
+    let mut rng = rng();
+    for _ in 0..50 {
+        let mut doc = Document::new();
+        let mut history = UndoStack::new();
+        let mut states = vec![String::new()];
+        let mut current = 0;
+
+        for _ in 0..200 {
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let len = doc.len() as u64;
+                    let edit: Box<dyn Command> = if len > 0 && rng.gen_bool(0.4) {
+                        let at = rng.gen_range(0..len);
+                        let n = rng.gen_range(0..len - at + 1);
+                        Box::new(Delete::new(at as usize, n as usize))
+                    } else {
+                        let at = rng.gen_range(0..len + 1);
+                        let text = ["a", "bc", "def", ""][rng.gen_range(0..4) as usize];
+                        Box::new(Insert::new(at as usize, text))
+                    };
+                    history.execute(&mut doc, edit).unwrap();
+                    states.truncate(current + 1);
+                    states.push(doc.text().to_string());
+                    current += 1;
+                }
+                2 => {
+                    assert_eq!(history.undo(&mut doc), current > 0);
+                    current = current.saturating_sub(1);
+                }
+                _ => {
+                    let can_redo = current + 1 < states.len();
+                    assert_eq!(history.redo(&mut doc), Ok(can_redo));
+                    if can_redo {
+                        current += 1;
+                    }
+                }
+            }
+            assert_eq!(doc.text(), states[current]);
+            assert_eq!(history.history().len(), current);
+        }
+    }
+}
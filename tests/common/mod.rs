@@ -0,0 +1,172 @@
+/*
+ * Setup code shared by the integration tests, so a new chapter's tests
+ * don't start with yet another temp dir or fixture loader, plus running
+ * the binaries and capturing their output. Declared with `mod common;`
+ * in each test file, and not every one of those uses every helper.
+ * */
+#![allow(dead_code)]
+
+use rust_exercises::extras::rng::XorShift64;
+use rust_exercises::extras::temp_dir::TempDir;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+
+// Used when TEST_SEED isn't set, any fixed value would do
+pub const DEFAULT_SEED: u64 = 0x5EED;
+
+/*
+ * test_name goes in the directory name, so that a leftover directory
+ * tells which test it came from
+ * */
+pub fn temp_dir(test_name: &str) -> TempDir {
+    TempDir::new(&format!("rust-exercises-{}", test_name))
+        .unwrap_or_else(|e| panic!("can't create a temp dir for {}: {}", test_name, e))
+}
+
+/*
+ * Creates the files under a fresh temp dir, with parent directories,
+ * from (relative path, contents) pairs
+ * */
+pub fn temp_tree(test_name: &str, files: &[(&str, &str)]) -> TempDir {
+    let dir = temp_dir(test_name);
+    for &(name, contents) in files {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, contents).unwrap();
+    }
+    dir
+}
+
+pub fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+// A file from tests/fixtures, panics with its path when it's missing
+pub fn fixture(name: &str) -> Vec<u8> {
+    let path = fixture_path(name);
+    fs::read(&path).unwrap_or_else(|e| panic!("can't read fixture {}: {}", path.display(), e))
+}
+
+pub fn fixture_str(name: &str) -> String {
+    String::from_utf8(fixture(name)).unwrap_or_else(|_| panic!("fixture {} isn't UTF-8", name))
+}
+
+/*
+ * Same numbers on every run unless TEST_SEED says otherwise. Derefs to
+ * the XorShift64 it wraps, and prints the seed when dropped by a failing
+ * test, so the run can be repeated with TEST_SEED=<seed>.
+ * */
+pub struct TestRng {
+    rng: XorShift64,
+    seed: u64,
+}
+
+pub fn rng() -> TestRng {
+    let seed = match env::var("TEST_SEED") {
+        Ok(seed) => seed
+            .parse()
+            .unwrap_or_else(|_| panic!("TEST_SEED={} isn't a number", seed)),
+        Err(_) => DEFAULT_SEED,
+    };
+    TestRng {
+        rng: XorShift64::new(seed),
+        seed,
+    }
+}
+
+impl Deref for TestRng {
+    type Target = XorShift64;
+
+    fn deref(&self) -> &XorShift64 {
+        &self.rng
+    }
+}
+
+impl DerefMut for TestRng {
+    fn deref_mut(&mut self) -> &mut XorShift64 {
+        &mut self.rng
+    }
+}
+
+impl Drop for TestRng {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            eprintln!("rng seed {}, TEST_SEED={} repeats this run", self.seed, self.seed);
+        }
+    }
+}
+
+/*
+ * What a function writing to an io::Write produces, as a String
+ * */
+pub fn capture<F>(write: F) -> String
+where
+    F: FnOnce(&mut dyn Write) -> io::Result<()>,
+{
+    let mut output = Vec::new();
+    write(&mut output).expect("writing to a Vec can't fail");
+    String::from_utf8(output).expect("output isn't UTF-8")
+}
+
+/*
+ * Runs exe with args, feeding it stdin, and panics with its stderr if it
+ * fails. exe is usually env!("CARGO_BIN_EXE_<name>"), cargo builds every
+ * binary before the integration tests.
+ * */
+pub fn run(exe: &str, args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(exe)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|e| panic!("can't start {}: {}", exe, e));
+
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "{} {:?} failed: {}",
+        exe,
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+pub fn stdout(exe: &str, args: &[&str], stdin: &[u8]) -> String {
+    String::from_utf8(run(exe, args, stdin).stdout).unwrap()
+}
+
+/*
+ * The crate built as a cdylib, for loading it the way C would. cargo
+ * test only builds the rlib, so cargo itself is run here, in a target
+ * directory of its own to stay clear of the one running us.
+ * */
+pub fn cdylib() -> PathBuf {
+    let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("cdylib");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--lib", "--manifest-path"])
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("couldn't start cargo");
+    assert!(status.success(), "cargo build --lib failed");
+
+    target_dir.join("debug").join(format!(
+        "{}rust_exercises{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ))
+}
@@ -0,0 +1,150 @@
+/*
+ * extras::config merging its three layers: defaults, an INI file
+ * written to a temp dir, and APP_ variables. load_from() takes the
+ * variables as a list so these tests don't depend on the environment
+ * they run in, only load_env_and_file() touches the real one.
+ * */
+mod common;
+
+use common::temp_tree;
+use rust_exercises::extras::config::{Config, LoadError, Source};
+use std::env;
+use std::path::Path;
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|&(var, value)| (var.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn defaults_alone() {
+    let config = Config::load_from(None, vars(&[("PATH", "/bin"), ("HOME", "/root")])).unwrap();
+    assert_eq!(config.host, "127.0.0.1");
+    assert_eq!(
+        (config.port, config.workers, config.verbose),
+        (8080, 4, false)
+    );
+    assert_eq!(config.log_level, "info");
+    assert_eq!(config.source("port"), Some(&Source::Default));
+    assert_eq!(config.source("nope"), None);
+}
+
+#[test]
+fn env_overrides_file_overrides_defaults() {
+    let dir = temp_tree(
+        "config_layers",
+        &[(
+            "app.ini",
+            "; settings\nport = 9000\nverbose = yes\nhost = 0.0.0.0\n",
+        )],
+    );
+    let file = dir.join("app.ini");
+    let config = Config::load_from(
+        Some(&file),
+        vars(&[
+            ("APP_PORT", "9100"),
+            ("APP_LOG_LEVEL", "debug"),
+            ("APP_CONFIG", "ignored"),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(config.port, 9100);
+    assert_eq!(
+        config.source("port"),
+        Some(&Source::Env("APP_PORT".to_string()))
+    );
+    assert!(config.verbose);
+    assert_eq!(config.source("verbose"), Some(&Source::File(file.clone())));
+    assert_eq!(config.workers, 4);
+    assert_eq!(config.log_level, "debug");
+
+    let explained = config.explain();
+    assert!(explained.starts_with("host = 0.0.0.0 (file "));
+    assert!(explained.contains("port = 9100 (env APP_PORT)\n"));
+    assert!(explained.ends_with("log_level = debug (env APP_LOG_LEVEL)\n"));
+}
+
+#[test]
+fn unknown_settings_are_errors() {
+    let dir = temp_tree(
+        "config_unknown",
+        &[
+            ("typo.ini", "prot = 1\n"),
+            ("section.ini", "[server]\nport = 1\n"),
+        ],
+    );
+    match Config::load_from(Some(&dir.join("typo.ini")), vars(&[])) {
+        Err(LoadError::UnknownKey {
+            key,
+            source: Source::File(_),
+        }) => assert_eq!(key, "prot"),
+        other => panic!("{:?}", other),
+    }
+    match Config::load_from(Some(&dir.join("section.ini")), vars(&[])) {
+        Err(LoadError::UnknownKey { key, .. }) => assert_eq!(key, "server.port"),
+        other => panic!("{:?}", other),
+    }
+    match Config::load_from(None, vars(&[("APP_PROT", "1"), ("APP_ZZZ", "1")])) {
+        // The first one in sorted order
+        Err(LoadError::UnknownKey { key, source }) => {
+            assert_eq!(key, "prot");
+            assert_eq!(source, Source::Env("APP_PROT".to_string()));
+        }
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn invalid_values_name_their_source() {
+    let dir = temp_tree("config_invalid", &[("app.ini", "workers = many\n")]);
+    let e = Config::load_from(Some(&dir.join("app.ini")), vars(&[])).unwrap_err();
+    assert!(
+        e.to_string()
+            .starts_with("invalid workers \"many\" from file "),
+        "{}",
+        e
+    );
+
+    let e = Config::load_from(None, vars(&[("APP_PORT", "99999")])).unwrap_err();
+    assert_eq!(e.to_string(), "invalid port \"99999\" from env APP_PORT");
+
+    // What an INI file couldn't hold either
+    let e = Config::load_from(None, vars(&[("APP_HOST", "a\nb")])).unwrap_err();
+    assert_eq!(e.to_string(), "invalid host \"a\\nb\" from env APP_HOST");
+}
+
+#[test]
+fn file_problems() {
+    let missing = Path::new("/nonexistent/app.ini");
+    assert!(matches!(
+        Config::load_from(Some(missing), vars(&[])),
+        Err(LoadError::Io(..))
+    ));
+
+    let dir = temp_tree("config_parse", &[("app.ini", "port = 1\n[open\n")]);
+    match Config::load_from(Some(&dir.join("app.ini")), vars(&[])) {
+        Err(LoadError::Parse(_, e)) => assert_eq!(e.line, 2),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn load_env_and_file() {
+    let dir = temp_tree("config_env", &[("app.ini", "workers = 16\n")]);
+    env::set_var("APP_CONFIG", dir.join("app.ini"));
+    env::set_var("APP_VERBOSE", "on");
+    let config = Config::load();
+    env::remove_var("APP_CONFIG");
+    env::remove_var("APP_VERBOSE");
+
+    let config = config.unwrap();
+    assert_eq!(config.workers, 16);
+    assert!(config.verbose);
+    assert_eq!(
+        config.source("verbose"),
+        Some(&Source::Env("APP_VERBOSE".to_string()))
+    );
+}
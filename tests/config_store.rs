@@ -0,0 +1,116 @@
+/*
+ * A writer swapping whole configurations while readers keep looking. A
+ * configuration is consistent when every key carries the same version,
+ * snapshot() and get_many() must never show two versions mixed.
+ * */
+use rust_exercises::extras::config_store::{ConfigStore, MutexConfigStore};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+const KEYS: [&str; 4] = ["version", "host", "port", "mode"];
+const VERSIONS: usize = 2_000;
+
+// The values of every key at a version
+fn config(version: usize) -> Vec<String> {
+    vec![
+        version.to_string(),
+        format!("host-{}", version),
+        (8000 + version).to_string(),
+        format!("mode-{}", version),
+    ]
+}
+
+fn install(store: &ConfigStore, version: usize) {
+    let values = config(version);
+    let pairs: Vec<(&str, &str)> = KEYS
+        .iter()
+        .zip(&values)
+        .map(|(&key, value)| (key, value.as_str()))
+        .collect();
+    store.update(&pairs);
+}
+
+// The version every value says it's from, None if they disagree
+fn version_of(values: &[Option<String>]) -> Option<usize> {
+    let version: usize = values[0].as_ref()?.parse().ok()?;
+    let expected: Vec<Option<String>> = config(version).into_iter().map(Some).collect();
+    if values == expected.as_slice() {
+        Some(version)
+    } else {
+        None
+    }
+}
+
+fn snapshot_values(snapshot: &HashMap<String, String>) -> Vec<Option<String>> {
+    KEYS.iter().map(|&key| snapshot.get(key).cloned()).collect()
+}
+
+#[test]
+fn readers_see_whole_configs_while_a_writer_swaps_them() {
+    let store = ConfigStore::new();
+    install(&store, 0);
+    let done = AtomicBool::new(false);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            for version in 1..=VERSIONS {
+                install(&store, version);
+            }
+            done.store(true, Ordering::SeqCst);
+        });
+
+        for reader in 0..4 {
+            let (store, done) = (&store, &done);
+            s.spawn(move || {
+                let mut last = 0;
+                let mut reads = 0;
+                // At least a few reads even if the writer finished first
+                while !done.load(Ordering::SeqCst) || reads < 100 {
+                    let values = if reader % 2 == 0 {
+                        snapshot_values(&store.snapshot())
+                    } else {
+                        store.get_many(&KEYS)
+                    };
+                    let version = version_of(&values)
+                        .unwrap_or_else(|| panic!("mixed configurations: {:?}", values));
+                    // One writer, so versions only go up
+                    assert!(version >= last, "went back from {} to {}", last, version);
+                    last = version;
+                    reads += 1;
+                }
+            });
+        }
+    });
+
+    assert_eq!(version_of(&store.get_many(&KEYS)), Some(VERSIONS));
+}
+
+#[test]
+fn snapshots_are_copies() {
+    let store = ConfigStore::new();
+    install(&store, 1);
+    let before = store.snapshot();
+    install(&store, 2);
+
+    assert_eq!(version_of(&snapshot_values(&before)), Some(1));
+    assert_eq!(version_of(&snapshot_values(&store.snapshot())), Some(2));
+}
+
+#[test]
+fn single_keys() {
+    let store = ConfigStore::default();
+    assert_eq!(store.get("host"), None);
+    store.set("host", "localhost");
+    store.set("host", "example.com");
+    assert_eq!(store.get("host").as_deref(), Some("example.com"));
+    assert_eq!(
+        store.get_many(&["host", "port"]),
+        [Some("example.com".to_string()), None]
+    );
+
+    let mutex = MutexConfigStore::new();
+    mutex.set("host", "localhost");
+    assert_eq!(mutex.get("host").as_deref(), Some("localhost"));
+    assert_eq!(mutex.get("port"), None);
+}
@@ -0,0 +1,66 @@
+/*
+ * extras::cow hands back Cow::Borrowed when there's nothing to change and
+ * only allocates, Cow::Owned, when there is
+ * */
+use rust_exercises::extras::cow::{clean_for_html, escape_html, normalize_whitespace, sanitize};
+use std::borrow::Cow;
+
+// A macro rather than a function, clippy doesn't want &Cow parameters
+macro_rules! is_borrowed {
+    ($cow:expr) => {
+        matches!($cow, Cow::Borrowed(_))
+    };
+}
+
+#[test]
+fn sanitize_borrows_clean_text() {
+    let clean = "two\nlines";
+    let out = sanitize(clean);
+    assert!(is_borrowed!(out));
+    // The very same bytes, not a copy
+    assert_eq!(out.as_ptr(), clean.as_ptr());
+
+    let out = sanitize("tab\there\u{7}\n");
+    assert!(!is_borrowed!(out));
+    assert_eq!(out, "tab here \n");
+}
+
+#[test]
+fn normalize_borrows_when_trimming_is_enough() {
+    let input = "  already single spaced  ";
+    let out = normalize_whitespace(input);
+    assert!(is_borrowed!(out));
+    assert_eq!(out, "already single spaced");
+    assert_eq!(out.as_ptr(), input[2..].as_ptr());
+
+    let cases = vec![
+        ("two  spaces", "two spaces"),
+        ("a\tb", "a b"),
+        (" a \n b ", "a b"),
+    ];
+    for (input, expected) in cases {
+        let out = normalize_whitespace(input);
+        assert!(!is_borrowed!(out), "{:?}", input);
+        assert_eq!(out, expected);
+    }
+    assert!(is_borrowed!(normalize_whitespace("")));
+}
+
+#[test]
+fn escape_only_allocates_for_special_characters() {
+    assert!(is_borrowed!(escape_html("plain text, nothing special")));
+
+    let out = escape_html("a < b && c > \"d\" 'e'");
+    assert!(!is_borrowed!(out));
+    assert_eq!(out, "a &lt; b &amp;&amp; c &gt; &quot;d&quot; &#39;e&#39;");
+    assert_eq!(escape_html("é&"), "é&amp;");
+}
+
+#[test]
+fn cleaning_chains_the_three() {
+    assert_eq!(
+        clean_for_html("  <b>\tbold\u{0}</b>  "),
+        "&lt;b&gt; bold &lt;/b&gt;"
+    );
+    assert_eq!(clean_for_html("fine"), "fine");
+}
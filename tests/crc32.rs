@@ -0,0 +1,53 @@
+/*
+ * CRC-32 against published check values, the bitwise reference, and
+ * itself fed in pieces
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::core_utils::crc32::{checksum, checksum_bitwise, Crc32};
+use rust_exercises::extras::crc32;
+use rust_exercises::extras::rng::Rng;
+use std::hash::Hasher;
+
+#[test]
+fn check_values() {
+    assert_eq!(checksum(b"123456789"), 0xCBF4_3926);
+    assert_eq!(checksum(b""), 0);
+    assert_eq!(checksum(b"a"), 0xE8B7_BE43);
+    assert_eq!(
+        checksum(b"The quick brown fox jumps over the lazy dog"),
+        0x414F_A339
+    );
+    // Still reachable from its old place
+    assert_eq!(crc32::checksum(b"123456789"), 0xCBF4_3926);
+    assert_eq!(checksum_bitwise(b"123456789"), 0xCBF4_3926);
+}
+
+#[test]
+fn table_agrees_with_bitwise() {
+    let mut rng = rng();
+    for _ in 0..200 {
+        let len = rng.gen_range(0..500) as usize;
+        let bytes: Vec<u8> = (0..len).map(|_| rng.next_u64() as u8).collect();
+        assert_eq!(checksum(&bytes), checksum_bitwise(&bytes));
+    }
+}
+
+#[test]
+fn pieces_give_the_same_result() {
+    let data = b"123456789";
+    for split in 0..=data.len() {
+        let mut crc = Crc32::new();
+        crc.update(&data[..split]);
+        // finalize() doesn't end anything
+        let _ = crc.finalize();
+        crc.update(&data[split..]);
+        assert_eq!(crc.finalize(), 0xCBF4_3926);
+    }
+
+    let mut hasher = Crc32::default();
+    hasher.write(b"1234");
+    hasher.write(b"56789");
+    assert_eq!(hasher.finish(), 0xCBF4_3926);
+}
@@ -0,0 +1,111 @@
+/*
+ * extras::csv on the quoting cases that trip up naive splitting on ',':
+ * commas, quotes and newlines inside quotes, empty fields, CRLF, and
+ * writing back what was read
+ * */
+use rust_exercises::extras::csv::{escape_field, CsvError, Reader, Writer};
+
+fn records(input: &str) -> Vec<Vec<String>> {
+    Reader::new(input.as_bytes())
+        .map(|record| record.unwrap())
+        .collect()
+}
+
+fn errors(input: &str) -> Vec<CsvError> {
+    Reader::new(input.as_bytes())
+        .filter_map(Result::err)
+        .collect()
+}
+
+#[test]
+fn tricky_quoting() {
+    let input = concat!(
+        "name,quote,note\n",
+        "plain,\"with, comma\",\"say \"\"hi\"\"\"\n",
+        "\"two\nlines\",\"\",end\n",
+        "\"\"\"\",x,\"  spaced  \"\n",
+    );
+    assert_eq!(
+        records(input),
+        [
+            vec!["name", "quote", "note"],
+            vec!["plain", "with, comma", "say \"hi\""],
+            vec!["two\nlines", "", "end"],
+            vec!["\"", "x", "  spaced  "],
+        ]
+    );
+}
+
+#[test]
+fn empty_fields_blank_lines_and_crlf() {
+    assert_eq!(
+        records("a,,c\r\n\r\n,\r\n\n,,\nlast"),
+        [
+            vec!["a", "", "c"],
+            vec!["", ""],
+            vec!["", "", ""],
+            vec!["last"],
+        ]
+    );
+    // A \r that isn't part of the line ending is kept
+    assert_eq!(records("a\rb,c\n"), [vec!["a\rb", "c"]]);
+    assert_eq!(records("\"a\r\nb\"\r\n"), [vec!["a\r\nb"]]);
+}
+
+#[test]
+fn malformed_quotes() {
+    assert!(matches!(
+        errors("ok\nab\"c,d\n")[..],
+        [CsvError::UnexpectedQuote { line: 2, column: 3 }]
+    ));
+    assert!(matches!(
+        errors("\"closed\"x,y\n")[..],
+        [CsvError::UnexpectedChar {
+            line: 1,
+            column: 9,
+            ch: 'x'
+        }]
+    ));
+    assert!(matches!(
+        errors("a\n\"never\nclosed,\n")[..],
+        [CsvError::UnterminatedQuote { line: 2 }]
+    ));
+}
+
+#[test]
+fn reading_goes_on_after_an_error() {
+    let results: Vec<_> = Reader::new(&b"bad\"quote\ngood,row\n"[..]).collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_err());
+    assert_eq!(results[1].as_ref().unwrap(), &["good", "row"]);
+}
+
+#[test]
+fn escaping_on_write() {
+    assert_eq!(escape_field("plain"), "plain");
+    assert_eq!(escape_field("a,b"), "\"a,b\"");
+    assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+    assert_eq!(escape_field(" padded"), "\" padded\"");
+    assert_eq!(escape_field(""), "");
+}
+
+#[test]
+fn written_records_read_back_the_same() {
+    let rows = vec![
+        vec!["id", "text"],
+        vec!["1", "with, comma"],
+        vec!["2", "quote \" inside"],
+        vec!["3", "multi\r\nline"],
+        vec!["", ""],
+        vec![""],
+        vec![" lead", "trail "],
+    ];
+    let mut writer = Writer::new(Vec::new());
+    for row in rows.iter() {
+        writer.write_record(row).unwrap();
+    }
+    let written = String::from_utf8(writer.into_inner()).unwrap();
+    assert!(written.starts_with("id,text\r\n1,\"with, comma\"\r\n"));
+    assert_eq!(records(&written), rows);
+}
@@ -0,0 +1,184 @@
+/*
+ * Calendar facts the day count has to get right: leap years, the epoch
+ * and its weekday, known dates checked against another implementation,
+ * and every day of a few thousand years there and back
+ * */
+use rust_exercises::extras::datetime::{
+    days_in_month, format_duration, is_leap_year, Date, DateError, Weekday,
+};
+use std::time::Duration;
+
+fn date(s: &str) -> Date {
+    s.parse().unwrap()
+}
+
+#[test]
+fn leap_years() {
+    for &year in &[2000, 2024, 1996, 1600, 0, -4, -400] {
+        assert!(is_leap_year(year), "{}", year);
+    }
+    // Centuries that aren't a multiple of 400
+    for &year in &[1900, 2023, 2100, 1800, 1, -1, -100] {
+        assert!(!is_leap_year(year), "{}", year);
+    }
+
+    assert_eq!(days_in_month(2000, 2), Some(29));
+    assert_eq!(days_in_month(1900, 2), Some(28));
+    assert_eq!(days_in_month(2024, 2), Some(29));
+    assert_eq!(days_in_month(2023, 2), Some(28));
+    assert_eq!(days_in_month(2023, 4), Some(30));
+    assert_eq!(days_in_month(2023, 12), Some(31));
+    assert_eq!(days_in_month(2023, 0), None);
+    assert_eq!(days_in_month(2023, 13), None);
+
+    assert!(Date::new(2024, 2, 29).is_ok());
+    assert_eq!(
+        Date::new(2023, 2, 29),
+        Err(DateError::InvalidDay {
+            year: 2023,
+            month: 2,
+            day: 29
+        })
+    );
+    assert!(Date::new(1900, 2, 29).is_err());
+    assert!(Date::new(2000, 2, 29).is_ok());
+}
+
+#[test]
+fn epoch() {
+    assert_eq!(Date::UNIX_EPOCH.days_since_epoch(), 0);
+    assert_eq!(Date::UNIX_EPOCH.to_string(), "1970-01-01");
+    assert_eq!(Date::UNIX_EPOCH.weekday(), Weekday::Thursday);
+    assert_eq!(Date::from_days_since_epoch(0), Date::UNIX_EPOCH);
+    assert_eq!(date("1970-01-01"), Date::UNIX_EPOCH);
+
+    let before = Date::UNIX_EPOCH - 1;
+    assert_eq!(before.to_string(), "1969-12-31");
+    assert_eq!(before.weekday(), Weekday::Wednesday);
+}
+
+// Day counts and weekdays from Python's datetime
+#[test]
+fn known_dates() {
+    let known = [
+        ("2000-01-01", 10_957, Weekday::Saturday),
+        ("2038-01-19", 24_855, Weekday::Tuesday),
+        ("1969-12-31", -1, Weekday::Wednesday),
+        ("2024-02-29", 19_782, Weekday::Thursday),
+        ("0001-01-01", -719_162, Weekday::Monday),
+        ("9999-12-31", 2_932_896, Weekday::Friday),
+        // The first day of the Gregorian calendar
+        ("1582-10-15", -141_427, Weekday::Friday),
+    ];
+    for &(text, days, weekday) in &known {
+        let d = date(text);
+        assert_eq!(d.days_since_epoch(), days, "{}", text);
+        assert_eq!(d.weekday(), weekday, "{}", text);
+        assert_eq!(Date::from_days_since_epoch(days), d, "{}", text);
+    }
+}
+
+#[test]
+fn every_day_round_trips() {
+    // From 1000 BC to 3000 AD, one day at a time
+    let first = date("-1000-01-01").days_since_epoch();
+    let last = date("3000-12-31").days_since_epoch();
+    let mut previous = Date::from_days_since_epoch(first - 1);
+    for days in first..=last {
+        let d = Date::from_days_since_epoch(days);
+        assert_eq!(d.days_since_epoch(), days);
+        assert!(d > previous, "{} after {}", d, previous);
+        assert_eq!(Date::new(d.year(), d.month(), d.day()), Ok(d));
+        assert_eq!(date(&d.to_string()), d);
+        previous = d;
+    }
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(date("2000-03-01") - date("2000-02-28"), 2);
+    assert_eq!(date("1900-03-01") - date("1900-02-28"), 1);
+    assert_eq!(date("2024-01-01") - date("2023-01-01"), 365);
+    assert_eq!(date("2025-01-01") - date("2024-01-01"), 366);
+    assert_eq!(date("2023-01-01") - date("2024-01-01"), -365);
+
+    assert_eq!(date("2023-12-31") + 1, date("2024-01-01"));
+    assert_eq!(date("2024-03-01") - 1, date("2024-02-29"));
+    assert_eq!(date("2024-01-31").add_days(30), date("2024-03-01"));
+    assert_eq!(date("0001-01-01") - 1, date("0000-12-31"));
+
+    // 400 years is always the same number of days, and whole weeks
+    assert_eq!(date("2400-01-01") - date("2000-01-01"), 146_097);
+    assert_eq!(date("2400-01-01").weekday(), date("2000-01-01").weekday());
+}
+
+#[test]
+fn ordinal() {
+    assert_eq!(date("2023-01-01").ordinal(), 1);
+    assert_eq!(date("2023-03-01").ordinal(), 60);
+    assert_eq!(date("2024-03-01").ordinal(), 61);
+    assert_eq!(date("2023-12-31").ordinal(), 365);
+    assert_eq!(date("2024-12-31").ordinal(), 366);
+}
+
+#[test]
+fn parse_and_display() {
+    for &text in &[
+        "2024-02-29",
+        "0000-01-01",
+        "-0001-12-31",
+        "-0044-03-15",
+        "12345-06-07",
+    ] {
+        assert_eq!(date(text).to_string(), text);
+    }
+    assert_eq!(date("-0001-12-31").year(), -1);
+    assert_eq!(date("-0001-12-31") + 1, date("0000-01-01"));
+
+    assert_eq!(
+        "2023-13-01".parse::<Date>(),
+        Err(DateError::InvalidMonth(13))
+    );
+    assert_eq!(
+        "2023-00-01".parse::<Date>(),
+        Err(DateError::InvalidMonth(0))
+    );
+    assert!(matches!(
+        "2023-02-29".parse::<Date>(),
+        Err(DateError::InvalidDay { .. })
+    ));
+    for &bad in &[
+        "",
+        "2023",
+        "2023-01",
+        "23-01-01",
+        "2023-1-01",
+        "2023-01-1",
+        "2023/01/01",
+        "2023-01-01x",
+        "+2023-01-01",
+        "--2023-01-01",
+        "2023-01-01-01",
+    ] {
+        assert_eq!(
+            bad.parse::<Date>(),
+            Err(DateError::InvalidFormat(bad.to_string())),
+            "{:?}",
+            bad
+        );
+    }
+}
+
+#[test]
+fn durations() {
+    assert_eq!(format_duration(Duration::from_millis(0)), "0ms");
+    assert_eq!(format_duration(Duration::from_millis(999)), "999ms");
+    assert_eq!(format_duration(Duration::from_millis(1_500)), "1s");
+    assert_eq!(format_duration(Duration::from_secs(60)), "1m");
+    assert_eq!(format_duration(Duration::from_secs(3_600 + 5)), "1h 5s");
+    assert_eq!(
+        format_duration(Duration::from_secs(86_400 + 2 * 3_600 + 3 * 60 + 4)),
+        "1d 2h 3m 4s"
+    );
+    assert_eq!(format_duration(Duration::from_secs(10 * 86_400)), "10d");
+}
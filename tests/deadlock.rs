@@ -0,0 +1,76 @@
+/*
+ * chapters::deadlock: the watchdog turns the deliberate deadlock into a
+ * failure instead of a hung test run, and both fixes finish with the
+ * money where it should be. The deadlocked threads are leaked until the
+ * test binary exits, as with_watchdog documents.
+ * */
+#![cfg(feature = "ch16")]
+
+use rust_exercises::chapters::deadlock::{
+    provoke_deadlock, run_opposite_transfers, transfer_backoff, transfer_ordered, with_watchdog,
+    Account, Hung,
+};
+use std::time::Duration;
+
+const HANG: Duration = Duration::from_millis(200);
+const PLENTY: Duration = Duration::from_secs(10);
+const ROUNDS: usize = 10_000;
+
+#[test]
+fn watchdog_lets_finished_work_through() {
+    assert_eq!(with_watchdog(PLENTY, || 6 * 7), Ok(42));
+}
+
+#[test]
+fn watchdog_reports_the_deadlock() {
+    assert_eq!(provoke_deadlock(HANG), Err(Hung(HANG)));
+}
+
+// What a test stuck on a deadlock looks like with the watchdog around it
+#[test]
+#[should_panic(expected = "probably deadlocked")]
+fn deadlocking_test_fails() {
+    if let Err(hung) = provoke_deadlock(HANG) {
+        panic!("{}", hung);
+    }
+}
+
+#[test]
+fn ordered_locking_never_deadlocks() {
+    let balances = run_opposite_transfers(transfer_ordered, ROUNDS, PLENTY).unwrap();
+    let rounds = ROUNDS as i64;
+    assert_eq!(balances, (1_000 + rounds, 1_000 - rounds));
+}
+
+#[test]
+fn backoff_never_deadlocks() {
+    let transfer = |from: &Account, to: &Account, amount| {
+        transfer_backoff(from, to, amount);
+    };
+    let balances = run_opposite_transfers(transfer, ROUNDS, PLENTY).unwrap();
+    let rounds = ROUNDS as i64;
+    assert_eq!(balances, (1_000 + rounds, 1_000 - rounds));
+}
+
+#[test]
+fn single_transfers() {
+    let (a, b) = (Account::new(1, 50), Account::new(2, 0));
+    transfer_ordered(&b, &a, 5);
+    assert_eq!(transfer_backoff(&a, &b, 20), 1);
+    assert_eq!((a.balance(), b.balance()), (35, 15));
+    assert_eq!((a.id(), b.id()), (1, 2));
+}
+
+#[test]
+#[should_panic(expected = "transfer to the same account")]
+fn ordered_transfer_to_itself_is_refused() {
+    let a = Account::new(1, 50);
+    transfer_ordered(&a, &a, 5);
+}
+
+#[test]
+#[should_panic(expected = "transfer to the same account")]
+fn backoff_transfer_to_itself_is_refused() {
+    let a = Account::new(1, 50);
+    transfer_backoff(&a, &a, 5);
+}
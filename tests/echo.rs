@@ -0,0 +1,69 @@
+/*
+ * extras::net::echo on an ephemeral port: several clients at once get
+ * their own bytes back, and setting the flag stops the server
+ * */
+use rust_exercises::extras::net::echo::{self, Client};
+use std::io::{ErrorKind, Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn clients_get_their_lines_back() {
+    let (addr, shutdown, server) = echo::spawn("127.0.0.1:0").unwrap();
+    assert_ne!(addr.port(), 0);
+
+    let clients: Vec<_> = (0..4)
+        .map(|id| {
+            thread::spawn(move || {
+                let mut client = Client::connect(addr).unwrap();
+                for i in 0..50 {
+                    let message = format!("client {} line {}", id, i);
+                    assert_eq!(client.send(&message).unwrap(), message);
+                }
+                assert_eq!(client.send("").unwrap(), "");
+            })
+        })
+        .collect();
+    for client in clients {
+        client.join().unwrap();
+    }
+
+    shutdown.store(true, Ordering::SeqCst);
+    server.join().unwrap().unwrap();
+}
+
+#[test]
+fn bytes_come_back_as_they_were() {
+    let (addr, shutdown, server) = echo::spawn("127.0.0.1:0").unwrap();
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+
+    let sent: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+    stream.write_all(&sent).unwrap();
+    let mut received = vec![0; sent.len()];
+    stream.read_exact(&mut received).unwrap();
+    assert_eq!(received, sent);
+
+    // The server lets go of connections that are still open
+    shutdown.store(true, Ordering::SeqCst);
+    server.join().unwrap().unwrap();
+    let mut rest = Vec::new();
+    assert_eq!(stream.read_to_end(&mut rest).unwrap(), 0);
+}
+
+#[test]
+fn client_refuses_multi_line_messages() {
+    let (addr, shutdown, server) = echo::spawn("127.0.0.1:0").unwrap();
+    let mut client = Client::connect(addr).unwrap();
+    let e = client.send("two\nlines").unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::InvalidInput);
+    // Nothing was sent, the connection still works
+    assert_eq!(client.send("one line").unwrap(), "one line");
+
+    shutdown.store(true, Ordering::SeqCst);
+    server.join().unwrap().unwrap();
+}
@@ -0,0 +1,65 @@
+/*
+ * extras::errors keeps every cause, the rendered chain has to show all
+ * of them from the outermost in
+ * */
+mod common;
+
+use common::temp_tree;
+use rust_exercises::extras::errors::{load_limits, render_error_chain, AppError};
+
+fn chain(error: AppError) -> String {
+    render_error_chain(&error)
+}
+
+#[test]
+fn good_file_loads() {
+    let dir = temp_tree("errors_good", &[("limits.txt", "10\n\n 250 \n10000\n")]);
+    let limits = load_limits(dir.path().join("limits.txt")).unwrap();
+    assert_eq!(limits, [10, 250, 10_000]);
+}
+
+#[test]
+fn parse_error_chain() {
+    let dir = temp_tree("errors_parse", &[("limits.txt", "10\n\nten\n")]);
+    let path = dir.path().join("limits.txt");
+    let error = load_limits(&path).unwrap_err();
+    assert_eq!(
+        chain(error),
+        format!(
+            "can't load {}, line 3\n  caused by: not a whole number\n  caused by: invalid digit found in string",
+            path.display()
+        )
+    );
+}
+
+#[test]
+fn validation_error_chain() {
+    let dir = temp_tree("errors_validation", &[("limits.txt", "0\n")]);
+    let path = dir.path().join("limits.txt");
+    let error = load_limits(&path).unwrap_err();
+    assert_eq!(
+        chain(error),
+        format!(
+            "can't load {}, line 1\n  caused by: value out of range\n  caused by: 0 is outside 1..=10000",
+            path.display()
+        )
+    );
+}
+
+#[test]
+fn io_error_chain() {
+    let dir = temp_tree("errors_io", &[]);
+    let path = dir.path().join("missing.txt");
+    let error = load_limits(&path).unwrap_err();
+
+    // The OS message differs between platforms, ask the OS for it
+    let os_message = std::fs::read(&path).unwrap_err().to_string();
+    assert_eq!(
+        chain(error),
+        format!(
+            "can't read {}\n  caused by: I/O error\n  caused by: {}",
+            path.display(),
+            os_message
+        )
+    );
+}
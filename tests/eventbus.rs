@@ -0,0 +1,68 @@
+/*
+ * Handlers for two unrelated event types on one extras::eventbus bus,
+ * each only ever seeing its own events
+ * */
+use rust_exercises::extras::eventbus::EventBus;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+struct UserLoggedIn {
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct OrderPlaced {
+    amount: u32,
+}
+
+#[test]
+fn handlers_get_only_their_event_type() {
+    let mut bus = EventBus::new();
+    let logins = Rc::new(RefCell::new(Vec::new()));
+    let total = Rc::new(RefCell::new(0));
+    let order_log = Rc::new(RefCell::new(Vec::new()));
+
+    let seen = logins.clone();
+    bus.subscribe(move |login: &UserLoggedIn| seen.borrow_mut().push(login.name.clone()));
+    let sum = total.clone();
+    bus.subscribe(move |order: &OrderPlaced| *sum.borrow_mut() += order.amount);
+    let log = order_log.clone();
+    bus.subscribe(move |order: &OrderPlaced| log.borrow_mut().push(order.clone()));
+
+    assert_eq!(bus.subscribers::<UserLoggedIn>(), 1);
+    assert_eq!(bus.subscribers::<OrderPlaced>(), 2);
+
+    let ferris = UserLoggedIn {
+        name: String::from("ferris"),
+    };
+    assert_eq!(bus.publish(&ferris), 1);
+    assert_eq!(bus.publish(&OrderPlaced { amount: 30 }), 2);
+    assert_eq!(bus.publish(&OrderPlaced { amount: 12 }), 2);
+
+    assert_eq!(*logins.borrow(), ["ferris"]);
+    assert_eq!(*total.borrow(), 42);
+    assert_eq!(
+        *order_log.borrow(),
+        [OrderPlaced { amount: 30 }, OrderPlaced { amount: 12 }]
+    );
+}
+
+#[test]
+fn unsubscribe_and_unknown_types() {
+    let mut bus = EventBus::new();
+    let calls = Rc::new(RefCell::new(0));
+
+    let counter = calls.clone();
+    let id = bus.subscribe(move |_: &OrderPlaced| *counter.borrow_mut() += 1);
+    bus.publish(&OrderPlaced { amount: 1 });
+
+    assert!(bus.unsubscribe(id));
+    assert!(!bus.unsubscribe(id));
+    assert_eq!(bus.publish(&OrderPlaced { amount: 1 }), 0);
+    assert_eq!(*calls.borrow(), 1);
+
+    // Nobody subscribed to these, nothing to call
+    assert_eq!(bus.publish(&String::from("nobody listens")), 0);
+    assert_eq!(bus.subscribers::<u32>(), 0);
+}
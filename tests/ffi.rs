@@ -0,0 +1,89 @@
+/*
+ * The safe wrappers of chapters::ffi calling into libc and into the C in
+ * c/ffi_helpers.c that build.rs compiles. Each result is checked against
+ * the same computation done in Rust.
+ * */
+#![cfg(feature = "ch19")]
+
+mod common;
+
+use common::rng;
+use rust_exercises::chapters::ffi::{c_abs, c_greet, c_map, c_strlen, c_sum, double};
+use rust_exercises::extras::rng::Rng;
+use std::ffi::CString;
+
+#[test]
+fn abs_from_libc() {
+    assert_eq!(c_abs(-5), Some(5));
+    assert_eq!(c_abs(7), Some(7));
+    assert_eq!(c_abs(0), Some(0));
+    assert_eq!(c_abs(i32::MAX), Some(i32::MAX));
+    assert_eq!(c_abs(i32::MIN + 1), Some(i32::MAX));
+    // Undefined in C, never sent there
+    assert_eq!(c_abs(i32::MIN), None);
+}
+
+#[test]
+fn strlen_counts_bytes() {
+    for s in ["", "a", "hello", "h\u{e9}llo", "\u{1f980}"] {
+        assert_eq!(c_strlen(&CString::new(s).unwrap()), s.len());
+    }
+}
+
+#[test]
+fn sum_in_c_doesnt_overflow() {
+    assert_eq!(c_sum(&[]), 0);
+    assert_eq!(c_sum(&[1, 2, 3]), 6);
+    // The total is an int64_t, past what an i32 holds
+    assert_eq!(c_sum(&[i32::MAX, i32::MAX, 2]), 2 * i32::MAX as i64 + 2);
+    assert_eq!(c_sum(&[i32::MIN, i32::MIN]), 2 * i32::MIN as i64);
+
+    let mut rng = rng();
+    for _ in 0..100 {
+        let values: Vec<i32> = (0..rng.gen_range(0..1_000))
+            .map(|_| rng.next_u64() as i32)
+            .collect();
+        assert_eq!(
+            c_sum(&values),
+            values.iter().map(|&v| v as i64).sum::<i64>()
+        );
+    }
+}
+
+#[test]
+fn greet_grows_its_buffer() {
+    assert_eq!(c_greet("Ferris").unwrap(), "Hello, Ferris!");
+    assert_eq!(c_greet("").unwrap(), "Hello, !");
+    // The greeting of a 7 byte name and its NUL just fit the 16 bytes
+    // tried first, an 8 byte name needs a second call
+    let seven = "a".repeat(7);
+    assert_eq!(c_greet(&seven).unwrap(), format!("Hello, {}!", seven));
+    let eight = "a".repeat(8);
+    assert_eq!(c_greet(&eight).unwrap(), format!("Hello, {}!", eight));
+    let long = "long name ".repeat(50);
+    assert_eq!(c_greet(&long).unwrap(), format!("Hello, {}!", long));
+    assert_eq!(c_greet("\u{e9}t\u{e9}").unwrap(), "Hello, \u{e9}t\u{e9}!");
+}
+
+#[test]
+fn greet_rejects_interior_nul() {
+    let e = c_greet("Fer\0ris").unwrap_err();
+    assert_eq!(e.nul_position(), 3);
+}
+
+extern "C" fn negate(n: i32) -> i32 {
+    n.wrapping_neg()
+}
+
+#[test]
+fn map_calls_back_into_rust() {
+    let mut values = [1, -2, 3, i32::MAX];
+    c_map(&mut values, double);
+    assert_eq!(values, [2, -4, 6, i32::MAX.wrapping_mul(2)]);
+
+    c_map(&mut values, negate);
+    assert_eq!(values, [-2, 4, -6, i32::MAX.wrapping_mul(2).wrapping_neg()]);
+
+    let mut empty: [i32; 0] = [];
+    c_map(&mut empty, double);
+}
@@ -0,0 +1,45 @@
+/*
+ * The ffi-dlopen binary failing to load a library, and the C header
+ * keeping up with the exports. Its output with a freshly built cdylib is
+ * checked in chapter_outputs.rs.
+ * */
+#![cfg(unix)]
+
+use std::process::Command;
+
+const FFI_DLOPEN: &str = env!("CARGO_BIN_EXE_ffi-dlopen");
+
+#[test]
+fn a_missing_library_is_reported() {
+    let output = Command::new(FFI_DLOPEN)
+        .arg("/no/such/librust_exercises.so")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.starts_with("ffi-dlopen: /no/such/librust_exercises.so: "),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn the_header_declares_every_export() {
+    let exports = include_str!("../src/ffi_exports.rs");
+    let header = include_str!("../include/rust_exercises.h");
+
+    let names: Vec<&str> = exports
+        .split("pub unsafe extern \"C\" fn ")
+        .skip(1)
+        .map(|rest| rest.split('(').next().unwrap())
+        .collect();
+    assert!(!names.is_empty());
+    for name in names {
+        assert!(
+            header.contains(&format!(" {}(", name)),
+            "{} isn't in the header",
+            name
+        );
+    }
+}
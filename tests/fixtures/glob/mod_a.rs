@@ -0,0 +1 @@
+mod_a.rs
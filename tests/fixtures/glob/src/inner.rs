@@ -0,0 +1 @@
+src/inner.rs
@@ -0,0 +1,57 @@
+/*
+ * extras::fizzbuzz: the classic output, then rules added from outside
+ * the module without touching the loop
+ * */
+use rust_exercises::extras::fizzbuzz::{Divisor, FizzBuzzer, Rule};
+
+#[test]
+fn classic_one_to_fifteen() {
+    assert_eq!(
+        FizzBuzzer::classic().run(1..=15),
+        [
+            "1", "2", "Fizz", "4", "Buzz", "Fizz", "7", "8", "Fizz", "Buzz", "11", "Fizz", "13",
+            "14", "FizzBuzz"
+        ]
+    );
+}
+
+#[test]
+fn bazz_on_seven() {
+    let fizzbuzzer = FizzBuzzer::classic().with_rule(Box::new(Divisor::new(7, "Bazz")));
+    assert_eq!(fizzbuzzer.line(7), "Bazz");
+    assert_eq!(fizzbuzzer.line(21), "FizzBazz");
+    assert_eq!(fizzbuzzer.line(35), "BuzzBazz");
+    assert_eq!(fizzbuzzer.line(105), "FizzBuzzBazz");
+    assert_eq!(fizzbuzzer.line(11), "11");
+}
+
+// A rule that isn't about divisors at all
+struct Prime;
+
+impl Rule for Prime {
+    fn label(&self, n: u32) -> Option<&str> {
+        if n >= 2 && (2..n).all(|d| !n.is_multiple_of(d)) {
+            Some("Prime")
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn custom_rules_apply_in_order() {
+    let fizzbuzzer = FizzBuzzer::new()
+        .with_rule(Box::new(Prime))
+        .with_rule(Box::new(Divisor::new(3, "Fizz")));
+    assert_eq!(
+        fizzbuzzer.run(1..=6),
+        ["1", "Prime", "PrimeFizz", "4", "Prime", "Fizz"]
+    );
+}
+
+#[test]
+fn no_rules_and_a_zero_divisor() {
+    assert_eq!(FizzBuzzer::new().run(1..=3), ["1", "2", "3"]);
+    let zero = FizzBuzzer::new().with_rule(Box::new(Divisor::new(0, "Never")));
+    assert_eq!(zero.run(0..=2), ["0", "1", "2"]);
+}
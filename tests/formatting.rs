@@ -0,0 +1,94 @@
+/*
+ * Chapter 5's hand-written Display and Debug, checked character for
+ * character, with the format options callers may pass
+ * */
+#![cfg(feature = "ch5")]
+
+use rust_exercises::chapters::formatting::{Grid, Rectangle};
+
+#[test]
+fn rectangle_display_takes_padding() {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    assert_eq!(rect.to_string(), "30x50");
+    assert_eq!(format!("[{:<8}]", rect), "[30x50   ]");
+    assert_eq!(format!("[{:^9}]", rect), "[  30x50  ]");
+    assert_eq!(format!("[{:*>7}]", rect), "[**30x50]");
+    // A width narrower than the text doesn't cut it
+    assert_eq!(format!("[{:2}]", rect), "[30x50]");
+    assert_eq!(
+        Rectangle {
+            width: 0,
+            height: 7
+        }
+        .to_string(),
+        "0x7"
+    );
+}
+
+#[test]
+fn rectangle_debug() {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+    assert_eq!(
+        format!("{:?}", rect),
+        "Rectangle { width: 30, height: 50, area: 1500 }"
+    );
+    assert_eq!(
+        format!("{:#?}", rect),
+        "Rectangle {\n    width: 30,\n    height: 50,\n    area: 1500,\n}"
+    );
+    // Nested, the pretty form indents it with its parent
+    assert_eq!(
+        format!("{:#?}", Some(rect)),
+        "Some(\n    Rectangle {\n        width: 30,\n        height: 50,\n        area: 1500,\n    },\n)"
+    );
+}
+
+#[test]
+fn grid_aligns_columns() {
+    let grid = Grid::new(vec![
+        vec![String::from("name"), String::from("qty")],
+        vec![String::from("apples"), String::from("3")],
+        vec![String::from("kiwi"), String::from("12")],
+    ]);
+    assert_eq!(
+        grid.to_string(),
+        "  name qty\n\
+         apples   3\n  \
+         kiwi  12\n"
+    );
+    assert_eq!(grid.columns(), 2);
+    assert_eq!(grid.rows().len(), 3);
+}
+
+#[test]
+fn grid_width_counts_chars() {
+    let grid = Grid::new(vec![vec!["é", "x"], vec!["ab", "yy"]]);
+    assert_eq!(grid.to_string(), " é  x\nab yy\n");
+}
+
+#[test]
+fn grid_of_floats_and_ragged_rows() {
+    let grid = Grid::new(vec![vec![1.5, 10.0], vec![-0.25], vec![]]);
+    assert_eq!(grid.to_string(), "  1.5 10\n-0.25\n\n");
+    assert_eq!(grid.columns(), 2);
+
+    let empty: Grid<i32> = Grid::new(Vec::new());
+    assert_eq!(empty.to_string(), "");
+    assert_eq!(empty.columns(), 0);
+}
+
+#[test]
+fn grid_debug_is_the_shape() {
+    let grid = Grid::new(vec![vec![1; 100]; 50]);
+    assert_eq!(format!("{:?}", grid), "Grid { rows: 50, columns: 100, .. }");
+    assert_eq!(
+        format!("{:#?}", grid),
+        "Grid {\n    rows: 50,\n    columns: 100,\n    ..\n}"
+    );
+}
@@ -0,0 +1,132 @@
+/*
+ * extras::fsm: every state against every event, what the machine moves
+ * to or which error it gives, then whole purchases and overflows
+ * */
+use rust_exercises::extras::fsm::{Event, State, TransitionError, VendingMachine};
+
+const PRICE: u32 = 50;
+
+// A machine with two items left, driven into the state asked for
+fn machine_in(state: State) -> VendingMachine {
+    let mut machine = VendingMachine::new(PRICE, if state == State::OutOfStock { 0 } else { 2 });
+    let events: &[Event] = match state {
+        State::Idle | State::OutOfStock => &[],
+        State::Collecting { .. } => &[Event::InsertCoin(30)],
+        State::Dispensing { .. } => &[Event::InsertCoin(70), Event::Select],
+    };
+    for &event in events {
+        machine.handle(event).unwrap();
+    }
+    assert_eq!(machine.state(), state);
+    machine
+}
+
+const STATES: [State; 4] = [
+    State::Idle,
+    State::Collecting { credit: 30 },
+    State::Dispensing { change: 20 },
+    State::OutOfStock,
+];
+
+const EVENTS: [Event; 6] = [
+    Event::InsertCoin(10),
+    Event::Select,
+    Event::Cancel,
+    Event::TakeItem,
+    Event::Restock(3),
+    Event::Restock(0),
+];
+
+// What each pair should give, None being Invalid
+fn expected(state: State, event: Event) -> Option<Result<State, TransitionError>> {
+    Some(Ok(match (state, event) {
+        (State::Idle, Event::InsertCoin(10)) => State::Collecting { credit: 10 },
+        (State::Idle, Event::Restock(_)) => State::Idle,
+        (State::Collecting { .. }, Event::InsertCoin(10)) => State::Collecting { credit: 40 },
+        (State::Collecting { .. }, Event::Select) => {
+            return Some(Err(TransitionError::InsufficientCredit {
+                credit: 30,
+                price: PRICE,
+            }))
+        }
+        (State::Collecting { .. }, Event::Cancel) => State::Idle,
+        (State::Dispensing { .. }, Event::TakeItem) => State::Idle,
+        (State::OutOfStock, Event::Restock(3)) => State::Idle,
+        _ => return None,
+    }))
+}
+
+#[test]
+fn every_state_and_event() {
+    for &state in STATES.iter() {
+        for &event in EVENTS.iter() {
+            let mut machine = machine_in(state);
+            let result = machine.handle(event);
+            let expected =
+                expected(state, event).unwrap_or(Err(TransitionError::Invalid { state, event }));
+            assert_eq!(result, expected, "{:?} on {:?}", event, state);
+            match result {
+                Ok(next) => assert_eq!(machine.state(), next),
+                // Errors leave the machine where it was
+                Err(_) => assert_eq!(machine.state(), state, "{:?} on {:?}", event, state),
+            }
+        }
+    }
+}
+
+#[test]
+fn buying_the_last_item() {
+    let mut machine = VendingMachine::new(PRICE, 1);
+    assert_eq!(
+        machine.handle(Event::InsertCoin(20)),
+        Ok(State::Collecting { credit: 20 })
+    );
+    assert_eq!(
+        machine.handle(Event::InsertCoin(40)),
+        Ok(State::Collecting { credit: 60 })
+    );
+    assert_eq!(
+        machine.handle(Event::Select),
+        Ok(State::Dispensing { change: 10 })
+    );
+    assert_eq!(machine.stock(), 0);
+    assert_eq!(machine.handle(Event::TakeItem), Ok(State::OutOfStock));
+    assert!(machine.handle(Event::InsertCoin(50)).is_err());
+    assert_eq!(machine.handle(Event::Restock(5)), Ok(State::Idle));
+    assert_eq!(machine.stock(), 5);
+}
+
+#[test]
+fn restocking_while_idle_adds_up() {
+    let mut machine = VendingMachine::new(PRICE, 2);
+    machine.handle(Event::Restock(3)).unwrap();
+    assert_eq!(machine.stock(), 5);
+    assert_eq!(VendingMachine::new(PRICE, 0).state(), State::OutOfStock);
+}
+
+#[test]
+fn overflows_are_errors() {
+    let mut machine = VendingMachine::new(PRICE, 2);
+    let event = Event::Restock(u32::MAX);
+    assert_eq!(
+        machine.handle(event),
+        Err(TransitionError::Overflow {
+            state: State::Idle,
+            event
+        })
+    );
+    assert_eq!(machine.stock(), 2);
+
+    machine.handle(Event::InsertCoin(u32::MAX)).unwrap();
+    let event = Event::InsertCoin(1);
+    let error = machine.handle(event).unwrap_err();
+    assert_eq!(
+        error,
+        TransitionError::Overflow {
+            state: State::Collecting { credit: u32::MAX },
+            event
+        }
+    );
+    assert_eq!(machine.state(), State::Collecting { credit: u32::MAX });
+    assert!(error.to_string().contains("overflows"));
+}
@@ -0,0 +1,114 @@
+/*
+ * extras::glob: the wildcard syntax case by case, the iterative matcher
+ * against a naive recursive one on random patterns, and filter_dir on
+ * the directory in tests/fixtures/glob
+ * */
+mod common;
+
+use common::{fixture_path, rng};
+use rust_exercises::extras::glob::{filter_dir, matches};
+use rust_exercises::extras::rng::{Rng, XorShift64};
+use std::time::{Duration, Instant};
+
+#[test]
+fn wildcard_syntax() {
+    let cases = vec![
+        ("*.rs", "main.rs", true),
+        ("*.rs", "main.rsx", false),
+        ("*", "", true),
+        ("", "", true),
+        ("", "a", false),
+        ("a**b", "ab", true),
+        ("?", "é", true),
+        ("??", "a", false),
+        ("m?in.*", "main.c", true),
+        ("[abc]x", "bx", true),
+        ("[abc]x", "dx", false),
+        ("[a-c0-9]", "7", true),
+        ("[!a-c]", "b", false),
+        ("[^a-c]", "d", true),
+        // A ] right after the [ is a member
+        ("[]]", "]", true),
+        ("[!]]", "a", true),
+        // A - at the end is a member too
+        ("[a-]", "-", true),
+        ("\\*", "*", true),
+        ("\\*", "a", false),
+        ("\\[a]", "[a]", true),
+        // Unclosed classes and a trailing backslash are literals
+        ("[abc", "[abc", true),
+        ("a\\", "a\\", true),
+        ("*a*b*c", "xxaxxbxxcxx", false),
+        ("*a*b*c*", "xxaxxbxxcxx", true),
+    ];
+    for (pattern, text, expected) in cases {
+        assert_eq!(
+            matches(pattern, text),
+            expected,
+            "{:?} on {:?}",
+            pattern,
+            text
+        );
+    }
+}
+
+// The textbook recursion, exponential but obviously right
+fn naive(pattern: &[char], text: &[char]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, _) => text.is_empty(),
+        (Some('*'), _) => {
+            naive(&pattern[1..], text) || (!text.is_empty() && naive(pattern, &text[1..]))
+        }
+        (Some(_), None) => false,
+        (Some(&p), Some(&t)) => (p == '?' || p == t) && naive(&pattern[1..], &text[1..]),
+    }
+}
+
+fn word(rng: &mut XorShift64, alphabet: &[char]) -> String {
+    (0..rng.gen_range(0..8))
+        .map(|_| alphabet[rng.gen_range(0..alphabet.len() as u64) as usize])
+        .collect()
+}
+
+#[test]
+fn agrees_with_the_naive_recursion() {
+    let mut rng = rng();
+    for _ in 0..5000 {
+        let pattern = word(&mut rng, &['a', 'b', '*', '?']);
+        let text = word(&mut rng, &['a', 'b']);
+        let (p, t): (Vec<char>, Vec<char>) = (pattern.chars().collect(), text.chars().collect());
+        assert_eq!(
+            matches(&pattern, &text),
+            naive(&p, &t),
+            "{:?} on {:?}",
+            pattern,
+            text
+        );
+    }
+}
+
+#[test]
+fn no_exponential_blow_up() {
+    let pattern = "a*".repeat(30) + "b";
+    let text = "a".repeat(200);
+    let start = Instant::now();
+    assert!(!matches(&pattern, &text));
+    // The recursive version would take longer than the universe has left
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn filter_a_fixture_dir() {
+    let dir = fixture_path("glob");
+    let filter = |pattern| filter_dir(&dir, pattern).unwrap();
+    assert_eq!(filter("*.rs"), ["lib.rs", "main.rs", "mod_a.rs"]);
+    assert_eq!(filter("mod_?.*"), ["mod_a.rs", "mod_b.txt"]);
+    assert_eq!(filter("*[0-9]*"), ["notes-2024.txt"]);
+    assert_eq!(filter("[A-Z]*"), ["README.md"]);
+    // Directory names count, nothing recurses into them
+    assert_eq!(filter("s*"), ["src"]);
+    // Hidden files need the pattern to start with a dot
+    assert_eq!(filter(".*"), [".hidden.rs"]);
+    assert!(filter("*.py").is_empty());
+    assert!(filter_dir(dir.join("missing"), "*").is_err());
+}
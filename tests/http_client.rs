@@ -0,0 +1,177 @@
+/*
+ * extras::http_client against a fixture server on an ephemeral port: a
+ * thread that accepts one connection, keeps the request it got and
+ * answers with canned bytes, then closes
+ * */
+use rust_exercises::extras::http_client::{self, parse_response, HttpError, Url};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+// The base URL to get from, and the request once it has been answered
+fn fixture_server(response: &'static [u8]) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request = String::new();
+        while !request.ends_with("\r\n\r\n") {
+            if reader.read_line(&mut request).unwrap() == 0 {
+                break;
+            }
+        }
+        let mut stream = stream;
+        stream.write_all(response).unwrap();
+        request
+    });
+    (base, handle)
+}
+
+#[test]
+fn get_with_content_length() {
+    let (base, server) = fixture_server(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\ncontent-length: 5\r\n\r\nhello and more",
+    );
+    let response = http_client::get(&format!("{}/greeting?lang=en#top", base)).unwrap();
+    assert_eq!((response.status, response.reason.as_str()), (200, "OK"));
+    assert_eq!(response.header("CONTENT-TYPE"), Some("text/plain"));
+    // Only Content-Length bytes are the body
+    assert_eq!(response.text(), "hello");
+
+    let request = server.join().unwrap();
+    let port = base.rsplit(':').next().unwrap();
+    assert!(
+        request.starts_with("GET /greeting?lang=en HTTP/1.1\r\n"),
+        "{}",
+        request
+    );
+    assert!(request.contains(&format!("\r\nHost: 127.0.0.1:{}\r\n", port)));
+    assert!(request.contains("\r\nConnection: close\r\n"));
+}
+
+#[test]
+fn body_without_length_runs_to_eof() {
+    let (base, server) =
+        fixture_server(b"HTTP/1.0 404 Not Found\r\nServer: fixture\r\n\r\nno such page\n");
+    let response = http_client::get(&base).unwrap();
+    assert_eq!(response.status, 404);
+    assert_eq!(response.reason, "Not Found");
+    assert_eq!(response.text(), "no such page\n");
+    assert!(server.join().unwrap().starts_with("GET / HTTP/1.1\r\n"));
+}
+
+#[test]
+fn no_body_statuses() {
+    let (base, server) = fixture_server(b"HTTP/1.1 204 No Content\r\nContent-Length: 10\r\n\r\n");
+    let response = http_client::get(&base).unwrap();
+    assert_eq!(response.status, 204);
+    assert!(response.body.is_empty());
+    server.join().unwrap();
+}
+
+// Whether the error is the one a case expects
+type Check = fn(&HttpError) -> bool;
+
+#[test]
+fn server_errors_in_the_response() {
+    let cases: Vec<(&'static [u8], Check)> = vec![
+        (b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort", |e| {
+            matches!(
+                e,
+                HttpError::TruncatedBody {
+                    expected: 10,
+                    received: 5
+                }
+            )
+        }),
+        (
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n0\r\n\r\n",
+            |e| matches!(e, HttpError::UnsupportedTransferEncoding(v) if v == "chunked"),
+        ),
+        (b"SMTP ready\r\n\r\n", |e| {
+            matches!(e, HttpError::InvalidStatusLine(_))
+        }),
+        (
+            b"HTTP/1.1 200 OK\r\nContent-Length: lots\r\n\r\n",
+            |e| matches!(e, HttpError::InvalidContentLength(v) if v == "lots"),
+        ),
+        (b"HTTP/1.1 200 OK\r\nno colon here\r\n\r\n", |e| {
+            matches!(e, HttpError::InvalidHeader(_))
+        }),
+        // Closed before the end of the headers
+        (
+            b"HTTP/1.1 200 OK\r\nServer: x\r\n",
+            |e| matches!(e, HttpError::InvalidHeader(line) if line.is_empty()),
+        ),
+    ];
+    for (bytes, expected) in cases {
+        let (base, server) = fixture_server(bytes);
+        let e = http_client::get(&base).unwrap_err();
+        assert!(
+            expected(&e),
+            "{:?} for {:?}",
+            e,
+            String::from_utf8_lossy(bytes)
+        );
+        server.join().unwrap();
+    }
+}
+
+#[test]
+fn connection_refused_is_io() {
+    // Bound and dropped right away, so nothing listens there anymore
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let e = http_client::get(&format!("http://127.0.0.1:{}/", port)).unwrap_err();
+    assert!(matches!(e, HttpError::Io(_)), "{:?}", e);
+}
+
+#[test]
+fn urls() {
+    let url = Url::parse("HTTP://example.com:8080/a/b?c=d#frag").unwrap();
+    assert_eq!(
+        url,
+        Url {
+            host: "example.com".to_string(),
+            port: 8080,
+            path: "/a/b?c=d".to_string()
+        }
+    );
+    assert_eq!(Url::parse("http://example.com").unwrap().path, "/");
+    assert_eq!(Url::parse("http://example.com?q").unwrap().path, "/?q");
+    assert_eq!(Url::parse("http://example.com").unwrap().port, 80);
+
+    assert!(
+        matches!(Url::parse("https://example.com"), Err(HttpError::UnsupportedScheme(s)) if s == "https")
+    );
+    for &bad in [
+        "example.com",
+        "http://",
+        "http://:80/",
+        "http://user@host/",
+        "http://host:port/",
+    ]
+    .iter()
+    {
+        assert!(
+            matches!(Url::parse(bad), Err(HttpError::InvalidUrl(_))),
+            "{}",
+            bad
+        );
+    }
+}
+
+#[test]
+fn parses_responses_from_any_reader() {
+    let response = parse_response(&b"HTTP/1.1 301 Moved\r\nLocation: /new\r\n\r\n"[..]).unwrap();
+    assert_eq!(response.status, 301);
+    assert_eq!(response.header("location"), Some("/new"));
+    assert_eq!(
+        response.headers,
+        [("Location".to_string(), "/new".to_string())]
+    );
+}
@@ -0,0 +1,125 @@
+/*
+ * extras::ini: Display followed by parse gives the same Config back, and
+ * set() turns away what would break that
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::ini::{Config, ParseError, ParseErrorKind, SetError};
+use rust_exercises::extras::rng::Rng;
+
+const SAMPLE: &str = "\
+; a comment
+name = global value
+empty =
+
+[server]
+port = 8080
+verbose = yes
+url = http://host/?a=b
+
+[empty]
+";
+
+#[test]
+fn parsed_file_round_trips() {
+    let config = Config::parse(SAMPLE).unwrap();
+    assert_eq!(config.sections(), ["", "server", "empty"]);
+    assert_eq!(config.get("server", "url"), Some("http://host/?a=b"));
+    assert_eq!(config.get("", "empty"), Some(""));
+
+    let written = config.to_string();
+    assert!(!written.contains("comment"));
+    assert_eq!(Config::parse(&written).unwrap(), config);
+}
+
+#[test]
+fn unnamed_section_is_written_first() {
+    let mut config = Config::new();
+    config.set("later", "a", "1").unwrap();
+    config.set("", "top", "2").unwrap();
+    let written = config.to_string();
+    assert!(written.starts_with("top = 2\n"));
+    assert_eq!(config.sections(), ["", "later"]);
+    assert_eq!(Config::parse(&written).unwrap(), config);
+}
+
+#[test]
+fn set_rejects_what_text_cannot_hold() {
+    let mut config = Config::new();
+    for &key in [
+        "",
+        "a=b",
+        ";key",
+        "#key",
+        "[key",
+        " key",
+        "key ",
+        "two\nlines",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            config.set("", key, "v"),
+            Err(SetError::InvalidKey(key.to_string())),
+            "{:?}",
+            key
+        );
+    }
+    for &value in [" padded", "padded ", "two\nlines", "cr\r"].iter() {
+        assert_eq!(
+            config.set("", "key", value),
+            Err(SetError::InvalidValue(value.to_string()))
+        );
+    }
+    for &section in [" s", "two\nlines"].iter() {
+        assert_eq!(
+            config.set(section, "key", "v"),
+            Err(SetError::InvalidSection(section.to_string()))
+        );
+    }
+    // Nothing was half set on the way
+    assert_eq!(config, Config::new());
+
+    // Only the start of a key is special, and = is fine in a value
+    config.set("s]x", "k;#[", "= ; # [").unwrap();
+    assert_eq!(Config::parse(&config.to_string()).unwrap(), config);
+}
+
+#[test]
+fn random_configs_round_trip() {
+    let alphabet: Vec<char> = "ab Z;#[]=_-.".chars().collect();
+    let mut rng = rng();
+    for _ in 0..300 {
+        let mut config = Config::new();
+        for _ in 0..rng.gen_range(0..8) {
+            let mut word = || -> String {
+                (0..rng.gen_range(0..5))
+                    .map(|_| alphabet[rng.gen_range(0..alphabet.len() as u64) as usize])
+                    .collect()
+            };
+            let (section, key, value) = (word(), word(), word());
+            if config.set(&section, &key, &value).is_ok() {
+                assert_eq!(config.get(&section, &key), Some(value.as_str()));
+            }
+        }
+        let written = config.to_string();
+        assert_eq!(Config::parse(&written).unwrap(), config, "{:?}", written);
+    }
+}
+
+#[test]
+fn parse_errors_name_the_line() {
+    let cases = vec![
+        ("[open", ParseErrorKind::UnclosedSection),
+        ("[ ]", ParseErrorKind::EmptySectionName),
+        ("no equals", ParseErrorKind::MissingEquals),
+        (" = value", ParseErrorKind::EmptyKey),
+    ];
+    for (line, kind) in cases {
+        assert_eq!(
+            Config::parse(&format!("ok = 1\n\n{}\n", line)),
+            Err(ParseError { line: 3, kind })
+        );
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * extras::interior: the Cell, RefCell and Mutex versions behave the same,
+ * and RefCell's borrow rules are enforced when the code runs
+ * */
+use rust_exercises::extras::interior::{CellCounter, MutexCache, MutexCounter, RefCellCache};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn counters_count() {
+    let cell = CellCounter::new();
+    let mutex = MutexCounter::new();
+    for expected in 1..=5 {
+        assert_eq!(cell.increment(), expected);
+        assert_eq!(mutex.increment(), expected);
+    }
+    assert_eq!((cell.get(), mutex.get()), (5, 5));
+}
+
+#[test]
+fn mutex_counter_is_shared_between_threads() {
+    let counter = Arc::new(MutexCounter::new());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    counter.increment();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(counter.get(), 4000);
+}
+
+#[test]
+fn caches_compute_once() {
+    let refcell = RefCellCache::new();
+    let mutex = MutexCache::new();
+    assert!(refcell.is_empty() && mutex.is_empty());
+    assert_eq!(refcell.get_or_insert_with(1, || 10), 10);
+    assert_eq!(mutex.get_or_insert_with(1, || 10), 10);
+    // Already there, the closure isn't called
+    assert_eq!(refcell.get_or_insert_with(1, || unreachable!()), 10);
+    assert_eq!(mutex.get_or_insert_with(1, || unreachable!()), 10);
+    assert_eq!((refcell.len(), mutex.len()), (1, 1));
+}
+
+#[test]
+fn double_borrow_panics_at_runtime() {
+    let cache = RefCellCache::new();
+    cache.get_or_insert_with(1, || 1);
+    assert!(cache.double_borrow_panics());
+    // The panic happened before clear(), and the cache is usable again
+    assert_eq!(cache.len(), 1);
+    assert!(cache.try_clear());
+    assert!(cache.is_empty());
+
+    // The same thing by hand
+    let cell = RefCell::new(vec![1]);
+    let reading = cell.borrow();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| cell.borrow_mut().push(2)));
+    let message = result.unwrap_err();
+    let message = message
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| message.downcast_ref::<&str>().copied())
+        .unwrap();
+    assert!(message.contains("already borrowed"), "{}", message);
+    assert!(cell.try_borrow_mut().is_err());
+    drop(reading);
+    assert!(cell.try_borrow_mut().is_ok());
+}
@@ -0,0 +1,99 @@
+/*
+ * extras::intervals on hand-picked edge cases, then on random sets of
+ * intervals checking what has to hold for any input: merge's output is
+ * sorted, never overlaps or touches, and covers exactly the same points.
+ * TEST_SEED=<n> repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::intervals::{any_overlap, gaps, merge, Interval};
+use rust_exercises::extras::rng::Rng;
+
+fn interval(start: i32, end: i32) -> Interval<i32> {
+    Interval::new(start, end).unwrap()
+}
+
+fn bounds(intervals: &[Interval<i32>]) -> Vec<(i32, i32)> {
+    intervals.iter().map(|i| (*i.start(), *i.end())).collect()
+}
+
+#[test]
+fn touching_ends_merge_but_dont_overlap() {
+    let a = interval(1, 3);
+    let b = interval(3, 5);
+    assert!(!a.overlaps(&b));
+    assert!(!any_overlap(&[a, b]));
+    assert_eq!(bounds(&merge(vec![b, a])), [(1, 5)]);
+    assert!(gaps(&[a, b]).is_empty());
+}
+
+#[test]
+fn edge_cases() {
+    assert_eq!(Interval::new(5, 1), None);
+    assert!(merge::<i32>(vec![]).is_empty());
+
+    // Empty intervals cover nothing, even inside another one
+    let empty = interval(2, 2);
+    assert!(!empty.overlaps(&interval(0, 10)));
+    assert!(!empty.contains(&2));
+    assert!(merge(vec![empty]).is_empty());
+
+    // One inside another
+    assert_eq!(
+        bounds(&merge(vec![interval(0, 10), interval(2, 3)])),
+        [(0, 10)]
+    );
+    assert!(any_overlap(&[
+        interval(0, 10),
+        interval(12, 13),
+        interval(2, 3)
+    ]));
+
+    let spread = [interval(8, 9), interval(0, 2), interval(1, 4)];
+    assert_eq!(bounds(&gaps(&spread)), [(4, 8)]);
+    assert_eq!(interval(4, 8).to_string(), "[4, 8)");
+
+    let words = merge(vec![
+        Interval::new("cat", "dog").unwrap(),
+        Interval::new("bee", "cow").unwrap(),
+    ]);
+    assert_eq!(words, [Interval::new("bee", "dog").unwrap()]);
+}
+
+#[test]
+fn merge_properties_on_random_input() {
+    let mut rng = rng();
+    for _ in 0..500 {
+        let count = rng.gen_range(0..12) as usize;
+        let input: Vec<Interval<i32>> = (0..count)
+            .map(|_| {
+                let start = rng.gen_range(0..50) as i32;
+                let len = rng.gen_range(0..10) as i32;
+                interval(start, start + len)
+            })
+            .collect();
+        let merged = merge(input.clone());
+
+        // Sorted, with a real gap between neighbours
+        for pair in merged.windows(2) {
+            assert!(pair[0].end() < pair[1].start(), "{:?}", merged);
+        }
+        assert!(!any_overlap(&merged));
+        assert!(merged.iter().all(|i| !i.is_empty()));
+
+        // The same points covered
+        for point in -1..61 {
+            let before = input.iter().any(|i| i.contains(&point));
+            let after = merged.iter().any(|i| i.contains(&point));
+            assert_eq!(before, after, "point {} of {:?}", point, input);
+        }
+
+        // Gaps fill in exactly what merge leaves out between its ends
+        let holes = gaps(&input);
+        assert_eq!(holes.len(), merged.len().saturating_sub(1));
+        assert!(holes
+            .iter()
+            .all(|hole| !merged.iter().any(|i| i.overlaps(hole))));
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * sliding_windows and chunks_of on ranges and vectors against the slice
+ * methods they copy, and on iterators that aren't fused
+ * */
+use rust_exercises::extras::iter_ext::IterExt;
+use std::cell::Cell;
+
+#[test]
+fn windows_like_slice_windows() {
+    let items: Vec<u32> = (0..10).collect();
+    for size in 1..=12 {
+        let expected: Vec<Vec<u32>> = items.windows(size).map(|w| w.to_vec()).collect();
+        assert_eq!(
+            items
+                .iter()
+                .copied()
+                .sliding_windows(size)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!((0..10).sliding_windows(size).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn chunks_like_slice_chunks() {
+    let items: Vec<u32> = (0..10).collect();
+    for size in 1..=12 {
+        let expected: Vec<Vec<u32>> = items.chunks(size).map(|c| c.to_vec()).collect();
+        assert_eq!(
+            items
+                .clone()
+                .into_iter()
+                .chunks_of(size)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!((0..10).chunks_of(size).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!((0..0).sliding_windows(1).next(), None);
+    assert_eq!((0..0).chunks_of(3).next(), None);
+    assert_eq!(
+        Vec::<String>::new().into_iter().sliding_windows(2).count(),
+        0
+    );
+}
+
+#[test]
+fn non_copy_items() {
+    let words = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    let windows: Vec<Vec<String>> = words.iter().cloned().sliding_windows(2).collect();
+    assert_eq!(windows, [["a", "b"], ["b", "c"]]);
+    let chunks: Vec<Vec<String>> = words.into_iter().chunks_of(2).collect();
+    assert_eq!(chunks, [vec!["a", "b"], vec!["c"]]);
+}
+
+#[test]
+fn endless_iterators() {
+    let windows: Vec<Vec<u64>> = (1..).sliding_windows(3).take(3).collect();
+    assert_eq!(windows, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    let chunks: Vec<Vec<u64>> = (1..).chunks_of(2).skip(1).take(2).collect();
+    assert_eq!(chunks, [[3, 4], [5, 6]]);
+}
+
+#[test]
+fn chained_with_std_adapters() {
+    let sums: Vec<u32> = (1..=5).sliding_windows(2).map(|w| w.iter().sum()).collect();
+    assert_eq!(sums, [3, 5, 7, 9]);
+    let lens: Vec<usize> = "abcdefg".chars().chunks_of(3).map(|c| c.len()).collect();
+    assert_eq!(lens, [3, 3, 1]);
+}
+
+/*
+ * Gives None once after `every` items, then carries on, the way an
+ * iterator that isn't fused is allowed to. Counts every call.
+ * */
+struct Flaky<'a> {
+    next: u32,
+    every: u32,
+    calls: &'a Cell<u32>,
+}
+
+impl Iterator for Flaky<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.calls.set(self.calls.get() + 1);
+        if self.calls.get() == self.every + 1 {
+            return None;
+        }
+        self.next += 1;
+        Some(self.next)
+    }
+}
+
+#[test]
+fn the_inner_iterator_isnt_called_after_none() {
+    let calls = Cell::new(0);
+    let mut windows = Flaky {
+        next: 0,
+        every: 3,
+        calls: &calls,
+    }
+    .sliding_windows(2);
+    assert_eq!(windows.next(), Some(vec![1, 2]));
+    assert_eq!(windows.next(), Some(vec![2, 3]));
+    assert_eq!(windows.next(), None);
+    let after_none = calls.get();
+    // Without fusing, these would pick up the items after the None
+    assert_eq!(windows.next(), None);
+    assert_eq!(windows.next(), None);
+    assert_eq!(calls.get(), after_none);
+
+    let calls = Cell::new(0);
+    let mut chunks = Flaky {
+        next: 0,
+        every: 3,
+        calls: &calls,
+    }
+    .chunks_of(2);
+    assert_eq!(chunks.next(), Some(vec![1, 2]));
+    assert_eq!(chunks.next(), Some(vec![3]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.next(), None);
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn zero_sized_windows() {
+    let _ = (0..3).sliding_windows(0);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn zero_sized_chunks() {
+    let _ = (0..3).chunks_of(0);
+}
@@ -0,0 +1,142 @@
+/*
+ * extras::json against a corpus of valid and invalid documents, then
+ * the depth limit, non-finite numbers and printing back what was parsed
+ * */
+use rust_exercises::extras::json::{parse, ErrorKind, Value, MAX_DEPTH};
+use std::collections::BTreeMap;
+
+const VALID: &[&str] = &[
+    "null",
+    "true",
+    "false",
+    "0",
+    "-0",
+    "42",
+    "-12.5e-3",
+    "1E+2",
+    r#""""#,
+    r#""tab\there""#,
+    r#""été""#,
+    r#""🦀""#,
+    r#""\"\\\/\b\f\n\r""#,
+    "[]",
+    "{}",
+    " [ 1 , [ 2 , [ ] ] ] ",
+    r#"{"a": {"b": [true, null]}, "c": "d"}"#,
+    "\n\t{\r\n\"k\" : 1 }\n",
+];
+
+const INVALID: &[(&str, ErrorKind)] = &[
+    ("", ErrorKind::UnexpectedEnd),
+    ("   ", ErrorKind::UnexpectedEnd),
+    ("nul", ErrorKind::UnexpectedEnd),
+    ("truth", ErrorKind::UnexpectedChar('t')),
+    ("01", ErrorKind::TrailingCharacters),
+    ("1.", ErrorKind::InvalidNumber),
+    ("-", ErrorKind::InvalidNumber),
+    (".5", ErrorKind::UnexpectedChar('.')),
+    ("1e", ErrorKind::InvalidNumber),
+    ("+1", ErrorKind::UnexpectedChar('+')),
+    (r#""open"#, ErrorKind::UnexpectedEnd),
+    (r#""\x""#, ErrorKind::InvalidEscape),
+    (r#""\u12G4""#, ErrorKind::InvalidUnicode),
+    (r#""\ud83e""#, ErrorKind::InvalidUnicode),
+    ("\"a\nb\"", ErrorKind::ControlCharInString),
+    ("[1,]", ErrorKind::UnexpectedChar(']')),
+    ("[1 2]", ErrorKind::UnexpectedChar('2')),
+    ("[", ErrorKind::UnexpectedEnd),
+    (r#"{"a" 1}"#, ErrorKind::UnexpectedChar('1')),
+    (r#"{"a": 1,}"#, ErrorKind::UnexpectedChar('}')),
+    ("{1: 2}", ErrorKind::UnexpectedChar('1')),
+    ("[] []", ErrorKind::TrailingCharacters),
+    ("'single'", ErrorKind::UnexpectedChar('\'')),
+];
+
+#[test]
+fn valid_corpus_parses_and_prints_back() {
+    for doc in VALID {
+        let value = parse(doc).unwrap_or_else(|e| panic!("{:?}: {}", doc, e));
+        assert_eq!(parse(&value.to_string()).unwrap(), value, "{}", doc);
+        assert_eq!(parse(&value.to_pretty()).unwrap(), value, "{}", doc);
+    }
+}
+
+#[test]
+fn invalid_corpus_fails_with_the_right_kind() {
+    for (doc, kind) in INVALID {
+        match parse(doc) {
+            Ok(value) => panic!("{:?} parsed as {:?}", doc, value),
+            Err(e) => assert_eq!(&e.kind, kind, "{:?}: {}", doc, e),
+        }
+    }
+}
+
+#[test]
+fn values_come_out_right() {
+    let mut inner = BTreeMap::new();
+    inner.insert(
+        String::from("b"),
+        Value::Array(vec![Value::Bool(true), Value::Null]),
+    );
+    let mut outer = BTreeMap::new();
+    outer.insert(String::from("a"), Value::Object(inner));
+    outer.insert(String::from("c"), Value::String(String::from("d")));
+    assert_eq!(parse(VALID[16]).unwrap(), Value::Object(outer));
+
+    assert_eq!(parse("-12.5e-3").unwrap(), Value::Number(-0.0125));
+    assert_eq!(
+        parse(r#""été""#).unwrap(),
+        Value::String(String::from("été"))
+    );
+    assert_eq!(parse(r#""🦀""#).unwrap(), Value::String(String::from("🦀")));
+}
+
+#[test]
+fn errors_point_at_line_and_column() {
+    let e = parse("{\n  \"a\": [1,\n    oops]\n}").unwrap_err();
+    assert_eq!((e.line, e.column), (3, 5));
+    assert_eq!(e.to_string(), "unexpected character 'o' at line 3 column 5");
+}
+
+#[test]
+fn depth_limit() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(parse(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        parse(&nested(MAX_DEPTH + 1)).unwrap_err().kind,
+        ErrorKind::TooDeep
+    );
+    let objects = format!(
+        "{}1{}",
+        r#"{"a":"#.repeat(MAX_DEPTH + 1),
+        "}".repeat(MAX_DEPTH + 1)
+    );
+    assert_eq!(parse(&objects).unwrap_err().kind, ErrorKind::TooDeep);
+    // Far past the limit fails the same way instead of overflowing the stack
+    assert_eq!(
+        parse(&"[".repeat(1_000_000)).unwrap_err().kind,
+        ErrorKind::TooDeep
+    );
+}
+
+#[test]
+fn non_finite_numbers() {
+    // Too big for an f64, JSON has no way to write infinity
+    assert_eq!(parse("1e999").unwrap_err().kind, ErrorKind::InvalidNumber);
+    assert_eq!(parse("-1e400").unwrap_err().kind, ErrorKind::InvalidNumber);
+    for word in ["NaN", "Infinity", "-Infinity"].iter() {
+        assert!(parse(word).is_err(), "{}", word);
+    }
+
+    // Printing one that was built by hand gives null, which still parses
+    let values = Value::Array(vec![
+        Value::Number(f64::NAN),
+        Value::Number(f64::INFINITY),
+        Value::Number(f64::NEG_INFINITY),
+    ]);
+    assert_eq!(values.to_string(), "[null,null,null]");
+    assert_eq!(
+        parse(&values.to_pretty()).unwrap(),
+        Value::Array(vec![Value::Null, Value::Null, Value::Null])
+    );
+}
@@ -0,0 +1,254 @@
+/*
+ * KvStore on a real file: what was written is there after reopening,
+ * compaction keeps the live values and shrinks the log, and a log cut in
+ * the middle of its last record opens with that record dropped
+ * */
+mod common;
+
+use common::{rng, temp_dir};
+use rust_exercises::extras::binfmt::{self, FormatError, Writer};
+use rust_exercises::extras::kvstore::{KvError, KvStore};
+use rust_exercises::extras::rng::Rng;
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+
+fn file_len(path: &std::path::Path) -> u64 {
+    fs::metadata(path).unwrap().len()
+}
+
+#[test]
+fn empty() {
+    let dir = temp_dir("kvstore_empty");
+    let path = dir.join("store.log");
+    let mut store = KvStore::open(&path).unwrap();
+    assert!(store.is_empty());
+    assert_eq!(store.get("nothing").unwrap(), None);
+    assert!(!store.remove("nothing").unwrap());
+    assert_eq!(file_len(&path), binfmt::HEADER_LEN);
+    drop(store);
+
+    assert!(KvStore::open(&path).unwrap().is_empty());
+}
+
+#[test]
+fn survives_reopen() {
+    let dir = temp_dir("kvstore_reopen");
+    let path = dir.join("store.log");
+    {
+        let mut store = KvStore::open(&path).unwrap();
+        store.set("name", b"ferris").unwrap();
+        store.set("lang", b"rust").unwrap();
+        store.set("name", b"crab").unwrap();
+        store.set("gone", b"soon").unwrap();
+        assert!(store.remove("gone").unwrap());
+        store.set("empty", b"").unwrap();
+        store.sync().unwrap();
+    }
+
+    let mut store = KvStore::open(&path).unwrap();
+    assert_eq!(store.keys(), ["empty", "lang", "name"]);
+    assert_eq!(store.get("name").unwrap().unwrap(), b"crab");
+    assert_eq!(store.get("lang").unwrap().unwrap(), b"rust");
+    assert_eq!(store.get("empty").unwrap().unwrap(), b"");
+    assert_eq!(store.get("gone").unwrap(), None);
+    assert!(store.garbage() > 0);
+
+    // And more after reopening lands after what was there
+    store.set("lang", b"still rust").unwrap();
+    drop(store);
+    let mut store = KvStore::open(&path).unwrap();
+    assert_eq!(store.get("lang").unwrap().unwrap(), b"still rust");
+    assert_eq!(store.get("name").unwrap().unwrap(), b"crab");
+}
+
+#[test]
+fn like_a_hashmap_across_reopens() {
+    let dir = temp_dir("kvstore_model");
+    let path = dir.join("store.log");
+    let mut rng = rng();
+    let mut model: HashMap<String, Vec<u8>> = HashMap::new();
+    let mut store = KvStore::open(&path)
+        .unwrap()
+        .with_compaction_threshold(2_000);
+
+    for step in 0..2_000 {
+        let key = format!("key{}", rng.gen_range(0..40));
+        if rng.gen_bool(0.7) {
+            let value: Vec<u8> = (0..rng.gen_range(0..50))
+                .map(|_| rng.next_u64() as u8)
+                .collect();
+            store.set(&key, &value).unwrap();
+            model.insert(key, value);
+        } else {
+            assert_eq!(store.remove(&key).unwrap(), model.remove(&key).is_some());
+        }
+
+        if step % 250 == 0 {
+            drop(store);
+            store = KvStore::open(&path)
+                .unwrap()
+                .with_compaction_threshold(2_000);
+        }
+        assert_eq!(store.len(), model.len());
+    }
+
+    let mut keys: Vec<&str> = model.keys().map(String::as_str).collect();
+    keys.sort();
+    assert_eq!(store.keys(), keys);
+    for (key, value) in &model {
+        assert_eq!(store.get(key).unwrap().as_ref(), Some(value));
+    }
+}
+
+#[test]
+fn compaction() {
+    let dir = temp_dir("kvstore_compaction");
+    let path = dir.join("store.log");
+    let mut store = KvStore::open(&path).unwrap();
+    for i in 0..100 {
+        store.set("counter", i.to_string().as_bytes()).unwrap();
+        store.set(&format!("tmp{}", i), b"x").unwrap();
+        store.remove(&format!("tmp{}", i)).unwrap();
+    }
+    store.set("other", b"value").unwrap();
+    let before = file_len(&path);
+    assert!(store.garbage() > 0);
+    assert!(store.garbage() < before);
+
+    store.compact().unwrap();
+    assert_eq!(store.garbage(), 0);
+    assert!(!path.with_extension("compact").exists());
+    // Nothing but the two live sets is left
+    let live =
+        binfmt::record_len(5 + "counter".len() + 2) + binfmt::record_len(5 + "other".len() + 5);
+    assert_eq!(file_len(&path), binfmt::HEADER_LEN + live);
+    assert_eq!(store.keys(), ["counter", "other"]);
+    assert_eq!(store.get("counter").unwrap().unwrap(), b"99");
+
+    // Writes after a compaction go to the new log
+    store.set("after", b"compaction").unwrap();
+    drop(store);
+    let mut store = KvStore::open(&path).unwrap();
+    assert_eq!(store.garbage(), 0);
+    assert_eq!(store.keys(), ["after", "counter", "other"]);
+    assert_eq!(store.get("counter").unwrap().unwrap(), b"99");
+    assert_eq!(store.get("after").unwrap().unwrap(), b"compaction");
+}
+
+#[test]
+fn compacts_by_itself_past_the_threshold() {
+    let dir = temp_dir("kvstore_threshold");
+    let path = dir.join("store.log");
+    let mut store = KvStore::open(&path)
+        .unwrap()
+        .with_compaction_threshold(1_000);
+    let value = [7u8; 100];
+    for _ in 0..100 {
+        store.set("same", &value).unwrap();
+        assert!(store.garbage() <= 1_000);
+    }
+    // Never more than the threshold of garbage, plus the live record
+    assert!(file_len(&path) < binfmt::HEADER_LEN + 1_000 + 2 * binfmt::record_len(109));
+    assert_eq!(store.get("same").unwrap().unwrap(), &value[..]);
+}
+
+#[test]
+fn drops_a_truncated_last_record() {
+    let dir = temp_dir("kvstore_truncated");
+    let path = dir.join("store.log");
+    {
+        let mut store = KvStore::open(&path).unwrap();
+        store.set("kept", b"one").unwrap();
+        store.set("also kept", b"two").unwrap();
+    }
+    let complete = file_len(&path);
+    {
+        let mut store = KvStore::open(&path).unwrap();
+        store.set("lost", b"in the crash").unwrap();
+    }
+    let full = file_len(&path);
+
+    // A crash at any point of the last write
+    for cut in complete + 1..full {
+        let copy = dir.join(format!("cut{}.log", cut));
+        fs::copy(&path, &copy).unwrap();
+        OpenOptions::new()
+            .write(true)
+            .open(&copy)
+            .unwrap()
+            .set_len(cut)
+            .unwrap();
+
+        let mut store = KvStore::open(&copy).unwrap();
+        assert_eq!(store.keys(), ["also kept", "kept"], "cut at {}", cut);
+        assert_eq!(store.get("kept").unwrap().unwrap(), b"one");
+        // The partial record is gone from the file too
+        assert_eq!(file_len(&copy), complete, "cut at {}", cut);
+
+        // So the next write starts on a record boundary
+        store.set("next", b"write").unwrap();
+        drop(store);
+        let mut store = KvStore::open(&copy).unwrap();
+        assert_eq!(store.get("next").unwrap().unwrap(), b"write");
+        assert_eq!(store.get("lost").unwrap(), None);
+    }
+}
+
+#[test]
+fn refuses_corruption_before_the_end() {
+    let dir = temp_dir("kvstore_corrupted");
+    let path = dir.join("store.log");
+    {
+        let mut store = KvStore::open(&path).unwrap();
+        store.set("first", b"value").unwrap();
+        store.set("second", b"value").unwrap();
+    }
+    let mut bytes = fs::read(&path).unwrap();
+    // Inside the first record's value
+    let offset = binfmt::HEADER_LEN as usize + 4 + 5 + "first".len();
+    bytes[offset] ^= 0xff;
+    fs::write(&path, &bytes).unwrap();
+
+    match KvStore::open(&path) {
+        Err(KvError::Format(FormatError::ChecksumMismatch { record: 0, .. })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    // Left alone for someone to look at, not truncated
+    assert_eq!(fs::read(&path).unwrap(), bytes);
+
+    fs::write(&path, b"not a log at all").unwrap();
+    assert!(matches!(
+        KvStore::open(&path),
+        Err(KvError::Format(FormatError::BadMagic(_)))
+    ));
+}
+
+#[test]
+fn refuses_records_that_are_not_operations() {
+    let dir = temp_dir("kvstore_invalid");
+    let path = dir.join("store.log");
+    drop(KvStore::open(&path).unwrap());
+
+    // Good checksums, nonsense inside
+    let bad: [&[u8]; 4] = [
+        &[0, 1],
+        &[9, 0, 0, 0, 0],
+        &[0, 200, 0, 0, 0, b'k'],
+        &[1, 1, 0, 0, 0, b'k', b'v'],
+    ];
+    for payload in bad.iter() {
+        let file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .open(&path)
+            .unwrap();
+        let mut writer = Writer::new(file).unwrap();
+        writer.write_record(payload).unwrap();
+        writer.finish().unwrap();
+        assert!(
+            matches!(KvStore::open(&path), Err(KvError::InvalidEntry(_))),
+            "{:?}",
+            payload
+        );
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ * extras::logger: RUST_LOG-style filters, the line format on a fixed
+ * clock, the global logger behind the macros, and the runner's -v and
+ * --log options
+ * */
+mod common;
+
+use common::run;
+use rust_exercises::extras::logger::{self, Filter, Level, Log, Logger, ParseLevelError, Record};
+use rust_exercises::{log_debug, log_error, log_info, log_trace, log_warn};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn filter(spec: &str) -> Filter {
+    spec.parse().unwrap()
+}
+
+#[test]
+fn filter_specs() {
+    let info = filter("info");
+    assert!(info.enabled("anything", Level::Warn));
+    assert!(info.enabled("anything", Level::Info));
+    assert!(!info.enabled("anything", Level::Debug));
+
+    let mixed = filter("warn, app::net=debug, app::net::udp=off");
+    assert_eq!(mixed.max_level("app"), Some(Level::Warn));
+    assert_eq!(mixed.max_level("app::net"), Some(Level::Debug));
+    assert_eq!(mixed.max_level("app::net::tcp"), Some(Level::Debug));
+    // The longest prefix wins, and only at a :: boundary
+    assert_eq!(mixed.max_level("app::net::udp"), None);
+    assert_eq!(mixed.max_level("app::network"), Some(Level::Warn));
+
+    // Whatever isn't mentioned is off
+    assert_eq!(filter("app=trace").max_level("other"), None);
+    assert_eq!(filter("off").max_level("app"), None);
+    assert_eq!(filter("WARNING").max_level("app"), Some(Level::Warn));
+    assert_eq!(
+        "app=loud".parse::<Filter>(),
+        Err(ParseLevelError("loud".to_string()))
+    );
+}
+
+#[test]
+fn levels_order_and_print() {
+    assert!(Level::Error < Level::Warn && Level::Debug < Level::Trace);
+    assert_eq!(format!("[{:<5}]", Level::Info), "[INFO ]");
+    assert_eq!(" debug ".parse(), Ok(Level::Debug));
+}
+
+fn fixed_clock() -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(1_700_000_000_042)
+}
+
+#[test]
+fn memory_logger_lines() {
+    let (logger, sink) = Logger::memory(filter("info"));
+    let logger = logger.with_clock(fixed_clock);
+    for &(level, message) in [
+        (Level::Info, "kept"),
+        (Level::Debug, "dropped"),
+        (Level::Error, "kept too"),
+    ]
+    .iter()
+    {
+        logger.log(&Record {
+            level,
+            target: "app::db",
+            args: format_args!("{}", message),
+        });
+    }
+    assert_eq!(
+        sink.lines(),
+        [
+            "1700000000.042 INFO  app::db: kept",
+            "1700000000.042 ERROR app::db: kept too"
+        ]
+    );
+    assert!(!logger.enabled(Level::Debug, "app::db"));
+}
+
+// The only test here installing the global logger, it can only be done once
+#[test]
+fn macros_go_through_the_global_logger() {
+    // Nothing is installed yet, so this goes nowhere
+    log_error!("before {}", 1);
+
+    let (installed, sink) = Logger::memory(filter("logger=debug"));
+    logger::set_logger(Box::new(installed.with_clock(fixed_clock))).unwrap();
+    let (second, _) = Logger::memory(filter("trace"));
+    assert!(logger::set_logger(Box::new(second)).is_err());
+
+    log_error!("error {}", 1);
+    log_warn!("warn");
+    log_info!("info");
+    log_debug!("debug");
+    log_trace!("trace");
+    // The macros log under the module they're called from, here the test crate
+    assert_eq!(
+        sink.lines(),
+        [
+            "1700000000.042 ERROR logger: error 1",
+            "1700000000.042 WARN  logger: warn",
+            "1700000000.042 INFO  logger: info",
+            "1700000000.042 DEBUG logger: debug",
+        ]
+    );
+}
+
+#[test]
+fn runner_logging_options() {
+    let runner = env!("CARGO_BIN_EXE_runner");
+    let stderr = |args: &[&str]| String::from_utf8(run(runner, args, b"").stderr).unwrap();
+
+    let verbose = stderr(&["-vv", "list"]);
+    assert!(
+        verbose.contains(" DEBUG runner: runner [\"list\"]"),
+        "{}",
+        verbose
+    );
+    let spec = stderr(&["-v", "--log", "runner=debug", "list"]);
+    assert!(spec.contains(" DEBUG runner: "), "{}", spec);
+    assert_eq!(stderr(&["--log", "off", "list"]), "");
+}
@@ -0,0 +1,86 @@
+/*
+ * Chapter 3's loops past the doc examples: the counting edges, ragged
+ * grids for the labeled break, and exactly how many times retry calls
+ * */
+#![cfg(feature = "ch3")]
+
+use rust_exercises::chapters::loops::{
+    countdown, countdown_rev, find_in_grid, retry, sum_inclusive,
+};
+
+#[test]
+fn countdowns_agree() {
+    assert_eq!(countdown(1), [1]);
+    assert_eq!(countdown(5), [5, 4, 3, 2, 1]);
+    for from in 0..50 {
+        let numbers = countdown(from);
+        assert_eq!(numbers, countdown_rev(from));
+        assert_eq!(numbers.len(), from as usize);
+        assert!(numbers.windows(2).all(|pair| pair[0] == pair[1] + 1));
+    }
+    assert!(countdown_rev(0).is_empty());
+}
+
+#[test]
+fn inclusive_sums() {
+    for n in 0..100 {
+        // Gauss: 1 + 2 + ... + n
+        assert_eq!(sum_inclusive(1, n), n * (n + 1) / 2);
+    }
+    assert_eq!(sum_inclusive(-3, 3), 0);
+    assert_eq!(sum_inclusive(-5, -1), -15);
+    assert_eq!(sum_inclusive(0, 0), 0);
+    assert_eq!(sum_inclusive(i64::MAX, i64::MAX), i64::MAX);
+    assert_eq!(sum_inclusive(10, 9), 0);
+}
+
+#[test]
+fn first_match_rows_first() {
+    let grid = vec![vec!['a', 'b'], vec!['x', 'c', 'x'], vec!['x']];
+    // Row 1 has two, row 2 another, only the first counts
+    assert_eq!(find_in_grid(&grid, &'x'), Some((1, 0)));
+    assert_eq!(find_in_grid(&grid, &'c'), Some((1, 1)));
+    assert_eq!(find_in_grid(&grid, &'z'), None);
+
+    // Ragged and empty rows are walked past
+    let ragged: Vec<Vec<i32>> = vec![vec![], vec![1], vec![], vec![2, 3, 4]];
+    assert_eq!(find_in_grid(&ragged, &4), Some((3, 2)));
+    assert_eq!(find_in_grid(&Vec::<Vec<i32>>::new(), &1), None);
+}
+
+#[test]
+fn retry_stops_at_the_first_success() {
+    let mut seen = Vec::new();
+    let result: Result<&str, u32> = retry(10, |n| {
+        seen.push(n);
+        if n == 4 {
+            Ok("up")
+        } else {
+            Err(n)
+        }
+    });
+    assert_eq!(result, Ok("up"));
+    assert_eq!(seen, [1, 2, 3, 4]);
+}
+
+#[test]
+fn retry_gives_the_last_error() {
+    let mut seen = Vec::new();
+    let result: Result<(), u32> = retry(3, |n| {
+        seen.push(n);
+        Err(n * 100)
+    });
+    assert_eq!(result, Err(300));
+    assert_eq!(seen, [1, 2, 3]);
+
+    // 0 and 1 both mean a single try
+    for &max in &[0, 1] {
+        let mut calls = 0;
+        let result: Result<(), &str> = retry(max, |_| {
+            calls += 1;
+            Err("down")
+        });
+        assert_eq!(result, Err("down"));
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * The three multiplications in extras::matrix add up every entry in the
+ * same order, so they have to agree exactly, on sizes that don't divide
+ * into BLOCK tiles or thread bands too
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::matrix::{Matrix, ShapeError, BLOCK};
+use rust_exercises::extras::rng::{Rng, XorShift64};
+
+fn random(rng: &mut XorShift64, rows: usize, cols: usize) -> Matrix {
+    Matrix::from_fn(rows, cols, |_, _| rng.gen_f64() * 200.0 - 100.0)
+}
+
+#[test]
+fn known_product() {
+    let a = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    let b = Matrix::from_rows(&[vec![7.0, 8.0], vec![9.0, 10.0], vec![11.0, 12.0]]).unwrap();
+    let expected = Matrix::from_rows(&[vec![58.0, 64.0], vec![139.0, 154.0]]).unwrap();
+
+    assert_eq!(a.mul(&b).unwrap(), expected);
+    assert_eq!(a.mul_blocked(&b).unwrap(), expected);
+    assert_eq!(a.mul_parallel(&b, 3).unwrap(), expected);
+    assert_eq!(Matrix::identity(2).mul(&expected).unwrap(), expected);
+}
+
+#[test]
+fn shapes_have_to_match() {
+    let a = Matrix::zeros(2, 3);
+    let error = ShapeError {
+        left: (2, 3),
+        right: (2, 3),
+    };
+    assert_eq!(a.mul(&a), Err(error));
+    assert_eq!(a.mul_blocked(&a), Err(error));
+    assert_eq!(a.mul_parallel(&a, 4), Err(error));
+    assert_eq!(Matrix::from_rows(&[vec![1.0], vec![1.0, 2.0]]), None);
+}
+
+#[test]
+fn all_versions_agree_exactly() {
+    let mut rng = rng();
+    let sizes = [
+        (1, 1, 1),
+        (3, 0, 4),
+        (0, 5, 2),
+        (7, 13, 5),
+        (BLOCK + 1, BLOCK - 1, 2 * BLOCK + 3),
+        (130, 70, 90),
+    ];
+    for &(n, m, p) in sizes.iter() {
+        let a = random(&mut rng, n, m);
+        let b = random(&mut rng, m, p);
+        let naive = a.mul(&b).unwrap();
+        assert_eq!(
+            a.mul_blocked(&b).unwrap(),
+            naive,
+            "{}x{} by {}x{}",
+            n,
+            m,
+            m,
+            p
+        );
+        for threads in [1, 2, 3, 8, 200] {
+            assert_eq!(
+                a.mul_parallel(&b, threads).unwrap(),
+                naive,
+                "{}x{} by {}x{} on {} threads",
+                n,
+                m,
+                m,
+                p,
+                threads
+            );
+        }
+    }
+}
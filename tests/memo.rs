@@ -0,0 +1,64 @@
+/*
+ * extras::memo: how many times the wrapped function really runs
+ * */
+use rust_exercises::extras::memo::{Memoized, RecursiveMemoized};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn each_argument_is_computed_once() {
+    let calls = Cell::new(0);
+    let mut square = Memoized::new(|&n: &u64| {
+        calls.set(calls.get() + 1);
+        n * n
+    });
+
+    assert_eq!(square.get(4), 16);
+    assert_eq!(square.get(4), 16);
+    assert_eq!(square.get(5), 25);
+    assert_eq!(square.get(4), 16);
+    assert_eq!(calls.get(), 2);
+    assert_eq!(square.cached(), 2);
+
+    square.clear();
+    assert_eq!(square.cached(), 0);
+    assert_eq!(square.get(4), 16);
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn results_are_cloned_out() {
+    let calls = Cell::new(0);
+    let mut shout = Memoized::new(|word: &String| {
+        calls.set(calls.get() + 1);
+        word.to_uppercase()
+    });
+    let mut first = shout.get(String::from("hi"));
+    first.push('!');
+    assert_eq!(shout.get(String::from("hi")), "HI");
+    assert_eq!(calls.get(), 1);
+}
+
+// Counts the calls to fib's body, only this test uses it
+static FIB_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn fib(fib: &mut RecursiveMemoized<u64, u64>, &n: &u64) -> u64 {
+    FIB_CALLS.fetch_add(1, Ordering::SeqCst);
+    if n < 2 {
+        n
+    } else {
+        fib.get(n - 1) + fib.get(n - 2)
+    }
+}
+
+#[test]
+fn recursive_fib_is_linear() {
+    let mut memo = RecursiveMemoized::new(fib);
+    assert_eq!(memo.get(90), 2_880_067_194_370_816_120);
+    // 0 to 90, once each, where plain recursion would take billions
+    assert_eq!(FIB_CALLS.load(Ordering::SeqCst), 91);
+    assert_eq!(memo.cached(), 91);
+
+    assert_eq!(memo.get(50), 12_586_269_025);
+    assert_eq!(FIB_CALLS.load(Ordering::SeqCst), 91);
+}
@@ -0,0 +1,139 @@
+/*
+ * minigrep_async over several files in a temp directory, each searched
+ * by its own task. Paths that can't be read come back as FileErrors next
+ * to the matches of the files that could.
+ *
+ *   cargo test --features async-rt --test minigrep_async
+ * */
+#![cfg(all(feature = "ch12", feature = "async-rt"))]
+
+mod common;
+
+use common::temp_tree;
+use rust_exercises::chapters::minigrep_async::{search, search_all, Config, Match};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const POEM: &str = "I'm nobody! Who are you?\nAre you nobody, too?\nThen there's a pair of us - don't tell!\nThey'd banish us, you know.\n";
+const RUST: &str = "Rust:\nsafe, fast, productive.\nPick three.\nTrust me.\n";
+
+fn config(query: &str, paths: Vec<PathBuf>, case_insensitive: bool) -> Config {
+    Config {
+        query: query.to_string(),
+        paths,
+        case_insensitive,
+    }
+}
+
+fn found(path: &Path, line_number: usize, line: &str) -> Match {
+    Match {
+        path: path.to_path_buf(),
+        line_number,
+        line: line.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn matches_from_every_file_sorted_by_file_then_line() {
+    let dir = temp_tree("minigrep_async_sorted", &[("a.txt", POEM), ("b.txt", RUST)]);
+    let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+
+    // b.txt comes first in the config, the result is still sorted
+    let (matches, errors) = search_all(config("us", vec![b.clone(), a.clone()], false)).await;
+    assert!(errors.is_empty());
+    assert_eq!(
+        matches,
+        [
+            found(&a, 3, "Then there's a pair of us - don't tell!"),
+            found(&a, 4, "They'd banish us, you know."),
+            found(&b, 1, "Rust:"),
+            found(&b, 4, "Trust me."),
+        ]
+    );
+    assert_eq!(
+        matches[0].to_string(),
+        format!("{}:3:Then there's a pair of us - don't tell!", a.display())
+    );
+}
+
+#[tokio::test]
+async fn case_insensitive() {
+    let dir = temp_tree("minigrep_async_case", &[("a.txt", POEM), ("b.txt", RUST)]);
+    let paths = vec![dir.join("a.txt"), dir.join("b.txt")];
+
+    let (sensitive, _) = search_all(config("rust", paths.clone(), false)).await;
+    assert_eq!(sensitive, [found(&paths[1], 4, "Trust me.")]);
+
+    let (insensitive, _) = search_all(config("rUsT", paths.clone(), true)).await;
+    assert_eq!(
+        insensitive,
+        [
+            found(&paths[1], 1, "Rust:"),
+            found(&paths[1], 4, "Trust me."),
+        ]
+    );
+
+    let (nobody, _) = search_all(config("NOBODY", paths, true)).await;
+    let lines: Vec<usize> = nobody.iter().map(|m| m.line_number).collect();
+    assert_eq!(lines, [1, 2]);
+}
+
+#[tokio::test]
+async fn unreadable_paths_are_reported_without_stopping_the_rest() {
+    let dir = temp_tree(
+        "minigrep_async_errors",
+        &[("a.txt", POEM), ("sub/b.txt", RUST)],
+    );
+    let missing = dir.join("missing.txt");
+    // Opening a directory works on some platforms, reading it never does
+    let directory = dir.join("sub");
+
+    let paths = vec![missing.clone(), dir.join("a.txt"), directory.clone()];
+    let (matches, mut errors) = search_all(config("nobody", paths, false)).await;
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(errors.len(), 2);
+    errors.sort_by(|a, b| a.path.cmp(&b.path));
+    assert_eq!(errors[0].path, missing);
+    assert_eq!(errors[0].error.kind(), ErrorKind::NotFound);
+    assert!(errors[0]
+        .to_string()
+        .starts_with(&*missing.to_string_lossy()));
+    assert_eq!(errors[1].path, directory);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn streamed_matches_keep_each_files_lines_in_order() {
+    let contents: Vec<(String, String)> = (0..8)
+        .map(|i| {
+            let lines: Vec<String> = (0..200)
+                .map(|n| format!("file {} line {} needle", i, n))
+                .collect();
+            (format!("{}.txt", i), lines.join("\n"))
+        })
+        .collect();
+    let files: Vec<(&str, &str)> = contents
+        .iter()
+        .map(|(name, text)| (name.as_str(), text.as_str()))
+        .collect();
+    let dir = temp_tree("minigrep_async_stream", &files);
+    let paths: Vec<PathBuf> = contents.iter().map(|(name, _)| dir.join(name)).collect();
+
+    let mut rx = search(config("needle", paths.clone(), false));
+    let mut last_line = vec![0; paths.len()];
+    let mut total = 0;
+    while let Some(result) = rx.recv().await {
+        let found = result.expect("every file is readable");
+        let file = paths.iter().position(|p| *p == found.path).unwrap();
+        assert_eq!(found.line_number, last_line[file] + 1);
+        last_line[file] = found.line_number;
+        total += 1;
+    }
+    assert_eq!(total, 8 * 200);
+}
+
+#[tokio::test]
+async fn nothing_to_search() {
+    let (matches, errors) = search_all(config("x", Vec::new(), false)).await;
+    assert!(matches.is_empty() && errors.is_empty());
+}
@@ -0,0 +1,70 @@
+/*
+ * Compiles src/core_utils as the whole of a #![no_std] crate, once with
+ * alloc and once without. Anything in there reaching for std, or for
+ * alloc outside the alloc feature, fails to resolve and fails the test.
+ * Only type checks (--emit metadata): nothing gets linked, so no panic
+ * handler is needed.
+ * */
+mod common;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const ROOT: &str = r#"#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[path = "CORE_UTILS"]
+pub mod core_utils;
+"#;
+
+fn build_no_std(test_name: &str, features: &[&str]) {
+    let dir = common::temp_dir(test_name);
+    let core_utils = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/core_utils/mod.rs");
+    let root = dir.join("lib.rs");
+    fs::write(
+        &root,
+        ROOT.replace("CORE_UTILS", core_utils.to_str().unwrap()),
+    )
+    .unwrap();
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let mut command = Command::new(&rustc);
+    command
+        .args([
+            "--edition",
+            "2018",
+            "--crate-type",
+            "rlib",
+            "--crate-name",
+            "core_utils",
+        ])
+        .args(["--emit", "metadata", "-D", "warnings", "--out-dir"])
+        .arg(dir.path())
+        .arg(&root);
+    for feature in features {
+        command.arg("--cfg").arg(format!("feature=\"{}\"", feature));
+    }
+
+    let output = command
+        .output()
+        .unwrap_or_else(|e| panic!("can't run {}: {}", rustc, e));
+    assert!(
+        output.status.success(),
+        "core_utils doesn't build as no_std with features {:?}:\n{}",
+        features,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn core_utils_builds_without_alloc() {
+    build_no_std("no_std_core", &[]);
+}
+
+#[test]
+fn core_utils_builds_with_alloc() {
+    build_no_std("no_std_alloc", &["alloc"]);
+}
@@ -0,0 +1,84 @@
+/*
+ * The generic aggregates against std's own sum and product, once per
+ * primitive number type, and Numeric used as a bound from outside
+ * */
+#![cfg(feature = "ch10")]
+
+use rust_exercises::chapters::numeric::{average, product_generic, sum_generic, Numeric};
+
+macro_rules! agrees_with_std {
+    ($($name:ident: $t:ty),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let list: [$t; 5] = [1 as $t, 2 as $t, 3 as $t, 4 as $t, 5 as $t];
+                assert_eq!(sum_generic(&list), list.iter().copied().sum::<$t>());
+                assert_eq!(product_generic(&list), list.iter().copied().product::<$t>());
+                assert_eq!(average(&list), Some(3 as $t));
+
+                assert_eq!(sum_generic::<$t>(&[]), <$t as Numeric>::zero());
+                assert_eq!(product_generic::<$t>(&[]), <$t as Numeric>::one());
+                assert_eq!(average::<$t>(&[]), None);
+                assert_eq!(average(&[7 as $t]), Some(7 as $t));
+            }
+        )*
+    };
+}
+
+agrees_with_std! {
+    agrees_for_i8: i8,
+    agrees_for_i16: i16,
+    agrees_for_i32: i32,
+    agrees_for_i64: i64,
+    agrees_for_i128: i128,
+    agrees_for_isize: isize,
+    agrees_for_u8: u8,
+    agrees_for_u16: u16,
+    agrees_for_u32: u32,
+    agrees_for_u64: u64,
+    agrees_for_u128: u128,
+    agrees_for_usize: usize,
+    agrees_for_f32: f32,
+    agrees_for_f64: f64,
+}
+
+#[test]
+fn integer_averages_round_toward_zero() {
+    assert_eq!(average(&[1, 2, 2]), Some(1));
+    assert_eq!(average(&[-1, -2, -2]), Some(-1));
+    assert_eq!(average(&[u64::MAX / 2, u64::MAX / 2]), Some(u64::MAX / 2));
+    assert_eq!(average(&[1.0, 2.0, 2.0]), Some(5.0 / 3.0));
+}
+
+#[test]
+fn counts_past_the_type_are_clamped() {
+    // 300 doesn't fit a u8, it divides by 255 instead of wrapping to 44
+    assert_eq!(255u8.div_usize(300), 1);
+    assert_eq!(200u8.div_usize(300), 0);
+    assert_eq!((-128i8).div_usize(1_000), -1);
+    assert_eq!(100i8.div_usize(4), 25);
+    // An average over that many items is still right when they're all 0
+    assert_eq!(average(&[0u8; 300]), Some(0));
+}
+
+#[test]
+fn floats_follow_ieee() {
+    assert!(sum_generic(&[1.0, f64::NAN]).is_nan());
+    assert_eq!(sum_generic(&[f64::INFINITY, 1.0]), f64::INFINITY);
+    assert_eq!(product_generic(&[-0.0f32, 5.0]), 0.0);
+    assert!(product_generic(&[-0.0f32, 5.0]).is_sign_negative());
+    assert_eq!(sum_generic(&[0.1f64; 10]), [0.1f64; 10].iter().sum::<f64>());
+}
+
+// Anything written against the trait works for every type it's implemented for
+fn sum_of_squares<T: Numeric>(list: &[T]) -> T {
+    let squares: Vec<T> = list.iter().map(|&x| x.mul(x)).collect();
+    sum_generic(&squares)
+}
+
+#[test]
+fn numeric_as_a_bound() {
+    assert_eq!(sum_of_squares(&[1, 2, 3]), 14);
+    assert_eq!(sum_of_squares(&[0.5, 1.5]), 2.5);
+    assert_eq!(sum_of_squares::<u16>(&[]), 0);
+}
@@ -0,0 +1,134 @@
+/*
+ * EventBus delivery: every subscriber in the order it subscribed, every
+ * event in the order it was published, and nothing after unsubscribing
+ * or dropping the receiving end of a channel.
+ * */
+use rust_exercises::extras::observer::{EventBus, Subscriber};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::thread;
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn logger(log: &Log, name: &'static str) -> Box<dyn Subscriber<u32>> {
+    let log = log.clone();
+    Box::new(move |event: &u32| log.borrow_mut().push(format!("{} {}", name, event)))
+}
+
+#[test]
+fn subscribers_are_called_in_subscription_order() {
+    let log = Log::default();
+    let mut bus = EventBus::new();
+    bus.subscribe(logger(&log, "a"));
+    bus.subscribe(logger(&log, "b"));
+    bus.subscribe(logger(&log, "c"));
+
+    bus.publish(&1);
+    bus.publish(&2);
+    assert_eq!(*log.borrow(), ["a 1", "b 1", "c 1", "a 2", "b 2", "c 2"]);
+}
+
+#[test]
+fn channels_get_every_event_in_order() {
+    let log = Log::default();
+    let mut bus = EventBus::new();
+    bus.subscribe(logger(&log, "direct"));
+    let (_, first) = bus.subscribe_channel();
+    let (_, second) = bus.subscribe_channel();
+
+    for event in 0..5 {
+        bus.publish(&event);
+    }
+    assert_eq!(first.try_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(second.try_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(log.borrow().len(), 5);
+}
+
+#[test]
+fn events_consumed_on_another_thread() {
+    let mut bus: EventBus<String> = EventBus::new();
+    let (_, events) = bus.subscribe_channel();
+    let consumer = thread::spawn(move || events.iter().collect::<Vec<_>>());
+
+    for word in ["one", "two", "three"] {
+        bus.publish(&word.to_string());
+    }
+    // Dropping the bus drops the sender, ending the consumer's loop
+    drop(bus);
+    assert_eq!(consumer.join().unwrap(), ["one", "two", "three"]);
+}
+
+#[test]
+fn unsubscribing_stops_delivery() {
+    let log = Log::default();
+    let mut bus = EventBus::new();
+    let a = bus.subscribe(logger(&log, "a"));
+    let b = bus.subscribe(logger(&log, "b"));
+    let (channel, events) = bus.subscribe_channel();
+    bus.publish(&1);
+
+    assert!(bus.unsubscribe(a));
+    assert!(bus.unsubscribe(channel));
+    assert_eq!(bus.subscribers(), 1);
+    bus.publish(&2);
+
+    assert_eq!(*log.borrow(), ["a 1", "b 1", "b 2"]);
+    assert_eq!(events.try_iter().collect::<Vec<_>>(), [1]);
+
+    // Only once, and ids aren't reused by later subscriptions
+    assert!(!bus.unsubscribe(a));
+    let c = bus.subscribe(logger(&log, "c"));
+    assert_ne!(c, a);
+    assert!(bus.unsubscribe(b));
+    assert!(bus.unsubscribe(c));
+    assert_eq!(bus.subscribers(), 0);
+    bus.publish(&3);
+    assert_eq!(log.borrow().len(), 3);
+}
+
+#[test]
+fn unsubscribing_keeps_the_others_in_order() {
+    let log = Log::default();
+    let mut bus = EventBus::new();
+    let ids: Vec<_> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|&name| bus.subscribe(logger(&log, name)))
+        .collect();
+    bus.unsubscribe(ids[1]);
+    bus.publish(&7);
+    assert_eq!(*log.borrow(), ["a 7", "c 7", "d 7"]);
+}
+
+#[test]
+fn dropped_receivers_are_removed_on_publish() {
+    let mut bus = EventBus::new();
+    let (id, events) = bus.subscribe_channel();
+    let (_, kept) = bus.subscribe_channel();
+    drop(events);
+    assert_eq!(bus.subscribers(), 2);
+
+    bus.publish(&1);
+    assert_eq!(bus.subscribers(), 1);
+    assert!(!bus.unsubscribe(id));
+    assert_eq!(kept.recv().unwrap(), 1);
+}
+
+// A subscriber that isn't a closure
+struct Sum(Rc<RefCell<u32>>);
+
+impl Subscriber<u32> for Sum {
+    fn notify(&mut self, event: &u32) {
+        *self.0.borrow_mut() += event;
+    }
+}
+
+#[test]
+fn struct_subscribers() {
+    let total = Rc::new(RefCell::new(0));
+    let mut bus = EventBus::default();
+    bus.subscribe(Box::new(Sum(total.clone())));
+    for event in 1..=10 {
+        bus.publish(&event);
+    }
+    assert_eq!(*total.borrow(), 55);
+}
@@ -0,0 +1,83 @@
+/*
+ * OrderedF64's order has to be total and agree with Eq and Hash, or the
+ * collections built on it misbehave without any error
+ * */
+#[cfg(feature = "ch10")]
+use rust_exercises::chapters::largest::largest_generic_copy;
+use rust_exercises::extras::ordered_float::OrderedF64;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BinaryHeap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn ordered(values: &[f64]) -> Vec<OrderedF64> {
+    values.iter().copied().map(OrderedF64::from).collect()
+}
+
+fn hash_of(value: OrderedF64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn nan_is_greatest_and_equal_to_itself() {
+    let nan = OrderedF64(f64::NAN);
+    assert_eq!(nan, nan);
+    assert_eq!(nan.cmp(&OrderedF64(f64::INFINITY)), Ordering::Greater);
+    assert_eq!(OrderedF64(f64::NEG_INFINITY).cmp(&nan), Ordering::Less);
+
+    // A NaN with other bits is still the same NaN
+    let other_nan = OrderedF64(f64::from_bits(f64::NAN.to_bits() | 1));
+    assert!(other_nan.get().is_nan());
+    assert_eq!(nan, other_nan);
+    assert_eq!(hash_of(nan), hash_of(other_nan));
+}
+
+#[test]
+fn zeros_are_equal() {
+    let zero = OrderedF64(0.0);
+    let negative_zero = OrderedF64(-0.0);
+    assert_eq!(zero, negative_zero);
+    assert_eq!(zero.cmp(&negative_zero), Ordering::Equal);
+    assert_eq!(hash_of(zero), hash_of(negative_zero));
+
+    let set: HashSet<OrderedF64> = ordered(&[0.0, -0.0]).into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn sorts_with_nan_last() {
+    let mut values = ordered(&[3.0, f64::NAN, -1.5, f64::INFINITY, 0.0]);
+    values.sort();
+    let sorted: Vec<f64> = values.into_iter().map(f64::from).collect();
+    assert_eq!(sorted[..4], [-1.5, 0.0, 3.0, f64::INFINITY]);
+    assert!(sorted[4].is_nan());
+}
+
+#[test]
+fn works_as_collection_keys() {
+    let mut counts = BTreeMap::new();
+    for value in ordered(&[2.5, 1.0, 2.5, f64::NAN, f64::NAN]) {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    let keys: Vec<String> = counts.keys().map(|key| key.to_string()).collect();
+    assert_eq!(keys, ["1", "2.5", "NaN"]);
+    assert_eq!(counts[&OrderedF64(f64::NAN)], 2);
+
+    let mut heap: BinaryHeap<OrderedF64> = ordered(&[1.0, 7.5, -2.0]).into_iter().collect();
+    assert_eq!(heap.pop(), Some(OrderedF64(7.5)));
+    assert_eq!(heap.pop(), Some(OrderedF64(1.0)));
+    assert_eq!(
+        ordered(&[4.0, 9.0]).into_iter().max(),
+        Some(OrderedF64(9.0))
+    );
+}
+
+#[cfg(feature = "ch10")]
+#[test]
+fn largest_sees_nan_anywhere() {
+    // Plain f64 only finds a NaN that comes first, see largest_generic_copy
+    let largest = largest_generic_copy(&ordered(&[1.0, f64::NAN, 2.0]));
+    assert!(largest.unwrap().get().is_nan());
+}
@@ -0,0 +1,122 @@
+/*
+ * extras::par_wc over the directory tree in tests/fixtures/par_wc: the
+ * pool gives the same counts as the sequential version whatever the
+ * number of threads, and so does the par-wc binary.
+ *
+ * latin1.txt isn't UTF-8 on purpose, its é becomes U+FFFD which splits
+ * the word in two.
+ * */
+mod common;
+
+use common::{fixture_path, stdout, temp_tree};
+use rust_exercises::extras::par_wc::{self, Frequencies};
+use std::path::PathBuf;
+
+fn files() -> Vec<PathBuf> {
+    par_wc::collect_files(&fixture_path("par_wc")).unwrap()
+}
+
+fn expected() -> Frequencies {
+    let counts = vec![
+        ("the", 7),
+        ("cat", 3),
+        ("dog", 4),
+        ("sat", 1),
+        ("on", 1),
+        ("mat", 1),
+        ("end", 1),
+        ("notes", 1),
+        ("and", 1),
+        ("bird", 1),
+        ("42", 1),
+        ("caf", 1),
+    ];
+    counts
+        .into_iter()
+        .map(|(word, count)| (word.to_string(), count))
+        .collect()
+}
+
+#[test]
+fn collects_every_file_sorted() {
+    let root = fixture_path("par_wc");
+    let relative: Vec<_> = files()
+        .iter()
+        .map(|path| {
+            path.strip_prefix(&root)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .replace('\\', "/")
+        })
+        .collect();
+    assert_eq!(
+        relative,
+        [
+            "cat.txt",
+            "latin1.txt",
+            "notes/deep/dogs.txt",
+            "notes/empty.txt",
+            "notes/pets.md"
+        ]
+    );
+}
+
+#[test]
+fn sequential_counts() {
+    assert_eq!(par_wc::count_sequential(&files()).unwrap(), expected());
+}
+
+#[test]
+fn parallel_matches_sequential() {
+    let files = files();
+    let sequential = par_wc::count_sequential(&files).unwrap();
+    // More threads than files too, and none at all
+    for threads in 1..=8 {
+        assert_eq!(par_wc::count_parallel(&files, threads).unwrap(), sequential);
+    }
+    assert!(par_wc::count_parallel(&[], 3).unwrap().is_empty());
+}
+
+#[test]
+fn parallel_on_a_bigger_tree() {
+    let texts: Vec<(String, String)> = (0..40)
+        .map(|i| {
+            (
+                format!("d{}/f{}.txt", i % 5, i),
+                "word ".repeat(i) + &format!("file{}", i % 3),
+            )
+        })
+        .collect();
+    let files: Vec<(&str, &str)> = texts
+        .iter()
+        .map(|(name, text)| (name.as_str(), text.as_str()))
+        .collect();
+    let dir = temp_tree("par_wc_bigger", &files);
+
+    let files = par_wc::collect_files(dir.path()).unwrap();
+    assert_eq!(files.len(), 40);
+    let sequential = par_wc::count_sequential(&files).unwrap();
+    assert_eq!(sequential["word"], (0..40).sum::<usize>());
+    assert_eq!(par_wc::count_parallel(&files, 4).unwrap(), sequential);
+}
+
+#[test]
+fn top_breaks_ties_alphabetically() {
+    let frequencies = expected();
+    assert_eq!(
+        par_wc::top(&frequencies, 4),
+        [("the", 7), ("dog", 4), ("cat", 3), ("42", 1)]
+    );
+}
+
+#[test]
+fn binary_prints_the_top_words() {
+    let root = fixture_path("par_wc");
+    let out = stdout(
+        env!("CARGO_BIN_EXE_par-wc"),
+        &[root.to_str().unwrap(), "--threads", "3", "--top", "3"],
+        b"",
+    );
+    assert_eq!(out, "       7 the\n       4 dog\n       3 cat\n");
+}
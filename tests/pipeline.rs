@@ -0,0 +1,133 @@
+/*
+ * extras::pipeline: stages that transform, skip and fail, a hand-written
+ * Stage next to closures, and grep() giving what minigrep gives
+ * */
+#[cfg(feature = "ch12")]
+use rust_exercises::chapters::minigrep;
+use rust_exercises::extras::pipeline::{self, Pipeline, Stage, Step};
+use std::cell::Cell;
+use std::error::Error;
+use std::num::ParseIntError;
+use std::rc::Rc;
+
+const POEM: &str = "I'm nobody! Who are you?
+Are you nobody, too?
+Then there's a pair of us - don't tell!
+They'd banish us, you know.
+
+How dreary to be somebody!
+How public, like a frog
+To tell your name the livelong day
+To an admiring bog!";
+
+// Doubles every number, refuses anything past a limit
+struct Double {
+    limit: i64,
+}
+
+impl Stage<i64> for Double {
+    fn process(&self, n: i64) -> Result<Step<i64>, Box<dyn Error>> {
+        if n > self.limit {
+            return Err(format!("{} is over {}", n, self.limit).into());
+        }
+        Ok(Step::Next(n * 2))
+    }
+}
+
+fn numbers() -> Pipeline<String> {
+    Pipeline::builder()
+        .map("trim", |line: String| line.trim().to_string())
+        .filter("not empty", |line| !line.is_empty())
+        .try_map("parse", |line| {
+            line.parse::<i64>().map(|n| (n + 1).to_string())
+        })
+        .build()
+}
+
+#[test]
+fn items_go_through_every_stage_in_order() {
+    let pipeline = numbers();
+    assert_eq!(pipeline.stage_names(), ["trim", "not empty", "parse"]);
+    assert_eq!(
+        pipeline.run(String::from(" 41 ")).unwrap(),
+        Some(String::from("42"))
+    );
+    assert_eq!(pipeline.run(String::from("   ")).unwrap(), None);
+
+    let lines = vec!["1", "", " 2", "3 "].into_iter().map(String::from);
+    assert_eq!(pipeline.run_all(lines).unwrap(), ["2", "3", "4"]);
+
+    let empty: Pipeline<i64> = Pipeline::builder().build();
+    assert_eq!(empty.run(7).unwrap(), Some(7));
+}
+
+#[test]
+fn an_error_stops_the_item_and_the_run() {
+    let pipeline = numbers();
+    let error = pipeline.run(String::from("forty")).unwrap_err();
+    assert_eq!(error.stage, "parse");
+    assert!(error.to_string().starts_with("stage parse failed: "));
+    assert!(error
+        .source()
+        .unwrap()
+        .downcast_ref::<ParseIntError>()
+        .is_some());
+
+    let lines = vec!["1", "x", "2"].into_iter().map(String::from);
+    assert_eq!(pipeline.run_all(lines).unwrap_err().stage, "parse");
+}
+
+#[test]
+fn stages_after_a_skip_or_an_error_never_run() {
+    let reached = Rc::new(Cell::new(0));
+    let counter = reached.clone();
+    let pipeline = Pipeline::builder()
+        .filter("odd", |n: &i64| n % 2 == 1)
+        .stage("double", Double { limit: 10 })
+        .map("count", move |n| {
+            counter.set(counter.get() + 1);
+            n
+        })
+        .build();
+
+    assert_eq!(pipeline.run_all(vec![1, 2, 3, 4, 5]).unwrap(), [2, 6, 10]);
+    assert_eq!(reached.get(), 3);
+
+    let error = pipeline.run(11).unwrap_err();
+    assert_eq!(error.stage, "double");
+    assert_eq!(error.source.to_string(), "11 is over 10");
+    assert_eq!(reached.get(), 3);
+}
+
+#[test]
+fn grep_matches_lines() {
+    assert_eq!(
+        pipeline::grep_lines("nobody", false, POEM),
+        ["I'm nobody! Who are you?", "Are you nobody, too?"]
+    );
+    assert_eq!(
+        pipeline::grep_lines("TO", true, POEM),
+        [
+            "Are you nobody, too?",
+            "How dreary to be somebody!",
+            "To tell your name the livelong day",
+            "To an admiring bog!"
+        ]
+    );
+    assert!(pipeline::grep_lines("rust", false, POEM).is_empty());
+}
+
+#[cfg(feature = "ch12")]
+#[test]
+fn grep_agrees_with_minigrep() {
+    for query in ["you", "To", "to", "us", "!", "", "xyz"].iter() {
+        assert_eq!(
+            pipeline::grep_lines(query, false, POEM),
+            minigrep::search(query, POEM)
+        );
+        assert_eq!(
+            pipeline::grep_lines(query, true, POEM),
+            minigrep::search_case_insensitive(query, POEM)
+        );
+    }
+}
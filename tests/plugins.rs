@@ -0,0 +1,100 @@
+/*
+ * extras::plugins from outside the crate: the builtin plugins, a plugin
+ * defined here and listed with register_plugins!, and the errors
+ * */
+use rust_exercises::extras::plugins::{Plugin, PluginError, Registry, Rot13, BUILTIN};
+use rust_exercises::register_plugins;
+
+struct Reverse;
+
+impl Plugin for Reverse {
+    fn name(&self) -> &str {
+        "reverse"
+    }
+
+    fn execute(&self, input: &str) -> Result<String, PluginError> {
+        Ok(input.chars().rev().collect())
+    }
+}
+
+struct Repeat {
+    times: usize,
+}
+
+impl Plugin for Repeat {
+    fn name(&self) -> &str {
+        "repeat"
+    }
+
+    fn execute(&self, input: &str) -> Result<String, PluginError> {
+        if input.is_empty() {
+            return Err(PluginError::Failed {
+                plugin: self.name().to_string(),
+                message: String::from("nothing to repeat"),
+            });
+        }
+        Ok(input.repeat(self.times))
+    }
+}
+
+register_plugins! {
+    static MINE = [Reverse, Repeat { times: 3 }, Rot13];
+}
+
+register_plugins! {
+    static TWICE = [Reverse, Reverse];
+}
+
+#[test]
+fn builtin_plugins() {
+    let registry = Registry::builtin();
+    assert_eq!(registry.names(), ["rot13", "wc"]);
+    assert_eq!(BUILTIN.len(), 2);
+
+    let secret = registry.run("rot13", "Hello, World").unwrap();
+    assert_eq!(secret, "Uryyb, Jbeyq");
+    assert_eq!(registry.run("rot13", &secret).unwrap(), "Hello, World");
+    assert_eq!(
+        registry
+            .run("wc", "one two\nthree\n")
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>(),
+        ["2", "3", "14"]
+    );
+}
+
+#[test]
+fn plugins_registered_with_the_macro() {
+    let registry = Registry::from_list(MINE).unwrap();
+    assert_eq!(registry.names(), ["reverse", "repeat", "rot13"]);
+    assert_eq!(registry.run("reverse", "abc").unwrap(), "cba");
+    assert_eq!(registry.run("repeat", "ab").unwrap(), "ababab");
+    assert_eq!(
+        registry.get("rot13").map(|plugin| plugin.name()),
+        Some("rot13")
+    );
+}
+
+#[test]
+fn errors() {
+    let mut registry = Registry::from_list(MINE).unwrap();
+    assert_eq!(
+        registry.run("upper", "abc"),
+        Err(PluginError::Unknown(String::from("upper")))
+    );
+    assert!(registry.get("upper").is_none());
+
+    let failed = registry.run("repeat", "").unwrap_err();
+    assert_eq!(failed.to_string(), "repeat: nothing to repeat");
+
+    assert_eq!(
+        registry.register(Box::new(Reverse)),
+        Err(PluginError::Duplicate(String::from("reverse")))
+    );
+    assert_eq!(registry.names().len(), 3);
+    assert!(matches!(
+        Registry::from_list(TWICE),
+        Err(PluginError::Duplicate(name)) if name == "reverse"
+    ));
+}
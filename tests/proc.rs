@@ -0,0 +1,170 @@
+/*
+ * extras::proc with commands that exist wherever the tests run: the
+ * crate's own wc and hexdump binaries, and this test binary started
+ * again as a child that only sleeps, see SLEEPER
+ * */
+mod common;
+
+use common::{fixture_path, temp_tree};
+use rust_exercises::extras::proc::{
+    describe_status, pipe, run, run_checked, run_with_timeout, ProcError,
+};
+use rust_exercises::extras::wc;
+use std::env;
+use std::io::ErrorKind;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const WC: &str = env!("CARGO_BIN_EXE_wc");
+const HEXDUMP: &str = env!("CARGO_BIN_EXE_hexdump");
+
+// Set in the child's environment, the test below then sleeps for a minute
+const SLEEPER: &str = "PROC_TEST_SLEEPER";
+
+#[test]
+fn sleeper() {
+    if env::var_os(SLEEPER).is_some() {
+        thread::sleep(Duration::from_secs(60));
+    }
+}
+
+fn sleeper_command() -> Command {
+    let mut command = Command::new(env::current_exe().unwrap());
+    command
+        .args(["sleeper", "--exact", "--nocapture"])
+        .env(SLEEPER, "1");
+    command
+}
+
+fn input() -> String {
+    fixture_path("wc_input.txt").to_str().unwrap().to_string()
+}
+
+#[test]
+fn run_captures_both_streams() {
+    let ok = run(WC, &[&input()]).unwrap();
+    assert!(ok.success());
+    assert!(ok.stdout.trim_end().ends_with("wc_input.txt"));
+    assert_eq!(ok.stderr, "");
+
+    let failed = run(WC, &["no such file"]).unwrap();
+    assert!(!failed.success());
+    assert_eq!(failed.stdout, "");
+    assert!(failed.stderr.starts_with("wc: no such file: "));
+}
+
+#[test]
+fn programs_that_dont_exist() {
+    let e = run("no-such-program-for-proc-tests", &[]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::NotFound);
+    match run_checked("no-such-program-for-proc-tests", &[]) {
+        Err(ProcError::Io(e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        other => panic!("expected an io error, got {:?}", other),
+    }
+}
+
+#[test]
+fn run_checked_turns_failures_into_errors() {
+    let stdout = run_checked(WC, &[&input()]).unwrap();
+    assert_eq!(stdout, run(WC, &[&input()]).unwrap().stdout);
+
+    match run_checked(WC, &["no such file"]) {
+        Err(e @ ProcError::Failed { .. }) => {
+            let message = e.to_string();
+            assert!(
+                message.starts_with("exited with code 1: wc: no such file: "),
+                "{}",
+                message
+            );
+            if let ProcError::Failed { status, stderr } = e {
+                assert_eq!(status.code(), Some(1));
+                assert!(stderr.ends_with('\n'));
+            }
+        }
+        other => panic!("expected a failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn pipe_feeds_one_into_the_other() {
+    let dump = run_checked(HEXDUMP, &[&input()]).unwrap();
+    let expected = format!("{}\n", wc::count(dump.as_bytes()).unwrap());
+    assert_eq!(pipe((HEXDUMP, &[&input()]), (WC, &[])).unwrap(), expected);
+}
+
+#[test]
+fn pipe_fails_if_either_side_does() {
+    match pipe((HEXDUMP, &["no such file"]), (WC, &[])) {
+        Err(ProcError::Failed { status, .. }) => assert_eq!(status.code(), Some(1)),
+        other => panic!("expected the producer to fail, got {:?}", other),
+    }
+    // An empty dump writes nothing, so the producer can't also fail with
+    // a broken pipe once the consumer has exited
+    let dir = temp_tree("proc_pipe", &[("empty", "")]);
+    let empty = dir.join("empty");
+    match pipe(
+        (HEXDUMP, &[empty.to_str().unwrap()]),
+        (WC, &["no such file"]),
+    ) {
+        Err(ProcError::Failed { status, stderr }) => {
+            assert_eq!(status.code(), Some(1));
+            assert!(stderr.starts_with("wc: no such file: "));
+        }
+        other => panic!("expected the consumer to fail, got {:?}", other),
+    }
+    assert!(matches!(
+        pipe(
+            (HEXDUMP, &[&input()]),
+            ("no-such-program-for-proc-tests", &[])
+        ),
+        Err(ProcError::Io(_))
+    ));
+}
+
+#[test]
+fn describe_exit_codes() {
+    let ok = run(WC, &[&input()]).unwrap();
+    assert_eq!(describe_status(ok.status), "exited with code 0");
+    let failed = run(WC, &["no such file"]).unwrap();
+    assert_eq!(describe_status(failed.status), "exited with code 1");
+}
+
+#[cfg(unix)]
+#[test]
+fn describe_a_killed_process() {
+    let mut child = sleeper_command().spawn().unwrap();
+    child.kill().unwrap();
+    let status = child.wait().unwrap();
+    assert_eq!(status.code(), None);
+    assert_eq!(describe_status(status), "killed by signal 9");
+}
+
+#[test]
+fn run_with_timeout_lets_a_quick_child_finish() {
+    let captured =
+        run_with_timeout(Command::new(WC).arg(input()), Duration::from_secs(30)).unwrap();
+    assert!(captured.success());
+    assert_eq!(captured.stdout, run(WC, &[&input()]).unwrap().stdout);
+
+    // Stdin is /dev/null, wc counts nothing instead of waiting for input
+    let empty = run_with_timeout(&mut Command::new(WC), Duration::from_secs(30)).unwrap();
+    assert_eq!(empty.stdout, format!("{}\n", wc::Counts::default()));
+}
+
+#[test]
+fn run_with_timeout_kills_a_slow_child() {
+    let start = Instant::now();
+    let timeout = Duration::from_millis(200);
+    match run_with_timeout(&mut sleeper_command(), timeout) {
+        Err(ProcError::TimedOut(after)) => assert_eq!(after, timeout),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert!(start.elapsed() >= timeout);
+    // Nowhere near the minute it would have slept
+    assert!(start.elapsed() < Duration::from_secs(30));
+    assert_eq!(
+        ProcError::TimedOut(timeout).to_string(),
+        "killed after 200ms"
+    );
+}
@@ -0,0 +1,16 @@
+/*
+ * Every quiz snippet still compiles and prints its stored answer, so a
+ * new Rust release changing one fails here before a learner sees it
+ * */
+use rust_exercises::extras::quiz::snippets::{Runner, SNIPPETS};
+
+#[test]
+fn snippets_print_their_expected_output() {
+    let runner = Runner::new().unwrap();
+    let failures: Vec<String> = SNIPPETS
+        .iter()
+        .filter_map(|snippet| runner.verify(snippet).err())
+        .map(|e| e.to_string())
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
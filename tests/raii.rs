@@ -0,0 +1,79 @@
+/*
+ * The guards in extras::raii clean up when their scope ends, and also
+ * when a panic unwinds through it, caught here with catch_unwind
+ * */
+mod common;
+
+use common::temp_dir;
+use rust_exercises::extras::raii::{FileLockGuard, ScopedTimer};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn lock_file_lives_as_long_as_the_guard() {
+    let dir = temp_dir("raii_lock");
+    let path = dir.path().join("job.lock");
+
+    {
+        let guard = FileLockGuard::acquire(&path).unwrap();
+        assert!(path.exists());
+        assert_eq!(guard.path(), path);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.trim(), std::process::id().to_string());
+
+        let second = FileLockGuard::acquire(&path).unwrap_err();
+        assert_eq!(second.kind(), io::ErrorKind::AlreadyExists);
+    }
+    assert!(!path.exists());
+
+    // Free again
+    drop(FileLockGuard::acquire(&path).unwrap());
+}
+
+#[test]
+fn lock_file_removed_on_panic() {
+    let dir = temp_dir("raii_lock_panic");
+    let path = dir.path().join("job.lock");
+
+    let result = panic::catch_unwind(|| {
+        let _guard = FileLockGuard::acquire(&path).unwrap();
+        assert!(path.exists());
+        panic!("the guarded work failed");
+    });
+    assert!(result.is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn timer_records_on_drop_and_on_panic() {
+    let timings: Arc<Mutex<Vec<(String, Duration)>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = |timings: &Arc<Mutex<Vec<(String, Duration)>>>| {
+        let timings = timings.clone();
+        move |label: &str, elapsed: Duration| {
+            timings.lock().unwrap().push((label.to_string(), elapsed));
+        }
+    };
+
+    {
+        let timer = ScopedTimer::new("sleep", sink(&timings));
+        thread::sleep(Duration::from_millis(20));
+        assert!(timer.elapsed() >= Duration::from_millis(20));
+        // Nothing recorded before the scope ends
+        assert!(timings.lock().unwrap().is_empty());
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _timer = ScopedTimer::new("panics", sink(&timings));
+        panic!("the timed work failed");
+    }));
+    assert!(result.is_err());
+
+    let timings = timings.lock().unwrap();
+    let labels: Vec<&str> = timings.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(labels, ["sleep", "panics"]);
+    assert!(timings[0].1 >= Duration::from_millis(20));
+}
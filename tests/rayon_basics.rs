@@ -0,0 +1,74 @@
+/*
+ * Every rayon_basics task gives the same result in parallel as
+ * sequentially, on inputs big enough for rayon to split them. The
+ * timings are in benches/rayon.rs.
+ *
+ *   cargo test --features parallel --test rayon_basics
+ * */
+#![cfg(all(feature = "ch16", feature = "parallel"))]
+
+mod common;
+
+use common::rng;
+use rust_exercises::chapters::rayon_basics::*;
+use rust_exercises::extras::rng::Rng;
+
+#[test]
+fn sum_of_squares_agrees() {
+    let numbers: Vec<u64> = (0..1_000_000).collect();
+    assert_eq!(sum_of_squares(&numbers), sum_of_squares_par(&numbers));
+
+    let mut rng = rng();
+    let random: Vec<u64> = (0..100_000).map(|_| rng.gen_range(0..1 << 20)).collect();
+    assert_eq!(sum_of_squares(&random), sum_of_squares_par(&random));
+
+    assert_eq!(sum_of_squares_par(&[]), 0);
+    assert_eq!(sum_of_squares_par(&[3, 4]), 25);
+}
+
+#[test]
+fn word_count_agrees() {
+    let text = "the quick brown fox jumps over the lazy dog The End";
+    let lines: Vec<&str> = (0..10_000).map(|_| text).collect();
+    let counts = word_count_par(&lines);
+    assert_eq!(counts, word_count(&lines));
+
+    // Lowercased, so "The" and "the" are one word
+    assert_eq!(counts["the"], 30_000);
+    assert_eq!(counts["end"], 10_000);
+    assert_eq!(counts.len(), 9);
+    assert!(word_count_par(&[]).is_empty());
+}
+
+#[test]
+fn word_count_agrees_on_random_words() {
+    let mut rng = rng();
+    let words = ["a", "B", "c", "b", "A", "dd", "Dd"];
+    let text: Vec<String> = (0..5_000)
+        .map(|_| {
+            let len = rng.gen_range(0..8) as usize;
+            let line: Vec<&str> = (0..len)
+                .map(|_| words[rng.gen_range(0..words.len() as u64) as usize])
+                .collect();
+            line.join("  ")
+        })
+        .collect();
+    let lines: Vec<&str> = text.iter().map(String::as_str).collect();
+    assert_eq!(word_count(&lines), word_count_par(&lines));
+}
+
+#[test]
+fn grayscale_agrees() {
+    let mut rng = rng();
+    let pixels: Vec<u8> = (0..300_000).map(|_| rng.gen_range(0..256) as u8).collect();
+    let mut sequential = pixels.clone();
+    let mut parallel = pixels;
+    grayscale(&mut sequential);
+    grayscale_par(&mut parallel);
+    assert!(sequential == parallel);
+    assert!(parallel.chunks(3).all(|p| p[0] == p[1] && p[1] == p[2]));
+
+    let mut known = [255, 255, 255, 0, 0, 0, 255, 0, 0];
+    grayscale_par(&mut known);
+    assert_eq!(known, [255, 255, 255, 0, 0, 0, 76, 76, 76]);
+}
@@ -0,0 +1,87 @@
+/*
+ * extras::retry with the sleep swapped for a closure writing down the
+ * delays, so nothing waits and the backoff can be checked exactly
+ * */
+use rust_exercises::extras::retry::{retry, retry_with_sleep, RetryError, RetryPolicy};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+// Fails until the given call, then succeeds with the call number
+fn flaky(succeeds_on: u32) -> impl FnMut() -> Result<u32, String> {
+    let mut calls = 0;
+    move || {
+        calls += 1;
+        if calls >= succeeds_on {
+            Ok(calls)
+        } else {
+            Err(format!("call {} failed", calls))
+        }
+    }
+}
+
+#[test]
+fn eventual_success() {
+    let mut slept = Vec::new();
+    let policy = RetryPolicy::exponential(ms(100)).max_attempts(5);
+    let result = retry_with_sleep(policy, flaky(3), |delay| slept.push(delay));
+    assert_eq!(result, Ok(3));
+    assert_eq!(slept, [ms(100), ms(200)]);
+}
+
+#[test]
+fn exhaustion_keeps_the_last_error() {
+    let mut slept = Vec::new();
+    let policy = RetryPolicy::fixed(ms(50)).max_attempts(4);
+    let result = retry_with_sleep(policy, flaky(100), |delay| slept.push(delay));
+    assert_eq!(
+        result,
+        Err(RetryError {
+            attempts: 4,
+            last: String::from("call 4 failed"),
+        })
+    );
+    // Waits between attempts only, not after the last one
+    assert_eq!(slept, [ms(50); 3]);
+    assert_eq!(result.unwrap_err().to_string(), "gave up after 4 attempts");
+}
+
+#[test]
+fn first_call_always_happens() {
+    let mut slept = Vec::new();
+    let policy = RetryPolicy::fixed(ms(1)).max_attempts(0);
+    assert_eq!(policy.attempts(), 1);
+    let result = retry_with_sleep(policy, flaky(2), |delay| slept.push(delay));
+    assert_eq!(result.unwrap_err().attempts, 1);
+    assert!(slept.is_empty());
+
+    // The real sleep, on the first call there's nothing to sleep for
+    assert_eq!(retry(RetryPolicy::fixed(ms(1)), flaky(1)), Ok(1));
+}
+
+#[test]
+fn backoff_delays() {
+    let delays =
+        |policy: RetryPolicy| -> Vec<Duration> { (1..=5).map(|n| policy.delay(n)).collect() };
+
+    assert_eq!(delays(RetryPolicy::fixed(ms(10))), [ms(10); 5]);
+    assert_eq!(
+        delays(RetryPolicy::linear(ms(10), ms(5))),
+        [ms(10), ms(15), ms(20), ms(25), ms(30)]
+    );
+    assert_eq!(
+        delays(RetryPolicy::exponential(ms(10))),
+        [ms(10), ms(20), ms(40), ms(80), ms(160)]
+    );
+    assert_eq!(
+        delays(RetryPolicy::exponential(ms(10)).max_delay(ms(50))),
+        [ms(10), ms(20), ms(40), ms(50), ms(50)]
+    );
+
+    // Far past overflow, capped instead of panicking
+    let policy = RetryPolicy::exponential(ms(10)).max_delay(Duration::from_secs(30));
+    assert_eq!(policy.delay(1_000), Duration::from_secs(30));
+    assert_eq!(RetryPolicy::exponential(ms(10)).delay(200), Duration::MAX);
+}
@@ -0,0 +1,111 @@
+/*
+ * Properties of the run-length format on random input, for the streaming
+ * extras::rle and the slice based core_utils::rle alike: decoding gives
+ * the input back, both produce the same bytes, and the output stays
+ * within max_encoded_len
+ * */
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use common::rng;
+use rust_exercises::core_utils::rle::{self as slices, RleError};
+use rust_exercises::extras::rle;
+use rust_exercises::extras::rng::{Rng, XorShift64};
+use std::io::ErrorKind;
+
+/*
+ * Runs of random length, some long enough to go past MAX_REPEAT, mixed
+ * with stretches of random bytes that go past MAX_LITERAL
+ * */
+fn input(rng: &mut XorShift64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for _ in 0..rng.gen_range(0..12) {
+        if rng.gen_bool(0.5) {
+            let byte = rng.next_u64() as u8;
+            let len = rng.gen_range(1..300) as usize;
+            bytes.extend(std::iter::repeat_n(byte, len));
+        } else {
+            // Only a few values, so short runs happen by chance too
+            let len = rng.gen_range(0..300);
+            bytes.extend((0..len).map(|_| rng.gen_range(0..4) as u8));
+        }
+    }
+    bytes
+}
+
+#[test]
+fn round_trips_and_agrees_with_core_utils() {
+    let mut rng = rng();
+    for _ in 0..500 {
+        let input = input(&mut rng);
+        let encoded = rle::compress_bytes(&input);
+        assert_eq!(rle::decompress_bytes(&encoded).unwrap(), input);
+        assert_eq!(slices::encode_to_vec(&input), encoded);
+        assert_eq!(slices::decode_to_vec(&encoded).unwrap(), input);
+        assert_eq!(slices::decoded_len(&encoded), Ok(input.len()));
+        assert!(encoded.len() <= slices::max_encoded_len(input.len()));
+    }
+}
+
+#[test]
+fn worst_cases() {
+    // No two equal bytes in a row: one control byte every 128
+    let distinct: Vec<u8> = (0..1000).map(|i| (i % 251) as u8).collect();
+    assert_eq!(rle::compress_bytes(&distinct).len(), 1000 + 8);
+
+    // A lone byte between every two repeats, "abbcdd"
+    let mixed = b"abbcddeff".repeat(20);
+    let encoded = rle::compress_bytes(&mixed);
+    assert_eq!(encoded.len(), slices::max_encoded_len(mixed.len()));
+
+    // Runs as long as a block can hold, and one byte more, which is a literal
+    for &(len, encoded_len) in [(129, 2), (130, 4), (131, 4), (258, 4), (259, 6)].iter() {
+        let run = vec![7; len];
+        let encoded = rle::compress_bytes(&run);
+        assert_eq!(encoded.len(), encoded_len, "{}", len);
+        assert_eq!(rle::decompress_bytes(&encoded).unwrap(), run);
+    }
+    assert!(rle::compress_bytes(&[]).is_empty());
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+    let mut rng = rng();
+    for _ in 0..200 {
+        let input = input(&mut rng);
+        let encoded = rle::compress_bytes(&input);
+        if encoded.is_empty() {
+            continue;
+        }
+        let cut = &encoded[..rng.gen_range(0..encoded.len() as u64) as usize];
+        // A cut between two blocks is still valid, just shorter
+        match slices::decode_to_vec(cut) {
+            Ok(decoded) => {
+                assert!(input.starts_with(&decoded));
+                assert_eq!(rle::decompress_bytes(cut).unwrap(), decoded);
+            }
+            Err(e) => {
+                assert_eq!(e, RleError::Truncated);
+                let streamed = rle::decompress_bytes(cut).unwrap_err();
+                assert_eq!(streamed.kind(), ErrorKind::UnexpectedEof);
+            }
+        }
+    }
+}
+
+#[test]
+fn small_output_buffers() {
+    let input = b"aaaabcd";
+    let encoded = rle::compress_bytes(input);
+    let mut output = vec![0; encoded.len() - 1];
+    assert_eq!(
+        slices::encode(input, &mut output),
+        Err(RleError::OutputTooSmall)
+    );
+    let mut output = vec![0; input.len() - 1];
+    assert_eq!(
+        slices::decode(&encoded, &mut output),
+        Err(RleError::OutputTooSmall)
+    );
+}
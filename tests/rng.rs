@@ -0,0 +1,127 @@
+/*
+ * Statistical sanity of extras::rng. The seeds are fixed so a result
+ * can't change from one run to the next, and the bounds are loose
+ * enough that a correct generator is nowhere near them: these catch a
+ * broken generator, they don't certify a good one.
+ * */
+use rust_exercises::extras::rng::{Rng, XorShift64};
+
+const DRAWS: usize = 100_000;
+
+// Pearson's statistic of the observed counts against equal ones
+fn chi_square(counts: &[usize]) -> f64 {
+    let expected = counts.iter().sum::<usize>() as f64 / counts.len() as f64;
+    counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+#[test]
+fn same_seed_same_sequence() {
+    let first: Vec<u64> = XorShift64::new(42).take(100).collect();
+    let second: Vec<u64> = XorShift64::new(42).take(100).collect();
+    assert_eq!(first, second);
+    assert_ne!(first, XorShift64::new(43).take(100).collect::<Vec<_>>());
+
+    // The three shifts of Marsaglia's paper, worked out by hand from 1
+    assert_eq!(XorShift64::new(1).next_u64(), 1_082_269_761);
+    // 0 would be stuck there
+    assert!(XorShift64::new(0).take(10).all(|x| x != 0));
+}
+
+#[test]
+fn gen_range_is_uniform() {
+    for &seed in [1, 2, 3].iter() {
+        let mut rng = XorShift64::new(seed);
+        let mut counts = [0; 10];
+        for _ in 0..DRAWS {
+            let value = rng.gen_range(5..15);
+            assert!((5..15).contains(&value));
+            counts[(value - 5) as usize] += 1;
+        }
+        // 9 degrees of freedom, 21.7 is p = 0.01 and 40 is far past it
+        assert!(chi_square(&counts) < 40.0, "{:?}", counts);
+    }
+    // A span that doesn't divide 2^64, where a plain % would lean low
+    let mut rng = XorShift64::new(7);
+    let span = u64::MAX / 3 * 2;
+    let low = (0..DRAWS)
+        .filter(|_| rng.gen_range(0..span) < span / 2)
+        .count();
+    assert!(
+        (DRAWS * 48 / 100..DRAWS * 52 / 100).contains(&low),
+        "{}",
+        low
+    );
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn gen_range_refuses_empty_ranges() {
+    XorShift64::new(1).gen_range(3..3);
+}
+
+#[test]
+fn floats_and_bools() {
+    let mut rng = XorShift64::new(11);
+    let floats: Vec<f64> = (0..DRAWS).map(|_| rng.gen_f64()).collect();
+    assert!(floats.iter().all(|&x| (0.0..1.0).contains(&x)));
+    let mean = floats.iter().sum::<f64>() / DRAWS as f64;
+    // The standard deviation of the mean is 0.0009
+    assert!((mean - 0.5).abs() < 0.005, "{}", mean);
+
+    let heads = (0..DRAWS).filter(|_| rng.gen_bool(0.3)).count();
+    assert!(
+        (DRAWS * 29 / 100..DRAWS * 31 / 100).contains(&heads),
+        "{}",
+        heads
+    );
+    assert!((0..1000).all(|_| !rng.gen_bool(0.0)));
+    assert!((0..1000).all(|_| rng.gen_bool(1.0)));
+}
+
+#[test]
+fn every_bit_is_a_coin_flip() {
+    let mut rng = XorShift64::new(5);
+    let mut ones = [0; 64];
+    for _ in 0..DRAWS {
+        let value = rng.next_u64();
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += (value >> bit & 1) as usize;
+        }
+    }
+    for (bit, &count) in ones.iter().enumerate() {
+        assert!(
+            (DRAWS * 48 / 100..DRAWS * 52 / 100).contains(&count),
+            "bit {}: {}",
+            bit,
+            count
+        );
+    }
+}
+
+#[test]
+fn shuffle_permutations_are_equally_likely() {
+    let mut rng = XorShift64::new(9);
+    // The 6 orders of [0, 1, 2], told apart by where 0 and 1 end up
+    let mut counts = [0; 6];
+    for _ in 0..60_000 {
+        let mut items = [0, 1, 2];
+        rng.shuffle(&mut items);
+        let zero = items.iter().position(|&x| x == 0).unwrap();
+        let one = items.iter().position(|&x| x == 1).unwrap();
+        // Where 1 is among the two places 0 left
+        let rest = if one < zero { one } else { one - 1 };
+        counts[zero * 2 + rest] += 1;
+    }
+    // 5 degrees of freedom, 15.1 is p = 0.01
+    assert!(chi_square(&counts) < 30.0, "{:?}", counts);
+
+    let mut items: Vec<u32> = (0..100).collect();
+    rng.shuffle(&mut items);
+    let mut sorted = items.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+    assert_ne!(items, sorted);
+}
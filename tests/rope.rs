@@ -0,0 +1,102 @@
+/*
+ * Rope against the String it stands for: the same length, the same char
+ * at every offset, the same text once flattened, whatever the pieces
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::rng::Rng;
+use rust_exercises::extras::rope::Rope;
+
+// Every char() of a String, one at a time, and past the end
+fn assert_same(rope: &Rope, text: &str) {
+    assert_eq!(rope.len(), text.len());
+    assert_eq!(rope.is_empty(), text.is_empty());
+    assert_eq!(rope.char_len(), text.chars().count());
+    assert_eq!(rope.to_string(), text);
+    for (offset, c) in text.chars().enumerate() {
+        assert_eq!(
+            rope.char_at(offset),
+            Some(c),
+            "offset {} of {:?}",
+            offset,
+            text
+        );
+    }
+    assert_eq!(rope.char_at(rope.char_len()), None);
+    assert_eq!(rope.char_at(usize::MAX), None);
+}
+
+#[test]
+fn empty() {
+    let mut rope = Rope::new();
+    assert_same(&rope, "");
+    assert_eq!(rope.pieces(), 0);
+    assert_eq!(rope.flatten(), "");
+    assert_eq!(Rope::new().into_string(), "");
+}
+
+#[test]
+fn appends_without_joining() {
+    let mut rope = Rope::new();
+    rope.append("hello");
+    rope.append(String::from(", "));
+    // Empty pieces aren't kept, they can't hold a char
+    rope.append("");
+    rope.append("world");
+    assert_eq!(rope.pieces(), 3);
+    assert_same(&rope, "hello, world");
+
+    assert_eq!(rope.flatten(), "hello, world");
+    assert_eq!(rope.pieces(), 1);
+    // Still appends after flattening
+    rope.append("!");
+    assert_same(&rope, "hello, world!");
+    assert_eq!(rope.into_string(), "hello, world!");
+}
+
+#[test]
+fn counts_chars_not_bytes() {
+    let rope: Rope = ["héllo", " ", "wörld ", "🦀", "日本"]
+        .iter()
+        .copied()
+        .collect();
+    assert_same(&rope, "héllo wörld 🦀日本");
+    assert_eq!(rope.len(), "héllo wörld 🦀日本".len());
+    assert_eq!(rope.char_len(), 15);
+    assert_eq!(rope.char_at(12), Some('🦀'));
+    assert_eq!(rope.char_at(13), Some('日'));
+}
+
+#[test]
+fn extend_and_collect() {
+    let mut rope: Rope = vec![String::from("a"), String::from("b")]
+        .into_iter()
+        .collect();
+    rope.extend(["c", "", "d"].iter().copied());
+    assert_eq!(rope.pieces(), 4);
+    assert_same(&rope, "abcd");
+}
+
+#[test]
+fn random_pieces() {
+    let alphabet: Vec<char> = "ab é🦀\n".chars().collect();
+    let mut rng = rng();
+    for _ in 0..100 {
+        let mut rope = Rope::new();
+        let mut text = String::new();
+        for _ in 0..rng.gen_range(0..30) {
+            let piece: String = (0..rng.gen_range(0..8))
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len() as u64) as usize])
+                .collect();
+            text.push_str(&piece);
+            rope.append(piece);
+        }
+        assert_same(&rope, &text);
+        if rng.gen_bool(0.5) {
+            assert_eq!(rope.flatten(), text);
+            assert_same(&rope, &text);
+        }
+        assert_eq!(rope.into_string(), text);
+    }
+}
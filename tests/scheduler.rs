@@ -0,0 +1,157 @@
+/*
+ * extras::scheduler on a ManualClock, time only moves when a test says
+ * so. The pools have one worker, so jobs run in the order tick() hands
+ * them over and each test can check that order.
+ * */
+#![cfg(feature = "alloc")]
+
+use rust_exercises::extras::scheduler::{Clock, Job, ManualClock, Scheduler};
+use rust_exercises::extras::thread_pool::ThreadPool;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+const SECOND: Duration = Duration::from_secs(1);
+
+fn scheduler() -> (Scheduler<ManualClock>, ManualClock) {
+    let clock = ManualClock::new();
+    (
+        Scheduler::with_clock(ThreadPool::new(1), clock.clone()),
+        clock,
+    )
+}
+
+fn job(name: &'static str, ran: &Sender<&'static str>) -> Job {
+    let ran = ran.clone();
+    Job::new(move || ran.send(name).unwrap())
+}
+
+// The next n jobs to finish
+fn finished(ran: &Receiver<&'static str>, n: usize) -> Vec<&'static str> {
+    (0..n)
+        .map(|_| ran.recv_timeout(Duration::from_secs(5)).unwrap())
+        .collect()
+}
+
+#[test]
+fn due_jobs_run_by_priority_then_submission() {
+    let (mut scheduler, _clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    scheduler.submit(job("low", &sender).priority(1));
+    scheduler.submit(job("high", &sender).priority(9));
+    scheduler.submit(job("default", &sender));
+    scheduler.submit(job("high again", &sender).priority(9));
+    scheduler.submit(job("mid", &sender).priority(5));
+
+    // Nothing runs before a tick
+    assert_eq!(scheduler.len(), 5);
+    assert!(ran.try_recv().is_err());
+
+    assert_eq!(scheduler.tick(), 5);
+    assert!(scheduler.is_empty());
+    assert_eq!(
+        finished(&ran, 5),
+        ["high", "high again", "mid", "low", "default"]
+    );
+    assert_eq!(scheduler.tick(), 0);
+}
+
+#[test]
+fn delayed_jobs_wait_for_the_clock() {
+    let (mut scheduler, clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    let start = clock.now();
+    scheduler.submit(job("in 10s", &sender).run_at(start + 10 * SECOND));
+    scheduler.submit(job("in 5s", &sender).run_at(start + 5 * SECOND));
+    scheduler.submit(job("now", &sender));
+    assert_eq!(scheduler.next_run_at(), Some(start + 5 * SECOND));
+
+    assert_eq!(scheduler.tick(), 1);
+    assert_eq!(finished(&ran, 1), ["now"]);
+
+    clock.advance(4 * SECOND);
+    assert_eq!(scheduler.tick(), 0);
+    clock.advance(SECOND);
+    assert_eq!(scheduler.tick(), 1);
+    assert_eq!(finished(&ran, 1), ["in 5s"]);
+    assert_eq!(scheduler.next_run_at(), Some(start + 10 * SECOND));
+
+    clock.advance(60 * SECOND);
+    assert_eq!(scheduler.tick(), 1);
+    assert_eq!(finished(&ran, 1), ["in 10s"]);
+    assert_eq!(scheduler.next_run_at(), None);
+}
+
+#[test]
+fn jobs_due_in_the_same_tick_go_by_priority() {
+    let (mut scheduler, clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    let start = clock.now();
+    // Due earlier but less important
+    scheduler.submit(job("early", &sender).run_at(start + SECOND).priority(1));
+    scheduler.submit(job("late", &sender).run_at(start + 2 * SECOND).priority(7));
+    scheduler.submit(job("never delayed", &sender).priority(4));
+
+    clock.advance(3 * SECOND);
+    assert_eq!(scheduler.tick(), 3);
+    assert_eq!(finished(&ran, 3), ["late", "never delayed", "early"]);
+}
+
+#[test]
+fn cancelled_jobs_never_run() {
+    let (mut scheduler, clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    let start = clock.now();
+    let now = scheduler.submit(job("now", &sender));
+    let later = scheduler.submit(job("later", &sender).run_at(start + SECOND));
+    let kept = scheduler.submit(job("kept", &sender).run_at(start + SECOND));
+
+    assert!(scheduler.cancel(now));
+    assert!(scheduler.cancel(later));
+    assert!(!scheduler.cancel(later));
+    assert_eq!(scheduler.len(), 1);
+
+    clock.advance(SECOND);
+    assert_eq!(scheduler.tick(), 1);
+    // Already handed to the pool, too late
+    assert!(!scheduler.cancel(kept));
+    drop(scheduler);
+    drop(sender);
+    assert_eq!(ran.iter().collect::<Vec<_>>(), ["kept"]);
+}
+
+#[test]
+fn shutdown_drains_every_waiting_job() {
+    let (mut scheduler, clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    let start = clock.now();
+    scheduler.submit(job("first tick", &sender));
+    scheduler.tick();
+    scheduler.submit(
+        job("tomorrow", &sender)
+            .run_at(start + 86_400 * SECOND)
+            .priority(3),
+    );
+    scheduler.submit(job("low", &sender));
+    let cancelled = scheduler.submit(job("cancelled", &sender).priority(9));
+    scheduler.cancel(cancelled);
+
+    // Returns once the pool has finished, nothing is left to wait for
+    assert_eq!(scheduler.shutdown(), 2);
+    drop(sender);
+    assert_eq!(
+        ran.iter().collect::<Vec<_>>(),
+        ["first tick", "tomorrow", "low"]
+    );
+}
+
+#[test]
+fn dropping_the_scheduler_discards_waiting_jobs() {
+    let (mut scheduler, _clock) = scheduler();
+    let (sender, ran) = mpsc::channel();
+    scheduler.submit(job("ticked", &sender));
+    scheduler.tick();
+    scheduler.submit(job("dropped", &sender));
+    drop(scheduler);
+    drop(sender);
+    assert_eq!(ran.iter().collect::<Vec<_>>(), ["ticked"]);
+}
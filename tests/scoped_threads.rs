@@ -0,0 +1,74 @@
+/*
+ * The scoped and Arc versions against a plain sum, for every way the
+ * data can split: fewer items than threads, uneven last chunks, nothing
+ * at all
+ * */
+#![cfg(feature = "ch16")]
+
+mod common;
+
+use common::rng;
+use rust_exercises::chapters::scoped_threads::{double_and_sum_scoped, sum_arc, sum_scoped};
+use rust_exercises::extras::rng::Rng;
+
+#[test]
+fn every_split_gives_the_same_sum() {
+    let mut rng = rng();
+    for len in [0, 1, 2, 7, 64, 1_001] {
+        let data: Vec<i64> = (0..len)
+            .map(|_| rng.gen_range(0..2_000) as i64 - 1_000)
+            .collect();
+        let expected: i64 = data.iter().sum();
+
+        for threads in [1, 2, 3, 8, 2_000] {
+            assert_eq!(
+                sum_scoped(&data, threads),
+                expected,
+                "{} items, {} threads",
+                len,
+                threads
+            );
+            assert_eq!(
+                sum_arc(data.clone(), threads),
+                expected,
+                "{} items, {} threads",
+                len,
+                threads
+            );
+        }
+    }
+}
+
+#[test]
+fn doubling_happens_in_place() {
+    for threads in [1, 3, 4, 100] {
+        let mut data: Vec<i64> = (1..=10).collect();
+        assert_eq!(double_and_sum_scoped(&mut data, threads), 110);
+        // Every chunk was handed to a thread exactly once
+        assert_eq!(data, (1..=10).map(|n| n * 2).collect::<Vec<i64>>());
+    }
+
+    let mut empty: Vec<i64> = Vec::new();
+    assert_eq!(double_and_sum_scoped(&mut empty, 4), 0);
+}
+
+#[test]
+fn the_borrowed_data_is_usable_after_the_scope() {
+    let data = vec![5; 100];
+    let total = sum_scoped(&data, 4);
+    // Still ours, nothing was moved into the threads
+    assert_eq!(total, data.iter().sum::<i64>());
+    assert_eq!(data.len(), 100);
+}
+
+#[test]
+#[should_panic(expected = "need at least one thread")]
+fn zero_threads_scoped() {
+    sum_scoped(&[1, 2, 3], 0);
+}
+
+#[test]
+#[should_panic(expected = "need at least one thread")]
+fn zero_threads_arc() {
+    sum_arc(vec![1, 2, 3], 0);
+}
@@ -0,0 +1,160 @@
+/*
+ * Teams through JSON and TOML and back, what the serde attributes do to
+ * the serialized form, and the hand-written parser agreeing with serde
+ *
+ *   cargo test --features serde --test serde_basics
+ * */
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::rng;
+use rust_exercises::chapters::serde_basics::*;
+use rust_exercises::extras::rng::{Rng, XorShift64};
+
+// What comes back from any format, rating is never serialized
+fn unrated(team: &Team) -> Team {
+    Team {
+        rating: None,
+        ..team.clone()
+    }
+}
+
+fn random_team(rng: &mut XorShift64) -> Team {
+    let positions = [Position::Forward, Position::Defense, Position::Goalie];
+    let players = (0..rng.gen_range(0..6))
+        .map(|i| Player {
+            name: format!("Player \"{}\" \u{e9}", i),
+            number: rng.gen_range(0..256) as u8,
+            position: positions[rng.gen_range(0..3) as usize],
+            captain: rng.gen_bool(0.2),
+        })
+        .collect();
+    Team {
+        name: format!("Team {}", rng.next_u64()),
+        home_city: String::from("Qu\u{e9}bec"),
+        players,
+        rating: Some(rng.gen_f64() * 100.0),
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let team = sample_team();
+    let json = to_json(&team).unwrap();
+    assert_eq!(from_json(&json).unwrap(), unrated(&team));
+
+    let mut rng = rng();
+    for _ in 0..200 {
+        let team = random_team(&mut rng);
+        assert_eq!(from_json(&to_json(&team).unwrap()).unwrap(), unrated(&team));
+    }
+}
+
+#[test]
+fn toml_round_trip() {
+    let team = sample_team();
+    let toml = to_toml(&team).unwrap();
+    assert_eq!(from_toml(&toml).unwrap(), unrated(&team));
+
+    let mut rng = rng();
+    for _ in 0..200 {
+        let team = random_team(&mut rng);
+        assert_eq!(from_toml(&to_toml(&team).unwrap()).unwrap(), unrated(&team));
+    }
+}
+
+#[test]
+fn renamed_and_skipped_fields() {
+    let json = to_json(&sample_team()).unwrap();
+    assert!(json.contains("\"city\": \"Montreal\""));
+    assert!(json.contains("\"isCaptain\": true"));
+    assert!(json.contains("\"position\": \"goalie\""));
+    assert!(!json.contains("home_city"));
+    assert!(!json.contains("rating"));
+
+    let toml = to_toml(&sample_team()).unwrap();
+    assert!(toml.contains("city = \"Montreal\""));
+    assert!(toml.contains("[[players]]"));
+    assert!(toml.contains("isCaptain = true"));
+    assert!(!toml.contains("rating"));
+}
+
+#[test]
+fn missing_position_and_captain_default() {
+    let json = r#"{"name": "A", "city": "B", "players": [{"name": "C", "number": 9}]}"#;
+    let expected = vec![Player {
+        name: String::from("C"),
+        number: 9,
+        position: Position::Forward,
+        captain: false,
+    }];
+    assert_eq!(from_json(json).unwrap().players, expected);
+    assert_eq!(from_json_by_hand(json).unwrap().players, expected);
+
+    let toml = "name = \"A\"\ncity = \"B\"\n\n[[players]]\nname = \"C\"\nnumber = 9\n";
+    assert_eq!(from_toml(toml).unwrap().players, expected);
+}
+
+#[test]
+fn serde_errors_name_the_field() {
+    let missing = from_json(r#"{"name": "A", "players": []}"#).unwrap_err();
+    assert!(
+        missing.to_string().contains("missing field `city`"),
+        "{}",
+        missing
+    );
+
+    let wrong =
+        from_json(r#"{"name": "A", "city": "B", "players": [{"name": "C", "number": 300}]}"#);
+    assert!(wrong.is_err());
+
+    let position = from_json(r#"{"name": "A", "city": "B", "players": [{"name": "C", "number": 1, "position": "coach"}]}"#)
+        .unwrap_err();
+    assert!(
+        position.to_string().contains("unknown variant `coach`"),
+        "{}",
+        position
+    );
+
+    let toml = from_toml("name = \"A\"\nplayers = []\n").unwrap_err();
+    assert!(
+        toml.to_string().contains("missing field `city`"),
+        "{}",
+        toml
+    );
+}
+
+#[test]
+fn by_hand_agrees_with_serde() {
+    let mut rng = rng();
+    for _ in 0..200 {
+        let json = to_json(&random_team(&mut rng)).unwrap();
+        assert_eq!(from_json_by_hand(&json).unwrap(), from_json(&json).unwrap());
+    }
+}
+
+#[test]
+fn by_hand_errors() {
+    assert_eq!(
+        from_json_by_hand(r#"{"name": "A", "players": []}"#),
+        Err(ManualError::Missing("city"))
+    );
+    assert_eq!(
+        from_json_by_hand(r#"{"name": "A", "city": "B", "players": {}}"#),
+        Err(ManualError::WrongType("players"))
+    );
+    assert_eq!(
+        from_json_by_hand(
+            r#"{"name": "A", "city": "B", "players": [{"name": "C", "number": 1.5}]}"#
+        ),
+        Err(ManualError::WrongType("number"))
+    );
+    assert_eq!(
+        from_json_by_hand(
+            r#"{"name": "A", "city": "B", "players": [{"name": "C", "number": 1, "position": "coach"}]}"#
+        ),
+        Err(ManualError::UnknownPosition(String::from("coach")))
+    );
+    assert!(matches!(from_json_by_hand("{"), Err(ManualError::Parse(_))));
+}
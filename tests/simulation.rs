@@ -0,0 +1,97 @@
+/*
+ * extras::simulation: the well known patterns do what they should, and
+ * the Barrier-synchronized run_parallel gives the same grid as
+ * run_sequential for any number of threads, on random grids too
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::rng::Rng;
+use rust_exercises::extras::simulation::{run_parallel, run_sequential, step, Grid, GridError};
+
+fn grid(text: &str) -> Grid {
+    text.parse().unwrap()
+}
+
+#[test]
+fn blinker_oscillates() {
+    let horizontal = grid(".....\n.###.\n.....");
+    let vertical = grid("..#..\n..#..\n..#..");
+    assert_eq!(step(&horizontal), vertical);
+    assert_eq!(run_sequential(&horizontal, 2), horizontal);
+    assert_eq!(run_parallel(&horizontal, 3, 2), vertical);
+}
+
+#[test]
+fn block_stays_still() {
+    let block = grid("....\n.##.\n.##.\n....");
+    assert_eq!(run_sequential(&block, 10), block);
+}
+
+#[test]
+fn glider_moves_one_cell_diagonally_every_four_generations() {
+    let start = grid(
+        "
+        .#....
+        ..#...
+        ###...
+        ......
+        ......
+        ......",
+    );
+    let moved = grid(
+        "
+        ......
+        ..#...
+        ...#..
+        .###..
+        ......
+        ......",
+    );
+    assert_eq!(run_sequential(&start, 4), moved);
+    assert_eq!(run_parallel(&start, 4, 3), moved);
+    // Nothing wraps around: in the corner it ends up as a still block
+    assert_eq!(run_sequential(&start, 40).alive(), 4);
+}
+
+#[test]
+fn parse_and_print() {
+    let text = "#..\n.#.\n..#\n";
+    let parsed = grid(text);
+    assert_eq!((parsed.width(), parsed.height(), parsed.alive()), (3, 3, 3));
+    assert!(parsed.get(1, 1) && !parsed.get(1, 0));
+    assert_eq!(parsed.to_string(), text);
+
+    assert_eq!("".parse::<Grid>(), Err(GridError::Empty));
+    assert_eq!("..\n...".parse::<Grid>(), Err(GridError::RaggedRow(1)));
+    assert_eq!(".o".parse::<Grid>(), Err(GridError::InvalidCell('o')));
+}
+
+#[test]
+fn parallel_matches_sequential() {
+    let mut rng = rng();
+    for _ in 0..30 {
+        let (width, height) = (rng.gen_range(1..30) as usize, rng.gen_range(1..30) as usize);
+        let mut start = Grid::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                start.set(x, y, rng.gen_bool(0.35));
+            }
+        }
+        let generations = rng.gen_range(0..20) as usize;
+        let expected = run_sequential(&start, generations);
+        // Down to one row per thread, and more threads than rows
+        for &threads in [1, 2, 3, 7, 40].iter() {
+            assert_eq!(
+                run_parallel(&start, generations, threads),
+                expected,
+                "{}x{}, {} generations, {} threads\n{}",
+                width,
+                height,
+                generations,
+                threads,
+                start
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * Hand-rolled snapshot testing, in the spirit of insta: the expected
+ * output of a test lives in tests/snapshots/<name>.snap and the test
+ * compares against it.
+ *
+ * When the output changes on purpose, regenerate the files and review
+ * them in the diff:
+ *   UPDATE_SNAPSHOTS=1 cargo test
+ * */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn snapshot_path(name: &str) -> PathBuf {
+    assert!(
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+        "snapshot names become file names, {:?} isn't a good one",
+        name
+    );
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.snap", name))
+}
+
+fn updating() -> bool {
+    env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1")
+}
+
+// Line by line, so a long output points straight at what changed
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return String::from("only line endings differ"),
+            (e, a) => {
+                return format!(
+                    "line {}:\n  expected: {}\n  actual:   {}",
+                    line,
+                    e.map_or(String::from("<end>"), |e| format!("{:?}", e)),
+                    a.map_or(String::from("<end>"), |a| format!("{:?}", a)),
+                )
+            }
+        }
+    }
+}
+
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if updating() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(_) => panic!(
+            "no snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it\nactual output:\n{}",
+            path.display(),
+            actual
+        ),
+    };
+
+    if expected != actual {
+        panic!(
+            "output differs from snapshot {}, {}\nrun with UPDATE_SNAPSHOTS=1 if the change is intended",
+            path.display(),
+            first_difference(&expected, actual)
+        );
+    }
+}
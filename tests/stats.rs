@@ -0,0 +1,75 @@
+/*
+ * extras::stats against datasets whose answers are known, from
+ * Wikipedia's standard deviation example and numpy's percentile
+ * */
+use rust_exercises::extras::stats::{self, Summary, Welford};
+
+// Mean 5, population standard deviation exactly 2
+const WIKIPEDIA: [i32; 8] = [2, 4, 4, 4, 5, 5, 7, 9];
+
+fn assert_close(actual: Option<f64>, expected: f64) {
+    let actual = actual.unwrap();
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "{} != {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn mean_variance_std_dev() {
+    assert_close(stats::mean(&WIKIPEDIA), 5.0);
+    assert_close(stats::variance(&WIKIPEDIA), 4.0);
+    assert_close(stats::std_dev(&WIKIPEDIA), 2.0);
+
+    let mut running = Welford::new();
+    for &value in WIKIPEDIA.iter() {
+        running.push(value.into());
+    }
+    assert_eq!(running.count(), 8);
+    assert_close(running.sample_variance(), 32.0 / 7.0);
+}
+
+#[test]
+fn welford_keeps_precision_on_large_values() {
+    // The textbook sum of squares gives garbage here, the variance is 22.5
+    let data = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+    assert_close(stats::variance(&data), 22.5);
+}
+
+#[test]
+fn percentiles_interpolate() {
+    let data = [4u8, 1, 3, 2];
+    assert_close(stats::percentile(&data, 0.0), 1.0);
+    assert_close(stats::percentile(&data, 25.0), 1.75);
+    assert_close(stats::median(&data), 2.5);
+    assert_close(stats::percentile(&data, 100.0), 4.0);
+    assert_close(stats::median(&[3.5f32]), 3.5);
+
+    assert_eq!(stats::percentile(&data, 101.0), None);
+    assert_eq!(stats::percentile(&data, -1.0), None);
+}
+
+#[test]
+fn empty_input_has_no_statistics() {
+    let empty: [f64; 0] = [];
+    assert_eq!(stats::mean(&empty), None);
+    assert_eq!(stats::std_dev(&empty), None);
+    assert_eq!(stats::median(&empty), None);
+    assert_eq!(Summary::of(&empty), None);
+    assert_eq!(Welford::new().sample_variance(), None);
+}
+
+#[test]
+fn summary_display() {
+    let summary = Summary::of(&WIKIPEDIA).unwrap();
+    assert_eq!(
+        summary.to_string(),
+        "n=8 mean=5.00 sd=2.00 min=2.00 median=4.50 max=9.00"
+    );
+    assert_eq!(
+        format!("{:.1}", summary),
+        "n=8 mean=5.0 sd=2.0 min=2.0 median=4.5 max=9.0"
+    );
+}
@@ -0,0 +1,86 @@
+/*
+ * StrSplit against str::split on the same haystacks, trailing and
+ * leading delimiters and empty pieces included
+ * */
+use rust_exercises::extras::str_split::{until_char, StrSplit};
+
+fn pieces<'a>(haystack: &'a str, delimiter: &str) -> Vec<&'a str> {
+    StrSplit::new(haystack, delimiter).collect()
+}
+
+#[test]
+fn same_pieces_as_str_split() {
+    let cases = [
+        ("a b c", " "),
+        ("a,,b,", ","),
+        (",a", ","),
+        (",", ","),
+        (",,,", ","),
+        ("", ","),
+        ("no delimiter", ","),
+        ("a--b----c--", "--"),
+        ("---", "--"),
+        ("aaa", "aa"),
+        ("h\u{e9}llo w\u{f6}rld", "\u{e9}"),
+        ("one\r\ntwo\r\n", "\r\n"),
+    ];
+    for &(haystack, delimiter) in cases.iter() {
+        let expected: Vec<&str> = haystack.split(delimiter).collect();
+        assert_eq!(
+            pieces(haystack, delimiter),
+            expected,
+            "{:?} on {:?}",
+            haystack,
+            delimiter
+        );
+    }
+}
+
+#[test]
+fn trailing_delimiters_give_an_empty_last_piece() {
+    assert_eq!(pieces("a,b,", ","), ["a", "b", ""]);
+    assert_eq!(pieces("a,b,,", ","), ["a", "b", "", ""]);
+    assert_eq!(pieces("ab", "b"), ["a", ""]);
+}
+
+#[test]
+fn empty_pieces() {
+    assert_eq!(pieces("", ","), [""]);
+    assert_eq!(pieces(",", ","), ["", ""]);
+    assert_eq!(pieces("a,,,b", ","), ["a", "", "", "b"]);
+    assert_eq!(pieces(",a", ","), ["", "a"]);
+}
+
+#[test]
+fn the_iterator_ends_for_good() {
+    let mut split = StrSplit::new("a,b", ",");
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.next(), None);
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn pieces_outlive_the_delimiter() {
+    let haystack = String::from("key=value");
+    let first = {
+        let delimiter = String::from("=");
+        StrSplit::new(&haystack, &delimiter).next()
+    };
+    assert_eq!(first, Some("key"));
+}
+
+#[test]
+fn until_char_stops_at_the_first_one() {
+    assert_eq!(until_char("hello world", 'o'), "hell");
+    assert_eq!(until_char("hello", 'z'), "hello");
+    assert_eq!(until_char("", 'z'), "");
+    assert_eq!(until_char("caf\u{e9}!", '\u{e9}'), "caf");
+    assert_eq!(until_char("-leading", '-'), "");
+}
+
+#[test]
+#[should_panic(expected = "empty delimiter")]
+fn empty_delimiters_panic() {
+    let _ = StrSplit::new("abc", "");
+}
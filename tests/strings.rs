@@ -0,0 +1,86 @@
+/*
+ * Where working on chars stops matching what a reader sees: combining
+ * marks, emoji made of several chars, and strings whose byte, char and
+ * grapheme counts all differ. The expected values are what the char
+ * based functions do, caveats included.
+ * */
+use rust_exercises::extras::strings::*;
+
+const PRECOMPOSED: &str = "\u{e9}"; // é as one char
+const DECOMPOSED: &str = "e\u{301}"; // e and a combining acute accent
+const THUMBS_UP: &str = "\u{1f44d}\u{1f3fd}"; // 👍🏽, with a skin tone modifier
+const FAMILY: &str = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"; // 👨‍👩‍👧, joined by ZWJs
+const CANADA: &str = "\u{1f1e8}\u{1f1e6}"; // 🇨🇦, two regional indicators
+
+#[test]
+fn byte_char_and_grapheme_lengths_differ() {
+    // (text, bytes, chars), each one is a single grapheme
+    let cases = [
+        ("a", 1, 1),
+        (PRECOMPOSED, 2, 1),
+        (DECOMPOSED, 3, 2),
+        (THUMBS_UP, 8, 2),
+        (FAMILY, 18, 5),
+        (CANADA, 8, 2),
+    ];
+    for &(text, bytes, chars) in cases.iter() {
+        assert_eq!(text.len(), bytes, "{:?}", text);
+        assert_eq!(text.chars().count(), chars, "{:?}", text);
+    }
+    // The two spellings of é look the same and aren't equal
+    assert_ne!(PRECOMPOSED, DECOMPOSED);
+}
+
+#[test]
+fn reversing_chars_breaks_graphemes() {
+    // The accent ends up on the a: "éa" comes back as "áe"
+    assert_eq!(reverse_chars("e\u{301}a"), "a\u{301}e");
+    assert_eq!(reverse_chars(PRECOMPOSED), PRECOMPOSED);
+    // The modifier moves in front of the thumb it belonged to
+    assert_eq!(reverse_chars(THUMBS_UP), "\u{1f3fd}\u{1f44d}");
+    // Canada's flag becomes Ascension Island's, AC
+    assert_eq!(reverse_chars(CANADA), "\u{1f1e6}\u{1f1e8}");
+    let family: Vec<char> = FAMILY.chars().rev().collect();
+    assert_eq!(
+        reverse_chars(FAMILY),
+        family.into_iter().collect::<String>()
+    );
+    assert_eq!(reverse_chars(&reverse_chars(FAMILY)), FAMILY);
+}
+
+#[test]
+fn reversing_words_keeps_graphemes_whole() {
+    let text = format!("caf{} {}  {}\t{}", DECOMPOSED, THUMBS_UP, FAMILY, CANADA);
+    assert_eq!(
+        reverse_words(&text),
+        format!("{} {} {} caf{}", CANADA, FAMILY, THUMBS_UP, DECOMPOSED)
+    );
+}
+
+#[test]
+fn palindromes_and_combining_marks() {
+    assert!(is_palindrome("A man, a plan, a canal: Panama!"));
+    // The combining accent isn't alphanumeric, the decomposed é is a plain e
+    assert!(is_palindrome(&format!("{}ve", DECOMPOSED)));
+    assert!(!is_palindrome(&format!("{}ve", PRECOMPOSED)));
+    assert!(is_palindrome(&format!("{}t{}", PRECOMPOSED, PRECOMPOSED)));
+    // Emoji are dropped along with the punctuation
+    assert!(is_palindrome(&format!("a{}b{}a", THUMBS_UP, FAMILY)));
+    assert!(is_palindrome(CANADA));
+}
+
+#[test]
+fn anagrams_and_lowercasing() {
+    assert!(are_anagrams("Dormitory", "dirty room!"));
+    assert!(are_anagrams(
+        &format!("th{}", PRECOMPOSED),
+        &format!("{}ht", PRECOMPOSED)
+    ));
+    // é and e + accent aren't the same letters, unless the accent is dropped
+    assert!(!are_anagrams(PRECOMPOSED, DECOMPOSED));
+    assert!(are_anagrams(DECOMPOSED, "e"));
+    // 'İ' lowercases to two chars, 'i' and a combining dot, the dot is kept
+    assert!(!are_anagrams("\u{130}", "i"));
+    assert!(are_anagrams("\u{130}x", "x\u{130}"));
+    assert!(are_anagrams(&format!("{}ab", THUMBS_UP), "b a"));
+}
@@ -0,0 +1,76 @@
+/*
+ * extras::thread_pool with both strategies: every job runs, and dropping
+ * the pool waits for the ones still queued or running instead of
+ * throwing them away
+ * */
+use rust_exercises::extras::thread_pool::{Strategy, ThreadPool};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const STRATEGIES: [Strategy; 2] = [Strategy::SharedQueue, Strategy::WorkStealing];
+
+#[test]
+fn every_job_runs() {
+    for &strategy in STRATEGIES.iter() {
+        let pool = ThreadPool::with_strategy(4, strategy);
+        assert_eq!(pool.size(), 4);
+        let (sender, receiver) = mpsc::channel();
+        for i in 0..100 {
+            let sender = sender.clone();
+            pool.execute(move || sender.send(i).unwrap());
+        }
+        drop(sender);
+        let mut done: Vec<i32> = receiver.iter().collect();
+        done.sort_unstable();
+        assert_eq!(done, (0..100).collect::<Vec<_>>(), "{:?}", strategy);
+    }
+}
+
+#[test]
+fn drop_drains_the_queue() {
+    for &strategy in STRATEGIES.iter() {
+        let pool = ThreadPool::with_strategy(2, strategy);
+        let finished = Arc::new(AtomicUsize::new(0));
+
+        // Both workers wait here, so every other job is still queued at the drop
+        let (open, gate) = mpsc::channel::<()>();
+        let gate = Arc::new(Mutex::new(gate));
+        for _ in 0..2 {
+            let (gate, finished) = (Arc::clone(&gate), Arc::clone(&finished));
+            pool.execute(move || {
+                let _ = gate.lock().unwrap().recv();
+                finished.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        for _ in 0..50 {
+            let finished = Arc::clone(&finished);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(1));
+                finished.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        let opener = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            drop(open);
+        });
+
+        drop(pool);
+        assert_eq!(finished.load(Ordering::SeqCst), 52, "{:?}", strategy);
+        opener.join().unwrap();
+    }
+}
+
+#[test]
+fn empty_pool_drops_right_away() {
+    for &strategy in STRATEGIES.iter() {
+        drop(ThreadPool::with_strategy(3, strategy));
+    }
+}
+
+#[test]
+#[should_panic]
+fn zero_threads_is_refused() {
+    ThreadPool::new(0);
+}
@@ -0,0 +1,45 @@
+/*
+ * tokio_basics on tokio's own test runtime. The timings race a few
+ * milliseconds against a second or more, so which side wins doesn't
+ * depend on how loaded the machine is.
+ *
+ *   cargo test --features async-rt --test tokio_basics
+ * */
+#![cfg(all(feature = "async", feature = "async-rt"))]
+
+use rust_exercises::chapters::tokio_basics::{channel_sum, race, spawn_squares, with_timeout};
+
+#[tokio::test]
+async fn squares_come_back_in_spawn_order() {
+    // 3, 6 and 9 sleep the least and finish first
+    let numbers: Vec<u64> = (1..=9).collect();
+    let squares: Vec<u64> = numbers.iter().map(|n| n * n).collect();
+    assert_eq!(spawn_squares(numbers).await, squares);
+    assert_eq!(spawn_squares(Vec::new()).await, Vec::<u64>::new());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn squares_on_several_workers() {
+    let numbers: Vec<u64> = (0..100).collect();
+    let squares = spawn_squares(numbers.clone()).await;
+    assert!(squares.iter().zip(&numbers).all(|(&s, &n)| s == n * n));
+}
+
+#[tokio::test]
+async fn the_shorter_sleep_wins_the_race() {
+    assert_eq!(race(1, 2_000).await, "first");
+    assert_eq!(race(2_000, 1).await, "second");
+}
+
+#[tokio::test]
+async fn timeouts() {
+    assert_eq!(with_timeout(1, 2_000).await, Ok(1));
+    assert!(with_timeout(2_000, 1).await.is_err());
+}
+
+#[tokio::test]
+async fn channel_sums_past_its_buffer() {
+    // The buffer holds 4, so the producer waits on the receiver many times
+    assert_eq!(channel_sum(100).await, 5050);
+    assert_eq!(channel_sum(0).await, 0);
+}
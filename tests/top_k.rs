@@ -0,0 +1,61 @@
+/*
+ * top_k_streaming only ever sees one item at a time, it has to pick the
+ * same items as sorting the whole slice, ties included. TEST_SEED=<n>
+ * repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::rng::Rng;
+use rust_exercises::extras::top_k::{top_k, top_k_by_key, top_k_streaming, top_k_streaming_by_key};
+
+#[test]
+fn small_cases() {
+    assert_eq!(top_k_streaming(vec![5, 1, 9, 3, 7], 3), [9, 7, 5]);
+    assert_eq!(top_k_streaming(vec![5, 1], 10), [5, 1]);
+    assert!(top_k_streaming(vec![5, 1], 0).is_empty());
+    assert!(top_k_streaming(Vec::<i32>::new(), 3).is_empty());
+
+    // Lazy and far too long to collect
+    let numbers = (0u64..).map(|n| n * 7 % 1_000).take(5_000_000);
+    assert_eq!(top_k_streaming(numbers, 3), [999, 999, 999]);
+}
+
+#[test]
+fn ties_keep_the_first_ones() {
+    let lines = ["bb", "a", "dd", "cc", "eee"];
+    assert_eq!(
+        top_k_streaming_by_key(lines, 3, |line| line.len()),
+        ["eee", "bb", "dd"]
+    );
+    assert_eq!(
+        top_k_by_key(&lines, 3, |line| line.len()),
+        ["eee", "bb", "dd"]
+    );
+}
+
+#[test]
+fn streaming_agrees_with_sorting() {
+    let mut rng = rng();
+    for _ in 0..1000 {
+        let len = rng.gen_range(0..40) as usize;
+        let k = rng.gen_range(0..45) as usize;
+        let items: Vec<(u64, usize)> = (0..len).map(|i| (rng.gen_range(0..10), i)).collect();
+
+        assert_eq!(
+            top_k_streaming(items.iter().copied(), k),
+            top_k(&items, k),
+            "k {} of {:?}",
+            k,
+            items
+        );
+        // By a key with many ties, the index tells which of them came back
+        assert_eq!(
+            top_k_streaming_by_key(items.iter().copied(), k, |item| item.0),
+            top_k_by_key(&items, k, |item| item.0),
+            "k {} of {:?}",
+            k,
+            items
+        );
+    }
+}
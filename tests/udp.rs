@@ -0,0 +1,104 @@
+/*
+ * extras::net::udp between sockets on the loopback: the server's
+ * answers, the counters it keeps, and the client retrying datagrams the
+ * server chose to lose
+ * */
+use rust_exercises::extras::net::udp::{Client, ClientError, Server, Stats};
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+type Running = (SocketAddr, Arc<AtomicBool>, JoinHandle<Stats>);
+
+fn start(server: Server) -> Running {
+    let addr = server.local_addr().unwrap();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let flag = shutdown.clone();
+    let handle = thread::spawn(move || server.serve(flag).unwrap());
+    (addr, shutdown, handle)
+}
+
+fn stop((_, shutdown, handle): Running) -> Stats {
+    shutdown.store(true, Ordering::SeqCst);
+    handle.join().unwrap()
+}
+
+#[test]
+fn plain_datagrams_between_two_sockets() {
+    let a = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let b = UdpSocket::bind("127.0.0.1:0").unwrap();
+    b.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+    a.send_to(b"hello", b.local_addr().unwrap()).unwrap();
+
+    let mut buf = [0; 16];
+    let (len, from) = b.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"hello");
+    assert_eq!(from, a.local_addr().unwrap());
+}
+
+#[test]
+fn answers_and_counts() {
+    let running = start(Server::bind("127.0.0.1:0").unwrap());
+    let client = Client::new(running.0).unwrap();
+
+    let time = client.request("TIME").unwrap();
+    let (secs, millis) = time["TIME ".len()..].split_once('.').unwrap();
+    // Some time after this was written
+    assert!(secs.parse::<u64>().unwrap() > 1_700_000_000);
+    assert_eq!(millis.len(), 3);
+
+    assert_eq!(client.request("HELLO").unwrap(), "ERROR unknown command");
+    assert_eq!(
+        client.request(" STATS\n").unwrap(),
+        "STATS requests=3 time=1 stats=1 unknown=1"
+    );
+
+    let stats = stop(running);
+    assert_eq!(
+        stats,
+        Stats {
+            requests: 3,
+            time: 1,
+            stats: 1,
+            unknown: 1
+        }
+    );
+}
+
+#[test]
+fn client_retries_lost_datagrams() {
+    // Every second datagram is ignored, so every other request needs a retry
+    let running = start(Server::bind("127.0.0.1:0").unwrap().with_loss(2));
+    let client = Client::new(running.0)
+        .unwrap()
+        .with_retries(Duration::from_millis(100), 3);
+    for _ in 0..4 {
+        assert!(client.request("TIME").unwrap().starts_with("TIME "));
+    }
+    // Only the datagrams that got through were answered and counted
+    assert_eq!(stop(running).requests, 4);
+}
+
+#[test]
+fn no_response_after_the_last_attempt() {
+    // Bound, so the datagrams go somewhere, but nobody answers
+    let silent = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let client = Client::new(silent.local_addr().unwrap())
+        .unwrap()
+        .with_retries(Duration::from_millis(20), 3);
+    match client.request("TIME") {
+        Err(ClientError::NoResponse(3)) => {}
+        other => panic!("{:?}", other),
+    }
+
+    let mut buf = [0; 16];
+    silent
+        .set_read_timeout(Some(Duration::from_secs(1)))
+        .unwrap();
+    for _ in 0..3 {
+        let (len, _) = silent.recv_from(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"TIME");
+    }
+}
@@ -0,0 +1,132 @@
+/*
+ * Everything in the library that uses unsafe, exercised in a way Miri
+ * can check for undefined behaviour:
+ *
+ *   rustup +nightly component add miri
+ *   cargo +nightly miri test --test unsafe_code
+ *   cargo run --bin runner -- verify --unsafe        the same
+ *
+ * Miri interprets the code, so no FFI into C and no files here, and loop
+ * counts shrink under cfg(miri) to keep the run in seconds. Plain
+ * cargo test runs the same tests at full size.
+ * */
+use rust_exercises::extras::atomics::{OnceInit, SpinLock};
+use rust_exercises::ffi_exports::{largest_byte_c, largest_f64_c, largest_i32_c};
+use std::ffi::CString;
+use std::sync::Arc;
+use std::thread;
+
+const THREADS: usize = if cfg!(miri) { 3 } else { 8 };
+const ROUNDS: usize = if cfg!(miri) { 20 } else { 10_000 };
+
+#[test]
+fn spin_lock_counts_every_increment() {
+    let lock = Arc::new(SpinLock::new(0));
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let lock = lock.clone();
+            thread::spawn(move || {
+                for _ in 0..ROUNDS {
+                    *lock.lock() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let lock = Arc::try_unwrap(lock).ok().unwrap();
+    assert_eq!(lock.into_inner(), THREADS * ROUNDS);
+}
+
+#[test]
+fn spin_lock_guard_gives_mutable_access() {
+    let lock = SpinLock::new(vec![1, 2]);
+    lock.lock().push(3);
+    assert_eq!(*lock.lock(), [1, 2, 3]);
+}
+
+// The guard is Sync for a Sync T, readers on other threads share it
+#[test]
+fn spin_lock_guard_shared_by_scoped_threads() {
+    let lock = SpinLock::new(String::from("shared"));
+    let guard = lock.lock();
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| assert_eq!(guard.as_str(), "shared"));
+        }
+    });
+    drop(guard);
+    lock.lock().push('!');
+    assert_eq!(lock.into_inner(), "shared!");
+}
+
+// Miri checks that what the lock holds is dropped once, and not leaked
+#[test]
+fn spin_lock_drops_its_value() {
+    let value = Arc::new(());
+    let lock = SpinLock::new(vec![value.clone(), value.clone()]);
+    lock.lock().pop();
+    assert_eq!(Arc::strong_count(&value), 2);
+    drop(lock);
+    assert_eq!(Arc::strong_count(&value), 1);
+}
+
+#[test]
+fn once_init_runs_init_once() {
+    let once = Arc::new(OnceInit::new());
+    assert_eq!(once.get(), None);
+
+    let handles: Vec<_> = (0..THREADS)
+        .map(|n| {
+            let once = once.clone();
+            thread::spawn(move || once.get_or_init(|| format!("thread {}", n)).clone())
+        })
+        .collect();
+    let seen: Vec<String> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    // Whoever won, everyone got the same value
+    assert!(seen.iter().all(|value| *value == seen[0]));
+    assert_eq!(once.get(), Some(&seen[0]));
+}
+
+#[test]
+fn largest_through_raw_pointers() {
+    let numbers = [3, -7, 42, 0];
+    let mut out = 0;
+    assert!(unsafe { largest_i32_c(numbers.as_ptr(), numbers.len(), &mut out) });
+    assert_eq!(out, 42);
+
+    let floats = [0.5, 2.25, -1.0];
+    let mut out = 0.0;
+    assert!(unsafe { largest_f64_c(floats.as_ptr(), floats.len(), &mut out) });
+    assert_eq!(out, 2.25);
+}
+
+#[test]
+fn largest_rejects_null_and_empty() {
+    let mut out = 7;
+    unsafe {
+        assert!(!largest_i32_c(std::ptr::null(), 3, &mut out));
+        assert!(!largest_i32_c([1].as_ptr(), 0, &mut out));
+        assert!(!largest_i32_c([1].as_ptr(), 1, std::ptr::null_mut()));
+    }
+    // out is left alone on failure
+    assert_eq!(out, 7);
+}
+
+#[test]
+fn largest_byte_of_c_string() {
+    let text = CString::new("rust").unwrap();
+    let empty = CString::new("").unwrap();
+    unsafe {
+        assert_eq!(largest_byte_c(text.as_ptr()), b'u');
+        assert_eq!(largest_byte_c(empty.as_ptr()), 0);
+        assert_eq!(largest_byte_c(std::ptr::null()), 0);
+    }
+}
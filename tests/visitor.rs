@@ -0,0 +1,148 @@
+/*
+ * The visitor passes against their enum matching twins on random trees,
+ * known outputs for both, and a visitor written out here to show a pass
+ * added from outside the module. TEST_SEED=<n> repeats a failing run.
+ * */
+mod common;
+
+use common::rng;
+use rust_exercises::extras::rng::{Rng, XorShift64};
+use rust_exercises::extras::visitor::*;
+
+const OPS: [Op; 4] = [Op::Add, Op::Sub, Op::Mul, Op::Div];
+
+// Small integers and a few zeros, so some divisions can't be folded
+fn random_expr(rng: &mut XorShift64, depth: u32) -> Expr {
+    if depth == 0 || rng.gen_bool(0.2) {
+        return if rng.gen_bool(0.3) {
+            var(["x", "y", "z"][rng.gen_range(0..3) as usize])
+        } else {
+            num(rng.gen_range(0..6) as f64)
+        };
+    }
+    if rng.gen_bool(0.15) {
+        return neg(random_expr(rng, depth - 1));
+    }
+    binary(
+        OPS[rng.gen_range(0..4) as usize],
+        random_expr(rng, depth - 1),
+        random_expr(rng, depth - 1),
+    )
+}
+
+#[test]
+fn both_approaches_agree_on_random_trees() {
+    let mut rng = rng();
+    for _ in 0..1_000 {
+        let expr = random_expr(&mut rng, 6);
+        assert_eq!(pretty_print(&expr), pretty_print_match(&expr));
+        let folded = fold_constants(&expr);
+        assert_eq!(
+            folded,
+            fold_constants_match(&expr),
+            "{}",
+            pretty_print(&expr)
+        );
+        // Folding twice changes nothing more
+        assert_eq!(fold_constants(&folded), folded);
+    }
+}
+
+#[test]
+fn as_few_parentheses_as_needed() {
+    let cases = [
+        (
+            binary(Op::Add, num(1.0), binary(Op::Mul, num(2.0), num(3.0))),
+            "1 + 2 * 3",
+        ),
+        (
+            binary(Op::Mul, binary(Op::Add, num(1.0), num(2.0)), num(3.0)),
+            "(1 + 2) * 3",
+        ),
+        (
+            binary(Op::Sub, binary(Op::Sub, num(1.0), num(2.0)), num(3.0)),
+            "1 - 2 - 3",
+        ),
+        (
+            binary(Op::Sub, num(1.0), binary(Op::Sub, num(2.0), num(3.0))),
+            "1 - (2 - 3)",
+        ),
+        (
+            binary(Op::Div, var("x"), binary(Op::Mul, var("y"), var("z"))),
+            "x / (y * z)",
+        ),
+        (neg(binary(Op::Add, var("x"), num(1.0))), "-(x + 1)"),
+        (binary(Op::Mul, neg(var("x")), num(2.5)), "-x * 2.5"),
+        (neg(neg(var("x"))), "--x"),
+    ];
+    for (expr, expected) in cases.iter() {
+        assert_eq!(pretty_print(expr), *expected);
+        assert_eq!(pretty_print_match(expr), *expected);
+    }
+}
+
+#[test]
+fn folding() {
+    // x * (2 + 3) is x * 5
+    let expr = binary(Op::Mul, var("x"), binary(Op::Add, num(2.0), num(3.0)));
+    assert_eq!(fold_constants(&expr), binary(Op::Mul, var("x"), num(5.0)));
+
+    assert_eq!(
+        fold_constants(&neg(binary(Op::Sub, num(1.0), num(4.0)))),
+        num(3.0)
+    );
+    // A division by zero stays as it was, with its sides folded
+    let by_zero = binary(Op::Div, num(1.0), binary(Op::Sub, num(2.0), num(2.0)));
+    assert_eq!(
+        fold_constants(&by_zero),
+        binary(Op::Div, num(1.0), num(0.0))
+    );
+    assert_eq!(
+        fold_constants_match(&by_zero),
+        binary(Op::Div, num(1.0), num(0.0))
+    );
+    // Nothing to do without constants side by side
+    let expr = binary(Op::Add, var("x"), neg(var("y")));
+    assert_eq!(fold_constants(&expr), expr);
+}
+
+/*
+ * A new pass without touching Expr: the variables used, in order. With
+ * enums this would be one more function matching on every variant.
+ * */
+struct Variables(Vec<String>);
+
+impl Visitor for Variables {
+    type Output = ();
+
+    fn visit_num(&mut self, _: f64) {}
+
+    fn visit_var(&mut self, name: &str) {
+        self.0.push(name.to_string());
+    }
+
+    fn visit_neg(&mut self, inner: &Expr) {
+        inner.accept(self)
+    }
+
+    fn visit_binary(&mut self, _: Op, left: &Expr, right: &Expr) {
+        left.accept(self);
+        right.accept(self);
+    }
+}
+
+#[test]
+fn a_visitor_from_outside() {
+    let expr = binary(Op::Add, var("x"), binary(Op::Mul, neg(var("y")), var("x")));
+    let mut variables = Variables(Vec::new());
+    expr.accept(&mut variables);
+    assert_eq!(variables.0, ["x", "y", "x"]);
+}
+
+#[test]
+fn visitors_as_trait_objects() {
+    let expr = binary(Op::Sub, num(1.0), var("x"));
+    let mut printer = PrettyPrinter;
+    let visitor: &mut dyn Visitor<Output = (String, u8)> = &mut printer;
+    assert_eq!(expr.accept(visitor), (String::from("1 - x"), 1));
+}
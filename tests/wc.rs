@@ -0,0 +1,40 @@
+/*
+ * extras::wc::count on the fixtures, where chars and bytes only agree
+ * for the ASCII one
+ * */
+mod common;
+
+use common::fixture;
+use rust_exercises::extras::wc::{count, Counts};
+
+#[test]
+fn ascii_fixture() {
+    let counts = count(&fixture("wc_input.txt")[..]).unwrap();
+    let expected = Counts {
+        lines: 2,
+        words: 3,
+        chars: 16,
+        bytes: 16,
+    };
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn multibyte_fixture() {
+    // Accents take 2 bytes, the Japanese 3 and the crabs 4, and the last
+    // line has no '\n'
+    let counts = count(&fixture("wc_multibyte.txt")[..]).unwrap();
+    let expected = Counts {
+        lines: 4,
+        words: 16,
+        chars: 78,
+        bytes: 108,
+    };
+    assert_eq!(counts, expected);
+}
+
+#[test]
+fn invalid_utf8_still_counts_bytes() {
+    let counts = count(&b"ok \xff\xfe\n"[..]).unwrap();
+    assert_eq!((counts.lines, counts.words, counts.bytes), (1, 2, 6));
+}